@@ -0,0 +1,190 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    enums::AccountType,
+    events::account::state::AccountState,
+    identifiers::account_id::AccountId,
+    types::{currency::Currency, money::Money},
+};
+
+use crate::account::{base::BaseAccount, cash::CashAccount, margin::MarginAccount, Account};
+
+/// Wraps any concrete account type so that accounts can be held in homogeneous collections.
+///
+/// Dereferences to the shared [`BaseAccount`].
+#[derive(Debug)]
+pub enum AccountAny {
+    Cash(CashAccount),
+    Margin(MarginAccount),
+}
+
+impl AccountAny {
+    /// Rebuilds an account from its `AccountState` history, which must not be empty.
+    ///
+    /// The account state is calculated locally unless the first event was reported by the venue.
+    pub fn from_events(events: Vec<AccountState>) -> Result<Self> {
+        let mut iter = events.into_iter();
+        let Some(first) = iter.next() else {
+            bail!("No account state events")
+        };
+
+        let calculate_account_state = !first.is_reported;
+        let mut account = match first.account_type {
+            AccountType::Cash => Self::Cash(CashAccount::new(first, calculate_account_state)?),
+            AccountType::Margin => Self::Margin(MarginAccount::new(first, calculate_account_state)?),
+            account_type => bail!("Unsupported account type {account_type}"),
+        };
+
+        for event in iter {
+            account.apply(event);
+        }
+
+        Ok(account)
+    }
+
+    #[must_use]
+    pub fn id(&self) -> AccountId {
+        self.base().id
+    }
+
+    #[must_use]
+    pub fn is_cash_account(&self) -> bool {
+        matches!(self, Self::Cash(_))
+    }
+
+    #[must_use]
+    pub fn is_margin_account(&self) -> bool {
+        matches!(self, Self::Margin(_))
+    }
+
+    #[must_use]
+    pub fn balance_free(&self, currency: Option<Currency>) -> Option<Money> {
+        match self {
+            Self::Cash(account) => account.balance_free(currency),
+            Self::Margin(account) => account.balance_free(currency),
+        }
+    }
+
+    #[must_use]
+    pub fn last_event(&self) -> Option<AccountState> {
+        match self {
+            Self::Cash(account) => account.last_event(),
+            Self::Margin(account) => account.last_event(),
+        }
+    }
+
+    #[must_use]
+    pub fn events(&self) -> Vec<AccountState> {
+        match self {
+            Self::Cash(account) => account.events(),
+            Self::Margin(account) => account.events(),
+        }
+    }
+
+    pub fn apply(&mut self, event: AccountState) {
+        match self {
+            Self::Cash(account) => account.apply(event),
+            Self::Margin(account) => account.apply(event),
+        }
+    }
+
+    fn base(&self) -> &BaseAccount {
+        match self {
+            Self::Cash(account) => &account.base,
+            Self::Margin(account) => &account.base,
+        }
+    }
+}
+
+impl Deref for AccountAny {
+    type Target = BaseAccount;
+
+    fn deref(&self) -> &Self::Target {
+        self.base()
+    }
+}
+
+impl DerefMut for AccountAny {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Cash(account) => &mut account.base,
+            Self::Margin(account) => &mut account.base,
+        }
+    }
+}
+
+impl From<CashAccount> for AccountAny {
+    fn from(account: CashAccount) -> Self {
+        Self::Cash(account)
+    }
+}
+
+impl From<MarginAccount> for AccountAny {
+    fn from(account: MarginAccount) -> Self {
+        Self::Margin(account)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::AccountType;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::account::stubs::*;
+
+    #[rstest]
+    fn test_cash_account_any(cash_account_million_usd: CashAccount) {
+        let account = AccountAny::from(cash_account_million_usd);
+
+        assert!(account.is_cash_account());
+        assert!(!account.is_margin_account());
+        assert_eq!(account.id(), AccountId::from("SIM-001"));
+        assert_eq!(account.account_type, AccountType::Cash);
+        assert_eq!(
+            account.balance_free(Some(Currency::USD())),
+            Some(Money::from("1000000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_margin_account_any(margin_account: MarginAccount) {
+        let account = AccountAny::from(margin_account);
+
+        assert!(account.is_margin_account());
+        assert_eq!(account.account_type, AccountType::Margin);
+    }
+
+    #[rstest]
+    fn test_from_events(margin_account: MarginAccount) {
+        let account = AccountAny::from_events(margin_account.events()).unwrap();
+
+        assert!(account.is_margin_account());
+        assert_eq!(account.id(), margin_account.id);
+        assert_eq!(account.events().len(), 1);
+    }
+
+    #[rstest]
+    fn test_from_events_when_empty() {
+        assert!(AccountAny::from_events(vec![]).is_err());
+    }
+}
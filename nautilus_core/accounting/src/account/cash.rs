@@ -426,7 +426,7 @@ mod tests {
             None,
             None,
         );
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         let pnls = cash_account_million_usd
             .calculate_pnls(audusd_sim, fill, Some(position))
             .unwrap();
@@ -461,7 +461,7 @@ mod tests {
             None,
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill1).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill1).unwrap();
         let result1 = cash_account_multi
             .calculate_pnls(currency_pair_btcusdt, fill1, Some(position.clone()))
             .unwrap();
@@ -67,6 +67,7 @@ pub trait Account {
     ) -> Result<Money>;
 }
 
+pub mod any;
 pub mod base;
 pub mod cash;
 pub mod margin;
@@ -48,7 +48,7 @@ pub fn test_position_long(mut order_factory: OrderFactory, audusd_sim: CurrencyP
         None,
         None,
     );
-    Position::new(audusd_sim, order_filled).unwrap()
+    Position::new(&audusd_sim, order_filled).unwrap()
 }
 
 #[fixture]
@@ -75,5 +75,5 @@ pub fn test_position_short(mut order_factory: OrderFactory, audusd_sim: Currency
         None,
         None,
     );
-    Position::new(audusd_sim, order_filled).unwrap()
+    Position::new(&audusd_sim, order_filled).unwrap()
 }
@@ -13,12 +13,42 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    time::{Duration, Instant},
+};
 
-use nautilus_common::{clock::TestClock, ffi::clock::TestClock_API, timer::TimeEventHandler};
+use indexmap::IndexMap;
+use nautilus_common::{
+    clock::{Clock, TestClock},
+    ffi::clock::TestClock_API,
+    handlers::EventHandler,
+    timer::{TimeEvent, TimeEventHandler},
+};
 use nautilus_core::{
     ffi::{cvec::CVec, parsing::u8_as_bool},
-    time::UnixNanos,
+    time::{AtomicTime, UnixNanos},
+    uuid::UUID4,
+};
+use nautilus_execution::messages::TradingCommand;
+use nautilus_model::{
+    data::{Data, HasTsInit},
+    enums::{BookType, OmsType},
+    events::order::{
+        cancel_rejected::OrderCancelRejected, denied::OrderDenied, event::OrderEvent,
+        modify_rejected::OrderModifyRejected,
+    },
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId, venue::Venue},
+    instruments::Instrument,
+};
+use nautilus_persistence::backend::session::DataBackendSession;
+use ustr::Ustr;
+
+use crate::{
+    exchange::SimulatedExchange,
+    matching_engine::OrderMatchingEngineConfig,
+    models::{fill::FillModel, latency::LatencyModel},
 };
 
 /// Provides a means of accumulating and draining time event handlers.
@@ -58,6 +88,373 @@ impl Default for TimeEventAccumulator {
     }
 }
 
+/// Handles the data, time events and order events dispatched by a [`BacktestEngine`].
+///
+/// Handlers can send order commands to the simulated venues by pushing them onto
+/// the given `commands`, which the engine routes once the handler returns.
+pub trait BacktestHandler {
+    fn on_data(&mut self, _data: &Data, _commands: &mut Vec<TradingCommand>) {}
+    fn on_time_event(&mut self, _event: &TimeEvent, _commands: &mut Vec<TradingCommand>) {}
+    fn on_order_event(&mut self, _event: &OrderEvent, _commands: &mut Vec<TradingCommand>) {}
+}
+
+/// Represents a summary of a backtest run.
+#[derive(Clone, Debug, Default)]
+pub struct BacktestResult {
+    /// The count of data items processed.
+    pub iterations: u64,
+    /// The count of time events dispatched.
+    pub total_time_events: u64,
+    /// The count of order commands sent to the simulated venues.
+    pub total_commands: u64,
+    /// The count of order events dispatched.
+    pub total_order_events: u64,
+    /// The UNIX timestamp (nanoseconds) of the first data processed.
+    pub backtest_start: Option<UnixNanos>,
+    /// The UNIX timestamp (nanoseconds) the backtest was run until.
+    pub backtest_end: Option<UnixNanos>,
+    /// The wall clock time taken for the run.
+    pub elapsed: Duration,
+}
+
+impl Display for BacktestResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BacktestResult(iterations={}, total_time_events={}, total_commands={}, total_order_events={}, backtest_start={:?}, backtest_end={:?}, elapsed={:?})",
+            self.iterations,
+            self.total_time_events,
+            self.total_commands,
+            self.total_order_events,
+            self.backtest_start,
+            self.backtest_end,
+            self.elapsed,
+        )
+    }
+}
+
+/// Provides a backtest engine which drives simulated venues and handlers from a
+/// stream of data ordered by `ts_init`.
+///
+/// For each data item, the engine first dispatches the time events due strictly
+/// before the data, then processes the data through the venue for its instrument
+/// and dispatches it to the handlers, and finally dispatches the time events due at
+/// the same time as the data. Time events are generated by timers set on the
+/// engine's [`TestClock`], which are dispatched to the handlers by name (any Python
+/// callbacks on the timers are not called).
+pub struct BacktestEngine {
+    time: &'static AtomicTime,
+    clock: TestClock,
+    accumulator: TimeEventAccumulator,
+    exchanges: IndexMap<Venue, SimulatedExchange>,
+    handlers: Vec<Box<dyn BacktestHandler>>,
+    commands: Vec<TradingCommand>,
+    result: BacktestResult,
+}
+
+impl BacktestEngine {
+    /// Initializes a new [`BacktestEngine`] instance.
+    ///
+    /// The `time` is shared with the simulated venues, and is set by the engine as it runs.
+    #[must_use]
+    pub fn new(time: &'static AtomicTime) -> Self {
+        Self {
+            time,
+            clock: Self::handler_clock(),
+            accumulator: TimeEventAccumulator::new(),
+            exchanges: IndexMap::new(),
+            handlers: Vec::new(),
+            commands: Vec::new(),
+            result: BacktestResult::default(),
+        }
+    }
+
+    fn handler_clock() -> TestClock {
+        // Time events are dispatched to the handlers by the engine, so the timers
+        // need no callback.
+        let mut clock = TestClock::new();
+        clock.register_default_handler(EventHandler::new(None, None));
+        clock
+    }
+
+    /// Returns the engine's clock, on which timers can be set for the handlers.
+    pub fn clock_mut(&mut self) -> &mut TestClock {
+        &mut self.clock
+    }
+
+    #[must_use]
+    pub fn get_exchange(&self, venue: &Venue) -> Option<&SimulatedExchange> {
+        self.exchanges.get(venue)
+    }
+
+    /// Adds a simulated venue to the engine.
+    pub fn add_venue(
+        &mut self,
+        venue: Venue,
+        oms_type: OmsType,
+        book_type: BookType,
+        account_id: AccountId,
+        latency_model: Option<Box<dyn LatencyModel>>,
+        config: OrderMatchingEngineConfig,
+    ) -> anyhow::Result<()> {
+        if self.exchanges.contains_key(&venue) {
+            anyhow::bail!("Venue {venue} already added");
+        }
+
+        let exchange = SimulatedExchange::new(
+            venue,
+            oms_type,
+            book_type,
+            account_id,
+            self.time,
+            latency_model,
+            config,
+        );
+        self.exchanges.insert(venue, exchange);
+        Ok(())
+    }
+
+    /// Adds the given `instrument` to the simulated venue it trades on.
+    pub fn add_instrument(
+        &mut self,
+        instrument: Box<dyn Instrument>,
+        fill_model: Box<dyn FillModel>,
+    ) -> anyhow::Result<()> {
+        let venue = instrument.venue();
+        match self.exchanges.get_mut(&venue) {
+            Some(exchange) => exchange.add_instrument(instrument, fill_model),
+            None => anyhow::bail!("Venue {venue} not added"),
+        }
+    }
+
+    pub fn add_handler(&mut self, handler: Box<dyn BacktestHandler>) {
+        self.handlers.push(handler);
+    }
+
+    /// Runs the backtest over the data queried by the given `session`, until the
+    /// data is exhausted or the optional `end` is reached.
+    pub fn run(
+        &mut self,
+        session: &mut DataBackendSession,
+        end: Option<UnixNanos>,
+    ) -> BacktestResult {
+        let query_result = session.get_query_result();
+        self.run_data(query_result, end)
+    }
+
+    /// Runs the backtest over the given `data`, which must be ordered by `ts_init`,
+    /// until the data is exhausted or the optional `end` is reached.
+    pub fn run_data<I>(&mut self, data: I, end: Option<UnixNanos>) -> BacktestResult
+    where
+        I: IntoIterator<Item = Data>,
+    {
+        let started = Instant::now();
+        self.result = BacktestResult::default();
+
+        for data in data {
+            let ts_init = data.get_ts_init();
+            if end.is_some_and(|end| ts_init > end) {
+                break;
+            }
+            if self.result.backtest_start.is_none() {
+                self.result.backtest_start = Some(ts_init);
+            }
+
+            let time_events = self.advance_time(ts_init);
+            self.process_data(&data);
+            for event in time_events {
+                self.process_time_event(&event);
+            }
+
+            self.result.iterations += 1;
+            self.result.backtest_end = Some(ts_init);
+        }
+
+        if let Some(end) = end {
+            for event in self.advance_time(end) {
+                self.process_time_event(&event);
+            }
+            self.process_venues(end);
+            self.result.backtest_end = Some(end);
+        }
+
+        self.result.elapsed = started.elapsed();
+        self.result.clone()
+    }
+
+    pub fn reset(&mut self) {
+        for exchange in self.exchanges.values_mut() {
+            exchange.reset();
+        }
+        self.clock = Self::handler_clock();
+        self.accumulator = TimeEventAccumulator::new();
+        self.commands.clear();
+        self.result = BacktestResult::default();
+    }
+
+    /// Advances the clock to `ts_now`, processing the time events due before it, and
+    /// returning the time events due at `ts_now`.
+    fn advance_time(&mut self, ts_now: UnixNanos) -> Vec<TimeEvent> {
+        self.accumulator
+            .advance_clock(&mut self.clock, ts_now, false);
+
+        let mut time_events = Vec::new();
+        for handler in self.accumulator.drain() {
+            if handler.event.ts_event < ts_now {
+                self.process_time_event(&handler.event);
+            } else {
+                time_events.push(handler.event);
+            }
+        }
+
+        self.set_time(ts_now);
+        time_events
+    }
+
+    fn set_time(&mut self, ts_now: UnixNanos) {
+        self.clock.set_time(ts_now);
+        self.time.set_time(ts_now);
+    }
+
+    fn process_time_event(&mut self, event: &TimeEvent) {
+        self.set_time(event.ts_event);
+        self.result.total_time_events += 1;
+
+        for handler in &mut self.handlers {
+            handler.on_time_event(event, &mut self.commands);
+        }
+        self.process_venues(event.ts_event);
+    }
+
+    fn process_data(&mut self, data: &Data) {
+        // Data for instruments without a matching engine is only for the handlers
+        let exchange = data_instrument_id(data).and_then(|instrument_id| {
+            self.exchanges
+                .get_mut(&instrument_id.venue)
+                .filter(|exchange| exchange.get_matching_engine(&instrument_id).is_some())
+        });
+        if let Some(exchange) = exchange {
+            match data {
+                Data::Delta(delta) => exchange.process_order_book_delta(*delta),
+                Data::Deltas(deltas) => exchange.process_order_book_deltas((**deltas).clone()),
+                Data::Depth10(depth) => exchange.process_order_book_depth10(*depth),
+                Data::Quote(quote) => exchange.process_quote_tick(quote),
+                Data::Trade(trade) => exchange.process_trade_tick(trade),
+                Data::Bar(bar) => exchange.process_bar(bar),
+                Data::Custom(_) => {} // Custom data is only for the handlers
+            }
+        }
+
+        for handler in &mut self.handlers {
+            handler.on_data(data, &mut self.commands);
+        }
+        self.process_venues(data.get_ts_init());
+    }
+
+    /// Sends the pending commands to the venues and dispatches the resulting order
+    /// events, until no further commands are sent by the handlers.
+    ///
+    /// Commands for a venue which was not added are denied.
+    fn process_venues(&mut self, ts_now: UnixNanos) {
+        loop {
+            let mut events = Vec::new();
+            for command in std::mem::take(&mut self.commands) {
+                self.result.total_commands += 1;
+                match self.exchanges.get_mut(&command.instrument_id().venue) {
+                    Some(exchange) => exchange.send(command),
+                    None => events.extend(deny_command(command, ts_now)),
+                }
+            }
+
+            for exchange in self.exchanges.values_mut() {
+                exchange.process(ts_now);
+                events.extend(exchange.drain_events());
+            }
+            if events.is_empty() {
+                break;
+            }
+
+            self.result.total_order_events += events.len() as u64;
+            for event in &events {
+                for handler in &mut self.handlers {
+                    handler.on_order_event(event, &mut self.commands);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the event denying the `command` for a venue which was not added, if the
+/// command is for a single order.
+fn deny_command(command: TradingCommand, ts_now: UnixNanos) -> Option<OrderEvent> {
+    let venue = command.instrument_id().venue;
+    let reason = Ustr::from(format!("No venue {venue}").as_str());
+    let event = match command {
+        TradingCommand::SubmitOrder(command) => OrderEvent::OrderDenied(
+            OrderDenied::new(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                reason,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+            )
+            .unwrap(),
+        ),
+        TradingCommand::ModifyOrder(command) => OrderEvent::OrderModifyRejected(
+            OrderModifyRejected::new(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                reason,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                command.venue_order_id,
+                None,
+            )
+            .unwrap(),
+        ),
+        TradingCommand::CancelOrder(command) => OrderEvent::OrderCancelRejected(
+            OrderCancelRejected::new(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                reason,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                command.venue_order_id,
+                None,
+            )
+            .unwrap(),
+        ),
+        TradingCommand::CancelAllOrders(command) => {
+            log::error!("No venue {venue} for command {command:?}");
+            return None;
+        }
+    };
+    Some(event)
+}
+
+fn data_instrument_id(data: &Data) -> Option<InstrumentId> {
+    match data {
+        Data::Delta(delta) => Some(delta.instrument_id),
+        Data::Deltas(deltas) => Some(deltas.instrument_id),
+        Data::Depth10(depth) => Some(depth.instrument_id),
+        Data::Quote(quote) => Some(quote.instrument_id),
+        Data::Trade(trade) => Some(trade.instrument_id),
+        Data::Bar(bar) => Some(bar.bar_type.instrument_id),
+        Data::Custom(custom) => custom.instrument_id(),
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
@@ -109,13 +506,284 @@ pub extern "C" fn time_event_accumulator_drain(accumulator: &mut TimeEventAccumu
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_common::timer::TimeEvent;
-    use nautilus_core::uuid::UUID4;
+    use std::sync::{Arc, Mutex};
+
+    use nautilus_execution::messages::submit::SubmitOrder;
+    use nautilus_model::{
+        data::{
+            custom::{stubs::FundingRate, CustomData},
+            quote::QuoteTick,
+        },
+        enums::{OrderSide, OrderType},
+        events::order::initialized::OrderInitializedBuilder,
+        identifiers::client_id::ClientId,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::any::OrderAny,
+        types::{price::Price, quantity::Quantity},
+    };
     use pyo3::{types::PyList, Py, Python};
     use rstest::*;
-    use ustr::Ustr;
+    use tempfile::tempdir;
 
     use super::*;
+    use crate::models::fill::FillAtTouchModel;
+
+    /// Records everything dispatched to it, and submits a market order on the first quote.
+    struct RecordingHandler {
+        log: Arc<Mutex<Vec<String>>>,
+        submitted: bool,
+    }
+
+    impl BacktestHandler for RecordingHandler {
+        fn on_data(&mut self, data: &Data, commands: &mut Vec<TradingCommand>) {
+            let ts_init = data.get_ts_init();
+            self.log.lock().unwrap().push(format!("data@{ts_init}"));
+
+            if let Data::Quote(quote) = data {
+                if !self.submitted {
+                    self.submitted = true;
+                    commands.push(submit_market(quote.instrument_id, ts_init));
+                }
+            }
+        }
+
+        fn on_time_event(&mut self, event: &TimeEvent, _commands: &mut Vec<TradingCommand>) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{}@{}", event.name, event.ts_event));
+        }
+
+        fn on_order_event(&mut self, event: &OrderEvent, _commands: &mut Vec<TradingCommand>) {
+            let name = match event {
+                OrderEvent::OrderAccepted(_) => "accepted",
+                OrderEvent::OrderFilled(_) => "filled",
+                OrderEvent::OrderDenied(_) => "denied",
+                OrderEvent::OrderRejected(_) => "rejected",
+                _ => "other",
+            };
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{name}@{}", event.ts_event()));
+        }
+    }
+
+    fn submit_market(instrument_id: InstrumentId, ts_init: UnixNanos) -> TradingCommand {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_type(OrderType::Market)
+            .order_side(OrderSide::Buy)
+            .quantity(Quantity::from("100000"))
+            .build()
+            .unwrap();
+        let order = OrderAny::from(init);
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            instrument_id,
+            order,
+            None,
+            UUID4::new(),
+            ts_init,
+        )
+        .unwrap();
+        TradingCommand::SubmitOrder(command)
+    }
+
+    fn quote(instrument_id: InstrumentId, ts: UnixNanos) -> Data {
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("1.00000"),
+            Price::from("1.00010"),
+            Quantity::from("1000000"),
+            Quantity::from("1000000"),
+            ts,
+            ts,
+        )
+        .unwrap();
+        Data::Quote(quote)
+    }
+
+    fn backtest_engine(instrument: CurrencyPair) -> (BacktestEngine, Arc<Mutex<Vec<String>>>) {
+        let time = Box::leak(Box::new(AtomicTime::new(false, 0)));
+        let mut engine = BacktestEngine::new(time);
+        engine
+            .add_venue(
+                Venue::from("SIM"),
+                OmsType::Netting,
+                BookType::L1_MBP,
+                AccountId::from("SIM-001"),
+                None,
+                OrderMatchingEngineConfig::default(),
+            )
+            .unwrap();
+        engine
+            .add_instrument(Box::new(instrument), Box::new(FillAtTouchModel))
+            .unwrap();
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        engine.add_handler(Box::new(RecordingHandler {
+            log: log.clone(),
+            submitted: false,
+        }));
+        (engine, log)
+    }
+
+    #[rstest]
+    fn test_run_dispatches_data_and_events_in_time_order(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (mut engine, log) = backtest_engine(audusd_sim);
+        engine.clock_mut().set_time_alert_ns("T1", 150, None);
+        engine.clock_mut().set_time_alert_ns("T2", 200, None);
+        let data = vec![
+            quote(instrument_id, 100),
+            quote(instrument_id, 200),
+            quote(instrument_id, 300),
+        ];
+
+        let result = engine.run_data(data, None);
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "data@100",
+                "accepted@100",
+                "filled@100",
+                "T1@150",
+                "data@200",
+                "T2@200",
+                "data@300",
+            ]
+        );
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.total_time_events, 2);
+        assert_eq!(result.total_commands, 1);
+        assert_eq!(result.total_order_events, 2);
+        assert_eq!(result.backtest_start, Some(100));
+        assert_eq!(result.backtest_end, Some(300));
+    }
+
+    #[rstest]
+    fn test_run_from_catalog_session(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (mut engine, log) = backtest_engine(audusd_sim);
+        let quotes: Vec<QuoteTick> = [100, 200, 300]
+            .into_iter()
+            .map(|ts| match quote(instrument_id, ts) {
+                Data::Quote(quote) => quote,
+                _ => unreachable!(),
+            })
+            .collect();
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("quotes.parquet");
+        let metadata = QuoteTick::get_metadata(&instrument_id, 5, 0);
+        DataBackendSession::write_parquet(&quotes, &metadata, &file_path).unwrap();
+
+        let mut session = DataBackendSession::new(2);
+        session
+            .add_file::<QuoteTick>("quotes", file_path.to_str().unwrap(), None)
+            .unwrap();
+        let result = engine.run(&mut session, None);
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "data@100",
+                "accepted@100",
+                "filled@100",
+                "data@200",
+                "data@300",
+            ]
+        );
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.total_order_events, 2);
+        assert_eq!(result.backtest_start, Some(100));
+        assert_eq!(result.backtest_end, Some(300));
+    }
+
+    #[rstest]
+    fn test_run_until_end(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (mut engine, log) = backtest_engine(audusd_sim);
+        engine.clock_mut().set_time_alert_ns("T1", 250, None);
+        let data = vec![
+            quote(instrument_id, 100),
+            quote(instrument_id, 200),
+            quote(instrument_id, 300),
+        ];
+
+        let result = engine.run_data(data, Some(250));
+
+        assert_eq!(log.lock().unwrap().last().unwrap(), "T1@250");
+        assert_eq!(result.iterations, 2);
+        assert_eq!(result.backtest_end, Some(250));
+    }
+
+    #[rstest]
+    fn test_run_dispatches_custom_data(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (mut engine, log) = backtest_engine(audusd_sim);
+        let funding = CustomData::new(FundingRate {
+            instrument_id,
+            rate: 0.0001,
+            ts_event: 150,
+            ts_init: 150,
+        });
+        let data = vec![
+            quote(instrument_id, 100),
+            Data::Custom(funding),
+            quote(instrument_id, 200),
+        ];
+
+        let result = engine.run_data(data, None);
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "data@100",
+                "accepted@100",
+                "filled@100",
+                "data@150",
+                "data@200"
+            ]
+        );
+        assert_eq!(result.iterations, 3);
+    }
+
+    #[rstest]
+    fn test_run_denies_command_for_unknown_venue(audusd_sim: CurrencyPair) {
+        let (mut engine, log) = backtest_engine(audusd_sim);
+        let data = vec![quote(InstrumentId::from("AUD/USD.OTHER"), 100)];
+
+        let result = engine.run_data(data, None);
+
+        assert_eq!(*log.lock().unwrap(), vec!["data@100", "denied@100"]);
+        assert_eq!(result.total_commands, 1);
+        assert_eq!(result.total_order_events, 1);
+    }
+
+    #[rstest]
+    fn test_run_with_data_for_instrument_not_added(audusd_sim: CurrencyPair) {
+        let (mut engine, log) = backtest_engine(audusd_sim);
+        let data = vec![quote(InstrumentId::from("EUR/USD.SIM"), 100)];
+
+        let result = engine.run_data(data, None);
+
+        assert_eq!(*log.lock().unwrap(), vec!["data@100", "rejected@100"]);
+        assert_eq!(result.iterations, 1);
+    }
+
+    #[rstest]
+    fn test_add_instrument_for_unknown_venue(audusd_sim: CurrencyPair) {
+        let time = Box::leak(Box::new(AtomicTime::new(false, 0)));
+        let mut engine = BacktestEngine::new(time);
+
+        let result = engine.add_instrument(Box::new(audusd_sim), Box::new(FillAtTouchModel));
+
+        assert!(result.is_err());
+    }
 
     #[rstest]
     fn test_accumulator_drain_sorted() {
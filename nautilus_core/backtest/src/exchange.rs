@@ -0,0 +1,623 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use nautilus_common::{
+    clock::{Clock, TestClock},
+    handlers::EventHandler,
+};
+use nautilus_core::{
+    time::{AtomicTime, UnixNanos},
+    uuid::UUID4,
+};
+use nautilus_execution::messages::TradingCommand;
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
+        quote::QuoteTick, trade::TradeTick,
+    },
+    enums::{BookType, OmsType},
+    events::order::{
+        cancel_rejected::OrderCancelRejected, event::OrderEvent,
+        modify_rejected::OrderModifyRejected, rejected::OrderRejected,
+    },
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId, venue::Venue},
+    instruments::Instrument,
+};
+use ustr::Ustr;
+
+use crate::{
+    engine::TimeEventAccumulator,
+    matching_engine::{OrderMatchingEngine, OrderMatchingEngineConfig},
+    models::{fill::FillModel, latency::LatencyModel},
+};
+
+/// Provides a simulated exchange for a single venue, routing market data and order
+/// commands to an [`OrderMatchingEngine`] per instrument.
+///
+/// When a [`LatencyModel`] is set, each command is held in flight until it reaches
+/// the venue, as a time alert on the exchange's [`TestClock`] which fires as the clock
+/// is advanced by [`SimulatedExchange::process`]. Events generated at the venue then
+/// have a `ts_init` of when they reach the client, and are only returned from
+/// [`SimulatedExchange::drain_events`] once that time has been reached.
+pub struct SimulatedExchange {
+    pub venue: Venue,
+    pub oms_type: OmsType,
+    pub book_type: BookType,
+    pub account_id: AccountId,
+    pub config: OrderMatchingEngineConfig,
+    time: &'static AtomicTime,
+    clock: TestClock,
+    accumulator: TimeEventAccumulator,
+    latency_model: Option<Box<dyn LatencyModel>>,
+    matching_engines: IndexMap<InstrumentId, OrderMatchingEngine>,
+    inflight: HashMap<Ustr, (u64, TradingCommand)>,
+    inflight_count: u64,
+    pending_events: Vec<OrderEvent>,
+    events: Vec<OrderEvent>,
+    last_ts_init: UnixNanos,
+}
+
+impl SimulatedExchange {
+    /// Initializes a new [`SimulatedExchange`] instance.
+    #[must_use]
+    pub fn new(
+        venue: Venue,
+        oms_type: OmsType,
+        book_type: BookType,
+        account_id: AccountId,
+        time: &'static AtomicTime,
+        latency_model: Option<Box<dyn LatencyModel>>,
+        config: OrderMatchingEngineConfig,
+    ) -> Self {
+        Self {
+            venue,
+            oms_type,
+            book_type,
+            account_id,
+            config,
+            time,
+            clock: Self::inflight_clock(),
+            accumulator: TimeEventAccumulator::new(),
+            latency_model,
+            matching_engines: IndexMap::new(),
+            inflight: HashMap::new(),
+            inflight_count: 0,
+            pending_events: Vec::new(),
+            events: Vec::new(),
+            last_ts_init: 0,
+        }
+    }
+
+    fn inflight_clock() -> TestClock {
+        // In-flight commands are dispatched by their time alert name,
+        // so the alerts need no callback.
+        let mut clock = TestClock::new();
+        clock.register_default_handler(EventHandler::new(None, None));
+        clock
+    }
+
+    /// Adds a matching engine for the given `instrument`, which must be for this venue.
+    pub fn add_instrument(
+        &mut self,
+        instrument: Box<dyn Instrument>,
+        fill_model: Box<dyn FillModel>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = instrument.id();
+        if instrument_id.venue != self.venue {
+            anyhow::bail!("Instrument {instrument_id} is not for venue {}", self.venue);
+        }
+        if self.matching_engines.contains_key(&instrument_id) {
+            anyhow::bail!("Instrument {instrument_id} already added");
+        }
+
+        let raw_id = (self.matching_engines.len() + 1) as u32;
+        let matching_engine = OrderMatchingEngine::new(
+            instrument,
+            raw_id,
+            self.book_type,
+            self.oms_type,
+            self.account_id,
+            self.time,
+            fill_model,
+            self.config.clone(),
+        );
+        self.matching_engines.insert(instrument_id, matching_engine);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get_matching_engine(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Option<&OrderMatchingEngine> {
+        self.matching_engines.get(instrument_id)
+    }
+
+    /// Returns the count of commands which have not yet reached the venue.
+    #[must_use]
+    pub fn inflight_count(&self) -> usize {
+        self.inflight.len()
+    }
+
+    /// Drains the order events which have reached the client, in the order they were generated.
+    pub fn drain_events(&mut self) -> Vec<OrderEvent> {
+        self.events.drain(..).collect()
+    }
+
+    /// Sends the given `command` to the venue, where it is processed once the latency
+    /// for its command type has elapsed.
+    pub fn send(&mut self, command: TradingCommand) {
+        let latency_ns = match &mut self.latency_model {
+            None => 0,
+            Some(model) => match &command {
+                TradingCommand::SubmitOrder(_) => model.insert_latency_nanos(),
+                TradingCommand::ModifyOrder(_) => model.modify_latency_nanos(),
+                TradingCommand::CancelOrder(_) | TradingCommand::CancelAllOrders(_) => {
+                    model.cancel_latency_nanos()
+                }
+            },
+        };
+
+        if latency_ns == 0 {
+            self.process_command(command);
+            self.collect_events();
+            return;
+        }
+
+        self.inflight_count += 1;
+        let name = Ustr::from(format!("{}-INFLIGHT-{}", self.venue, self.inflight_count).as_str());
+        let alert_time_ns = self.time.get_time_ns() + latency_ns;
+        self.clock.set_time_alert_ns(&name, alert_time_ns, None);
+        self.inflight.insert(name, (self.inflight_count, command));
+    }
+
+    /// Processes the exchange up to `ts_now`, processing the commands which have
+    /// reached the venue in the order they arrived, and iterating the matching engines.
+    pub fn process(&mut self, ts_now: UnixNanos) {
+        self.accumulator
+            .advance_clock(&mut self.clock, ts_now, true);
+
+        let mut arrived = Vec::new();
+        for handler in self.accumulator.drain() {
+            let name = handler.event.name;
+            self.clock.cancel_timer(&name);
+            if let Some((sequence, command)) = self.inflight.remove(&name) {
+                arrived.push((handler.event.ts_event, sequence, command));
+            }
+        }
+        // Commands arriving at the same time are processed in the order they were sent
+        arrived.sort_by_key(|(ts_event, sequence, _)| (*ts_event, *sequence));
+
+        for (ts_event, _, command) in arrived {
+            self.time.set_time(ts_event);
+            self.process_command(command);
+        }
+
+        self.time.set_time(ts_now);
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.iterate();
+        }
+        self.collect_events();
+    }
+
+    pub fn reset(&mut self) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.reset();
+        }
+        self.clock = Self::inflight_clock();
+        self.accumulator = TimeEventAccumulator::new();
+        self.inflight.clear();
+        self.inflight_count = 0;
+        self.pending_events.clear();
+        self.events.clear();
+        self.last_ts_init = 0;
+    }
+
+    // -- DATA PROCESSING -------------------------------------------------------------------------
+
+    pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
+        if let Some(matching_engine) = self.get_matching_engine_mut(&delta.instrument_id) {
+            matching_engine.process_order_book_delta(delta);
+            self.collect_events();
+        }
+    }
+
+    pub fn process_order_book_deltas(&mut self, deltas: OrderBookDeltas) {
+        if let Some(matching_engine) = self.get_matching_engine_mut(&deltas.instrument_id) {
+            matching_engine.process_order_book_deltas(deltas);
+            self.collect_events();
+        }
+    }
+
+    pub fn process_order_book_depth10(&mut self, depth: OrderBookDepth10) {
+        if let Some(matching_engine) = self.get_matching_engine_mut(&depth.instrument_id) {
+            matching_engine.process_order_book_depth10(depth);
+            self.collect_events();
+        }
+    }
+
+    pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
+        if let Some(matching_engine) = self.get_matching_engine_mut(&quote.instrument_id) {
+            matching_engine.process_quote_tick(quote);
+            self.collect_events();
+        }
+    }
+
+    pub fn process_trade_tick(&mut self, trade: &TradeTick) {
+        if let Some(matching_engine) = self.get_matching_engine_mut(&trade.instrument_id) {
+            matching_engine.process_trade_tick(trade);
+            self.collect_events();
+        }
+    }
+
+    pub fn process_bar(&mut self, bar: &Bar) {
+        if let Some(matching_engine) = self.get_matching_engine_mut(&bar.bar_type.instrument_id) {
+            matching_engine.process_bar(bar);
+            self.collect_events();
+        }
+    }
+
+    // -- INTERNAL --------------------------------------------------------------------------------
+
+    /// Returns the matching engine for the `instrument_id`, logging an error if there is none.
+    fn get_matching_engine_mut(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> Option<&mut OrderMatchingEngine> {
+        let matching_engine = self.matching_engines.get_mut(instrument_id);
+        if matching_engine.is_none() {
+            log::error!("No matching engine found for {instrument_id}");
+        }
+        matching_engine
+    }
+
+    fn process_command(&mut self, command: TradingCommand) {
+        let Some(matching_engine) = self.get_matching_engine_mut(&command.instrument_id()) else {
+            self.reject_command(command);
+            return;
+        };
+        match command {
+            TradingCommand::SubmitOrder(command) => matching_engine.process_order(command.order),
+            TradingCommand::ModifyOrder(command) => matching_engine.process_modify(&command),
+            TradingCommand::CancelOrder(command) => matching_engine.process_cancel(&command),
+            TradingCommand::CancelAllOrders(command) => {
+                matching_engine.process_cancel_all(&command);
+            }
+        }
+    }
+
+    /// Rejects a command for an instrument the exchange has no matching engine for.
+    fn reject_command(&mut self, command: TradingCommand) {
+        let reason = Ustr::from(
+            format!("No matching engine found for {}", command.instrument_id()).as_str(),
+        );
+        let ts_now = self.time.get_time_ns();
+        let event = match command {
+            TradingCommand::SubmitOrder(command) => OrderEvent::OrderRejected(
+                OrderRejected::new(
+                    command.trader_id,
+                    command.strategy_id,
+                    command.instrument_id,
+                    command.client_order_id,
+                    self.account_id,
+                    reason,
+                    UUID4::new(),
+                    ts_now,
+                    ts_now,
+                    false,
+                )
+                .unwrap(),
+            ),
+            TradingCommand::ModifyOrder(command) => OrderEvent::OrderModifyRejected(
+                OrderModifyRejected::new(
+                    command.trader_id,
+                    command.strategy_id,
+                    command.instrument_id,
+                    command.client_order_id,
+                    reason,
+                    UUID4::new(),
+                    ts_now,
+                    ts_now,
+                    false,
+                    command.venue_order_id,
+                    Some(self.account_id),
+                )
+                .unwrap(),
+            ),
+            TradingCommand::CancelOrder(command) => OrderEvent::OrderCancelRejected(
+                OrderCancelRejected::new(
+                    command.trader_id,
+                    command.strategy_id,
+                    command.instrument_id,
+                    command.client_order_id,
+                    reason,
+                    UUID4::new(),
+                    ts_now,
+                    ts_now,
+                    false,
+                    command.venue_order_id,
+                    Some(self.account_id),
+                )
+                .unwrap(),
+            ),
+            TradingCommand::CancelAllOrders(_) => return, // No orders to cancel
+        };
+        self.buffer_event(event);
+    }
+
+    fn collect_events(&mut self) {
+        let events: Vec<OrderEvent> = self
+            .matching_engines
+            .values_mut()
+            .flat_map(OrderMatchingEngine::drain_events)
+            .collect();
+        for event in events {
+            self.buffer_event(event);
+        }
+
+        let ts_now = self.time.get_time_ns();
+        let count = self
+            .pending_events
+            .iter()
+            .take_while(|event| event.ts_init() <= ts_now)
+            .count();
+        self.events.extend(self.pending_events.drain(..count));
+    }
+
+    fn buffer_event(&mut self, mut event: OrderEvent) {
+        if let Some(model) = &mut self.latency_model {
+            // Events reach the client in the order they were generated
+            let ts_init = event.ts_event() + model.response_latency_nanos();
+            self.last_ts_init = ts_init.max(self.last_ts_init);
+            event.set_ts_init(self.last_ts_init);
+        }
+        self.pending_events.push(event);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_execution::messages::{cancel::CancelOrder, submit::SubmitOrder};
+    use nautilus_model::{
+        enums::{OrderSide, OrderType, TimeInForce},
+        events::order::initialized::OrderInitializedBuilder,
+        identifiers::{
+            client_id::ClientId, client_order_id::ClientOrderId, strategy_id::StrategyId,
+            trader_id::TraderId,
+        },
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::any::OrderAny,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::models::{fill::FillAtTouchModel, latency::FixedLatencyModel};
+
+    #[fixture]
+    fn time() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, 0)))
+    }
+
+    fn exchange(
+        instrument: CurrencyPair,
+        time: &'static AtomicTime,
+        latency_model: Option<Box<dyn LatencyModel>>,
+    ) -> SimulatedExchange {
+        let mut exchange = SimulatedExchange::new(
+            Venue::from("SIM"),
+            OmsType::Netting,
+            BookType::L1_MBP,
+            AccountId::from("SIM-001"),
+            time,
+            latency_model,
+            OrderMatchingEngineConfig::default(),
+        );
+        exchange
+            .add_instrument(Box::new(instrument), Box::new(FillAtTouchModel))
+            .unwrap();
+        exchange
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str, ts: UnixNanos) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("1000000"),
+            Quantity::from("1000000"),
+            ts,
+            ts,
+        )
+        .unwrap()
+    }
+
+    fn submit(
+        instrument_id: InstrumentId,
+        order_type: OrderType,
+        price: Option<&str>,
+    ) -> TradingCommand {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from("O-1"))
+            .order_type(order_type)
+            .order_side(OrderSide::Buy)
+            .quantity(Quantity::from("100000"))
+            .time_in_force(TimeInForce::Gtc)
+            .price(price.map(Price::from))
+            .build()
+            .unwrap();
+        let order = OrderAny::from(init);
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            instrument_id,
+            order,
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        TradingCommand::SubmitOrder(command)
+    }
+
+    fn cancel(exchange: &SimulatedExchange, instrument_id: InstrumentId) -> TradingCommand {
+        let client_order_id = ClientOrderId::from("O-1");
+        let order = exchange
+            .get_matching_engine(&instrument_id)
+            .and_then(|engine| engine.get_order(&client_order_id))
+            .unwrap();
+        let command = CancelOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            instrument_id,
+            client_order_id,
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        TradingCommand::CancelOrder(command)
+    }
+
+    #[rstest]
+    fn test_add_instrument_for_other_venue(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut exchange = exchange(audusd_sim, time, None);
+        let mut other = audusd_sim;
+        other.id = InstrumentId::from("AUD/USD.OTHER");
+
+        assert!(exchange
+            .add_instrument(Box::new(other), Box::new(FillAtTouchModel))
+            .is_err());
+        assert!(exchange
+            .add_instrument(Box::new(audusd_sim), Box::new(FillAtTouchModel))
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_commands_for_unknown_instrument_are_rejected(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let mut exchange = exchange(audusd_sim, time, None);
+        let instrument_id = InstrumentId::from("EUR/USD.SIM");
+        exchange.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010", 0));
+
+        exchange.send(submit(instrument_id, OrderType::Market, None));
+        let command = CancelOrder::new(
+            TraderId::from("TRADER-001"),
+            ClientId::from("SIM"),
+            StrategyId::from("S-001"),
+            instrument_id,
+            ClientOrderId::from("O-1"),
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        exchange.send(TradingCommand::CancelOrder(command));
+
+        let events = exchange.drain_events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+        assert!(matches!(events[1], OrderEvent::OrderCancelRejected(_)));
+    }
+
+    #[rstest]
+    fn test_send_without_latency_model(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let instrument_id = audusd_sim.id;
+        let mut exchange = exchange(audusd_sim, time, None);
+        exchange.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010", 0));
+
+        exchange.send(submit(instrument_id, OrderType::Market, None));
+
+        let events = exchange.drain_events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        assert!(matches!(events[1], OrderEvent::OrderFilled(_)));
+        assert!(events
+            .iter()
+            .all(|event| event.ts_init() == event.ts_event()));
+        assert_eq!(exchange.inflight_count(), 0);
+    }
+
+    #[rstest]
+    fn test_send_with_latency_model_delays_command_and_events(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let latency_model = FixedLatencyModel::new(100, 200, 300, 400);
+        let instrument_id = audusd_sim.id;
+        let mut exchange = exchange(audusd_sim, time, Some(Box::new(latency_model)));
+        time.set_time(1_000);
+        exchange.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010", 1_000));
+
+        exchange.send(submit(instrument_id, OrderType::Market, None));
+        assert_eq!(exchange.inflight_count(), 1);
+
+        // The market moves before the order reaches the venue
+        time.set_time(1_200);
+        exchange.process(1_200);
+        exchange.process_quote_tick(&quote(instrument_id, "1.00010", "1.00020", 1_200));
+        assert!(exchange.drain_events().is_empty());
+
+        // The order arrives at the venue, though the events are still in flight
+        exchange.process(1_300);
+        assert_eq!(exchange.inflight_count(), 0);
+        assert!(exchange.drain_events().is_empty());
+
+        exchange.process(1_400);
+        let events = exchange.drain_events();
+        assert_eq!(events.len(), 2);
+        assert!(events
+            .iter()
+            .all(|event| event.ts_event() == 1_300 && event.ts_init() == 1_400));
+        match &events[1] {
+            OrderEvent::OrderFilled(fill) => assert_eq!(fill.last_px, Price::from("1.00020")),
+            event => panic!("Expected fill, was {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_insert_and_cancel_latencies(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let latency_model = FixedLatencyModel::new(100, 200, 300, 50);
+        let instrument_id = audusd_sim.id;
+        let mut exchange = exchange(audusd_sim, time, Some(Box::new(latency_model)));
+        exchange.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010", 0));
+
+        exchange.send(submit(instrument_id, OrderType::Limit, Some("0.99000")));
+        exchange.process(300);
+        let command = cancel(&exchange, instrument_id);
+        exchange.send(command);
+        exchange.process(600);
+
+        let events = exchange.drain_events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        assert!(matches!(events[1], OrderEvent::OrderCanceled(_)));
+        assert_eq!(events[0].ts_event(), 300);
+        assert_eq!(events[1].ts_event(), 450);
+        assert_eq!(events[1].ts_init(), 550);
+        assert_eq!(exchange.drain_events().len(), 0);
+    }
+}
@@ -14,3 +14,6 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod engine;
+pub mod exchange;
+pub mod matching_engine;
+pub mod models;
@@ -0,0 +1,2155 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::cmp::{max, min};
+
+use indexmap::IndexMap;
+use nautilus_core::{time::AtomicTime, uuid::UUID4};
+use nautilus_execution::messages::{
+    cancel::CancelOrder, cancel_all::CancelAllOrders, modify::ModifyOrder,
+};
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
+        order::BookOrder, quote::QuoteTick, trade::TradeTick,
+    },
+    enums::{
+        AggressorSide, BookType, LiquiditySide, OmsType, OrderSide, OrderStatus, OrderType,
+        PriceType, TimeInForce, TrailingOffsetType, TriggerType,
+    },
+    events::order::{
+        accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
+        event::OrderEvent, expired::OrderExpired, filled::OrderFilled,
+        modify_rejected::OrderModifyRejected, rejected::OrderRejected, triggered::OrderTriggered,
+        updated::OrderUpdated,
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue::Venue, venue_order_id::VenueOrderId,
+    },
+    instruments::Instrument,
+    orderbook::any::OrderBookAny,
+    orders::any::OrderAny,
+    types::{price::Price, quantity::Quantity},
+};
+use ustr::Ustr;
+
+use crate::models::fill::FillModel;
+
+/// Configuration for an [`OrderMatchingEngine`].
+#[derive(Clone, Debug)]
+pub struct OrderMatchingEngineConfig {
+    /// If bars should be processed as open, high, low and close price updates.
+    pub bar_execution: bool,
+    /// If stop orders are rejected when their trigger price is already in the market.
+    pub reject_stop_orders: bool,
+    /// If orders with `GTD` time in force are supported (otherwise rejected).
+    pub support_gtd_orders: bool,
+}
+
+impl Default for OrderMatchingEngineConfig {
+    fn default() -> Self {
+        Self {
+            bar_execution: true,
+            reject_stop_orders: true,
+            support_gtd_orders: true,
+        }
+    }
+}
+
+/// Provides an order matching engine for a single market of a simulated venue.
+///
+/// The engine maintains an order book from market data and matches the open
+/// orders it holds against it, generating the resulting order events. Resting
+/// orders which the market touches (but does not trade through) are filled as
+/// decided by the engine's [`FillModel`]. Events
+/// are applied to the engine's own copy of each order, and are buffered so the
+/// caller can drain and route them with [`OrderMatchingEngine::drain_events`].
+pub struct OrderMatchingEngine {
+    pub venue: Venue,
+    pub instrument: Box<dyn Instrument>,
+    pub raw_id: u32,
+    pub book_type: BookType,
+    pub oms_type: OmsType,
+    pub account_id: AccountId,
+    pub config: OrderMatchingEngineConfig,
+    clock: &'static AtomicTime,
+    fill_model: Box<dyn FillModel>,
+    book: OrderBookAny,
+    orders: IndexMap<ClientOrderId, OrderAny>,
+    events: Vec<OrderEvent>,
+    last_trade: Option<Price>,
+    trade: Option<(Price, Quantity)>,
+    last_bid_bar: Option<Bar>,
+    last_ask_bar: Option<Bar>,
+    venue_order_id_count: usize,
+    execution_count: usize,
+    position_count: usize,
+}
+
+impl OrderMatchingEngine {
+    /// Initializes a new [`OrderMatchingEngine`] instance.
+    #[must_use]
+    pub fn new(
+        instrument: Box<dyn Instrument>,
+        raw_id: u32,
+        book_type: BookType,
+        oms_type: OmsType,
+        account_id: AccountId,
+        clock: &'static AtomicTime,
+        fill_model: Box<dyn FillModel>,
+        config: OrderMatchingEngineConfig,
+    ) -> Self {
+        let book = OrderBookAny::new(instrument.id(), book_type);
+        Self {
+            venue: instrument.venue(),
+            instrument,
+            raw_id,
+            book_type,
+            oms_type,
+            account_id,
+            config,
+            clock,
+            fill_model,
+            book,
+            orders: IndexMap::new(),
+            events: Vec::new(),
+            last_trade: None,
+            trade: None,
+            last_bid_bar: None,
+            last_ask_bar: None,
+            venue_order_id_count: 0,
+            execution_count: 0,
+            position_count: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        self.instrument.id()
+    }
+
+    #[must_use]
+    pub fn book(&self) -> &OrderBookAny {
+        &self.book
+    }
+
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        self.book.best_bid_price()
+    }
+
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        self.book.best_ask_price()
+    }
+
+    #[must_use]
+    pub fn last_trade_price(&self) -> Option<Price> {
+        self.last_trade
+    }
+
+    #[must_use]
+    pub fn get_order(&self, client_order_id: &ClientOrderId) -> Option<&OrderAny> {
+        self.orders.get(client_order_id)
+    }
+
+    /// Returns the open orders in the sequence they were received.
+    #[must_use]
+    pub fn get_open_orders(&self) -> Vec<&OrderAny> {
+        self.orders.values().collect()
+    }
+
+    /// Drains the order events generated since the last call.
+    pub fn drain_events(&mut self) -> Vec<OrderEvent> {
+        self.events.drain(..).collect()
+    }
+
+    pub fn reset(&mut self) {
+        self.book.reset();
+        self.orders.clear();
+        self.events.clear();
+        self.last_trade = None;
+        self.last_bid_bar = None;
+        self.last_ask_bar = None;
+        self.venue_order_id_count = 0;
+        self.execution_count = 0;
+        self.position_count = 0;
+    }
+
+    // -- DATA PROCESSING -------------------------------------------------------------------------
+
+    pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
+        self.book.apply_delta(delta);
+        self.iterate();
+    }
+
+    pub fn process_order_book_deltas(&mut self, deltas: OrderBookDeltas) {
+        self.book.apply_deltas(deltas);
+        self.iterate();
+    }
+
+    pub fn process_order_book_depth10(&mut self, depth: OrderBookDepth10) {
+        self.book.apply_depth(depth);
+        self.iterate();
+    }
+
+    /// Processes the given quote, which updates the book for `L1_MBP` book types only.
+    pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
+        if self.book_type == BookType::L1_MBP {
+            // An L1_MBP book is always an MBP book
+            self.book.update_quote_tick(quote).unwrap();
+        }
+        self.iterate();
+    }
+
+    /// Processes the given trade, which updates the book for `L1_MBP` book types only.
+    pub fn process_trade_tick(&mut self, trade: &TradeTick) {
+        if self.book_type == BookType::L1_MBP {
+            // An L1_MBP book is always an MBP book
+            self.book.update_trade_tick(trade).unwrap();
+        }
+        self.last_trade = Some(trade.price);
+
+        // Resting orders can also be touched or traded through by the trade itself
+        self.trade = Some((trade.price, trade.size));
+        self.iterate();
+        self.trade = None;
+    }
+
+    /// Processes the given bar as a sequence of open, high, low and close price updates.
+    ///
+    /// Bid and ask bars are processed together once both bars for the same
+    /// `ts_event` have been received. Bars are only used for `L1_MBP` book types
+    /// when bar execution is enabled.
+    pub fn process_bar(&mut self, bar: &Bar) {
+        if !self.config.bar_execution || self.book_type != BookType::L1_MBP {
+            return;
+        }
+
+        match bar.bar_type.spec.price_type {
+            PriceType::Last | PriceType::Mid => self.process_trade_bar(bar),
+            PriceType::Bid => {
+                self.last_bid_bar = Some(*bar);
+                self.process_quote_bars();
+            }
+            PriceType::Ask => {
+                self.last_ask_bar = Some(*bar);
+                self.process_quote_bars();
+            }
+        }
+    }
+
+    fn process_trade_bar(&mut self, bar: &Bar) {
+        let size = self.bar_tick_size(bar.volume);
+        let trade_id = TradeId::from(format!("{}", bar.ts_event).as_str());
+        let mut last_px = None;
+
+        for price in [bar.open, bar.high, bar.low, bar.close] {
+            if last_px == Some(price) {
+                continue;
+            }
+            let trade = TradeTick::new(
+                self.instrument.id(),
+                price,
+                size,
+                AggressorSide::NoAggressor,
+                trade_id,
+                bar.ts_event,
+                bar.ts_init,
+            );
+            self.process_trade_tick(&trade);
+            last_px = Some(price);
+        }
+    }
+
+    fn process_quote_bars(&mut self) {
+        let (Some(bid_bar), Some(ask_bar)) = (self.last_bid_bar, self.last_ask_bar) else {
+            return;
+        };
+        if bid_bar.ts_event != ask_bar.ts_event {
+            return;
+        }
+
+        let bid_size = self.bar_tick_size(bid_bar.volume);
+        let ask_size = self.bar_tick_size(ask_bar.volume);
+        let prices = [
+            (bid_bar.open, ask_bar.open),
+            (bid_bar.high, ask_bar.high),
+            (bid_bar.low, ask_bar.low),
+            (bid_bar.close, ask_bar.close),
+        ];
+
+        for (bid, ask) in prices {
+            let quote = QuoteTick::new(
+                self.instrument.id(),
+                bid,
+                ask,
+                bid_size,
+                ask_size,
+                bid_bar.ts_event,
+                bid_bar.ts_init,
+            )
+            .expect("Bid and ask bars should have the instrument precisions");
+            self.process_quote_tick(&quote);
+        }
+
+        self.last_bid_bar = None;
+        self.last_ask_bar = None;
+    }
+
+    fn bar_tick_size(&self, volume: Quantity) -> Quantity {
+        // Spread the bar volume evenly across the four price points
+        let size_increment = self.instrument.size_increment();
+        let size = self
+            .instrument
+            .make_qty(volume.as_f64() / 4.0)
+            .unwrap_or(size_increment);
+        max(size, size_increment)
+    }
+
+    // -- COMMAND PROCESSING ----------------------------------------------------------------------
+
+    /// Processes the given order, which is accepted (or rejected) and then matched
+    /// immediately against the current market.
+    pub fn process_order(&mut self, order: OrderAny) {
+        let client_order_id = order.client_order_id();
+        if self.orders.contains_key(&client_order_id) {
+            return; // Already working on the venue
+        }
+
+        let order_type = order.order_type();
+        let validation = self.validate_order(&order);
+        self.orders.insert(client_order_id, order);
+
+        if let Err(reason) = validation {
+            self.generate_order_rejected(client_order_id, &reason);
+            return;
+        }
+
+        match order_type {
+            OrderType::Market => self.process_market_order(client_order_id),
+            OrderType::MarketToLimit => self.process_market_to_limit_order(client_order_id),
+            OrderType::Limit => self.process_limit_order(client_order_id),
+            OrderType::StopMarket | OrderType::StopLimit => {
+                self.process_stop_order(client_order_id);
+            }
+            OrderType::MarketIfTouched
+            | OrderType::LimitIfTouched
+            | OrderType::TrailingStopMarket
+            | OrderType::TrailingStopLimit => {
+                self.generate_order_accepted(client_order_id);
+                self.update_trailing_stop(client_order_id);
+                self.match_order(client_order_id);
+            }
+        }
+    }
+
+    /// Processes the given modify command for an open order.
+    pub fn process_modify(&mut self, command: &ModifyOrder) {
+        let client_order_id = command.client_order_id;
+        let Some(order) = self.orders.get(&client_order_id) else {
+            self.generate_order_modify_rejected(
+                command.trader_id,
+                command.strategy_id,
+                client_order_id,
+                command.venue_order_id,
+                &format!("Order {client_order_id} not found"),
+            );
+            return;
+        };
+
+        let quantity = command.quantity.unwrap_or(order.quantity());
+        let venue_order_id = order.venue_order_id();
+        if let Err(reason) = self.validate_modify(order, command, quantity) {
+            self.generate_order_modify_rejected(
+                command.trader_id,
+                command.strategy_id,
+                client_order_id,
+                venue_order_id,
+                &reason,
+            );
+            return;
+        }
+
+        self.generate_order_updated(
+            client_order_id,
+            quantity,
+            command.price,
+            command.trigger_price,
+        );
+
+        // A working limit order modified into the market takes liquidity
+        let Some(order) = self.orders.get(&client_order_id) else {
+            return;
+        };
+        if let Some(price) = order.price() {
+            if is_working_limit(order) && self.is_limit_matched(order.side(), price) {
+                self.fill_limit_order(client_order_id, LiquiditySide::Taker);
+                return;
+            }
+        }
+
+        self.match_order(client_order_id);
+    }
+
+    /// Processes the given cancel command for an open order.
+    pub fn process_cancel(&mut self, command: &CancelOrder) {
+        if self.orders.contains_key(&command.client_order_id) {
+            self.generate_order_canceled(command.client_order_id);
+        } else {
+            self.generate_order_cancel_rejected(
+                command.trader_id,
+                command.strategy_id,
+                command.client_order_id,
+                command.venue_order_id,
+                &format!("Order {} not found", command.client_order_id),
+            );
+        }
+    }
+
+    /// Processes the given cancel all command for the open orders on the commands side.
+    pub fn process_cancel_all(&mut self, command: &CancelAllOrders) {
+        let client_order_ids: Vec<ClientOrderId> = self
+            .orders
+            .values()
+            .filter(|order| {
+                command.order_side == OrderSide::NoOrderSide || command.order_side == order.side()
+            })
+            .map(|order| order.client_order_id())
+            .collect();
+
+        for client_order_id in client_order_ids {
+            self.generate_order_canceled(client_order_id);
+        }
+    }
+
+    fn validate_order(&self, order: &OrderAny) -> Result<(), String> {
+        if order.instrument_id() != self.instrument.id() {
+            return Err(format!(
+                "Instrument {} not found in matching engine for {}",
+                order.instrument_id(),
+                self.instrument.id(),
+            ));
+        }
+
+        let size_precision = self.instrument.size_precision();
+        if order.quantity().precision != size_precision {
+            return Err(format!(
+                "Invalid order quantity precision for order {}, was {} when {} size precision is {}",
+                order.client_order_id(),
+                order.quantity().precision,
+                self.instrument.id(),
+                size_precision,
+            ));
+        }
+
+        let price_precision = self.instrument.price_precision();
+        for price in [order.price(), order.trigger_price()].into_iter().flatten() {
+            if price.precision != price_precision {
+                return Err(format!(
+                    "Invalid order price precision for order {}, was {} when {} price precision is {}",
+                    order.client_order_id(),
+                    price.precision,
+                    self.instrument.id(),
+                    price_precision,
+                ));
+            }
+        }
+
+        if order.time_in_force() == TimeInForce::Gtd && !self.config.support_gtd_orders {
+            return Err(format!(
+                "GTD orders not supported for {}",
+                self.instrument.id()
+            ));
+        }
+
+        if let Some(offset_type) = order.trailing_offset_type() {
+            if !matches!(
+                offset_type,
+                TrailingOffsetType::Price
+                    | TrailingOffsetType::BasisPoints
+                    | TrailingOffsetType::Ticks
+            ) {
+                return Err(format!("Trailing offset type {offset_type} not supported"));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_modify(
+        &self,
+        order: &OrderAny,
+        command: &ModifyOrder,
+        quantity: Quantity,
+    ) -> Result<(), String> {
+        if quantity <= order.filled_qty() {
+            return Err(format!(
+                "Modified quantity {quantity} would be less than or equal to the filled quantity {}",
+                order.filled_qty(),
+            ));
+        }
+
+        let has_price = order.price().is_some() || order.order_type() == OrderType::MarketToLimit;
+        if command.price.is_some() && !has_price {
+            return Err(format!(
+                "Cannot modify price of {} order",
+                order.order_type()
+            ));
+        }
+
+        if command.trigger_price.is_some() && order.trigger_price().is_none() {
+            return Err(format!(
+                "Cannot modify trigger price of {} order",
+                order.order_type()
+            ));
+        }
+
+        if let Some(price) = command.price {
+            if order.is_post_only() && self.is_limit_matched(order.side(), price) {
+                return Err(format!(
+                    "POST_ONLY {} {} order with new limit px of {price} would have been a TAKER",
+                    order.order_type(),
+                    order.side(),
+                ));
+            }
+        }
+
+        if let Some(trigger_price) = command.trigger_price {
+            let is_stop = matches!(
+                order.order_type(),
+                OrderType::StopMarket | OrderType::StopLimit
+            );
+            if is_stop
+                && !is_triggered(order)
+                && self.is_stop_triggered(order.side(), trigger_price, order.trigger_type())
+            {
+                return Err(format!(
+                    "{} {} order new trigger px of {trigger_price} was in the market",
+                    order.order_type(),
+                    order.side(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn process_market_order(&mut self, client_order_id: ClientOrderId) {
+        let side = self.orders[&client_order_id].side();
+        if !self.has_market(side) {
+            self.generate_order_rejected(
+                client_order_id,
+                &format!("No market for {}", self.instrument.id()),
+            );
+            return;
+        }
+
+        self.generate_order_accepted(client_order_id);
+        self.fill_market_order(client_order_id);
+    }
+
+    fn process_market_to_limit_order(&mut self, client_order_id: ClientOrderId) {
+        let side = self.orders[&client_order_id].side();
+        if !self.has_market(side) {
+            self.generate_order_rejected(
+                client_order_id,
+                &format!("No market for {}", self.instrument.id()),
+            );
+            return;
+        }
+
+        self.generate_order_accepted(client_order_id);
+
+        // Fill at the top level only, then work any remainder as a limit order at that price
+        let (side, leaves_qty, quantity) = {
+            let order = &self.orders[&client_order_id];
+            (order.side(), order.leaves_qty(), order.quantity())
+        };
+        let mut fills = self.determine_market_fills(side, leaves_qty);
+        let Some(&(top_px, _)) = fills.first() else {
+            return;
+        };
+        fills.retain(|(px, _)| *px == top_px);
+        self.apply_fills(client_order_id, fills, LiquiditySide::Taker);
+
+        if self.orders.contains_key(&client_order_id) {
+            self.generate_order_updated(client_order_id, quantity, Some(top_px), None);
+        }
+    }
+
+    fn process_limit_order(&mut self, client_order_id: ClientOrderId) {
+        let (side, price, order_type, is_post_only, time_in_force) = {
+            let order = &self.orders[&client_order_id];
+            (
+                order.side(),
+                order.price().expect("Limit order should have a price"),
+                order.order_type(),
+                order.is_post_only(),
+                order.time_in_force(),
+            )
+        };
+
+        let is_marketable = self.is_limit_matched(side, price);
+        if is_post_only && is_marketable {
+            let reason = format!(
+                "POST_ONLY {order_type} {side} order limit px of {price} would have been a TAKER: bid={}, ask={}",
+                format_price(self.book.best_bid_price()),
+                format_price(self.book.best_ask_price()),
+            );
+            self.generate_order_rejected(client_order_id, &reason);
+            return;
+        }
+
+        self.generate_order_accepted(client_order_id);
+
+        if is_marketable {
+            self.fill_limit_order(client_order_id, LiquiditySide::Taker);
+        } else if matches!(time_in_force, TimeInForce::Ioc | TimeInForce::Fok) {
+            self.generate_order_canceled(client_order_id);
+        }
+    }
+
+    fn process_stop_order(&mut self, client_order_id: ClientOrderId) {
+        let (side, trigger_price, trigger_type, order_type) = {
+            let order = &self.orders[&client_order_id];
+            (
+                order.side(),
+                order
+                    .trigger_price()
+                    .expect("Stop order should have a trigger price"),
+                order.trigger_type(),
+                order.order_type(),
+            )
+        };
+
+        if self.config.reject_stop_orders
+            && self.is_stop_triggered(side, trigger_price, trigger_type)
+        {
+            let reason = format!(
+                "{order_type} {side} order trigger px of {trigger_price} was in the market: bid={}, ask={}",
+                format_price(self.book.best_bid_price()),
+                format_price(self.book.best_ask_price()),
+            );
+            self.generate_order_rejected(client_order_id, &reason);
+            return;
+        }
+
+        self.generate_order_accepted(client_order_id);
+        self.match_order(client_order_id);
+    }
+
+    // -- MATCHING --------------------------------------------------------------------------------
+
+    /// Iterates the open orders against the current market, expiring, updating,
+    /// triggering and filling orders as required.
+    pub fn iterate(&mut self) {
+        let ts_now = self.clock.get_time_ns();
+        let client_order_ids: Vec<ClientOrderId> = self.orders.keys().copied().collect();
+
+        for client_order_id in client_order_ids {
+            // Orders can close while iterating (e.g. on a contingent cancel)
+            let Some(order) = self.orders.get(&client_order_id) else {
+                continue;
+            };
+
+            if order.time_in_force() == TimeInForce::Gtd
+                && order
+                    .expire_time()
+                    .is_some_and(|expire_time| ts_now >= expire_time)
+            {
+                self.generate_order_expired(client_order_id);
+                continue;
+            }
+
+            self.update_trailing_stop(client_order_id);
+            self.match_order(client_order_id);
+        }
+    }
+
+    fn match_order(&mut self, client_order_id: ClientOrderId) {
+        let Some(order) = self.orders.get(&client_order_id) else {
+            return;
+        };
+
+        match order.order_type() {
+            OrderType::Limit | OrderType::MarketToLimit => self.match_limit_order(client_order_id),
+            OrderType::StopMarket | OrderType::TrailingStopMarket => {
+                self.match_stop_market_order(client_order_id);
+            }
+            OrderType::StopLimit | OrderType::TrailingStopLimit => {
+                self.match_triggered_limit_order(client_order_id, false);
+            }
+            OrderType::MarketIfTouched => self.match_market_if_touched_order(client_order_id),
+            OrderType::LimitIfTouched => {
+                self.match_triggered_limit_order(client_order_id, true);
+            }
+            OrderType::Market => {} // Market orders never rest on the book
+        }
+    }
+
+    fn match_limit_order(&mut self, client_order_id: ClientOrderId) {
+        let order = &self.orders[&client_order_id];
+        let Some(price) = order.price() else {
+            return; // Market-to-limit order yet to receive its limit price
+        };
+
+        if self.is_passive_filled(client_order_id, price) {
+            self.fill_limit_order(client_order_id, LiquiditySide::Maker);
+        }
+    }
+
+    fn match_stop_market_order(&mut self, client_order_id: ClientOrderId) {
+        let order = &self.orders[&client_order_id];
+        let trigger_price = order
+            .trigger_price()
+            .expect("Stop order should have a trigger price");
+
+        if self.is_stop_triggered(order.side(), trigger_price, order.trigger_type()) {
+            self.fill_market_order(client_order_id);
+        }
+    }
+
+    fn match_market_if_touched_order(&mut self, client_order_id: ClientOrderId) {
+        let order = &self.orders[&client_order_id];
+        let trigger_price = order
+            .trigger_price()
+            .expect("Market-if-touched order should have a trigger price");
+
+        if self.is_touch_triggered(order.side(), trigger_price, order.trigger_type()) {
+            self.fill_market_order(client_order_id);
+        }
+    }
+
+    /// Matches a stop-limit or limit-if-touched style order, which works as a
+    /// limit order once triggered.
+    fn match_triggered_limit_order(&mut self, client_order_id: ClientOrderId, is_touch: bool) {
+        let order = &self.orders[&client_order_id];
+        let side = order.side();
+        let price = order.price().expect("Order should have a limit price");
+
+        if is_triggered(order) {
+            if self.is_passive_filled(client_order_id, price) {
+                self.fill_limit_order(client_order_id, LiquiditySide::Maker);
+            }
+            return;
+        }
+
+        let trigger_price = order
+            .trigger_price()
+            .expect("Order should have a trigger price");
+        let trigger_type = order.trigger_type();
+        let triggered = if is_touch {
+            self.is_touch_triggered(side, trigger_price, trigger_type)
+        } else {
+            self.is_stop_triggered(side, trigger_price, trigger_type)
+        };
+        if !triggered {
+            return;
+        }
+
+        self.generate_order_triggered(client_order_id);
+
+        // The limit price may already be marketable once triggered
+        if self.is_limit_matched(side, price) {
+            self.fill_limit_order(client_order_id, LiquiditySide::Taker);
+        }
+    }
+
+    fn update_trailing_stop(&mut self, client_order_id: ClientOrderId) {
+        let order = &self.orders[&client_order_id];
+        if !matches!(
+            order.order_type(),
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+        ) || is_triggered(order)
+        {
+            return;
+        }
+
+        let side = order.side();
+        let quantity = order.quantity();
+        let (Some(trigger_price), Some(trailing_offset), Some(offset_type)) = (
+            order.trigger_price(),
+            order.trailing_offset(),
+            order.trailing_offset_type(),
+        ) else {
+            return;
+        };
+        let Some(reference) = self.trigger_reference_price(side, order.trigger_type()) else {
+            return;
+        };
+
+        let trailed_price = |offset: Price| -> Option<Price> {
+            let offset = self.offset_value(reference, offset, offset_type);
+            let value = match side {
+                OrderSide::Buy => reference + offset,
+                _ => reference - offset,
+            };
+            self.instrument.make_price(value).ok()
+        };
+
+        let Some(new_trigger_price) = trailed_price(trailing_offset) else {
+            return;
+        };
+        let is_improved = match side {
+            OrderSide::Buy => new_trigger_price < trigger_price,
+            _ => new_trigger_price > trigger_price,
+        };
+        if !is_improved {
+            return;
+        }
+
+        let new_price = match order.order_type() {
+            OrderType::TrailingStopLimit => order.limit_offset().and_then(trailed_price),
+            _ => None,
+        };
+
+        self.generate_order_updated(
+            client_order_id,
+            quantity,
+            new_price,
+            Some(new_trigger_price),
+        );
+    }
+
+    fn offset_value(
+        &self,
+        reference: Price,
+        offset: Price,
+        offset_type: TrailingOffsetType,
+    ) -> f64 {
+        match offset_type {
+            TrailingOffsetType::BasisPoints => reference.as_f64() * offset.as_f64() / 10_000.0,
+            TrailingOffsetType::Ticks => {
+                offset.as_f64() * self.instrument.price_increment().as_f64()
+            }
+            _ => offset.as_f64(),
+        }
+    }
+
+    // -- FILLING ---------------------------------------------------------------------------------
+
+    fn fill_market_order(&mut self, client_order_id: ClientOrderId) {
+        let (side, leaves_qty, time_in_force) = {
+            let order = &self.orders[&client_order_id];
+            (order.side(), order.leaves_qty(), order.time_in_force())
+        };
+
+        let fills = self.determine_market_fills(side, leaves_qty);
+        if time_in_force == TimeInForce::Fok && total_fill_qty(&fills) < leaves_qty {
+            self.generate_order_canceled(client_order_id);
+            return;
+        }
+
+        self.apply_fills(client_order_id, fills, LiquiditySide::Taker);
+
+        // Any remainder could not be filled from the available liquidity
+        if self.orders.contains_key(&client_order_id) {
+            self.generate_order_canceled(client_order_id);
+        }
+    }
+
+    fn fill_limit_order(&mut self, client_order_id: ClientOrderId, liquidity_side: LiquiditySide) {
+        let (side, price, leaves_qty, time_in_force) = {
+            let order = &self.orders[&client_order_id];
+            (
+                order.side(),
+                order.price().expect("Limit order should have a price"),
+                order.leaves_qty(),
+                order.time_in_force(),
+            )
+        };
+
+        let fills = match liquidity_side {
+            // Resting orders fill at their limit price once touched, up to the size available
+            LiquiditySide::Maker => {
+                vec![(price, min(leaves_qty, self.passive_fill_qty(side, price)))]
+            }
+            _ => self.determine_limit_fills(side, price, leaves_qty),
+        };
+
+        if time_in_force == TimeInForce::Fok && total_fill_qty(&fills) < leaves_qty {
+            self.generate_order_canceled(client_order_id);
+            return;
+        }
+
+        self.apply_fills(client_order_id, fills, liquidity_side);
+
+        if matches!(time_in_force, TimeInForce::Ioc | TimeInForce::Fok)
+            && self.orders.contains_key(&client_order_id)
+        {
+            self.generate_order_canceled(client_order_id);
+        }
+    }
+
+    fn determine_market_fills(
+        &mut self,
+        side: OrderSide,
+        quantity: Quantity,
+    ) -> Vec<(Price, Quantity)> {
+        let price = match side {
+            OrderSide::Buy => Price::max(self.instrument.price_precision()),
+            _ => Price::min(self.instrument.price_precision()),
+        };
+        let mut fills = self.determine_limit_fills(side, price, quantity);
+
+        // Slippage only applies to a top-of-book market, deeper books are swept
+        if self.book_type == BookType::L1_MBP && self.fill_model.is_slipped() {
+            let tick = self.instrument.price_increment();
+            for (fill_px, _) in &mut fills {
+                *fill_px = match side {
+                    OrderSide::Buy => *fill_px + tick,
+                    _ => *fill_px - tick,
+                };
+            }
+        }
+
+        fills
+    }
+
+    fn determine_limit_fills(
+        &mut self,
+        side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+    ) -> Vec<(Price, Quantity)> {
+        let mut fills = self
+            .fill_model
+            .simulate_fills(&self.book, &BookOrder::new(side, price, quantity, 0));
+
+        // An L1_MBP book only represents the top of the market, so any remainder
+        // is assumed to fill at the last price reached
+        if self.book_type == BookType::L1_MBP {
+            let filled_qty = total_fill_qty(&fills);
+            if let Some(&(last_px, _)) = fills.last() {
+                if filled_qty < quantity {
+                    fills.push((last_px, quantity - filled_qty));
+                }
+            }
+        }
+
+        fills
+    }
+
+    fn apply_fills(
+        &mut self,
+        client_order_id: ClientOrderId,
+        fills: Vec<(Price, Quantity)>,
+        liquidity_side: LiquiditySide,
+    ) {
+        for (fill_px, fill_qty) in fills {
+            // The order is removed once filled
+            let Some(order) = self.orders.get(&client_order_id) else {
+                break;
+            };
+            let last_qty = min(fill_qty, order.leaves_qty());
+            if !last_qty.is_positive() {
+                break;
+            }
+
+            self.generate_order_filled(client_order_id, last_qty, fill_px, liquidity_side);
+        }
+    }
+
+    // -- MARKET STATE ----------------------------------------------------------------------------
+
+    fn has_market(&self, side: OrderSide) -> bool {
+        match side {
+            OrderSide::Buy => self.book.has_ask(),
+            _ => self.book.has_bid(),
+        }
+    }
+
+    /// Returns the price an order on the given `side` would trade at, used to
+    /// evaluate triggers for the given `trigger_type`.
+    fn trigger_reference_price(
+        &self,
+        side: OrderSide,
+        trigger_type: Option<TriggerType>,
+    ) -> Option<Price> {
+        let market_px = match side {
+            OrderSide::Buy => self.book.best_ask_price(),
+            _ => self.book.best_bid_price(),
+        };
+
+        match trigger_type {
+            Some(TriggerType::LastTrade) => self.last_trade.or(market_px),
+            _ => market_px.or(self.last_trade),
+        }
+    }
+
+    /// Returns whether the resting order at `price` fills, either because the
+    /// market traded through its price, or the fill model fills it at the touch.
+    fn is_passive_filled(&mut self, client_order_id: ClientOrderId, price: Price) -> bool {
+        let order = &self.orders[&client_order_id];
+        let side = order.side();
+        let market_px = match side {
+            OrderSide::Buy => self.book.best_ask_price(),
+            _ => self.book.best_bid_price(),
+        };
+
+        let mut is_touched = false;
+        let trade_px = self.trade.map(|(px, _)| px);
+        for px in [market_px, trade_px].into_iter().flatten() {
+            let is_crossed = match side {
+                OrderSide::Buy => px < price,
+                _ => px > price,
+            };
+            if is_crossed {
+                return true;
+            }
+            is_touched |= px == price;
+        }
+
+        is_touched && self.fill_model.is_limit_filled(order, &self.book)
+    }
+
+    /// Returns the size available to a resting order at its limit `price`, from the
+    /// opposite side of the book through to that price or from a trade reaching it.
+    fn passive_fill_qty(&self, side: OrderSide, price: Price) -> Quantity {
+        let (levels, reaches): (Vec<_>, fn(Price, Price) -> bool) = match side {
+            OrderSide::Buy => (self.book.asks(), |px, price| px <= price),
+            _ => (self.book.bids(), |px, price| px >= price),
+        };
+        let book_raw: u64 = levels
+            .iter()
+            .take_while(|level| reaches(level.price.value, price))
+            .map(|level| level.size_raw())
+            .sum();
+        let trade_raw = match self.trade {
+            Some((trade_px, trade_qty)) if reaches(trade_px, price) => trade_qty.raw,
+            _ => 0,
+        };
+
+        // Trade size is already reflected in an `L1_MBP` book, so is not added to it
+        Quantity::from_raw(max(book_raw, trade_raw), self.instrument.size_precision()).unwrap()
+    }
+
+    fn is_limit_matched(&self, side: OrderSide, price: Price) -> bool {
+        match side {
+            OrderSide::Buy => self.book.best_ask_price().is_some_and(|ask| ask <= price),
+            _ => self.book.best_bid_price().is_some_and(|bid| bid >= price),
+        }
+    }
+
+    fn is_stop_triggered(
+        &self,
+        side: OrderSide,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+    ) -> bool {
+        self.trigger_reference_price(side, trigger_type)
+            .is_some_and(|px| match side {
+                OrderSide::Buy => px >= trigger_price,
+                _ => px <= trigger_price,
+            })
+    }
+
+    fn is_touch_triggered(
+        &self,
+        side: OrderSide,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+    ) -> bool {
+        self.trigger_reference_price(side, trigger_type)
+            .is_some_and(|px| match side {
+                OrderSide::Buy => px <= trigger_price,
+                _ => px >= trigger_price,
+            })
+    }
+
+    // -- IDENTIFIERS -----------------------------------------------------------------------------
+
+    fn generate_venue_order_id(&mut self) -> VenueOrderId {
+        self.venue_order_id_count += 1;
+        VenueOrderId::from(
+            format!(
+                "{}-{}-{:03}",
+                self.venue, self.raw_id, self.venue_order_id_count
+            )
+            .as_str(),
+        )
+    }
+
+    fn generate_trade_id(&mut self) -> TradeId {
+        self.execution_count += 1;
+        TradeId::from(
+            format!("{}-{}-{:03}", self.venue, self.raw_id, self.execution_count).as_str(),
+        )
+    }
+
+    fn generate_position_id(&mut self) -> PositionId {
+        self.position_count += 1;
+        PositionId::from(
+            format!("{}-{}-{:03}", self.venue, self.raw_id, self.position_count).as_str(),
+        )
+    }
+
+    // -- EVENT GENERATION ------------------------------------------------------------------------
+
+    /// Applies the event to the engine's copy of the order and buffers it.
+    ///
+    /// An event the order cannot apply (an invalid state transition) is logged and dropped.
+    fn apply_event(&mut self, event: OrderEvent) {
+        let client_order_id = event.client_order_id();
+        if let Some(order) = self.orders.get_mut(&client_order_id) {
+            if let Err(e) = order.apply(event.clone()) {
+                log::error!("Error applying event {event:?} to order {client_order_id}: {e}");
+                return;
+            }
+
+            if order.is_closed() {
+                self.fill_model.on_order_closed(&client_order_id);
+                self.orders.shift_remove(&client_order_id);
+            } else if is_working_limit(order) {
+                let is_repriced = match &event {
+                    OrderEvent::OrderAccepted(_) | OrderEvent::OrderTriggered(_) => true,
+                    OrderEvent::OrderUpdated(updated) => updated.price.is_some(),
+                    _ => false,
+                };
+                if is_repriced {
+                    self.fill_model.on_order_resting(order, &self.book);
+                }
+            }
+        }
+        self.events.push(event);
+    }
+
+    fn generate_order_rejected(&mut self, client_order_id: ClientOrderId, reason: &str) {
+        let ts_now = self.clock.get_time_ns();
+        let order = &self.orders[&client_order_id];
+        let event = OrderRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            self.account_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+        )
+        .unwrap();
+        self.apply_event(OrderEvent::OrderRejected(event));
+    }
+
+    fn generate_order_accepted(&mut self, client_order_id: ClientOrderId) {
+        let venue_order_id = self.generate_venue_order_id();
+        let ts_now = self.clock.get_time_ns();
+        let order = &self.orders[&client_order_id];
+        let event = OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            venue_order_id,
+            self.account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+        )
+        .unwrap();
+        self.apply_event(OrderEvent::OrderAccepted(event));
+    }
+
+    fn generate_order_triggered(&mut self, client_order_id: ClientOrderId) {
+        let ts_now = self.clock.get_time_ns();
+        let order = &self.orders[&client_order_id];
+        let event = OrderTriggered::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id),
+        )
+        .unwrap();
+        self.apply_event(OrderEvent::OrderTriggered(event));
+    }
+
+    fn generate_order_updated(
+        &mut self,
+        client_order_id: ClientOrderId,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) {
+        let ts_now = self.clock.get_time_ns();
+        let order = &self.orders[&client_order_id];
+        let event = OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            quantity,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id),
+            price,
+            trigger_price,
+        )
+        .unwrap();
+        self.apply_event(OrderEvent::OrderUpdated(event));
+    }
+
+    fn generate_order_canceled(&mut self, client_order_id: ClientOrderId) {
+        let ts_now = self.clock.get_time_ns();
+        let order = &self.orders[&client_order_id];
+        let event = OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id),
+        )
+        .unwrap();
+        self.apply_event(OrderEvent::OrderCanceled(event));
+    }
+
+    fn generate_order_expired(&mut self, client_order_id: ClientOrderId) {
+        let ts_now = self.clock.get_time_ns();
+        let order = &self.orders[&client_order_id];
+        let event = OrderExpired::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id),
+        )
+        .unwrap();
+        self.apply_event(OrderEvent::OrderExpired(event));
+    }
+
+    fn generate_order_modify_rejected(
+        &mut self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+        reason: &str,
+    ) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderModifyRejected::new(
+            trader_id,
+            strategy_id,
+            self.instrument.id(),
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            venue_order_id,
+            Some(self.account_id),
+        )
+        .unwrap();
+        // The order state is unchanged by a rejected modification
+        self.events.push(OrderEvent::OrderModifyRejected(event));
+    }
+
+    fn generate_order_cancel_rejected(
+        &mut self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+        reason: &str,
+    ) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderCancelRejected::new(
+            trader_id,
+            strategy_id,
+            self.instrument.id(),
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            venue_order_id,
+            Some(self.account_id),
+        )
+        .unwrap();
+        // The order state is unchanged by a rejected cancel
+        self.events.push(OrderEvent::OrderCancelRejected(event));
+    }
+
+    fn generate_order_filled(
+        &mut self,
+        client_order_id: ClientOrderId,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+    ) {
+        let trade_id = self.generate_trade_id();
+        let position_id = match self.oms_type {
+            OmsType::Hedging => Some(match self.orders[&client_order_id].position_id() {
+                Some(position_id) => position_id,
+                None => self.generate_position_id(),
+            }),
+            _ => None,
+        };
+        let ts_now = self.clock.get_time_ns();
+        let order = &self.orders[&client_order_id];
+        let event = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            order
+                .venue_order_id()
+                .expect("Order should be accepted before filling"),
+            self.account_id,
+            trade_id,
+            order.side(),
+            order.order_type(),
+            last_qty,
+            last_px,
+            self.instrument.quote_currency(),
+            liquidity_side,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            position_id,
+            None,
+        )
+        .unwrap();
+        self.apply_event(OrderEvent::OrderFilled(event));
+    }
+}
+
+/// Returns whether the order has been triggered and is now working as a limit order.
+fn is_triggered(order: &OrderAny) -> bool {
+    matches!(
+        order.status(),
+        OrderStatus::Triggered | OrderStatus::PartiallyFilled
+    )
+}
+
+/// Returns whether the order is working as a limit order at its limit price.
+fn is_working_limit(order: &OrderAny) -> bool {
+    match order.order_type() {
+        OrderType::Limit => true,
+        OrderType::MarketToLimit => order.price().is_some(),
+        OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+            is_triggered(order)
+        }
+        _ => false,
+    }
+}
+
+fn total_fill_qty(fills: &[(Price, Quantity)]) -> Quantity {
+    fills
+        .iter()
+        .map(|(_, qty)| *qty)
+        .reduce(|total, qty| total + qty)
+        .unwrap_or_default()
+}
+
+fn format_price(price: Option<Price>) -> String {
+    price.map_or_else(|| "None".to_string(), |price| format!("{price}"))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::bar::BarType,
+        enums::BookAction,
+        events::order::initialized::OrderInitializedBuilder,
+        identifiers::client_id::ClientId,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::models::fill::{FillAtTouchModel, ProbabilisticFillModel, QueuePositionFillModel};
+
+    #[fixture]
+    fn clock() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, 0)))
+    }
+
+    fn engine(
+        instrument: CurrencyPair,
+        book_type: BookType,
+        oms_type: OmsType,
+        clock: &'static AtomicTime,
+        fill_model: Box<dyn FillModel>,
+    ) -> OrderMatchingEngine {
+        OrderMatchingEngine::new(
+            Box::new(instrument),
+            1,
+            book_type,
+            oms_type,
+            AccountId::from("SIM-001"),
+            clock,
+            fill_model,
+            OrderMatchingEngineConfig::default(),
+        )
+    }
+
+    #[fixture]
+    fn engine_l1(audusd_sim: CurrencyPair, clock: &'static AtomicTime) -> OrderMatchingEngine {
+        engine(
+            audusd_sim,
+            BookType::L1_MBP,
+            OmsType::Netting,
+            clock,
+            Box::new(FillAtTouchModel),
+        )
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("1000000"),
+            Quantity::from("1000000"),
+            0,
+            0,
+        )
+        .unwrap()
+    }
+
+    fn order(
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        order_type: OrderType,
+        side: OrderSide,
+        price: Option<&str>,
+        trigger_price: Option<&str>,
+    ) -> OrderAny {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .order_type(order_type)
+            .order_side(side)
+            .quantity(Quantity::from("100000"))
+            .time_in_force(TimeInForce::Gtc)
+            .price(price.map(Price::from))
+            .trigger_price(trigger_price.map(Price::from))
+            .trigger_type(trigger_price.map(|_| TriggerType::Default))
+            .build()
+            .unwrap();
+        OrderAny::from(init)
+    }
+
+    fn event_names(events: &[OrderEvent]) -> Vec<&'static str> {
+        events
+            .iter()
+            .map(|event| match event {
+                OrderEvent::OrderAccepted(_) => "accepted",
+                OrderEvent::OrderRejected(_) => "rejected",
+                OrderEvent::OrderCanceled(_) => "canceled",
+                OrderEvent::OrderExpired(_) => "expired",
+                OrderEvent::OrderTriggered(_) => "triggered",
+                OrderEvent::OrderUpdated(_) => "updated",
+                OrderEvent::OrderFilled(_) => "filled",
+                OrderEvent::OrderModifyRejected(_) => "modify_rejected",
+                OrderEvent::OrderCancelRejected(_) => "cancel_rejected",
+                _ => "other",
+            })
+            .collect()
+    }
+
+    fn last_fill(events: &[OrderEvent]) -> OrderFilled {
+        events
+            .iter()
+            .rev()
+            .find_map(|event| match event {
+                OrderEvent::OrderFilled(fill) => Some(*fill),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_market_order_with_no_market_rejected(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            None,
+        );
+
+        engine_l1.process_order(order);
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["rejected"]);
+        assert!(engine_l1.get_open_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_market_order_fills_at_top_of_book(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            None,
+        );
+
+        engine_l1.process_order(order);
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["accepted", "filled"]);
+        let fill = last_fill(&events);
+        assert_eq!(fill.last_px, Price::from("1.00010"));
+        assert_eq!(fill.last_qty, Quantity::from("100000"));
+        assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+        assert_eq!(fill.venue_order_id, VenueOrderId::from("SIM-1-001"));
+        assert_eq!(fill.trade_id, TradeId::from("SIM-1-001"));
+    }
+
+    #[rstest]
+    fn test_limit_order_rests_then_fills_at_touch(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("0.99990"),
+            None,
+        );
+
+        engine_l1.process_order(order);
+        assert_eq!(event_names(&engine_l1.drain_events()), vec!["accepted"]);
+        assert_eq!(engine_l1.get_open_orders().len(), 1);
+
+        engine_l1.process_quote_tick(&quote(instrument_id, "0.99980", "0.99990"));
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        let fill = last_fill(&events);
+        assert_eq!(fill.last_px, Price::from("0.99990"));
+        assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+        assert!(engine_l1.get_open_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_limit_order_at_touch_fills_up_to_available_size(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("0.99990"),
+            None,
+        );
+        engine_l1.process_order(order);
+        engine_l1.drain_events();
+
+        engine_l1.process_quote_tick(
+            &QuoteTick::new(
+                instrument_id,
+                Price::from("0.99980"),
+                Price::from("0.99990"),
+                Quantity::from("1000000"),
+                Quantity::from("40000"),
+                0,
+                0,
+            )
+            .unwrap(),
+        );
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        assert_eq!(last_fill(&events).last_qty, Quantity::from("40000"));
+        assert_eq!(engine_l1.get_open_orders().len(), 1);
+    }
+
+    #[rstest]
+    fn test_marketable_limit_order_fills_as_taker(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Limit,
+            OrderSide::Sell,
+            Some("0.99990"),
+            None,
+        );
+
+        engine_l1.process_order(order);
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["accepted", "filled"]);
+        let fill = last_fill(&events);
+        assert_eq!(fill.last_px, Price::from("1.00000"));
+        assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+    }
+
+    #[rstest]
+    fn test_post_only_limit_order_rejected_when_marketable(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_type(OrderType::Limit)
+            .order_side(OrderSide::Buy)
+            .price(Some(Price::from("1.00010")))
+            .post_only(true)
+            .build()
+            .unwrap();
+
+        engine_l1.process_order(OrderAny::from(init));
+
+        assert_eq!(event_names(&engine_l1.drain_events()), vec!["rejected"]);
+    }
+
+    #[rstest]
+    fn test_ioc_limit_order_canceled_when_not_marketable(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_type(OrderType::Limit)
+            .order_side(OrderSide::Buy)
+            .price(Some(Price::from("0.99000")))
+            .time_in_force(TimeInForce::Ioc)
+            .build()
+            .unwrap();
+
+        engine_l1.process_order(OrderAny::from(init));
+
+        assert_eq!(
+            event_names(&engine_l1.drain_events()),
+            vec!["accepted", "canceled"]
+        );
+    }
+
+    #[rstest]
+    fn test_stop_market_order_in_market_rejected(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::StopMarket,
+            OrderSide::Buy,
+            None,
+            Some("1.00000"),
+        );
+
+        engine_l1.process_order(order);
+
+        assert_eq!(event_names(&engine_l1.drain_events()), vec!["rejected"]);
+    }
+
+    #[rstest]
+    fn test_stop_market_order_triggers_and_fills(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::StopMarket,
+            OrderSide::Buy,
+            None,
+            Some("1.00050"),
+        );
+        engine_l1.process_order(order);
+        engine_l1.drain_events();
+
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00050", "1.00060"));
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        assert_eq!(last_fill(&events).last_px, Price::from("1.00060"));
+    }
+
+    #[rstest]
+    fn test_stop_limit_order_triggers_then_rests(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::StopLimit,
+            OrderSide::Sell,
+            Some("0.99990"),
+            Some("0.99980"),
+        );
+        engine_l1.process_order(order);
+        engine_l1.drain_events();
+
+        engine_l1.process_quote_tick(&quote(instrument_id, "0.99970", "0.99980"));
+        assert_eq!(event_names(&engine_l1.drain_events()), vec!["triggered"]);
+
+        engine_l1.process_quote_tick(&quote(instrument_id, "0.99990", "1.00000"));
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        assert_eq!(last_fill(&events).last_px, Price::from("0.99990"));
+    }
+
+    #[rstest]
+    fn test_market_if_touched_order_fills_on_touch(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::MarketIfTouched,
+            OrderSide::Buy,
+            None,
+            Some("0.99950"),
+        );
+        engine_l1.process_order(order);
+        assert_eq!(event_names(&engine_l1.drain_events()), vec!["accepted"]);
+
+        engine_l1.process_quote_tick(&quote(instrument_id, "0.99940", "0.99950"));
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        assert_eq!(last_fill(&events).last_px, Price::from("0.99950"));
+    }
+
+    #[rstest]
+    fn test_limit_if_touched_order_triggers_then_fills(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::LimitIfTouched,
+            OrderSide::Sell,
+            Some("1.00040"),
+            Some("1.00050"),
+        );
+        engine_l1.process_order(order);
+        engine_l1.drain_events();
+
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00050", "1.00060"));
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["triggered", "filled"]);
+        assert_eq!(last_fill(&events).last_px, Price::from("1.00050"));
+        assert_eq!(last_fill(&events).liquidity_side, LiquiditySide::Taker);
+    }
+
+    #[rstest]
+    fn test_trailing_stop_market_order_trails_then_fills(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_type(OrderType::TrailingStopMarket)
+            .order_side(OrderSide::Sell)
+            .trigger_price(Some(Price::from("0.99900")))
+            .trigger_type(Some(TriggerType::Default))
+            .trailing_offset(Some(Price::from("0.00050")))
+            .trailing_offset_type(Some(TrailingOffsetType::Price))
+            .build()
+            .unwrap();
+        engine_l1.process_order(OrderAny::from(init));
+        assert_eq!(
+            event_names(&engine_l1.drain_events()),
+            vec!["accepted", "updated"]
+        );
+
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00100", "1.00110"));
+        let order = engine_l1.get_open_orders()[0];
+        assert_eq!(order.trigger_price(), Some(Price::from("1.00050")));
+        engine_l1.drain_events();
+
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00040", "1.00050"));
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        assert_eq!(last_fill(&events).last_px, Price::from("1.00040"));
+    }
+
+    #[rstest]
+    fn test_market_to_limit_order_rests_remainder_at_fill_price(
+        audusd_sim: CurrencyPair,
+        clock: &'static AtomicTime,
+    ) {
+        let mut engine = engine(
+            audusd_sim,
+            BookType::L2_MBP,
+            OmsType::Netting,
+            clock,
+            Box::new(FillAtTouchModel),
+        );
+        let instrument_id = engine.instrument_id();
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1.00010"),
+            Quantity::from("40000"),
+            1,
+        );
+        engine.process_order_book_delta(OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            ask,
+            0,
+            1,
+            0,
+            0,
+        ));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::MarketToLimit,
+            OrderSide::Buy,
+            None,
+            None,
+        );
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(event_names(&events), vec!["accepted", "filled", "updated"]);
+        let order = engine.get_order(&ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.price(), Some(Price::from("1.00010")));
+        assert_eq!(order.leaves_qty(), Quantity::from("60000"));
+    }
+
+    #[rstest]
+    fn test_market_order_sweeps_l2_book(audusd_sim: CurrencyPair, clock: &'static AtomicTime) {
+        let mut engine = engine(
+            audusd_sim,
+            BookType::L2_MBP,
+            OmsType::Hedging,
+            clock,
+            Box::new(FillAtTouchModel),
+        );
+        let instrument_id = engine.instrument_id();
+        for (i, (price, size)) in [("1.00010", "40000"), ("1.00020", "100000")]
+            .into_iter()
+            .enumerate()
+        {
+            let ask = BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from(size),
+                i as u64,
+            );
+            engine.process_order_book_delta(OrderBookDelta::new(
+                instrument_id,
+                BookAction::Add,
+                ask,
+                0,
+                i as u64,
+                0,
+                0,
+            ));
+        }
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            None,
+        );
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(event_names(&events), vec!["accepted", "filled", "filled"]);
+        let fill = last_fill(&events);
+        assert_eq!(fill.last_px, Price::from("1.00020"));
+        assert_eq!(fill.last_qty, Quantity::from("60000"));
+        assert_eq!(fill.position_id, Some(PositionId::from("SIM-1-001")));
+    }
+
+    #[rstest]
+    fn test_gtd_order_expires(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_type(OrderType::Limit)
+            .order_side(OrderSide::Buy)
+            .price(Some(Price::from("0.99000")))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(Some(1_000))
+            .build()
+            .unwrap();
+        engine_l1.process_order(OrderAny::from(init));
+        engine_l1.drain_events();
+
+        engine_l1.clock.set_time(1_000);
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+
+        assert_eq!(event_names(&engine_l1.drain_events()), vec!["expired"]);
+        assert!(engine_l1.get_open_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_modify_and_cancel_order(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("0.99900"),
+            None,
+        );
+        engine_l1.process_order(order);
+        engine_l1.drain_events();
+        let client_id = ClientId::from("SIM");
+        let client_order_id = ClientOrderId::from("O-1");
+
+        let modify = ModifyOrder::new(
+            TraderId::default(),
+            client_id,
+            StrategyId::default(),
+            instrument_id,
+            client_order_id,
+            None,
+            Some(Quantity::from("50000")),
+            Some(Price::from("0.99950")),
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        engine_l1.process_modify(&modify);
+
+        assert_eq!(event_names(&engine_l1.drain_events()), vec!["updated"]);
+        let order = engine_l1.get_order(&client_order_id).unwrap();
+        assert_eq!(order.quantity(), Quantity::from("50000"));
+        assert_eq!(order.price(), Some(Price::from("0.99950")));
+
+        let cancel = CancelOrder::new(
+            TraderId::default(),
+            client_id,
+            StrategyId::default(),
+            instrument_id,
+            client_order_id,
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        engine_l1.process_cancel(&cancel);
+        engine_l1.process_cancel(&cancel);
+
+        assert_eq!(
+            event_names(&engine_l1.drain_events()),
+            vec!["canceled", "cancel_rejected"]
+        );
+    }
+
+    #[rstest]
+    fn test_process_trade_bar_fills_resting_order(mut engine_l1: OrderMatchingEngine) {
+        let instrument_id = engine_l1.instrument_id();
+        engine_l1.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("0.99900"),
+            None,
+        );
+        engine_l1.process_order(order);
+        engine_l1.drain_events();
+        let bar = Bar::new(
+            BarType::from("AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL"),
+            Price::from("1.00000"),
+            Price::from("1.00020"),
+            Price::from("0.99880"),
+            Price::from("0.99950"),
+            Quantity::from("400000"),
+            0,
+            0,
+        );
+
+        engine_l1.process_bar(&bar);
+
+        let events = engine_l1.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        assert_eq!(last_fill(&events).last_px, Price::from("0.99900"));
+    }
+
+    #[rstest]
+    fn test_resting_order_not_filled_at_touch_with_zero_fill_probability(
+        audusd_sim: CurrencyPair,
+        clock: &'static AtomicTime,
+    ) {
+        let fill_model = ProbabilisticFillModel::new(0.0, 0.0, Some(42)).unwrap();
+        let mut engine = engine(
+            audusd_sim,
+            BookType::L1_MBP,
+            OmsType::Netting,
+            clock,
+            Box::new(fill_model),
+        );
+        let instrument_id = engine.instrument_id();
+        engine.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("0.99990"),
+            None,
+        );
+        engine.process_order(order);
+        engine.drain_events();
+
+        engine.process_quote_tick(&quote(instrument_id, "0.99980", "0.99990"));
+        assert!(engine.drain_events().is_empty());
+
+        engine.process_quote_tick(&quote(instrument_id, "0.99970", "0.99980"));
+        let events = engine.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        assert_eq!(last_fill(&events).last_px, Price::from("0.99990"));
+    }
+
+    #[rstest]
+    fn test_market_order_slippage(audusd_sim: CurrencyPair, clock: &'static AtomicTime) {
+        let fill_model = ProbabilisticFillModel::new(1.0, 1.0, Some(42)).unwrap();
+        let mut engine = engine(
+            audusd_sim,
+            BookType::L1_MBP,
+            OmsType::Netting,
+            clock,
+            Box::new(fill_model),
+        );
+        let instrument_id = engine.instrument_id();
+        engine.process_quote_tick(&quote(instrument_id, "1.00000", "1.00010"));
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            None,
+        );
+
+        engine.process_order(order);
+
+        let events = engine.drain_events();
+        assert_eq!(last_fill(&events).last_px, Price::from("1.00011"));
+    }
+
+    #[rstest]
+    fn test_queue_position_with_mbo_book(audusd_sim: CurrencyPair, clock: &'static AtomicTime) {
+        let mut engine = engine(
+            audusd_sim,
+            BookType::L3_MBO,
+            OmsType::Netting,
+            clock,
+            Box::new(QueuePositionFillModel::new()),
+        );
+        let instrument_id = engine.instrument_id();
+        let bid = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from("50000"),
+            1,
+        );
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1.00010"),
+            Quantity::from("50000"),
+            2,
+        );
+        for (sequence, book_order) in [(1, bid), (2, ask)] {
+            engine.process_order_book_delta(OrderBookDelta::new(
+                instrument_id,
+                BookAction::Add,
+                book_order,
+                0,
+                sequence,
+                0,
+                0,
+            ));
+        }
+        let order = order(
+            instrument_id,
+            "O-1",
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("1.00000"),
+            None,
+        );
+        engine.process_order(order);
+        engine.drain_events();
+        let trade = TradeTick::new(
+            instrument_id,
+            Price::from("1.00000"),
+            Quantity::from("10000"),
+            AggressorSide::Seller,
+            TradeId::from("1"),
+            0,
+            0,
+        );
+
+        // The order is still queued behind the resting bid
+        engine.process_trade_tick(&trade);
+        assert!(engine.drain_events().is_empty());
+
+        engine.process_order_book_delta(OrderBookDelta::new(
+            instrument_id,
+            BookAction::Delete,
+            bid,
+            0,
+            3,
+            0,
+            0,
+        ));
+        engine.process_trade_tick(&trade);
+
+        let events = engine.drain_events();
+        assert_eq!(event_names(&events), vec!["filled"]);
+        assert_eq!(last_fill(&events).last_px, Price::from("1.00000"));
+        assert_eq!(last_fill(&events).liquidity_side, LiquiditySide::Maker);
+    }
+}
@@ -0,0 +1,281 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use nautilus_core::correctness::check_f64_in_range_inclusive;
+use nautilus_model::{
+    data::order::{BookOrder, OrderId},
+    identifiers::client_order_id::ClientOrderId,
+    orderbook::any::OrderBookAny,
+    orders::any::OrderAny,
+    types::{price::Price, quantity::Quantity},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Models how simulated orders are filled by an `OrderMatchingEngine`.
+///
+/// A fill model decides whether a resting order fills when the market touches
+/// (but does not trade through) its price, which allows modelling queue position,
+/// and whether aggressive fills slip.
+pub trait FillModel {
+    /// Returns whether the given resting `order` fills with the market at its limit price.
+    fn is_limit_filled(&mut self, order: &OrderAny, book: &OrderBookAny) -> bool;
+
+    /// Returns whether an aggressive fill from a top-of-book only market slips by one tick.
+    fn is_slipped(&mut self) -> bool {
+        false
+    }
+
+    /// Simulates the fills for an aggressive `order` against the given `book`.
+    fn simulate_fills(&mut self, book: &OrderBookAny, order: &BookOrder) -> Vec<(Price, Quantity)> {
+        book.simulate_fills(order)
+    }
+
+    /// Called when the given `order` starts resting at its limit price (or is re-priced).
+    fn on_order_resting(&mut self, _order: &OrderAny, _book: &OrderBookAny) {}
+
+    /// Called when the order with the given `client_order_id` is closed.
+    fn on_order_closed(&mut self, _client_order_id: &ClientOrderId) {}
+}
+
+/// Provides a fill model where resting orders always fill when the market touches
+/// their price, with no slippage.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FillAtTouchModel;
+
+impl FillModel for FillAtTouchModel {
+    fn is_limit_filled(&mut self, _order: &OrderAny, _book: &OrderBookAny) -> bool {
+        true
+    }
+}
+
+/// Provides a fill model where resting orders fill at touch, and aggressive fills
+/// slip, with the configured probabilities.
+#[derive(Clone, Debug)]
+pub struct ProbabilisticFillModel {
+    /// The probability of a resting order filling when the market touches its price.
+    pub prob_fill_on_limit: f64,
+    /// The probability of an aggressive fill slipping by one tick.
+    pub prob_slippage: f64,
+    rng: StdRng,
+}
+
+impl ProbabilisticFillModel {
+    /// Initializes a new [`ProbabilisticFillModel`] instance.
+    ///
+    /// A `random_seed` makes the sequence of fill decisions reproducible.
+    pub fn new(
+        prob_fill_on_limit: f64,
+        prob_slippage: f64,
+        random_seed: Option<u64>,
+    ) -> Result<Self> {
+        check_f64_in_range_inclusive(prob_fill_on_limit, 0.0, 1.0, "prob_fill_on_limit")?;
+        check_f64_in_range_inclusive(prob_slippage, 0.0, 1.0, "prob_slippage")?;
+
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Ok(Self {
+            prob_fill_on_limit,
+            prob_slippage,
+            rng,
+        })
+    }
+}
+
+impl FillModel for ProbabilisticFillModel {
+    fn is_limit_filled(&mut self, _order: &OrderAny, _book: &OrderBookAny) -> bool {
+        self.rng.gen_bool(self.prob_fill_on_limit)
+    }
+
+    fn is_slipped(&mut self) -> bool {
+        self.rng.gen_bool(self.prob_slippage)
+    }
+}
+
+/// Provides a fill model which tracks the queue position of resting orders in an
+/// `L3_MBO` book.
+///
+/// When an order starts resting, the book orders already queued at its price are
+/// recorded as being ahead of it. The order only fills at touch once all of those
+/// orders have left the book (filled or canceled). Orders resting in an MBP book
+/// have no queue to track, and fill at touch.
+#[derive(Clone, Debug, Default)]
+pub struct QueuePositionFillModel {
+    queues: HashMap<ClientOrderId, HashSet<OrderId>>,
+}
+
+impl QueuePositionFillModel {
+    /// Initializes a new [`QueuePositionFillModel`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the quantity still queued ahead of the given resting `order` (if tracked).
+    #[must_use]
+    pub fn queue_ahead(&self, order: &OrderAny, book: &OrderBookAny) -> Option<Quantity> {
+        let ahead = self.queues.get(&order.client_order_id())?;
+        let price = order.price()?;
+
+        let mut qty = Quantity::zero(order.quantity().precision);
+        if let Some(level) = book.level(order.side(), price) {
+            for book_order in level.get_orders() {
+                if ahead.contains(&book_order.order_id) {
+                    qty += book_order.size;
+                }
+            }
+        }
+
+        Some(qty)
+    }
+}
+
+impl FillModel for QueuePositionFillModel {
+    fn is_limit_filled(&mut self, order: &OrderAny, book: &OrderBookAny) -> bool {
+        self.queue_ahead(order, book)
+            .map_or(true, |qty| !qty.is_positive())
+    }
+
+    fn on_order_resting(&mut self, order: &OrderAny, book: &OrderBookAny) {
+        let ahead = match (order.price(), book) {
+            (Some(price), OrderBookAny::Mbo(_)) => book
+                .level(order.side(), price)
+                .map(|level| {
+                    level
+                        .get_orders()
+                        .iter()
+                        .map(|book_order| book_order.order_id)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => HashSet::new(),
+        };
+        self.queues.insert(order.client_order_id(), ahead);
+    }
+
+    fn on_order_closed(&mut self, client_order_id: &ClientOrderId) {
+        self.queues.remove(client_order_id);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{BookType, OrderSide, OrderType},
+        events::order::initialized::OrderInitializedBuilder,
+        identifiers::instrument_id::InstrumentId,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn limit_order(instrument_id: InstrumentId, price: &str) -> OrderAny {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_type(OrderType::Limit)
+            .order_side(OrderSide::Buy)
+            .price(Some(Price::from(price)))
+            .build()
+            .unwrap();
+        OrderAny::from(init)
+    }
+
+    #[rstest]
+    fn test_fill_at_touch_model() {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let book = OrderBookAny::new(instrument_id, BookType::L1_MBP);
+        let mut model = FillAtTouchModel;
+
+        assert!(model.is_limit_filled(&limit_order(instrument_id, "1.00000"), &book));
+        assert!(!model.is_slipped());
+    }
+
+    #[rstest]
+    #[case(0.0, false)]
+    #[case(1.0, true)]
+    fn test_probabilistic_model_bounds(#[case] prob: f64, #[case] expected: bool) {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let book = OrderBookAny::new(instrument_id, BookType::L1_MBP);
+        let mut model = ProbabilisticFillModel::new(prob, prob, Some(42)).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(
+                model.is_limit_filled(&limit_order(instrument_id, "1.00000"), &book),
+                expected
+            );
+            assert_eq!(model.is_slipped(), expected);
+        }
+    }
+
+    #[rstest]
+    fn test_probabilistic_model_is_reproducible_with_seed() {
+        let mut model1 = ProbabilisticFillModel::new(0.5, 0.5, Some(1)).unwrap();
+        let mut model2 = ProbabilisticFillModel::new(0.5, 0.5, Some(1)).unwrap();
+
+        let results1: Vec<bool> = (0..20).map(|_| model1.is_slipped()).collect();
+        let results2: Vec<bool> = (0..20).map(|_| model2.is_slipped()).collect();
+
+        assert_eq!(results1, results2);
+    }
+
+    #[rstest]
+    fn test_probabilistic_model_with_invalid_probability() {
+        assert!(ProbabilisticFillModel::new(1.5, 0.0, None).is_err());
+        assert!(ProbabilisticFillModel::new(0.0, -0.1, None).is_err());
+    }
+
+    #[rstest]
+    fn test_queue_position_model_fills_once_queue_ahead_depleted() {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let mut book = OrderBookAny::new(instrument_id, BookType::L3_MBO);
+        let price = Price::from("1.00000");
+        let ahead1 = BookOrder::new(OrderSide::Buy, price, Quantity::from("10"), 1);
+        let ahead2 = BookOrder::new(OrderSide::Buy, price, Quantity::from("20"), 2);
+        book.add(ahead1, 0, 1);
+        book.add(ahead2, 0, 2);
+        let order = limit_order(instrument_id, "1.00000");
+        let mut model = QueuePositionFillModel::new();
+
+        model.on_order_resting(&order, &book);
+        // Orders joining the queue later are behind the order
+        book.add(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from("50"), 3),
+            0,
+            3,
+        );
+
+        assert_eq!(model.queue_ahead(&order, &book), Some(Quantity::from("30")));
+        assert!(!model.is_limit_filled(&order, &book));
+
+        if let OrderBookAny::Mbo(mbo) = &mut book {
+            mbo.delete(ahead1, 0, 4);
+            mbo.delete(ahead2, 0, 5);
+        }
+
+        assert_eq!(model.queue_ahead(&order, &book), Some(Quantity::from("0")));
+        assert!(model.is_limit_filled(&order, &book));
+
+        model.on_order_closed(&order.client_order_id());
+        assert_eq!(model.queue_ahead(&order, &book), None);
+    }
+}
@@ -0,0 +1,186 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Models the latency between a client and a simulated venue.
+///
+/// Order commands reach the venue after the insert, modify or cancel latency for
+/// their command type, and events generated at the venue reach the client after the
+/// response latency. All latencies are in nanoseconds.
+pub trait LatencyModel {
+    /// Returns the latency for an order submission to reach the venue.
+    fn insert_latency_nanos(&mut self) -> u64;
+
+    /// Returns the latency for an order modification to reach the venue.
+    fn modify_latency_nanos(&mut self) -> u64;
+
+    /// Returns the latency for an order cancellation to reach the venue.
+    fn cancel_latency_nanos(&mut self) -> u64;
+
+    /// Returns the latency for an event generated at the venue to reach the client.
+    fn response_latency_nanos(&mut self) -> u64;
+}
+
+/// Provides a latency model with fixed latencies.
+///
+/// The base latency applies in both directions, with the insert, modify and cancel
+/// latencies added on top of it for each command type.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FixedLatencyModel {
+    /// The base latency (nanoseconds) between the client and the venue.
+    pub base_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order submissions.
+    pub insert_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order modifications.
+    pub modify_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order cancellations.
+    pub cancel_latency_nanos: u64,
+}
+
+impl FixedLatencyModel {
+    /// Initializes a new [`FixedLatencyModel`] instance.
+    #[must_use]
+    pub fn new(
+        base_latency_nanos: u64,
+        insert_latency_nanos: u64,
+        modify_latency_nanos: u64,
+        cancel_latency_nanos: u64,
+    ) -> Self {
+        Self {
+            base_latency_nanos,
+            insert_latency_nanos,
+            modify_latency_nanos,
+            cancel_latency_nanos,
+        }
+    }
+}
+
+impl LatencyModel for FixedLatencyModel {
+    fn insert_latency_nanos(&mut self) -> u64 {
+        self.base_latency_nanos + self.insert_latency_nanos
+    }
+
+    fn modify_latency_nanos(&mut self) -> u64 {
+        self.base_latency_nanos + self.modify_latency_nanos
+    }
+
+    fn cancel_latency_nanos(&mut self) -> u64 {
+        self.base_latency_nanos + self.cancel_latency_nanos
+    }
+
+    fn response_latency_nanos(&mut self) -> u64 {
+        self.base_latency_nanos
+    }
+}
+
+/// Provides a latency model where each latency is drawn from a uniform distribution.
+///
+/// Every latency is the corresponding [`FixedLatencyModel`] latency plus a jitter
+/// drawn uniformly from `[0, jitter_nanos]`.
+#[derive(Clone, Debug)]
+pub struct UniformLatencyModel {
+    /// The fixed part of the latencies.
+    pub fixed: FixedLatencyModel,
+    /// The maximum jitter (nanoseconds) added to each latency.
+    pub jitter_nanos: u64,
+    rng: StdRng,
+}
+
+impl UniformLatencyModel {
+    /// Initializes a new [`UniformLatencyModel`] instance.
+    ///
+    /// A `random_seed` makes the sequence of latencies reproducible.
+    #[must_use]
+    pub fn new(fixed: FixedLatencyModel, jitter_nanos: u64, random_seed: Option<u64>) -> Self {
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Self {
+            fixed,
+            jitter_nanos,
+            rng,
+        }
+    }
+
+    fn jitter(&mut self) -> u64 {
+        self.rng.gen_range(0..=self.jitter_nanos)
+    }
+}
+
+impl LatencyModel for UniformLatencyModel {
+    fn insert_latency_nanos(&mut self) -> u64 {
+        self.fixed.insert_latency_nanos() + self.jitter()
+    }
+
+    fn modify_latency_nanos(&mut self) -> u64 {
+        self.fixed.modify_latency_nanos() + self.jitter()
+    }
+
+    fn cancel_latency_nanos(&mut self) -> u64 {
+        self.fixed.cancel_latency_nanos() + self.jitter()
+    }
+
+    fn response_latency_nanos(&mut self) -> u64 {
+        self.fixed.response_latency_nanos() + self.jitter()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_fixed_latency_model() {
+        let mut model = FixedLatencyModel::new(1_000, 200, 300, 400);
+
+        assert_eq!(model.insert_latency_nanos(), 1_200);
+        assert_eq!(model.modify_latency_nanos(), 1_300);
+        assert_eq!(model.cancel_latency_nanos(), 1_400);
+        assert_eq!(model.response_latency_nanos(), 1_000);
+    }
+
+    #[rstest]
+    fn test_uniform_latency_model_within_bounds() {
+        let fixed = FixedLatencyModel::new(1_000, 200, 300, 400);
+        let mut model = UniformLatencyModel::new(fixed, 50, Some(42));
+
+        for _ in 0..100 {
+            assert!((1_200..=1_250).contains(&model.insert_latency_nanos()));
+            assert!((1_300..=1_350).contains(&model.modify_latency_nanos()));
+            assert!((1_400..=1_450).contains(&model.cancel_latency_nanos()));
+            assert!((1_000..=1_050).contains(&model.response_latency_nanos()));
+        }
+    }
+
+    #[rstest]
+    fn test_uniform_latency_model_is_reproducible_with_seed() {
+        let fixed = FixedLatencyModel::new(1_000, 0, 0, 0);
+        let mut model1 = UniformLatencyModel::new(fixed, 1_000, Some(1));
+        let mut model2 = UniformLatencyModel::new(fixed, 1_000, Some(1));
+
+        let latencies1: Vec<u64> = (0..20).map(|_| model1.insert_latency_nanos()).collect();
+        let latencies2: Vec<u64> = (0..20).map(|_| model2.insert_latency_nanos()).collect();
+
+        assert_eq!(latencies1, latencies2);
+    }
+}
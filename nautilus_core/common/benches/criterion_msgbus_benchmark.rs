@@ -0,0 +1,103 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use nautilus_common::{
+    handlers::MessageHandler,
+    msgbus::{is_matching, MessageBus},
+};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use ustr::Ustr;
+
+const NUM_INSTRUMENTS: usize = 2_000;
+
+/// The previous dynamic programming implementation of `is_matching`, as a baseline.
+fn is_matching_table(topic: &Ustr, pattern: &Ustr) -> bool {
+    let mut table = [[false; 256]; 256];
+    table[0][0] = true;
+
+    let m = pattern.len();
+    let n = topic.len();
+
+    pattern.chars().enumerate().for_each(|(j, c)| {
+        if c == '*' {
+            table[0][j + 1] = table[0][j];
+        }
+    });
+
+    topic.chars().enumerate().for_each(|(i, tc)| {
+        pattern.chars().enumerate().for_each(|(j, pc)| {
+            if pc == '*' {
+                table[i + 1][j + 1] = table[i][j + 1] || table[i + 1][j];
+            } else if pc == '?' || tc == pc {
+                table[i + 1][j + 1] = table[i][j];
+            }
+        });
+    });
+
+    table[n][m]
+}
+
+fn instrument_topics() -> Vec<Ustr> {
+    (0..NUM_INSTRUMENTS)
+        .map(|i| Ustr::from(&format!("data.quotes.BINANCE.SYM{i}USDT")))
+        .collect()
+}
+
+fn is_matching_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_matching");
+    let topic = Ustr::from("data.trades.BINANCE.ETHUSDT-PERP");
+    let pattern = Ustr::from("data.*.BINANCE.ETH*");
+
+    group.bench_function("table", |b| {
+        b.iter(|| is_matching_table(black_box(&topic), black_box(&pattern)));
+    });
+    group.bench_function("backtracking", |b| {
+        b.iter(|| is_matching(black_box(&topic), black_box(&pattern)));
+    });
+    group.finish();
+}
+
+fn publish_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("publish");
+    let topics = instrument_topics();
+    let topic = topics[NUM_INSTRUMENTS / 2];
+
+    // Resolving subscribers by scanning every subscription, as before the topic cache
+    group.bench_function("scan_table", |b| {
+        b.iter(|| {
+            topics
+                .iter()
+                .filter(|pattern| is_matching_table(black_box(&topic), pattern))
+                .count()
+        });
+    });
+
+    let mut msgbus =
+        MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap();
+    for (i, topic) in topics.iter().enumerate() {
+        let handler = MessageHandler::with_typed_callback(Ustr::from(&i.to_string()), |_: &u64| {});
+        msgbus.subscribe(topic, handler, None);
+    }
+
+    group.bench_function("cached", |b| {
+        b.iter(|| msgbus.publish(black_box(topic.as_str()), &1_u64));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, is_matching_bench, publish_bench);
+criterion_main!(benches);
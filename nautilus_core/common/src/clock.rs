@@ -113,6 +113,9 @@ impl TestClock {
     }
 
     /// Assumes time events are sorted by their `ts_event`.
+    ///
+    /// Handlers without a Python callback are matched with a null `callback_ptr`,
+    /// and are expected to be dispatched by the owner of the clock using the event name.
     #[must_use]
     pub fn match_handlers(&self, events: Vec<TimeEvent>) -> Vec<TimeEventHandler> {
         events
@@ -123,9 +126,13 @@ impl TestClock {
                     // TODO: clone for now
                     self.default_callback.clone().unwrap()
                 });
+                let callback_ptr = match handler.py_callback {
+                    Some(_) => handler.as_ptr(),
+                    None => std::ptr::null_mut(),
+                };
                 TimeEventHandler {
                     event,
-                    callback_ptr: handler.as_ptr(),
+                    callback_ptr,
                 }
             })
             .collect()
@@ -511,4 +518,17 @@ mod tests {
             assert_eq!(test_clock.get_time_ns(), 0);
         });
     }
+
+    #[rstest]
+    fn test_match_handlers_without_py_callback(mut test_clock: TestClock) {
+        test_clock.register_default_handler(EventHandler::new(None, None));
+        test_clock.set_time_alert_ns("TEST_ALERT", 5, None);
+
+        let events = test_clock.advance_time(5, true);
+        let handlers = test_clock.match_handlers(events);
+
+        assert_eq!(handlers.len(), 1);
+        assert_eq!(handlers[0].event.name, Ustr::from("TEST_ALERT"));
+        assert!(handlers[0].callback_ptr.is_null());
+    }
 }
@@ -80,12 +80,9 @@ pub unsafe extern "C" fn msgbus_new(
     let name = optional_cstr_to_str(name_ptr).map(|s| s.to_string());
     let instance_id = UUID4::from(cstr_to_str(instance_id_ptr));
     let config = optional_bytes_to_json(config_ptr);
-    MessageBus_API(Box::new(MessageBus::new(
-        trader_id,
-        instance_id,
-        name,
-        config,
-    )))
+    let msgbus = MessageBus::new(trader_id, instance_id, name, config)
+        .unwrap_or_else(|e| panic!("Error creating `MessageBus`: {e}"));
+    MessageBus_API(Box::new(msgbus))
 }
 
 #[no_mangle]
@@ -235,6 +232,13 @@ pub extern "C" fn msgbus_pub_count(bus: &MessageBus_API) -> u64 {
     bus.pub_count
 }
 
+/// Publishes the messages consumed from external streams since the last call, returning
+/// the number of messages published.
+#[no_mangle]
+pub extern "C" fn msgbus_process_external_messages(bus: &mut MessageBus_API) -> u64 {
+    bus.process_external_messages() as u64
+}
+
 /// # Safety
 ///
 /// - Assumes `endpoint_ptr` is a valid C string pointer.
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{fmt, sync::Arc};
+use std::{any::Any, fmt, sync::Arc};
 
 use nautilus_core::message::Message;
 use pyo3::{ffi, prelude::*};
@@ -42,6 +42,14 @@ pub struct SafeMessageCallback {
 unsafe impl Send for SafeMessageCallback {}
 unsafe impl Sync for SafeMessageCallback {}
 
+#[derive(Clone)]
+pub struct SafeAnyMessageCallback {
+    pub callback: Arc<dyn Fn(&dyn Any) + Send>,
+}
+
+unsafe impl Send for SafeAnyMessageCallback {}
+unsafe impl Sync for SafeAnyMessageCallback {}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct SafeTimeEventCallback {
@@ -59,7 +67,8 @@ unsafe impl Sync for SafeTimeEventCallback {}
 )]
 pub struct MessageHandler {
     pub handler_id: Ustr,
-    _callback: Option<SafeMessageCallback>,
+    callback: Option<SafeMessageCallback>,
+    any_callback: Option<SafeAnyMessageCallback>,
 }
 
 impl MessageHandler {
@@ -68,7 +77,49 @@ impl MessageHandler {
     pub fn new(handler_id: Ustr, callback: Option<SafeMessageCallback>) -> Self {
         Self {
             handler_id,
-            _callback: callback,
+            callback,
+            any_callback: None,
+        }
+    }
+
+    /// Initializes a new handler for messages of any type published from Rust.
+    #[must_use]
+    pub fn with_any_callback(handler_id: Ustr, callback: SafeAnyMessageCallback) -> Self {
+        Self {
+            handler_id,
+            callback: None,
+            any_callback: Some(callback),
+        }
+    }
+
+    /// Initializes a new handler for messages of type `T` published from Rust.
+    ///
+    /// Messages of any other type are ignored by the handler.
+    #[must_use]
+    pub fn with_typed_callback<T: 'static>(
+        handler_id: Ustr,
+        callback: impl Fn(&T) + Send + 'static,
+    ) -> Self {
+        let callback = SafeAnyMessageCallback {
+            callback: Arc::new(move |message: &dyn Any| {
+                if let Some(message) = message.downcast_ref::<T>() {
+                    callback(message);
+                }
+            }),
+        };
+        Self::with_any_callback(handler_id, callback)
+    }
+
+    /// Handles the given `message` with the Rust callback (if any).
+    ///
+    /// A handler with a [`Message`] callback only handles messages of that type.
+    pub fn handle(&self, message: &dyn Any) {
+        if let Some(any_callback) = &self.any_callback {
+            (any_callback.callback)(message);
+        } else if let (Some(callback), Some(message)) =
+            (&self.callback, message.downcast_ref::<Message>())
+        {
+            (callback.callback)(message.clone());
         }
     }
 }
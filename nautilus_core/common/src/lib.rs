@@ -22,6 +22,7 @@ pub mod logging;
 pub mod msgbus;
 pub mod testing;
 pub mod timer;
+pub mod transport;
 
 #[cfg(feature = "stubs")]
 pub mod stubs;
@@ -14,20 +14,31 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    cell::RefCell,
     collections::HashMap,
     fmt,
     hash::{Hash, Hasher},
-    sync::mpsc::{channel, Receiver, SendError, Sender},
+    sync::{
+        mpsc::{channel, Receiver, SendError, Sender, TryRecvError},
+        Arc, Mutex,
+    },
     thread,
 };
 
+use anyhow::{bail, Result};
 use indexmap::IndexMap;
 use nautilus_core::uuid::UUID4;
 use nautilus_model::identifiers::trader_id::TraderId;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use crate::{handlers::MessageHandler, redis::handle_messages_with_redis};
+use crate::{
+    handlers::MessageHandler,
+    redis::{
+        consume_messages_with_redis, get_buffer_interval, get_external_streams, RedisTransport,
+    },
+    transport::{create_transport, handle_messages_with_transport, MessageBusTransport},
+};
 
 // Represents a subscription to a particular topic.
 //
@@ -97,6 +108,22 @@ pub struct BusMessage {
     pub payload: Vec<u8>,
 }
 
+/// Represents a message consumed from an external stream.
+///
+/// The message is acknowledged by sending it back to the consumer once published on the
+/// bus, so the consumer only checkpoints messages which have been handled.
+#[derive(Clone, Debug)]
+pub struct ExternalMessage {
+    /// The topic to publish on.
+    pub topic: String,
+    /// The encoded payload for the message (`None` for entries with no payload).
+    pub payload: Option<Vec<u8>>,
+    /// The key of the stream the message was consumed from.
+    pub stream: String,
+    /// The ID of the message in its stream.
+    pub id: String,
+}
+
 impl fmt::Display for BusMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -128,6 +155,12 @@ impl fmt::Display for BusMessage {
 /// A question mark matches a single character once. For example, `c?mp` matches
 /// `camp` and `comp`. The question mark can also be used more than once.
 /// For example, `c??p` would match both of the above examples and `coop`.
+///
+/// Messages published or sent from Rust are delivered synchronously to the Rust
+/// callbacks of handlers (typed handlers ignore messages of other types). When the
+/// bus is shared through a [`RefCell`], the [`publish`], [`send`], [`request`] and
+/// [`response`] functions release the borrow of the bus before calling the handlers,
+/// so handlers may themselves use the bus.
 #[derive(Clone)]
 pub struct MessageBus {
     /// The trader ID associated with the message bus.
@@ -147,14 +180,20 @@ pub struct MessageBus {
     /// If the message bus is backed by a database.
     pub has_backing: bool,
     tx: Option<Sender<BusMessage>>,
+    /// Receives the messages consumed from external streams.
+    external_rx: Option<Arc<Mutex<Receiver<ExternalMessage>>>>,
+    /// Acknowledges the external messages published back to their consumer.
+    external_ack_tx: Option<Sender<ExternalMessage>>,
+    /// The encoding of external message payloads (`msgpack` or `json`).
+    encoding: String,
     /// mapping from topic to the corresponding handler
     /// a topic can be a string with wildcards
     /// * '?' - any character
     /// * '*' - any number of any characters
     subscriptions: IndexMap<Subscription, Vec<Ustr>>,
-    /// maps a pattern to all the handlers registered for it
-    /// this is updated whenever a new subscription is created.
-    patterns: IndexMap<Ustr, Vec<Subscription>>,
+    /// Caches the subscriptions matching each published topic (in priority order),
+    /// which is updated whenever a subscription is created or removed.
+    topic_cache: IndexMap<Ustr, Vec<Subscription>>,
     /// handles a message or a request destined for a specific endpoint.
     endpoints: IndexMap<Ustr, MessageHandler>,
     /// Relates a request with a response
@@ -165,29 +204,57 @@ pub struct MessageBus {
 
 impl MessageBus {
     /// Initializes a new instance of the [`MessageBus`].
-    #[must_use]
+    ///
+    /// With a backing database in the `config`, its transport is created (and external
+    /// streams are validated) before the threads handling messages are started, returning
+    /// any error.
     pub fn new(
         trader_id: TraderId,
         instance_id: UUID4,
         name: Option<String>,
         config: Option<HashMap<String, serde_json::Value>>,
-    ) -> Self {
+    ) -> Result<Self> {
         let config = config.unwrap_or_default();
         let has_backing = config
             .get("database")
             .map_or(false, |v| v != &serde_json::Value::Null);
+        let encoding = get_encoding(&config);
+        let has_external_streams = has_backing && !get_external_streams(&config).is_empty();
+        if has_external_streams {
+            match get_backing_type(&config)?.as_str() {
+                "redis" => {}
+                other => bail!("Cannot consume external streams from a '{other}' database"),
+            }
+        }
+
         let tx = if has_backing {
+            let mut transport = Self::create_transport(trader_id, instance_id, &config)?;
+            let buffer_interval = get_buffer_interval(&config);
             let (tx, rx) = channel::<BusMessage>();
             thread::spawn(move || {
-                Self::handle_messages(rx, trader_id, instance_id, config);
+                handle_messages_with_transport(rx, transport.as_mut(), buffer_interval);
             });
             Some(tx)
         } else {
             None
         };
 
-        Self {
+        let (external_rx, external_ack_tx) = if has_external_streams {
+            let (tx, rx) = channel::<ExternalMessage>();
+            let (ack_tx, ack_rx) = channel::<ExternalMessage>();
+            thread::spawn(move || {
+                consume_messages_with_redis(tx, ack_rx, trader_id, config);
+            });
+            (Some(Arc::new(Mutex::new(rx))), Some(ack_tx))
+        } else {
+            (None, None)
+        };
+
+        Ok(Self {
             tx,
+            external_rx,
+            external_ack_tx,
+            encoding,
             trader_id,
             instance_id,
             name: name.unwrap_or_else(|| stringify!(MessageBus).to_owned()),
@@ -196,11 +263,11 @@ impl MessageBus {
             res_count: 0,
             pub_count: 0,
             subscriptions: IndexMap::new(),
-            patterns: IndexMap::new(),
+            topic_cache: IndexMap::new(),
             endpoints: IndexMap::new(),
             correlation_index: IndexMap::new(),
             has_backing,
-        }
+        })
     }
 
     /// Returns the registered endpoint addresses.
@@ -289,26 +356,29 @@ impl MessageBus {
             return;
         }
 
-        // Find existing patterns which match this topic
+        // Add to the cached topics which match the subscription
         let mut matches = Vec::new();
-        for (pattern, subs) in &mut self.patterns {
-            if is_matching(&topic, pattern) {
+        for (cached_topic, subs) in &mut self.topic_cache {
+            if is_matching(cached_topic, &topic) {
                 subs.push(sub.clone());
                 subs.sort();
-                // subs.sort_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.cmp(b)));
-                matches.push(*pattern);
+                matches.push(*cached_topic);
             }
         }
 
-        matches.sort();
-
         self.subscriptions.insert(sub, matches);
     }
 
     /// Unsubscribes the given `handler` from the `topic`.
     pub fn unsubscribe(&mut self, topic: &str, handler: MessageHandler) {
         let sub = Subscription::new(Ustr::from(topic), handler, self.subscriptions.len(), None);
-        self.subscriptions.shift_remove(&sub);
+        if let Some(matches) = self.subscriptions.shift_remove(&sub) {
+            for cached_topic in matches {
+                if let Some(subs) = self.topic_cache.get_mut(&cached_topic) {
+                    subs.retain(|s| s != &sub);
+                }
+            }
+        }
     }
 
     /// Returns the handler for the given `endpoint`.
@@ -347,36 +417,40 @@ impl MessageBus {
         self.correlation_index.shift_remove(correlation_id)
     }
 
+    /// Returns the subscriptions matching the given `topic`, in priority order.
     #[must_use]
-    pub fn matching_subscriptions<'a>(&'a self, pattern: &'a Ustr) -> Vec<&'a Subscription> {
-        let mut matching_subs: Vec<&'a Subscription> = Vec::new();
-
-        // Collect matching subscriptions from direct subscriptions
-        matching_subs.extend(self.subscriptions.iter().filter_map(|(sub, _)| {
-            if is_matching(&sub.topic, pattern) {
-                Some(sub)
-            } else {
-                None
-            }
-        }));
-
-        // Collect matching subscriptions from pattern-based subscriptions
-        // TODO: Improve efficiency of this
-        for subs in self.patterns.values() {
-            let filtered_subs: Vec<&Subscription> = subs
-                .iter()
-                // .filter(|sub| is_matching(&sub.topic, pattern))
-                // .filter(|sub| !matching_subs.contains(sub) && is_matching(&sub.topic, pattern))
-                .collect();
-
-            matching_subs.extend(filtered_subs);
+    pub fn matching_subscriptions<'a>(&'a self, topic: &'a Ustr) -> Vec<&'a Subscription> {
+        if let Some(subs) = self.topic_cache.get(topic) {
+            return subs.iter().collect();
         }
 
-        // Sort into priority order
+        let mut matching_subs: Vec<&'a Subscription> = self
+            .subscriptions
+            .keys()
+            .filter(|sub| is_matching(topic, &sub.topic))
+            .collect();
         matching_subs.sort();
         matching_subs
     }
 
+    /// Returns the subscriptions matching the given `topic`, in priority order, caching
+    /// the result for subsequent messages published on the topic.
+    fn resolve_subscriptions(&mut self, topic: Ustr) -> &[Subscription] {
+        if !self.topic_cache.contains_key(&topic) {
+            let mut subs: Vec<Subscription> = Vec::new();
+            for (sub, matches) in &mut self.subscriptions {
+                if is_matching(&topic, &sub.topic) {
+                    subs.push(sub.clone());
+                    matches.push(topic);
+                }
+            }
+            subs.sort();
+            self.topic_cache.insert(topic, subs);
+        }
+
+        &self.topic_cache[&topic]
+    }
+
     fn matching_handlers<'a>(
         &'a self,
         pattern: &'a Ustr,
@@ -390,6 +464,94 @@ impl MessageBus {
         })
     }
 
+    /// Returns the handler registered for the `endpoint` to send a message to, counting
+    /// the message as sent.
+    pub fn prepare_send(&mut self, endpoint: &str) -> Result<MessageHandler> {
+        let endpoint = Ustr::from(endpoint);
+        let Some(handler) = self.endpoints.get(&endpoint) else {
+            bail!("No handler registered for endpoint '{endpoint}'");
+        };
+
+        let handler = handler.clone();
+        self.sent_count += 1;
+        Ok(handler)
+    }
+
+    /// Returns the handler registered for the `endpoint` to send a request to, with the
+    /// `response_handler` receiving the response correlated with the `request_id`.
+    pub fn prepare_request(
+        &mut self,
+        endpoint: &str,
+        request_id: UUID4,
+        response_handler: MessageHandler,
+    ) -> Result<MessageHandler> {
+        let endpoint = Ustr::from(endpoint);
+        let Some(handler) = self.request_handler(&endpoint, request_id, response_handler) else {
+            bail!("No handler registered for endpoint '{endpoint}'");
+        };
+
+        let handler = handler.clone();
+        self.req_count += 1;
+        Ok(handler)
+    }
+
+    /// Returns the handler of the request with the `correlation_id` to send a response to.
+    pub fn prepare_response(&mut self, correlation_id: &UUID4) -> Result<MessageHandler> {
+        let Some(handler) = self.response_handler(correlation_id) else {
+            bail!("No pending request for correlation ID {correlation_id}");
+        };
+
+        self.res_count += 1;
+        Ok(handler)
+    }
+
+    /// Returns the handlers subscribed to patterns matching the `topic` to publish a
+    /// message to, in priority order (then subscription order).
+    pub fn prepare_publish(&mut self, topic: &str) -> Vec<MessageHandler> {
+        let handlers = self
+            .resolve_subscriptions(Ustr::from(topic))
+            .iter()
+            .map(|sub| sub.handler.clone())
+            .collect();
+        self.pub_count += 1;
+        handlers
+    }
+
+    /// Sends the given `message` to the handler registered for the `endpoint`.
+    pub fn send<T: 'static>(&mut self, endpoint: &str, message: &T) -> Result<()> {
+        self.prepare_send(endpoint)?.handle(message);
+        Ok(())
+    }
+
+    /// Sends the given request `message` to the handler registered for the `endpoint`,
+    /// with the `response_handler` receiving the response correlated with the `request_id`.
+    pub fn request<T: 'static>(
+        &mut self,
+        endpoint: &str,
+        request_id: UUID4,
+        message: &T,
+        response_handler: MessageHandler,
+    ) -> Result<()> {
+        self.prepare_request(endpoint, request_id, response_handler)?
+            .handle(message);
+        Ok(())
+    }
+
+    /// Sends the given response `message` to the handler of the request with the
+    /// `correlation_id`.
+    pub fn response<T: 'static>(&mut self, correlation_id: &UUID4, message: &T) -> Result<()> {
+        self.prepare_response(correlation_id)?.handle(message);
+        Ok(())
+    }
+
+    /// Publishes the given `message` to the handlers subscribed to patterns matching the
+    /// `topic`, in priority order (then subscription order).
+    pub fn publish<T: 'static>(&mut self, topic: &str, message: &T) {
+        for handler in self.prepare_publish(topic) {
+            handler.handle(message);
+        }
+    }
+
     pub fn publish_external(&self, topic: String, payload: Vec<u8>) {
         if let Some(tx) = &self.tx {
             let msg = BusMessage { topic, payload };
@@ -401,26 +563,191 @@ impl MessageBus {
         }
     }
 
-    fn handle_messages(
-        rx: Receiver<BusMessage>,
+    /// Publishes the messages consumed from external streams since the last call on their
+    /// topics (in the order consumed from each stream), with their payloads decoded as
+    /// [`serde_json::Value`]s.
+    ///
+    /// Each message is acknowledged to its consumer once published, or once it fails to
+    /// decode (it would never succeed). Returns the number of messages published.
+    pub fn process_external_messages(&mut self) -> usize {
+        let mut count = 0;
+        for msg in self.receive_external_messages() {
+            if let Some((handlers, value)) = self.prepare_external(&msg) {
+                for handler in handlers {
+                    handler.handle(&value);
+                }
+                count += 1;
+            }
+            self.acknowledge_external(msg);
+        }
+        count
+    }
+
+    /// Receives the messages consumed from external streams since the last call.
+    fn receive_external_messages(&self) -> Vec<ExternalMessage> {
+        let Some(external_rx) = &self.external_rx else {
+            return Vec::new();
+        };
+        let rx = external_rx
+            .lock()
+            .expect("Error acquiring external messages lock");
+
+        let mut messages = Vec::new();
+        loop {
+            match rx.try_recv() {
+                Ok(msg) => messages.push(msg),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    eprintln!("Error consuming external messages: consumer disconnected");
+                    break;
+                }
+            }
+        }
+        messages
+    }
+
+    /// Returns the handlers to publish the external `msg` to with its decoded payload,
+    /// or `None` if the payload fails to decode.
+    fn prepare_external(
+        &mut self,
+        msg: &ExternalMessage,
+    ) -> Option<(Vec<MessageHandler>, serde_json::Value)> {
+        match decode_payload(&self.encoding, msg.payload.as_deref()) {
+            Ok(value) => Some((self.prepare_publish(&msg.topic), value)),
+            Err(e) => {
+                eprintln!(
+                    "Error decoding external message {} from '{}': {e}",
+                    msg.id, msg.stream
+                );
+                None
+            }
+        }
+    }
+
+    fn acknowledge_external(&self, msg: ExternalMessage) {
+        if let Some(ack_tx) = &self.external_ack_tx {
+            // The consumer may have stopped, when there is nothing to acknowledge
+            let _ = ack_tx.send(msg);
+        }
+    }
+
+    /// Creates the transport for the backing database in the `config`.
+    fn create_transport(
         trader_id: TraderId,
         instance_id: UUID4,
-        config: HashMap<String, serde_json::Value>,
-    ) {
-        let database_config = config
-            .get("database")
-            .expect("No `MessageBusConfig` `database` config specified");
-        let backing_type = database_config
-            .get("type")
-            .expect("No `MessageBusConfig` database config `type` specified")
-            .as_str()
-            .expect("`MessageBusConfig` database `type` must be a valid string");
-
-        match backing_type {
-            "redis" => handle_messages_with_redis(rx, trader_id, instance_id, config),
-            other => panic!("Unsupported message bus backing database type '{other}'"),
+        config: &HashMap<String, serde_json::Value>,
+    ) -> Result<Box<dyn MessageBusTransport>> {
+        match get_backing_type(config)?.as_str() {
+            "redis" => Ok(Box::new(RedisTransport::new(
+                trader_id,
+                instance_id,
+                config,
+            )?)),
+            backing_type => create_transport(backing_type, config),
+        }
+    }
+}
+
+/// Publishes the given `message` on the shared `msgbus` (see [`MessageBus::publish`]).
+///
+/// The bus is not borrowed while the handlers are called.
+pub fn publish<T: 'static>(msgbus: &RefCell<MessageBus>, topic: &str, message: &T) {
+    let handlers = msgbus.borrow_mut().prepare_publish(topic);
+    for handler in handlers {
+        handler.handle(message);
+    }
+}
+
+/// Sends the given `message` on the shared `msgbus` (see [`MessageBus::send`]).
+///
+/// The bus is not borrowed while the handler is called.
+pub fn send<T: 'static>(msgbus: &RefCell<MessageBus>, endpoint: &str, message: &T) -> Result<()> {
+    let handler = msgbus.borrow_mut().prepare_send(endpoint)?;
+    handler.handle(message);
+    Ok(())
+}
+
+/// Sends the given request `message` on the shared `msgbus` (see [`MessageBus::request`]).
+///
+/// The bus is not borrowed while the handler is called.
+pub fn request<T: 'static>(
+    msgbus: &RefCell<MessageBus>,
+    endpoint: &str,
+    request_id: UUID4,
+    message: &T,
+    response_handler: MessageHandler,
+) -> Result<()> {
+    let handler = msgbus
+        .borrow_mut()
+        .prepare_request(endpoint, request_id, response_handler)?;
+    handler.handle(message);
+    Ok(())
+}
+
+/// Sends the given response `message` on the shared `msgbus` (see [`MessageBus::response`]).
+///
+/// The bus is not borrowed while the handler is called.
+pub fn response<T: 'static>(
+    msgbus: &RefCell<MessageBus>,
+    correlation_id: &UUID4,
+    message: &T,
+) -> Result<()> {
+    let handler = msgbus.borrow_mut().prepare_response(correlation_id)?;
+    handler.handle(message);
+    Ok(())
+}
+
+/// Publishes the messages consumed from external streams on the shared `msgbus` (see
+/// [`MessageBus::process_external_messages`]).
+///
+/// The bus is not borrowed while the handlers are called.
+pub fn process_external_messages(msgbus: &RefCell<MessageBus>) -> usize {
+    let messages = msgbus.borrow().receive_external_messages();
+    let mut count = 0;
+    for msg in messages {
+        let prepared = msgbus.borrow_mut().prepare_external(&msg);
+        if let Some((handlers, value)) = prepared {
+            for handler in handlers {
+                handler.handle(&value);
+            }
+            count += 1;
         }
+        msgbus.borrow().acknowledge_external(msg);
     }
+    count
+}
+
+fn get_encoding(config: &HashMap<String, serde_json::Value>) -> String {
+    config
+        .get("encoding")
+        .and_then(|v| v.as_str())
+        .unwrap_or("msgpack")
+        .to_string()
+}
+
+/// Decodes the given external message `payload` with the `encoding` (`msgpack` or `json`).
+fn decode_payload(encoding: &str, payload: Option<&[u8]>) -> Result<serde_json::Value> {
+    let Some(payload) = payload else {
+        bail!("no payload");
+    };
+    match encoding {
+        "msgpack" => Ok(rmp_serde::from_slice(payload)?),
+        "json" => Ok(serde_json::from_slice(payload)?),
+        other => bail!("unsupported encoding '{other}'"),
+    }
+}
+
+fn get_backing_type(config: &HashMap<String, serde_json::Value>) -> Result<String> {
+    let Some(database_config) = config.get("database") else {
+        bail!("No `MessageBusConfig` `database` config specified");
+    };
+    let Some(backing_type) = database_config.get("type") else {
+        bail!("No `MessageBusConfig` database config `type` specified");
+    };
+    let Some(backing_type) = backing_type.as_str() else {
+        bail!("`MessageBusConfig` database `type` must be a valid string");
+    };
+    Ok(backing_type.to_string())
 }
 
 /// Match a topic and a string pattern
@@ -428,31 +755,36 @@ impl MessageBus {
 /// '*' - match 0 or more characters after this
 /// '?' - match any character once
 /// 'a-z' - match the specific character
+///
+/// Matching is greedy with backtracking to the last `*`, so runs in linear time for
+/// typical patterns without allocating, and topics and patterns may be of any length.
 #[must_use]
 pub fn is_matching(topic: &Ustr, pattern: &Ustr) -> bool {
-    let mut table = [[false; 256]; 256];
-    table[0][0] = true;
-
-    let m = pattern.len();
-    let n = topic.len();
-
-    pattern.chars().enumerate().for_each(|(j, c)| {
-        if c == '*' {
-            table[0][j + 1] = table[0][j];
+    let topic = topic.as_bytes();
+    let pattern = pattern.as_bytes();
+
+    let (mut t, mut p) = (0, 0);
+    // The position after the last `*` in the pattern, and the topic position it matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < topic.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == topic[t]) {
+            t += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            p += 1;
+            backtrack = Some((p, t));
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Extend the match of the last `*` by one character
+            p = star_p;
+            t = star_t + 1;
+            backtrack = Some((star_p, t));
+        } else {
+            return false;
         }
-    });
-
-    topic.chars().enumerate().for_each(|(i, tc)| {
-        pattern.chars().enumerate().for_each(|(j, pc)| {
-            if pc == '*' {
-                table[i + 1][j + 1] = table[i][j + 1] || table[i + 1][j];
-            } else if pc == '?' || tc == pc {
-                table[i + 1][j + 1] = table[i][j];
-            }
-        });
-    });
+    }
 
-    table[n][m]
+    pattern[p..].iter().all(|&c| c == b'*')
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -460,7 +792,7 @@ pub fn is_matching(topic: &Ustr, pattern: &Ustr) -> bool {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
     use nautilus_core::{message::Message, uuid::UUID4};
     use rstest::*;
@@ -469,7 +801,7 @@ mod tests {
     use crate::handlers::{MessageHandler, SafeMessageCallback};
 
     fn stub_msgbus() -> MessageBus {
-        MessageBus::new(TraderId::from("trader-001"), UUID4::new(), None, None)
+        MessageBus::new(TraderId::from("trader-001"), UUID4::new(), None, None).unwrap()
     }
 
     fn stub_rust_callback() -> SafeMessageCallback {
@@ -483,12 +815,32 @@ mod tests {
     #[rstest]
     fn test_new() {
         let trader_id = TraderId::from("trader-001");
-        let msgbus = MessageBus::new(trader_id, UUID4::new(), None, None);
+        let msgbus = MessageBus::new(trader_id, UUID4::new(), None, None).unwrap();
 
         assert_eq!(msgbus.trader_id, trader_id);
         assert_eq!(msgbus.name, stringify!(MessageBus));
     }
 
+    #[rstest]
+    #[case(serde_json::json!({"database": {"type": "file"}}))]
+    #[case(serde_json::json!({"database": {"type": "kafka"}}))]
+    #[case(serde_json::json!({"database": {}}))]
+    #[case(serde_json::json!({
+        "database": {"type": "file", "path": "msgbus.journal"},
+        "external_streams": ["stream"],
+    }))]
+    fn test_new_with_invalid_backing_config(#[case] config: serde_json::Value) {
+        let config: HashMap<String, serde_json::Value> = serde_json::from_value(config).unwrap();
+        let result = MessageBus::new(
+            TraderId::from("trader-001"),
+            UUID4::new(),
+            None,
+            Some(config),
+        );
+
+        assert!(result.is_err());
+    }
+
     #[rstest]
     fn test_endpoints_when_no_endpoints() {
         let msgbus = stub_msgbus();
@@ -660,6 +1012,247 @@ mod tests {
         assert_eq!(subs[3].handler.handler_id, handler_id2);
     }
 
+    #[rstest]
+    fn test_publish_in_priority_order() {
+        let mut msgbus = stub_msgbus();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        for (id, topic, priority) in [
+            ("1", "data.quotes.*", None),
+            ("2", "data.quotes.SIM.AUD/USD", Some(5)),
+            ("3", "data.trades.*", Some(10)),
+        ] {
+            let received = received.clone();
+            let handler = MessageHandler::with_typed_callback(Ustr::from(id), move |m: &u64| {
+                received.lock().unwrap().push((id, *m));
+            });
+            msgbus.subscribe(topic, handler, priority);
+        }
+
+        msgbus.publish("data.quotes.SIM.AUD/USD", &1_u64);
+
+        assert_eq!(*received.lock().unwrap(), vec![("2", 1), ("1", 1)]);
+        assert_eq!(msgbus.pub_count, 1);
+    }
+
+    #[rstest]
+    fn test_publish_to_typed_handlers() {
+        let mut msgbus = stub_msgbus();
+        let numbers = Arc::new(Mutex::new(Vec::new()));
+        let strings = Arc::new(Mutex::new(Vec::new()));
+
+        let sink = numbers.clone();
+        let handler = MessageHandler::with_typed_callback(Ustr::from("1"), move |m: &u64| {
+            sink.lock().unwrap().push(*m);
+        });
+        msgbus.subscribe("my-topic", handler, None);
+        let sink = strings.clone();
+        let handler = MessageHandler::with_typed_callback(Ustr::from("2"), move |m: &String| {
+            sink.lock().unwrap().push(m.clone());
+        });
+        msgbus.subscribe("my-topic", handler, None);
+
+        msgbus.publish("my-topic", &1_u64);
+        msgbus.publish("my-topic", &"hello".to_string());
+
+        assert_eq!(*numbers.lock().unwrap(), vec![1]);
+        assert_eq!(*strings.lock().unwrap(), vec!["hello".to_string()]);
+    }
+
+    #[rstest]
+    fn test_send() {
+        let mut msgbus = stub_msgbus();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let handler = MessageHandler::with_typed_callback(Ustr::from("1"), move |m: &u64| {
+            sink.lock().unwrap().push(*m);
+        });
+        msgbus.register("MyEndpoint", handler);
+
+        msgbus.send("MyEndpoint", &1_u64).unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec![1]);
+        assert_eq!(msgbus.sent_count, 1);
+        assert!(msgbus.send("OtherEndpoint", &1_u64).is_err());
+    }
+
+    #[rstest]
+    fn test_request_response() {
+        let mut msgbus = stub_msgbus();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let responses = Arc::new(Mutex::new(Vec::new()));
+        let sink = requests.clone();
+        let handler = MessageHandler::with_typed_callback(Ustr::from("1"), move |m: &String| {
+            sink.lock().unwrap().push(m.clone());
+        });
+        msgbus.register("MyEndpoint", handler);
+        let sink = responses.clone();
+        let response_handler =
+            MessageHandler::with_typed_callback(Ustr::from("2"), move |m: &u64| {
+                sink.lock().unwrap().push(*m);
+            });
+        let request_id = UUID4::new();
+
+        msgbus
+            .request(
+                "MyEndpoint",
+                request_id,
+                &"ping".to_string(),
+                response_handler,
+            )
+            .unwrap();
+        assert!(msgbus.is_pending_response(&request_id));
+        msgbus.response(&request_id, &42_u64).unwrap();
+
+        assert_eq!(*requests.lock().unwrap(), vec!["ping".to_string()]);
+        assert_eq!(*responses.lock().unwrap(), vec![42]);
+        assert_eq!((msgbus.req_count, msgbus.res_count), (1, 1));
+        assert!(msgbus.response(&request_id, &42_u64).is_err());
+    }
+
+    #[rstest]
+    fn test_publish_and_send_from_handlers_of_shared_bus() {
+        thread_local! {
+            static MSGBUS: RefCell<MessageBus> = RefCell::new(stub_msgbus());
+        }
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let publisher = MessageHandler::with_typed_callback(Ustr::from("1"), |m: &u64| {
+            MSGBUS.with(|msgbus| publish(msgbus, "topic-b", &(m + 1)));
+        });
+        let sender = MessageHandler::with_typed_callback(Ustr::from("2"), |m: &u64| {
+            MSGBUS.with(|msgbus| send(msgbus, "MyEndpoint", &(m + 1)).unwrap());
+        });
+        let handler = MessageHandler::with_typed_callback(Ustr::from("3"), move |m: &u64| {
+            sink.lock().unwrap().push(*m);
+        });
+        MSGBUS.with(|msgbus| {
+            let mut msgbus = msgbus.borrow_mut();
+            msgbus.subscribe("topic-a", publisher, None);
+            msgbus.subscribe("topic-b", sender, None);
+            msgbus.register("MyEndpoint", handler);
+        });
+
+        MSGBUS.with(|msgbus| publish(msgbus, "topic-a", &1_u64));
+
+        assert_eq!(*received.lock().unwrap(), vec![3]);
+        MSGBUS.with(|msgbus| {
+            let msgbus = msgbus.borrow();
+            assert_eq!((msgbus.pub_count, msgbus.sent_count), (2, 1));
+        });
+    }
+
+    #[rstest]
+    fn test_process_external_messages_when_no_external_streams() {
+        let mut msgbus = stub_msgbus();
+
+        assert_eq!(msgbus.process_external_messages(), 0);
+        assert_eq!(msgbus.pub_count, 0);
+    }
+
+    #[rstest]
+    #[case("json", br#"{"signal":1}"#.to_vec())]
+    #[case("msgpack", rmp_serde::to_vec_named(&serde_json::json!({"signal": 1})).unwrap())]
+    fn test_process_external_messages_decodes_and_acknowledges(
+        #[case] encoding: &str,
+        #[case] payload: Vec<u8>,
+    ) {
+        let mut msgbus = stub_msgbus();
+        let (tx, rx) = channel::<ExternalMessage>();
+        let (ack_tx, ack_rx) = channel::<ExternalMessage>();
+        msgbus.external_rx = Some(Arc::new(Mutex::new(rx)));
+        msgbus.external_ack_tx = Some(ack_tx);
+        msgbus.encoding = encoding.to_string();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let handler =
+            MessageHandler::with_typed_callback(Ustr::from("1"), move |m: &serde_json::Value| {
+                sink.lock().unwrap().push(m.clone());
+            });
+        msgbus.subscribe("signals", handler, None);
+        let message = |id: &str, payload: Option<Vec<u8>>| ExternalMessage {
+            topic: "signals".to_string(),
+            payload,
+            stream: "trader-002:stream:signals".to_string(),
+            id: id.to_string(),
+        };
+        tx.send(message("1-0", Some(payload))).unwrap();
+        tx.send(message("2-0", None)).unwrap();
+
+        assert_eq!(msgbus.process_external_messages(), 1);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![serde_json::json!({"signal": 1})]
+        );
+        let acks: Vec<String> = ack_rx.try_iter().map(|msg| msg.id).collect();
+        assert_eq!(acks, vec!["1-0", "2-0"]);
+    }
+
+    #[rstest]
+    fn test_process_external_messages_on_shared_bus() {
+        thread_local! {
+            static MSGBUS: RefCell<MessageBus> = RefCell::new(stub_msgbus());
+        }
+        let (tx, rx) = channel::<ExternalMessage>();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let republisher =
+            MessageHandler::with_typed_callback(Ustr::from("1"), |m: &serde_json::Value| {
+                MSGBUS.with(|msgbus| publish(msgbus, "signals-out", &m.clone()));
+            });
+        let handler =
+            MessageHandler::with_typed_callback(Ustr::from("2"), move |m: &serde_json::Value| {
+                sink.lock().unwrap().push(m.clone());
+            });
+        MSGBUS.with(|msgbus| {
+            let mut msgbus = msgbus.borrow_mut();
+            msgbus.external_rx = Some(Arc::new(Mutex::new(rx)));
+            msgbus.encoding = "json".to_string();
+            msgbus.subscribe("signals", republisher, None);
+            msgbus.subscribe("signals-out", handler, None);
+        });
+        tx.send(ExternalMessage {
+            topic: "signals".to_string(),
+            payload: Some(br#"{"signal":1}"#.to_vec()),
+            stream: "trader-002:stream:signals".to_string(),
+            id: "1-0".to_string(),
+        })
+        .unwrap();
+
+        assert_eq!(MSGBUS.with(process_external_messages), 1);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![serde_json::json!({"signal": 1})]
+        );
+    }
+
+    #[rstest]
+    fn test_publish_after_subscription_changes() {
+        let mut msgbus = stub_msgbus();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let handler = |id: &'static str| {
+            let received = received.clone();
+            MessageHandler::with_typed_callback(Ustr::from(id), move |_: &u64| {
+                received.lock().unwrap().push(id);
+            })
+        };
+        let topic = "data.quotes.SIM.AUD/USD";
+
+        msgbus.subscribe("data.*", handler("1"), None);
+        msgbus.publish(topic, &1_u64); // Caches the subscriptions for the topic
+        msgbus.subscribe("data.quotes.*", handler("2"), Some(1));
+        msgbus.subscribe("data.trades.*", handler("3"), Some(2));
+        msgbus.publish(topic, &1_u64);
+        msgbus.unsubscribe("data.*", handler("1"));
+        msgbus.publish(topic, &1_u64);
+
+        assert_eq!(*received.lock().unwrap(), vec!["1", "2", "1", "2"]);
+        let topic = Ustr::from(topic);
+        let subs = msgbus.matching_subscriptions(&topic);
+        assert_eq!(subs.len(), 1);
+        assert_eq!(subs[0].handler.handler_id, Ustr::from("2"));
+    }
+
     #[rstest]
     #[case("*", "*", true)]
     #[case("a", "*", true)]
@@ -670,10 +1263,29 @@ mod tests {
     #[case("data.quotes.BINANCE", "data.*.BINANCE", true)]
     #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.*", true)]
     #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.ETH*", true)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.BTC*", false)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.*.*USDT", true)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.*.*USDT?", false)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.?rades.*", true)]
+    #[case("", "*", true)]
+    #[case("", "?", false)]
+    #[case("a", "", false)]
+    #[case("aaab", "*a*b", true)]
+    #[case("abcabd", "*abd", true)]
     fn test_is_matching(#[case] topic: &str, #[case] pattern: &str, #[case] expected: bool) {
         assert_eq!(
             is_matching(&Ustr::from(topic), &Ustr::from(pattern)),
             expected
         );
     }
+
+    #[rstest]
+    fn test_is_matching_with_long_topic() {
+        let symbol = "X".repeat(300);
+        let topic = Ustr::from(&format!("data.quotes.SIM.{symbol}"));
+
+        assert!(is_matching(&topic, &Ustr::from("data.quotes.*")));
+        assert!(is_matching(&topic, &Ustr::from("*X")));
+        assert!(!is_matching(&topic, &Ustr::from("data.trades.*")));
+    }
 }
@@ -15,9 +15,9 @@
 
 use std::{
     collections::{HashMap, VecDeque},
-    sync::mpsc::{Receiver, TryRecvError},
+    sync::mpsc::{Receiver, Sender},
     thread,
-    time::{Duration, Instant},
+    time::Duration,
 };
 
 use nautilus_core::{time::duration_since_unix_epoch, uuid::UUID4};
@@ -25,69 +25,80 @@ use nautilus_model::identifiers::trader_id::TraderId;
 use redis::*;
 use serde_json::{json, Value};
 
-use crate::msgbus::BusMessage;
+use crate::{
+    msgbus::{BusMessage, ExternalMessage},
+    transport::MessageBusTransport,
+};
 
 const DELIMITER: char = ':';
 const XTRIM: &str = "XTRIM";
 const MINID: &str = "MINID";
+const XREAD: &str = "XREAD";
+const XREADGROUP: &str = "XREADGROUP";
+const XREVRANGE: &str = "XREVRANGE";
+const XGROUP: &str = "XGROUP";
+const XACK: &str = "XACK";
+const CONSUMER_BATCH_SIZE: usize = 100;
+const CONSUMER_BLOCK_MS: usize = 100;
+const CONSUMER_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Represents an entry read from a Redis stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct StreamEntry {
+    stream: String,
+    id: String,
+    payload: Option<Vec<u8>>,
+}
 
-pub fn handle_messages_with_redis(
-    rx: Receiver<BusMessage>,
-    trader_id: TraderId,
-    instance_id: UUID4,
-    config: HashMap<String, Value>,
-) {
-    let redis_url = get_redis_url(&config);
-    let client = redis::Client::open(redis_url).unwrap();
-    let mut conn = client.get_connection().unwrap();
-    let stream_name = get_stream_name(trader_id, instance_id, &config);
-
-    // Autotrimming
-    let autotrim_mins = config
-        .get("autotrim_mins")
-        .and_then(|v| v.as_u64())
-        .unwrap_or(0) as usize;
-    let autotrim_duration = if autotrim_mins > 0 {
-        Some(Duration::from_secs(autotrim_mins as u64 * 60))
-    } else {
-        None
-    };
-    let mut last_trim_index: HashMap<String, usize> = HashMap::new();
-
-    // Buffering
-    let mut buffer: VecDeque<BusMessage> = VecDeque::new();
-    let mut last_drain = Instant::now();
-    let recv_interval = Duration::from_millis(1);
-    let buffer_interval = get_buffer_interval(&config);
+/// Provides a [`MessageBusTransport`] which adds messages to Redis streams, with a stream
+/// per topic.
+pub struct RedisTransport {
+    conn: Connection,
+    stream_name: String,
+    autotrim_duration: Option<Duration>,
+    last_trim_index: HashMap<String, usize>,
+}
 
-    loop {
-        if last_drain.elapsed() >= buffer_interval && !buffer.is_empty() {
-            drain_buffer(
-                &mut conn,
-                &stream_name,
-                autotrim_duration,
-                &mut last_trim_index,
-                &mut buffer,
-            );
-            last_drain = Instant::now();
+impl RedisTransport {
+    /// Initializes a new [`RedisTransport`] instance, connecting to the configured database.
+    pub fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: &HashMap<String, Value>,
+    ) -> RedisResult<Self> {
+        let redis_url = get_redis_url(config);
+        let client = redis::Client::open(redis_url)?;
+        let conn = client.get_connection()?;
+        let stream_name = get_stream_name(trader_id, instance_id, config);
+
+        // Autotrimming
+        let autotrim_mins = config
+            .get("autotrim_mins")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as usize;
+        let autotrim_duration = if autotrim_mins > 0 {
+            Some(Duration::from_secs(autotrim_mins as u64 * 60))
         } else {
-            // Continue to receive and handle messages until channel is hung up
-            match rx.try_recv() {
-                Ok(msg) => buffer.push_back(msg),
-                Err(TryRecvError::Empty) => thread::sleep(recv_interval),
-                Err(TryRecvError::Disconnected) => break, // Channel hung up
-            }
-        }
+            None
+        };
+
+        Ok(Self {
+            conn,
+            stream_name,
+            autotrim_duration,
+            last_trim_index: HashMap::new(),
+        })
     }
+}
 
-    // Drain any remaining messages
-    if !buffer.is_empty() {
+impl MessageBusTransport for RedisTransport {
+    fn write(&mut self, buffer: &mut VecDeque<BusMessage>) {
         drain_buffer(
-            &mut conn,
-            &stream_name,
-            autotrim_duration,
-            &mut last_trim_index,
-            &mut buffer,
+            &mut self.conn,
+            &self.stream_name,
+            self.autotrim_duration,
+            &mut self.last_trim_index,
+            buffer,
         );
     }
 }
@@ -138,6 +149,297 @@ fn drain_buffer(
     }
 }
 
+/// Consumes the `external_streams` in the `config`, forwarding their messages to `tx`
+/// until it hangs up.
+///
+/// Each message is forwarded on the topic following the last delimiter of its stream key
+/// (so the streams written by another node's [`RedisTransport`] map back to
+/// their original topics), in the order of its stream. The position consumed up to is
+/// checkpointed in Redis as messages are acknowledged on `ack_rx` (once published on the
+/// bus), so consumption resumes after the last message handled following a restart:
+/// - With an `external_streams_group`, streams are read as that consumer group (with the
+///   trader ID as the consumer name), acknowledging messages in the group. Messages
+///   delivered but never acknowledged are redelivered first.
+/// - Otherwise the last IDs acknowledged are stored in a hash per trader, and streams
+///   with no checkpoint are read from their current end.
+pub fn consume_messages_with_redis(
+    tx: Sender<ExternalMessage>,
+    ack_rx: Receiver<ExternalMessage>,
+    trader_id: TraderId,
+    config: HashMap<String, Value>,
+) {
+    let redis_url = get_redis_url(&config);
+    let client = redis::Client::open(redis_url).unwrap();
+    let mut conn = client.get_connection().unwrap();
+    let streams = get_external_streams(&config);
+
+    let result = match config
+        .get("external_streams_group")
+        .and_then(|v| v.as_str())
+    {
+        Some(group) => consume_as_group(
+            &mut conn,
+            &tx,
+            &ack_rx,
+            &streams,
+            group,
+            trader_id.value.as_str(),
+        ),
+        None => consume_with_checkpoints(
+            &mut conn,
+            &tx,
+            &ack_rx,
+            &streams,
+            &get_checkpoint_key(trader_id),
+        ),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error consuming external streams: {e}");
+    }
+}
+
+fn consume_with_checkpoints(
+    conn: &mut Connection,
+    tx: &Sender<ExternalMessage>,
+    ack_rx: &Receiver<ExternalMessage>,
+    streams: &[String],
+    checkpoint_key: &str,
+) -> RedisResult<()> {
+    let checkpoints: HashMap<String, String> = conn.hgetall(checkpoint_key)?;
+    let mut last_ids = Vec::with_capacity(streams.len());
+    for stream in streams {
+        let last_id = match checkpoints.get(stream) {
+            Some(id) => id.clone(),
+            None => get_last_entry_id(conn, stream)?,
+        };
+        last_ids.push(last_id);
+    }
+
+    let checkpoint = |pipe: &mut Pipeline, msg: &ExternalMessage| {
+        pipe.hset(checkpoint_key, &msg.stream, &msg.id).ignore();
+    };
+
+    loop {
+        acknowledge(conn, ack_rx, checkpoint);
+
+        let reply: RedisResult<redis::Value> = redis::cmd(XREAD)
+            .arg("COUNT")
+            .arg(CONSUMER_BATCH_SIZE)
+            .arg("BLOCK")
+            .arg(CONSUMER_BLOCK_MS)
+            .arg("STREAMS")
+            .arg(streams)
+            .arg(&last_ids)
+            .query(conn);
+        let entries = match reply {
+            Ok(reply) => parse_stream_reply(reply),
+            Err(e) => {
+                eprintln!("Error reading external streams: {e}");
+                thread::sleep(CONSUMER_RETRY_INTERVAL);
+                continue;
+            }
+        };
+
+        for entry in entries {
+            if let Some(index) = streams.iter().position(|s| s == &entry.stream) {
+                last_ids[index] = entry.id.clone();
+            }
+            if !forward_entry(tx, entry) {
+                acknowledge(conn, ack_rx, checkpoint);
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn consume_as_group(
+    conn: &mut Connection,
+    tx: &Sender<ExternalMessage>,
+    ack_rx: &Receiver<ExternalMessage>,
+    streams: &[String],
+    group: &str,
+    consumer: &str,
+) -> RedisResult<()> {
+    for stream in streams {
+        let result: RedisResult<()> = redis::cmd(XGROUP)
+            .arg("CREATE")
+            .arg(stream)
+            .arg(group)
+            .arg("$")
+            .arg("MKSTREAM")
+            .query(conn);
+        if let Err(e) = result {
+            if e.code() != Some("BUSYGROUP") {
+                return Err(e); // Otherwise the group already exists
+            }
+        }
+    }
+
+    // Reading from an ID returns this consumer's pending messages after it (so reading
+    // continues after those already forwarded but not yet acknowledged), and `>` new messages
+    let mut pending_ids = vec!["0".to_string(); streams.len()];
+    let new_ids = vec![">".to_string(); streams.len()];
+    let mut is_pending = true;
+
+    let xack = |pipe: &mut Pipeline, msg: &ExternalMessage| {
+        pipe.cmd(XACK)
+            .arg(&msg.stream)
+            .arg(group)
+            .arg(&msg.id)
+            .ignore();
+    };
+
+    loop {
+        acknowledge(conn, ack_rx, xack);
+
+        let mut cmd = redis::cmd(XREADGROUP);
+        cmd.arg("GROUP")
+            .arg(group)
+            .arg(consumer)
+            .arg("COUNT")
+            .arg(CONSUMER_BATCH_SIZE);
+        if !is_pending {
+            cmd.arg("BLOCK").arg(CONSUMER_BLOCK_MS);
+        }
+        cmd.arg("STREAMS")
+            .arg(streams)
+            .arg(if is_pending { &pending_ids } else { &new_ids });
+
+        let entries = match cmd.query::<redis::Value>(conn) {
+            Ok(reply) => parse_stream_reply(reply),
+            Err(e) => {
+                eprintln!("Error reading external streams as group '{group}': {e}");
+                thread::sleep(CONSUMER_RETRY_INTERVAL);
+                continue;
+            }
+        };
+        if entries.is_empty() {
+            is_pending = false; // Pending messages redelivered, or timed out
+            continue;
+        }
+
+        for entry in entries {
+            if is_pending {
+                if let Some(index) = streams.iter().position(|s| s == &entry.stream) {
+                    pending_ids[index] = entry.id.clone();
+                }
+            }
+            if !forward_entry(tx, entry) {
+                acknowledge(conn, ack_rx, xack);
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Acknowledges the messages published on the bus since the last call, with `ack` adding
+/// the command acknowledging each message to a pipeline.
+fn acknowledge(
+    conn: &mut Connection,
+    ack_rx: &Receiver<ExternalMessage>,
+    ack: impl Fn(&mut Pipeline, &ExternalMessage),
+) {
+    let mut pipe = redis::pipe();
+    let mut count = 0;
+    for msg in ack_rx.try_iter() {
+        ack(&mut pipe, &msg);
+        count += 1;
+    }
+
+    if count == 0 {
+        return;
+    }
+    if let Err(e) = pipe.query::<()>(conn) {
+        eprintln!("Error acknowledging external messages: {e}");
+    }
+}
+
+/// Forwards the given stream `entry` to `tx`, returning whether `tx` is still connected.
+fn forward_entry(tx: &Sender<ExternalMessage>, entry: StreamEntry) -> bool {
+    let msg = ExternalMessage {
+        topic: get_external_topic(&entry.stream).to_string(),
+        payload: entry.payload,
+        stream: entry.stream,
+        id: entry.id,
+    };
+    tx.send(msg).is_ok()
+}
+
+fn get_last_entry_id(conn: &mut Connection, stream: &str) -> RedisResult<String> {
+    let reply: redis::Value = redis::cmd(XREVRANGE)
+        .arg(stream)
+        .arg("+")
+        .arg("-")
+        .arg("COUNT")
+        .arg(1)
+        .query(conn)?;
+    let last_id = match reply {
+        redis::Value::Bulk(entries) => parse_stream_entries(entries).into_iter().next(),
+        _ => None,
+    };
+    Ok(last_id.map_or_else(|| "0-0".to_string(), |(id, _)| id))
+}
+
+/// Parses the reply of an `XREAD` or `XREADGROUP` command (`Nil` when the read timed out).
+fn parse_stream_reply(reply: redis::Value) -> Vec<StreamEntry> {
+    let mut entries = Vec::new();
+    let redis::Value::Bulk(streams) = reply else {
+        return entries;
+    };
+
+    for stream in streams {
+        let redis::Value::Bulk(stream) = stream else {
+            continue;
+        };
+        let mut stream = stream.into_iter();
+        let (Some(redis::Value::Data(key)), Some(redis::Value::Bulk(stream_entries))) =
+            (stream.next(), stream.next())
+        else {
+            continue;
+        };
+
+        let key = String::from_utf8_lossy(&key).to_string();
+        for (id, payload) in parse_stream_entries(stream_entries) {
+            entries.push(StreamEntry {
+                stream: key.clone(),
+                id,
+                payload,
+            });
+        }
+    }
+    entries
+}
+
+/// Parses stream entries of an ID and field-value pairs into the ID and `payload` value.
+fn parse_stream_entries(entries: Vec<redis::Value>) -> Vec<(String, Option<Vec<u8>>)> {
+    entries
+        .into_iter()
+        .filter_map(|entry| {
+            let redis::Value::Bulk(entry) = entry else {
+                return None;
+            };
+            let mut entry = entry.into_iter();
+            let Some(redis::Value::Data(id)) = entry.next() else {
+                return None;
+            };
+
+            let payload = match entry.next() {
+                Some(redis::Value::Bulk(fields)) => fields.chunks(2).find_map(|pair| match pair {
+                    [redis::Value::Data(field), redis::Value::Data(value)]
+                        if field == b"payload" =>
+                    {
+                        Some(value.clone())
+                    }
+                    _ => None,
+                }),
+                _ => None, // Entries deleted while pending have no fields
+            };
+            Some((String::from_utf8_lossy(&id).to_string(), payload))
+        })
+        .collect()
+}
+
 pub fn get_redis_url(config: &HashMap<String, Value>) -> String {
     let empty = Value::Object(serde_json::Map::new());
     let database = config.get("database").unwrap_or(&empty);
@@ -175,6 +477,27 @@ pub fn get_buffer_interval(config: &HashMap<String, Value>) -> Duration {
     Duration::from_millis(buffer_interval_ms.unwrap_or(0))
 }
 
+pub fn get_external_streams(config: &HashMap<String, Value>) -> Vec<String> {
+    config
+        .get("external_streams")
+        .and_then(|v| v.as_array())
+        .map(|streams| {
+            streams
+                .iter()
+                .filter_map(|v| v.as_str().map(ToString::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn get_external_topic(stream: &str) -> &str {
+    stream.rsplit(DELIMITER).next().unwrap_or(stream)
+}
+
+fn get_checkpoint_key(trader_id: TraderId) -> String {
+    format!("trader-{trader_id}{DELIMITER}stream_checkpoints")
+}
+
 fn get_stream_name(
     trader_id: TraderId,
     instance_id: UUID4,
@@ -244,6 +567,60 @@ mod tests {
         assert_eq!(key, format!("streams:"));
     }
 
+    #[rstest]
+    fn test_get_external_streams() {
+        let mut config = HashMap::new();
+        assert!(get_external_streams(&config).is_empty());
+
+        config.insert(
+            "external_streams".to_string(),
+            json!(["trader-TRADER-002:streams:events.signals"]),
+        );
+
+        assert_eq!(
+            get_external_streams(&config),
+            vec!["trader-TRADER-002:streams:events.signals".to_string()]
+        );
+        assert_eq!(
+            get_external_topic("trader-TRADER-002:streams:events.signals"),
+            "events.signals"
+        );
+    }
+
+    #[rstest]
+    fn test_parse_stream_reply() {
+        let data = |s: &str| redis::Value::Data(s.as_bytes().to_vec());
+        let reply = redis::Value::Bulk(vec![redis::Value::Bulk(vec![
+            data("streams:events.signals"),
+            redis::Value::Bulk(vec![
+                redis::Value::Bulk(vec![
+                    data("1-0"),
+                    redis::Value::Bulk(vec![data("payload"), data("a")]),
+                ]),
+                redis::Value::Bulk(vec![data("2-0"), redis::Value::Nil]),
+            ]),
+        ])]);
+
+        let entries = parse_stream_reply(reply);
+
+        assert_eq!(
+            entries,
+            vec![
+                StreamEntry {
+                    stream: "streams:events.signals".to_string(),
+                    id: "1-0".to_string(),
+                    payload: Some(b"a".to_vec()),
+                },
+                StreamEntry {
+                    stream: "streams:events.signals".to_string(),
+                    id: "2-0".to_string(),
+                    payload: None,
+                },
+            ]
+        );
+        assert!(parse_stream_reply(redis::Value::Nil).is_empty());
+    }
+
     #[rstest]
     fn test_get_buffer_interval_default() {
         let config = HashMap::new();
@@ -0,0 +1,198 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, net::SocketAddr};
+
+use anyhow::Result;
+use nautilus_network::{socket::SocketServer, websocket::WebSocketServer};
+use tokio::runtime::Runtime;
+
+use super::MessageBusTransport;
+use crate::msgbus::BusMessage;
+
+/// The capacity of the broadcast channel, beyond which slow clients miss messages.
+const CHANNEL_CAPACITY: usize = 10_000;
+
+/// The protocol of a [`BroadcastTransport`] server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BroadcastMode {
+    /// Frames are written to a TCP stream, with the length of their topic and payload.
+    Tcp,
+    /// Frames are sent as binary WebSocket messages.
+    WebSocket,
+}
+
+enum BroadcastServer {
+    Tcp(SocketServer),
+    WebSocket(WebSocketServer),
+}
+
+/// Provides a [`MessageBusTransport`] which serves the messages to all connected clients
+/// over TCP or WebSocket, with the servers from the network crate.
+///
+/// For TCP, each message is framed as the little-endian topic length (`u32`), topic,
+/// payload length (`u32`) and payload (as for the journal file), so binary payloads can
+/// be streamed. For WebSocket, each message is framed as its topic, a space, then its
+/// payload. Clients only receive the messages written after they connect, and a client
+/// lagging more than the channel capacity misses the oldest messages.
+pub struct BroadcastTransport {
+    server: BroadcastServer,
+    runtime: Option<Runtime>,
+}
+
+impl BroadcastTransport {
+    /// Initializes a new [`BroadcastTransport`] instance, listening on the given `addr`.
+    pub fn new(mode: BroadcastMode, addr: &str) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()?;
+        let server = runtime.block_on(async {
+            match mode {
+                // Frames carry their lengths, so need no suffix
+                BroadcastMode::Tcp => SocketServer::bind(addr, Vec::new(), CHANNEL_CAPACITY)
+                    .await
+                    .map(BroadcastServer::Tcp),
+                BroadcastMode::WebSocket => WebSocketServer::bind(addr, CHANNEL_CAPACITY)
+                    .await
+                    .map(BroadcastServer::WebSocket),
+            }
+        })?;
+
+        Ok(Self {
+            server,
+            runtime: Some(runtime),
+        })
+    }
+
+    /// Returns the address the server is listening on.
+    #[must_use]
+    pub fn local_addr(&self) -> SocketAddr {
+        match &self.server {
+            BroadcastServer::Tcp(server) => server.local_addr(),
+            BroadcastServer::WebSocket(server) => server.local_addr(),
+        }
+    }
+
+    /// Returns the number of connected clients.
+    #[must_use]
+    pub fn client_count(&self) -> usize {
+        match &self.server {
+            BroadcastServer::Tcp(server) => server.client_count(),
+            BroadcastServer::WebSocket(server) => server.client_count(),
+        }
+    }
+}
+
+impl Drop for BroadcastTransport {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background(); // Closes the client connections
+        }
+    }
+}
+
+impl MessageBusTransport for BroadcastTransport {
+    fn write(&mut self, buffer: &mut VecDeque<BusMessage>) {
+        for msg in buffer.drain(..) {
+            match &self.server {
+                BroadcastServer::Tcp(server) => match encode_length_prefixed_frame(&msg) {
+                    Ok(frame) => server.send_bytes(frame),
+                    Err(e) => eprintln!("Error encoding message on '{}': {e}", msg.topic),
+                },
+                BroadcastServer::WebSocket(server) => server.send_bytes(encode_frame(&msg)),
+            }
+        }
+    }
+}
+
+/// Encodes the given `msg` as a frame of its topic, a space, then its payload.
+#[must_use]
+pub fn encode_frame(msg: &BusMessage) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(msg.topic.len() + 1 + msg.payload.len());
+    frame.extend_from_slice(msg.topic.as_bytes());
+    frame.push(b' ');
+    frame.extend_from_slice(&msg.payload);
+    frame
+}
+
+/// Encodes the given `msg` as a frame of the little-endian topic length (`u32`), topic,
+/// payload length (`u32`) and payload.
+pub fn encode_length_prefixed_frame(msg: &BusMessage) -> Result<Vec<u8>> {
+    let mut frame = Vec::with_capacity(8 + msg.topic.len() + msg.payload.len());
+    frame.extend_from_slice(&u32::try_from(msg.topic.len())?.to_le_bytes());
+    frame.extend_from_slice(msg.topic.as_bytes());
+    frame.extend_from_slice(&u32::try_from(msg.payload.len())?.to_le_bytes());
+    frame.extend_from_slice(&msg.payload);
+    Ok(frame)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, net::TcpStream, thread, time::Duration};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn read_frame(stream: &mut TcpStream) -> (String, Vec<u8>) {
+        let mut read_field = || {
+            let mut len = [0; 4];
+            stream.read_exact(&mut len).unwrap();
+            let mut field = vec![0; u32::from_le_bytes(len) as usize];
+            stream.read_exact(&mut field).unwrap();
+            field
+        };
+        let topic = String::from_utf8(read_field()).unwrap();
+        (topic, read_field())
+    }
+
+    #[rstest]
+    fn test_tcp_broadcast_to_clients() {
+        let mut transport = BroadcastTransport::new(BroadcastMode::Tcp, "127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(transport.local_addr()).unwrap();
+        while transport.client_count() == 0 {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        // Binary payloads may contain any bytes, including line endings
+        let payload =
+            rmp_serde::to_vec_named(&serde_json::json!({"id": 2, "note": "\r\n"})).unwrap();
+        let mut buffer = VecDeque::from([
+            BusMessage {
+                topic: "events.order".to_string(),
+                payload: b"{\"id\":1}".to_vec(),
+            },
+            BusMessage {
+                topic: "events.order".to_string(),
+                payload: payload.clone(),
+            },
+        ]);
+        transport.write(&mut buffer);
+
+        assert_eq!(
+            read_frame(&mut client),
+            ("events.order".to_string(), b"{\"id\":1}".to_vec())
+        );
+        assert_eq!(
+            read_frame(&mut client),
+            ("events.order".to_string(), payload)
+        );
+        assert!(buffer.is_empty());
+    }
+}
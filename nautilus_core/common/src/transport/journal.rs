@@ -0,0 +1,269 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Result};
+use nautilus_core::time::{duration_since_unix_epoch, UnixNanos};
+
+use super::MessageBusTransport;
+use crate::msgbus::BusMessage;
+
+/// Represents a message recorded in a message bus journal.
+#[derive(Clone, Debug)]
+pub struct JournalRecord {
+    /// The UNIX timestamp (nanoseconds) when the message was written.
+    pub ts_written: UnixNanos,
+    /// The message written.
+    pub message: BusMessage,
+}
+
+/// Provides a [`MessageBusTransport`] which appends the messages to a journal file.
+///
+/// Each record is the little-endian write timestamp (`u64`), topic length (`u32`),
+/// topic, payload length (`u32`) and payload. The file is only ever appended to (after
+/// recovering from an interrupted write when opened), and is flushed after each write,
+/// so it can be read back with [`read_journal`] while still being written.
+pub struct JournalTransport {
+    path: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl JournalTransport {
+    /// Initializes a new [`JournalTransport`] instance, appending to the file at `path`
+    /// (which is created if it does not exist).
+    ///
+    /// An existing file is first truncated after its last complete record, so a record
+    /// truncated by an interrupted write is not followed by new records.
+    pub fn new(path: &Path) -> Result<Self> {
+        if path.exists() {
+            recover_journal(path)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: BufWriter::new(file),
+        })
+    }
+
+    fn write_record(&mut self, ts_written: UnixNanos, msg: &BusMessage) -> Result<()> {
+        self.writer.write_all(&ts_written.to_le_bytes())?;
+        self.writer
+            .write_all(&u32::try_from(msg.topic.len())?.to_le_bytes())?;
+        self.writer.write_all(msg.topic.as_bytes())?;
+        self.writer
+            .write_all(&u32::try_from(msg.payload.len())?.to_le_bytes())?;
+        self.writer.write_all(&msg.payload)?;
+        Ok(())
+    }
+}
+
+impl MessageBusTransport for JournalTransport {
+    fn write(&mut self, buffer: &mut VecDeque<BusMessage>) {
+        let ts_written = duration_since_unix_epoch().as_nanos() as UnixNanos;
+        for msg in buffer.drain(..) {
+            if let Err(e) = self.write_record(ts_written, &msg) {
+                eprintln!("Error writing to journal '{}': {e}", self.path.display());
+            }
+        }
+
+        if let Err(e) = self.writer.flush() {
+            eprintln!("Error flushing journal '{}': {e}", self.path.display());
+        }
+    }
+}
+
+/// Reads the records of the journal file at `path` in the order they were written.
+///
+/// A record truncated at the end of the file (from an interrupted write) is ignored.
+pub fn read_journal(path: &Path) -> Result<Vec<JournalRecord>> {
+    let bytes = std::fs::read(path)?;
+    let mut reader = RecordReader {
+        bytes: &bytes,
+        pos: 0,
+    };
+
+    let mut records = Vec::new();
+    while reader.pos < bytes.len() {
+        let Some(record) = reader.read_record()? else {
+            break; // Truncated record
+        };
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Truncates the journal file at `path` after its last complete record.
+fn recover_journal(path: &Path) -> Result<()> {
+    let bytes = std::fs::read(path)?;
+    let mut reader = RecordReader {
+        bytes: &bytes,
+        pos: 0,
+    };
+
+    let mut valid_len = 0;
+    while reader.pos < bytes.len() && reader.read_record()?.is_some() {
+        valid_len = reader.pos;
+    }
+
+    if valid_len < bytes.len() {
+        let file = OpenOptions::new().write(true).open(path)?;
+        file.set_len(valid_len as u64)?;
+        file.sync_all()?;
+    }
+    Ok(())
+}
+
+struct RecordReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> RecordReader<'a> {
+    fn read_record(&mut self) -> Result<Option<JournalRecord>> {
+        let Some(ts_written) = self.take(8) else {
+            return Ok(None);
+        };
+        let ts_written = UnixNanos::from_le_bytes(ts_written.try_into()?);
+        let Some(topic) = self.take_prefixed() else {
+            return Ok(None);
+        };
+        let Ok(topic) = String::from_utf8(topic.to_vec()) else {
+            bail!(
+                "Invalid journal record at byte {}: topic not UTF-8",
+                self.pos
+            );
+        };
+        let Some(payload) = self.take_prefixed() else {
+            return Ok(None);
+        };
+
+        Ok(Some(JournalRecord {
+            ts_written,
+            message: BusMessage {
+                topic,
+                payload: payload.to_vec(),
+            },
+        }))
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.bytes.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn take_prefixed(&mut self) -> Option<&'a [u8]> {
+        let len = u32::from_le_bytes(self.take(4)?.try_into().ok()?);
+        self.take(len as usize)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn message(topic: &str, payload: &str) -> BusMessage {
+        BusMessage {
+            topic: topic.to_string(),
+            payload: payload.as_bytes().to_vec(),
+        }
+    }
+
+    #[rstest]
+    fn test_journal_appends_and_reads_back() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("msgbus.journal");
+
+        let mut transport = JournalTransport::new(&path).unwrap();
+        transport.write(&mut VecDeque::from([message("events.order", "1")]));
+        drop(transport);
+        let mut transport = JournalTransport::new(&path).unwrap();
+        transport.write(&mut VecDeque::from([
+            message("events.order", "2"),
+            message("events.position", ""),
+        ]));
+
+        let records = read_journal(&path).unwrap();
+        let messages: Vec<(&str, &[u8])> = records
+            .iter()
+            .map(|r| (r.message.topic.as_str(), r.message.payload.as_slice()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                ("events.order", b"1".as_slice()),
+                ("events.order", b"2".as_slice()),
+                ("events.position", b"".as_slice()),
+            ]
+        );
+        assert!(records[0].ts_written <= records[1].ts_written);
+    }
+
+    #[rstest]
+    fn test_read_journal_ignores_truncated_record() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("msgbus.journal");
+        let mut transport = JournalTransport::new(&path).unwrap();
+        transport.write(&mut VecDeque::from([
+            message("events.order", "1"),
+            message("events.order", "2"),
+        ]));
+        let len = std::fs::metadata(&path).unwrap().len();
+        let file = OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len(len - 1).unwrap();
+
+        let records = read_journal(&path).unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message.payload, b"1".to_vec());
+    }
+
+    #[rstest]
+    fn test_journal_recovers_truncated_record_on_open() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("msgbus.journal");
+        let mut transport = JournalTransport::new(&path).unwrap();
+        transport.write(&mut VecDeque::from([
+            message("events.order", "1"),
+            message("events.order", "2"),
+        ]));
+        drop(transport);
+        let len = std::fs::metadata(&path).unwrap().len();
+        let file = OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len(len - 1).unwrap();
+
+        let mut transport = JournalTransport::new(&path).unwrap();
+        transport.write(&mut VecDeque::from([message("events.order", "3")]));
+
+        let records = read_journal(&path).unwrap();
+        let payloads: Vec<&[u8]> = records
+            .iter()
+            .map(|r| r.message.payload.as_slice())
+            .collect();
+        assert_eq!(payloads, vec![b"1".as_slice(), b"3".as_slice()]);
+    }
+}
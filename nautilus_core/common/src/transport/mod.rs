@@ -0,0 +1,152 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Transports for the messages published externally on a `MessageBus`.
+//!
+//! The backing transport is selected by the `type` of the `database` in the message bus
+//! config:
+//! - `redis`: Redis streams, with a stream per topic.
+//! - `tcp`: a TCP server broadcasting to all connected clients.
+//! - `websocket`: a WebSocket server broadcasting to all connected clients.
+//! - `file`: an append-only journal file, which can be read back for audits and replay.
+
+pub mod broadcast;
+pub mod journal;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    path::Path,
+    sync::mpsc::{Receiver, TryRecvError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Result};
+use serde_json::Value;
+
+use self::{
+    broadcast::{BroadcastMode, BroadcastTransport},
+    journal::JournalTransport,
+};
+use crate::msgbus::BusMessage;
+
+/// Provides a transport for the messages published externally on a `MessageBus`.
+///
+/// Transports are created with the bus, then moved to the thread handling its messages.
+pub trait MessageBusTransport: Send {
+    /// Writes the buffered messages in order, draining the `buffer`.
+    ///
+    /// Errors are reported by the transport, as there is no caller to handle them.
+    fn write(&mut self, buffer: &mut VecDeque<BusMessage>);
+}
+
+/// Handles the messages received on `rx` with the given `transport` until the channel
+/// hangs up, buffering them for the `buffer_interval` between writes.
+pub fn handle_messages_with_transport(
+    rx: Receiver<BusMessage>,
+    transport: &mut dyn MessageBusTransport,
+    buffer_interval: Duration,
+) {
+    let mut buffer: VecDeque<BusMessage> = VecDeque::new();
+    let mut last_drain = Instant::now();
+    let recv_interval = Duration::from_millis(1);
+
+    loop {
+        if last_drain.elapsed() >= buffer_interval && !buffer.is_empty() {
+            transport.write(&mut buffer);
+            last_drain = Instant::now();
+        } else {
+            // Continue to receive and handle messages until channel is hung up
+            match rx.try_recv() {
+                Ok(msg) => buffer.push_back(msg),
+                Err(TryRecvError::Empty) => thread::sleep(recv_interval),
+                Err(TryRecvError::Disconnected) => break, // Channel hung up
+            }
+        }
+    }
+
+    // Drain any remaining messages
+    if !buffer.is_empty() {
+        transport.write(&mut buffer);
+    }
+}
+
+/// Creates the transport for a `tcp`, `websocket` or `file` database in the `config`.
+///
+/// The servers listen on the database `host` (default 127.0.0.1) and `port`. The journal
+/// is written to `path`.
+pub fn create_transport(
+    backing_type: &str,
+    config: &HashMap<String, Value>,
+) -> Result<Box<dyn MessageBusTransport>> {
+    let get_database_str = |key: &str| -> Option<String> {
+        match config.get("database")?.get(key)? {
+            Value::String(value) => Some(value.clone()),
+            Value::Number(value) => Some(value.to_string()),
+            _ => None,
+        }
+    };
+
+    let transport: Box<dyn MessageBusTransport> = match backing_type {
+        "tcp" | "websocket" => {
+            let host = get_database_str("host").unwrap_or_else(|| "127.0.0.1".to_string());
+            let Some(port) = get_database_str("port") else {
+                bail!("No `port` specified for the '{backing_type}' message bus database");
+            };
+            let mode = if backing_type == "tcp" {
+                BroadcastMode::Tcp
+            } else {
+                BroadcastMode::WebSocket
+            };
+            Box::new(BroadcastTransport::new(mode, &format!("{host}:{port}"))?)
+        }
+        "file" => {
+            let Some(path) = get_database_str("path") else {
+                bail!("No `path` specified for the 'file' message bus database");
+            };
+            Box::new(JournalTransport::new(Path::new(&path))?)
+        }
+        other => bail!("Unsupported message bus transport type '{other}'"),
+    };
+    Ok(transport)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    #[rstest]
+    #[case(json!({"type": "tcp"}))]
+    #[case(json!({"type": "file"}))]
+    fn test_create_transport_with_missing_config(#[case] database: Value) {
+        let backing_type = database["type"].as_str().unwrap().to_string();
+        let config = HashMap::from([("database".to_string(), database)]);
+
+        assert!(create_transport(&backing_type, &config).is_err());
+    }
+
+    #[rstest]
+    fn test_create_transport_with_unsupported_type() {
+        let config = HashMap::from([("database".to_string(), json!({"type": "kafka"}))]);
+
+        assert!(create_transport("kafka", &config).is_err());
+    }
+}
@@ -92,7 +92,7 @@ impl Serialize for UUID4 {
     where
         S: Serializer,
     {
-        self.value.serialize(serializer)
+        self.to_string().serialize(serializer)
     }
 }
 
@@ -159,4 +159,12 @@ mod tests {
         let result_string = format!("{uuid}");
         assert_eq!(result_string, uuid_string);
     }
+
+    #[rstest]
+    fn test_uuid4_serde_json_round_trip() {
+        let uuid = UUID4::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        let json = serde_json::to_string(&uuid).unwrap();
+        assert_eq!(json, "\"6ba7b810-9dad-11d1-80b4-00c04fd430c8\"");
+        assert_eq!(serde_json::from_str::<UUID4>(&json).unwrap(), uuid);
+    }
 }
@@ -0,0 +1,1031 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bar aggregation from quote and trade ticks.
+//!
+//! Aggregators are deterministic given the same sequence of ticks (and time events for
+//! time bars), so bars built in a backtest are identical to those built live.
+
+use std::cmp::{max, min, Ordering};
+
+use anyhow::{bail, Result};
+use nautilus_common::{clock::Clock, handlers::EventHandler, timer::TimeEvent};
+use nautilus_core::{
+    datetime::{NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    time::UnixNanos,
+};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarSpecification, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::{AggressorSide, BarAggregation},
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Provides a generic bar builder for aggregation.
+#[derive(Clone, Debug)]
+pub struct BarBuilder {
+    bar_type: BarType,
+    size_precision: u8,
+    open: Option<Price>,
+    high: Option<Price>,
+    low: Option<Price>,
+    close: Option<Price>,
+    volume: Quantity,
+    count: usize,
+    ts_last: UnixNanos,
+    last_close: Option<Price>,
+}
+
+impl BarBuilder {
+    /// Initializes a new [`BarBuilder`] instance.
+    #[must_use]
+    pub fn new(bar_type: BarType, size_precision: u8) -> Self {
+        Self {
+            bar_type,
+            size_precision,
+            open: None,
+            high: None,
+            low: None,
+            close: None,
+            volume: Quantity::zero(size_precision),
+            count: 0,
+            ts_last: 0,
+            last_close: None,
+        }
+    }
+
+    /// Returns the number of updates since the last bar was built.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    #[must_use]
+    pub fn volume(&self) -> Quantity {
+        self.volume
+    }
+
+    #[must_use]
+    pub fn ts_last(&self) -> UnixNanos {
+        self.ts_last
+    }
+
+    /// Updates the bar with the given `price` and `size`.
+    ///
+    /// Updates with a `ts_event` earlier than the last update are ignored.
+    pub fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        if ts_event < self.ts_last {
+            return; // Not applicable
+        }
+
+        match (self.high, self.low) {
+            (Some(high), Some(low)) => {
+                self.high = Some(max(high, price));
+                self.low = Some(min(low, price));
+            }
+            _ => {
+                self.open = Some(price);
+                self.high = Some(price);
+                self.low = Some(price);
+            }
+        }
+        self.close = Some(price);
+        self.volume = Quantity::from_raw(self.volume.raw + size.raw, self.size_precision).unwrap();
+        self.count += 1;
+        self.ts_last = ts_event;
+    }
+
+    /// Resets the bar being built (the last close is kept).
+    pub fn reset(&mut self) {
+        self.open = None;
+        self.high = None;
+        self.low = None;
+        self.close = None;
+        self.volume = Quantity::zero(self.size_precision);
+        self.count = 0;
+    }
+
+    /// Builds a bar from the updates so far and resets the builder.
+    ///
+    /// With no updates, a flat bar at the last close with zero volume is built (if a bar
+    /// was previously built), otherwise `None` is returned.
+    pub fn build(&mut self, ts_event: UnixNanos, ts_init: UnixNanos) -> Option<Bar> {
+        let (open, high, low, close) = match (self.open, self.high, self.low, self.close) {
+            (Some(open), Some(high), Some(low), Some(close)) => (open, high, low, close),
+            _ => {
+                let close = self.last_close?;
+                (close, close, close, close)
+            }
+        };
+
+        let bar = Bar::new(
+            self.bar_type,
+            open,
+            high,
+            low,
+            close,
+            self.volume,
+            ts_event,
+            ts_init,
+        );
+
+        self.last_close = Some(close);
+        self.reset();
+        Some(bar)
+    }
+
+    /// Builds a bar timestamped at the last update and resets the builder.
+    pub fn build_now(&mut self) -> Option<Bar> {
+        self.build(self.ts_last, self.ts_last)
+    }
+}
+
+/// Provides a means of aggregating specified bars from ticks.
+pub trait BarAggregator {
+    /// Returns the bar type being aggregated.
+    fn bar_type(&self) -> BarType;
+
+    /// Updates the aggregator with the given tick values, returning any completed bars.
+    fn update(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        aggressor_side: AggressorSide,
+        ts_event: UnixNanos,
+    ) -> Vec<Bar>;
+
+    /// Updates the aggregator with the price and size of the given `quote` for the
+    /// bar type's price type, returning any completed bars.
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) -> Vec<Bar> {
+        let price_type = self.bar_type().spec.price_type;
+        self.update(
+            quote.extract_price(price_type),
+            quote.extract_volume(price_type),
+            AggressorSide::NoAggressor,
+            quote.ts_event,
+        )
+    }
+
+    /// Updates the aggregator with the given `trade`, returning any completed bars.
+    fn handle_trade_tick(&mut self, trade: &TradeTick) -> Vec<Bar> {
+        self.update(
+            trade.price,
+            trade.size,
+            trade.aggressor_side,
+            trade.ts_event,
+        )
+    }
+
+    /// Handles an event from the aggregator's timer (time bars only).
+    fn on_time_event(&mut self, _event: &TimeEvent) -> Option<Bar> {
+        None
+    }
+
+    /// Stops the aggregator, canceling any timer set on the given `clock`.
+    fn stop(&mut self, _clock: &mut dyn Clock) {}
+}
+
+/// Creates a new aggregator for the given `bar_type`.
+///
+/// Time bar aggregators are started immediately, setting their timer on the given `clock`
+/// aligned to the bar interval from `ts_now`.
+pub fn create_bar_aggregator(
+    bar_type: BarType,
+    size_precision: u8,
+    clock: &mut dyn Clock,
+    ts_now: UnixNanos,
+) -> Result<Box<dyn BarAggregator>> {
+    let aggregator: Box<dyn BarAggregator> = match bar_type.spec.aggregation {
+        BarAggregation::Tick => Box::new(TickBarAggregator::new(bar_type, size_precision)?),
+        BarAggregation::Volume => Box::new(VolumeBarAggregator::new(bar_type, size_precision)?),
+        BarAggregation::Value => Box::new(ValueBarAggregator::new(bar_type, size_precision)?),
+        BarAggregation::TickImbalance
+        | BarAggregation::VolumeImbalance
+        | BarAggregation::ValueImbalance => {
+            Box::new(ImbalanceBarAggregator::new(bar_type, size_precision)?)
+        }
+        BarAggregation::TickRuns | BarAggregation::VolumeRuns | BarAggregation::ValueRuns => {
+            Box::new(RunsBarAggregator::new(bar_type, size_precision)?)
+        }
+        _ => {
+            let mut aggregator = TimeBarAggregator::new(bar_type, size_precision, true)?;
+            aggregator.start(clock, ts_now);
+            Box::new(aggregator)
+        }
+    };
+    Ok(aggregator)
+}
+
+/// Returns the interval of time bars with the given `spec` in nanoseconds.
+pub fn get_bar_interval_ns(spec: &BarSpecification) -> Result<u64> {
+    let unit_ns = match spec.aggregation {
+        BarAggregation::Millisecond => NANOSECONDS_IN_MILLISECOND,
+        BarAggregation::Second => NANOSECONDS_IN_SECOND,
+        BarAggregation::Minute => 60 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Hour => 60 * 60 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Day => 24 * 60 * 60 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Week => 7 * 24 * 60 * 60 * NANOSECONDS_IN_SECOND,
+        aggregation => bail!("Cannot get a fixed interval for {aggregation} bars"),
+    };
+    check_step(spec)?;
+    Ok(spec.step as u64 * unit_ns)
+}
+
+/// Checks the `step` of the given `spec` is positive, as every aggregator requires.
+fn check_step(spec: &BarSpecification) -> Result<()> {
+    if spec.step == 0 {
+        bail!("Invalid `step` for {spec}, was 0");
+    }
+    Ok(())
+}
+
+/// Provides a means of building tick bars from ticks.
+///
+/// When the number of ticks reaches the step threshold of the bar specification,
+/// a bar is created.
+pub struct TickBarAggregator {
+    builder: BarBuilder,
+}
+
+impl TickBarAggregator {
+    /// Initializes a new [`TickBarAggregator`] instance.
+    ///
+    /// Returns an error if the `step` of the bar type is zero.
+    pub fn new(bar_type: BarType, size_precision: u8) -> Result<Self> {
+        check_step(&bar_type.spec)?;
+        Ok(Self {
+            builder: BarBuilder::new(bar_type, size_precision),
+        })
+    }
+}
+
+impl BarAggregator for TickBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.builder.bar_type
+    }
+
+    fn update(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        _aggressor_side: AggressorSide,
+        ts_event: UnixNanos,
+    ) -> Vec<Bar> {
+        self.builder.update(price, size, ts_event);
+        if self.builder.count() >= self.builder.bar_type.spec.step {
+            return self.builder.build_now().into_iter().collect();
+        }
+        vec![]
+    }
+}
+
+/// Provides a means of building volume bars from ticks.
+///
+/// When the volume reaches the step threshold of the bar specification, a bar is
+/// created. A tick larger than the volume remaining is split across bars.
+pub struct VolumeBarAggregator {
+    builder: BarBuilder,
+}
+
+impl VolumeBarAggregator {
+    /// Initializes a new [`VolumeBarAggregator`] instance.
+    ///
+    /// Returns an error if the `step` of the bar type is zero.
+    pub fn new(bar_type: BarType, size_precision: u8) -> Result<Self> {
+        check_step(&bar_type.spec)?;
+        Ok(Self {
+            builder: BarBuilder::new(bar_type, size_precision),
+        })
+    }
+}
+
+impl BarAggregator for VolumeBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.builder.bar_type
+    }
+
+    fn update(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        _aggressor_side: AggressorSide,
+        ts_event: UnixNanos,
+    ) -> Vec<Bar> {
+        let size_precision = self.builder.size_precision;
+        let step_raw = Quantity::new(self.builder.bar_type.spec.step as f64, size_precision)
+            .unwrap()
+            .raw;
+
+        let mut bars = Vec::new();
+        let mut raw_remaining = size.raw;
+        while raw_remaining > 0 {
+            let raw_diff = step_raw.saturating_sub(self.builder.volume().raw);
+            if raw_remaining < raw_diff {
+                let size_update = Quantity::from_raw(raw_remaining, size_precision).unwrap();
+                self.builder.update(price, size_update, ts_event);
+                break;
+            }
+
+            let size_diff = Quantity::from_raw(raw_diff, size_precision).unwrap();
+            self.builder.update(price, size_diff, ts_event);
+            bars.extend(self.builder.build_now());
+            raw_remaining -= raw_diff;
+        }
+        bars
+    }
+}
+
+/// Provides a means of building value bars from ticks.
+///
+/// When the notional value (price * size) reaches the step threshold of the bar
+/// specification, a bar is created. A tick larger than the value remaining is split
+/// across bars.
+pub struct ValueBarAggregator {
+    builder: BarBuilder,
+    cum_value: f64,
+}
+
+impl ValueBarAggregator {
+    /// Initializes a new [`ValueBarAggregator`] instance.
+    ///
+    /// Returns an error if the `step` of the bar type is zero.
+    pub fn new(bar_type: BarType, size_precision: u8) -> Result<Self> {
+        check_step(&bar_type.spec)?;
+        Ok(Self {
+            builder: BarBuilder::new(bar_type, size_precision),
+            cum_value: 0.0,
+        })
+    }
+
+    /// Returns the cumulative value of the bar being built.
+    #[must_use]
+    pub fn cum_value(&self) -> f64 {
+        self.cum_value
+    }
+}
+
+impl BarAggregator for ValueBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.builder.bar_type
+    }
+
+    fn update(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        _aggressor_side: AggressorSide,
+        ts_event: UnixNanos,
+    ) -> Vec<Bar> {
+        let size_precision = self.builder.size_precision;
+        let step = self.builder.bar_type.spec.step as f64;
+
+        let mut bars = Vec::new();
+        let mut size_update = size;
+        while size_update.raw > 0 {
+            let value_update = price.as_f64() * size_update.as_f64();
+            if self.cum_value + value_update < step {
+                self.cum_value += value_update;
+                self.builder.update(price, size_update, ts_event);
+                break;
+            }
+
+            let value_diff = step - self.cum_value;
+            let size_diff = Quantity::new(
+                size_update.as_f64() * (value_diff / value_update),
+                size_precision,
+            )
+            .unwrap();
+            let size_diff =
+                Quantity::from_raw(size_diff.raw.min(size_update.raw), size_precision).unwrap();
+            self.builder.update(price, size_diff, ts_event);
+            bars.extend(self.builder.build_now());
+            self.cum_value = 0.0;
+            size_update =
+                Quantity::from_raw(size_update.raw - size_diff.raw, size_precision).unwrap();
+        }
+        bars
+    }
+}
+
+/// Classifies ticks as buys or sells.
+///
+/// The aggressor side is used when known, otherwise the tick rule applies: an uptick
+/// is a buy, a downtick is a sell, and an unchanged price repeats the last sign.
+#[derive(Clone, Debug, Default)]
+struct TickRule {
+    last_price: Option<Price>,
+    last_sign: f64,
+}
+
+impl TickRule {
+    fn classify(&mut self, price: Price, aggressor_side: AggressorSide) -> f64 {
+        let sign = match aggressor_side {
+            AggressorSide::Buyer => 1.0,
+            AggressorSide::Seller => -1.0,
+            AggressorSide::NoAggressor => match self.last_price.map(|last| price.cmp(&last)) {
+                Some(Ordering::Greater) => 1.0,
+                Some(Ordering::Less) => -1.0,
+                _ => self.last_sign,
+            },
+        };
+        self.last_price = Some(price);
+        self.last_sign = sign;
+        sign
+    }
+}
+
+/// The number of bars over which the expectations of information-driven bars are
+/// (exponentially) weighted.
+const EXPECTED_WINDOW: usize = 20;
+
+/// Provides an exponentially weighted moving average, seeded by its first value.
+#[derive(Clone, Debug)]
+struct Ewma {
+    alpha: f64,
+    value: Option<f64>,
+}
+
+impl Ewma {
+    fn new(window: usize) -> Self {
+        Self {
+            alpha: 2.0 / (window as f64 + 1.0),
+            value: None,
+        }
+    }
+
+    fn update(&mut self, value: f64) {
+        self.value = Some(match self.value {
+            Some(last) => self.alpha.mul_add(value - last, last),
+            None => value,
+        });
+    }
+}
+
+/// Returns the amount a tick contributes to an information-driven bar: one per tick,
+/// its size, or its notional value.
+fn information_amount(aggregation: BarAggregation, price: Price, size: Quantity) -> f64 {
+    match aggregation {
+        BarAggregation::TickImbalance | BarAggregation::TickRuns => 1.0,
+        BarAggregation::VolumeImbalance | BarAggregation::VolumeRuns => size.as_f64(),
+        _ => price.as_f64() * size.as_f64(),
+    }
+}
+
+/// Provides a means of building imbalance bars from ticks.
+///
+/// Each tick is signed as a buy or sell (see the tick rule), and its amount (one, its
+/// size or its notional value for tick, volume or value imbalance bars) is added to the
+/// signed imbalance. A bar is created when the absolute imbalance reaches the expected
+/// imbalance, being the expected number of ticks per bar times the absolute expected
+/// imbalance per tick, both weighted over previous bars. The step of the bar
+/// specification is the threshold until the first bar is created.
+pub struct ImbalanceBarAggregator {
+    builder: BarBuilder,
+    rule: TickRule,
+    imbalance: f64,
+    expected_ticks: Ewma,
+    expected_tick_imbalance: Ewma,
+}
+
+impl ImbalanceBarAggregator {
+    /// Initializes a new [`ImbalanceBarAggregator`] instance.
+    ///
+    /// Returns an error if the `step` of the bar type is zero.
+    pub fn new(bar_type: BarType, size_precision: u8) -> Result<Self> {
+        check_step(&bar_type.spec)?;
+        Ok(Self {
+            builder: BarBuilder::new(bar_type, size_precision),
+            rule: TickRule::default(),
+            imbalance: 0.0,
+            expected_ticks: Ewma::new(EXPECTED_WINDOW),
+            expected_tick_imbalance: Ewma::new(EXPECTED_WINDOW),
+        })
+    }
+
+    /// Returns the signed imbalance of the bar being built.
+    #[must_use]
+    pub fn imbalance(&self) -> f64 {
+        self.imbalance
+    }
+
+    /// Returns the absolute imbalance at which the bar being built is created.
+    #[must_use]
+    pub fn expected_imbalance(&self) -> f64 {
+        match (
+            self.expected_ticks.value,
+            self.expected_tick_imbalance.value,
+        ) {
+            (Some(ticks), Some(tick_imbalance)) => ticks * tick_imbalance.abs(),
+            _ => self.builder.bar_type.spec.step as f64,
+        }
+    }
+}
+
+impl BarAggregator for ImbalanceBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.builder.bar_type
+    }
+
+    fn update(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        aggressor_side: AggressorSide,
+        ts_event: UnixNanos,
+    ) -> Vec<Bar> {
+        let aggregation = self.builder.bar_type.spec.aggregation;
+        let sign = self.rule.classify(price, aggressor_side);
+        self.builder.update(price, size, ts_event);
+        self.imbalance += sign * information_amount(aggregation, price, size);
+
+        if self.imbalance == 0.0 || self.imbalance.abs() < self.expected_imbalance() {
+            return vec![];
+        }
+
+        let ticks = self.builder.count() as f64;
+        self.expected_ticks.update(ticks);
+        self.expected_tick_imbalance.update(self.imbalance / ticks);
+        self.imbalance = 0.0;
+        self.builder.build_now().into_iter().collect()
+    }
+}
+
+/// Provides a means of building runs bars from ticks.
+///
+/// Each tick is signed as a buy or sell (see the tick rule), and its amount (one, its
+/// size or its notional value for tick, volume or value runs bars) is added to the
+/// buy or sell run of the bar. A bar is created when the larger run reaches the
+/// expected run, being the expected number of ticks per bar times the larger of the
+/// expected buy and sell amounts per tick, all weighted over previous bars. The step
+/// of the bar specification is the threshold until the first bar is created.
+pub struct RunsBarAggregator {
+    builder: BarBuilder,
+    rule: TickRule,
+    buy_run: f64,
+    sell_run: f64,
+    buy_ticks: usize,
+    sell_ticks: usize,
+    expected_ticks: Ewma,
+    expected_buy_fraction: Ewma,
+    expected_buy_amount: Ewma,
+    expected_sell_amount: Ewma,
+}
+
+impl RunsBarAggregator {
+    /// Initializes a new [`RunsBarAggregator`] instance.
+    ///
+    /// Returns an error if the `step` of the bar type is zero.
+    pub fn new(bar_type: BarType, size_precision: u8) -> Result<Self> {
+        check_step(&bar_type.spec)?;
+        Ok(Self {
+            builder: BarBuilder::new(bar_type, size_precision),
+            rule: TickRule::default(),
+            buy_run: 0.0,
+            sell_run: 0.0,
+            buy_ticks: 0,
+            sell_ticks: 0,
+            expected_ticks: Ewma::new(EXPECTED_WINDOW),
+            expected_buy_fraction: Ewma::new(EXPECTED_WINDOW),
+            expected_buy_amount: Ewma::new(EXPECTED_WINDOW),
+            expected_sell_amount: Ewma::new(EXPECTED_WINDOW),
+        })
+    }
+
+    /// Returns the buy run of the bar being built.
+    #[must_use]
+    pub fn buy_run(&self) -> f64 {
+        self.buy_run
+    }
+
+    /// Returns the sell run of the bar being built.
+    #[must_use]
+    pub fn sell_run(&self) -> f64 {
+        self.sell_run
+    }
+
+    /// Returns the run at which the bar being built is created.
+    #[must_use]
+    pub fn expected_run(&self) -> f64 {
+        let (Some(ticks), Some(buy_fraction)) =
+            (self.expected_ticks.value, self.expected_buy_fraction.value)
+        else {
+            return self.builder.bar_type.spec.step as f64;
+        };
+        let buy = buy_fraction * self.expected_buy_amount.value.unwrap_or(0.0);
+        let sell = (1.0 - buy_fraction) * self.expected_sell_amount.value.unwrap_or(0.0);
+        ticks * buy.max(sell)
+    }
+}
+
+impl BarAggregator for RunsBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.builder.bar_type
+    }
+
+    fn update(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        aggressor_side: AggressorSide,
+        ts_event: UnixNanos,
+    ) -> Vec<Bar> {
+        let aggregation = self.builder.bar_type.spec.aggregation;
+        let sign = self.rule.classify(price, aggressor_side);
+        self.builder.update(price, size, ts_event);
+
+        let amount = information_amount(aggregation, price, size);
+        if sign > 0.0 {
+            self.buy_run += amount;
+            self.buy_ticks += 1;
+        } else if sign < 0.0 {
+            self.sell_run += amount;
+            self.sell_ticks += 1;
+        } else {
+            return vec![]; // Cannot classify the tick yet
+        }
+
+        if self.buy_run.max(self.sell_run) < self.expected_run() {
+            return vec![];
+        }
+
+        let ticks = (self.buy_ticks + self.sell_ticks) as f64;
+        self.expected_ticks.update(ticks);
+        self.expected_buy_fraction
+            .update(self.buy_ticks as f64 / ticks);
+        if self.buy_ticks > 0 {
+            self.expected_buy_amount
+                .update(self.buy_run / self.buy_ticks as f64);
+        }
+        if self.sell_ticks > 0 {
+            self.expected_sell_amount
+                .update(self.sell_run / self.sell_ticks as f64);
+        }
+        self.buy_run = 0.0;
+        self.sell_run = 0.0;
+        self.buy_ticks = 0;
+        self.sell_ticks = 0;
+        self.builder.build_now().into_iter().collect()
+    }
+}
+
+/// Provides a means of building time bars from ticks with an internal timer.
+///
+/// The timer is set on a [`Clock`] with intervals aligned to the bar interval (e.g.
+/// one minute bars close on the minute). The owner of the clock must pass each of the
+/// timer's events (named by the bar type) to [`BarAggregator::on_time_event`], which
+/// closes the bar with `ts_event` at the interval close.
+pub struct TimeBarAggregator {
+    builder: BarBuilder,
+    interval_ns: u64,
+    timer_name: String,
+    build_with_no_updates: bool,
+}
+
+impl TimeBarAggregator {
+    /// Initializes a new [`TimeBarAggregator`] instance.
+    ///
+    /// If `build_with_no_updates` then intervals without any updates build a flat bar
+    /// at the last close.
+    pub fn new(bar_type: BarType, size_precision: u8, build_with_no_updates: bool) -> Result<Self> {
+        Ok(Self {
+            builder: BarBuilder::new(bar_type, size_precision),
+            interval_ns: get_bar_interval_ns(&bar_type.spec)?,
+            timer_name: bar_type.to_string(),
+            build_with_no_updates,
+        })
+    }
+
+    #[must_use]
+    pub fn interval_ns(&self) -> u64 {
+        self.interval_ns
+    }
+
+    #[must_use]
+    pub fn timer_name(&self) -> &str {
+        &self.timer_name
+    }
+
+    /// Starts the aggregator, setting its timer on the given `clock` from the start
+    /// of the interval containing `ts_now`.
+    pub fn start(&mut self, clock: &mut dyn Clock, ts_now: UnixNanos) {
+        let start_time_ns = ts_now - (ts_now % self.interval_ns);
+        clock.set_timer_ns(
+            &self.timer_name,
+            self.interval_ns,
+            start_time_ns,
+            None,
+            Some(EventHandler::new(None, None)),
+        );
+    }
+}
+
+impl BarAggregator for TimeBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.builder.bar_type
+    }
+
+    fn update(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        _aggressor_side: AggressorSide,
+        ts_event: UnixNanos,
+    ) -> Vec<Bar> {
+        self.builder.update(price, size, ts_event);
+        vec![]
+    }
+
+    fn on_time_event(&mut self, event: &TimeEvent) -> Option<Bar> {
+        if event.name.as_str() != self.timer_name {
+            return None;
+        }
+        if self.builder.count() == 0 && !self.build_with_no_updates {
+            return None;
+        }
+        self.builder.build(event.ts_event, event.ts_init)
+    }
+
+    fn stop(&mut self, clock: &mut dyn Clock) {
+        clock.cancel_timer(&self.timer_name);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::clock::TestClock;
+    use nautilus_model::identifiers::trade_id::TradeId;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn trade(price: &str, size: &str, aggressor_side: AggressorSide, ts: UnixNanos) -> TradeTick {
+        TradeTick::new(
+            "AUD/USD.SIM".into(),
+            Price::from(price),
+            Quantity::from(size),
+            aggressor_side,
+            TradeId::from("1"),
+            ts,
+            ts,
+        )
+    }
+
+    fn handle_trades(aggregator: &mut dyn BarAggregator, trades: &[TradeTick]) -> Vec<Bar> {
+        trades
+            .iter()
+            .flat_map(|trade| aggregator.handle_trade_tick(trade))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_bar_builder_build() {
+        let bar_type = BarType::from("AUD/USD.SIM-3-TICK-LAST-INTERNAL");
+        let mut builder = BarBuilder::new(bar_type, 0);
+
+        builder.update(Price::from("1.00002"), Quantity::from(1), 1);
+        builder.update(Price::from("1.00005"), Quantity::from(2), 2);
+        builder.update(Price::from("1.00000"), Quantity::from(3), 3);
+        builder.update(Price::from("1.00010"), Quantity::from(4), 2); // Ignored (older)
+        let bar = builder.build_now().unwrap();
+
+        assert_eq!(bar.open, Price::from("1.00002"));
+        assert_eq!(bar.high, Price::from("1.00005"));
+        assert_eq!(bar.low, Price::from("1.00000"));
+        assert_eq!(bar.close, Price::from("1.00000"));
+        assert_eq!(bar.volume, Quantity::from(6));
+        assert_eq!(bar.ts_event, 3);
+        assert_eq!(builder.count(), 0);
+    }
+
+    #[rstest]
+    fn test_bar_builder_build_with_no_updates() {
+        let bar_type = BarType::from("AUD/USD.SIM-1-SECOND-LAST-INTERNAL");
+        let mut builder = BarBuilder::new(bar_type, 0);
+        assert!(builder.build(1, 1).is_none());
+
+        builder.update(Price::from("1.00002"), Quantity::from(1), 1);
+        builder.build(2, 2).unwrap();
+        let bar = builder.build(3, 3).unwrap();
+
+        assert_eq!(bar.open, Price::from("1.00002"));
+        assert_eq!(bar.close, Price::from("1.00002"));
+        assert_eq!(bar.volume, Quantity::from(0));
+    }
+
+    #[rstest]
+    fn test_tick_bar_aggregator() {
+        let bar_type = BarType::from("AUD/USD.SIM-3-TICK-LAST-INTERNAL");
+        let mut aggregator = TickBarAggregator::new(bar_type, 0).unwrap();
+        let trades: Vec<_> = (1..=7)
+            .map(|ts| trade("1.00000", "1", AggressorSide::Buyer, ts))
+            .collect();
+
+        let bars = handle_trades(&mut aggregator, &trades);
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].ts_event, 3);
+        assert_eq!(bars[1].ts_event, 6);
+    }
+
+    #[rstest]
+    fn test_volume_bar_aggregator_splits_large_ticks() {
+        let bar_type = BarType::from("AUD/USD.SIM-10-VOLUME-LAST-INTERNAL");
+        let mut aggregator = VolumeBarAggregator::new(bar_type, 0).unwrap();
+        let trades = [
+            trade("1.00000", "4", AggressorSide::Buyer, 1),
+            trade("1.00001", "4", AggressorSide::Buyer, 2),
+            trade("1.00002", "25", AggressorSide::Buyer, 3),
+        ];
+
+        let bars = handle_trades(&mut aggregator, &trades);
+
+        assert_eq!(bars.len(), 3);
+        assert!(bars.iter().all(|bar| bar.volume == Quantity::from(10)));
+        assert_eq!(bars[0].open, Price::from("1.00000"));
+        assert_eq!(bars[1].open, Price::from("1.00002"));
+        assert_eq!(aggregator.builder.volume(), Quantity::from(3));
+    }
+
+    #[rstest]
+    fn test_value_bar_aggregator_splits_large_ticks() {
+        let bar_type = BarType::from("AUD/USD.SIM-100-VALUE-LAST-INTERNAL");
+        let mut aggregator = ValueBarAggregator::new(bar_type, 0).unwrap();
+        let trades = [trade("10.00", "25", AggressorSide::Buyer, 1)];
+
+        let bars = handle_trades(&mut aggregator, &trades);
+
+        assert_eq!(bars.len(), 2);
+        assert!(bars.iter().all(|bar| bar.volume == Quantity::from(10)));
+        assert_eq!(aggregator.cum_value(), 50.0);
+    }
+
+    #[rstest]
+    fn test_tick_imbalance_bar_aggregator() {
+        let bar_type = BarType::from("AUD/USD.SIM-2-TICK_IMBALANCE-LAST-INTERNAL");
+        let mut aggregator = ImbalanceBarAggregator::new(bar_type, 0).unwrap();
+        let trades = [
+            trade("1.00000", "1", AggressorSide::Buyer, 1),
+            trade("1.00000", "1", AggressorSide::Seller, 2),
+            trade("1.00000", "1", AggressorSide::Seller, 3),
+            trade("1.00000", "1", AggressorSide::Seller, 4),
+        ];
+
+        let bars = handle_trades(&mut aggregator, &trades);
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].ts_event, 4);
+        assert_eq!(bars[0].volume, Quantity::from(4));
+    }
+
+    #[rstest]
+    fn test_volume_imbalance_bar_aggregator_with_tick_rule() {
+        let bar_type = BarType::from("AUD/USD.SIM-5-VOLUME_IMBALANCE-LAST-INTERNAL");
+        let mut aggregator = ImbalanceBarAggregator::new(bar_type, 0).unwrap();
+        let trades = [
+            trade("1.00000", "2", AggressorSide::NoAggressor, 1), // Unclassified
+            trade("1.00001", "2", AggressorSide::NoAggressor, 2), // Uptick
+            trade("1.00001", "3", AggressorSide::NoAggressor, 3), // Unchanged (buy)
+        ];
+
+        let bars = handle_trades(&mut aggregator, &trades);
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].volume, Quantity::from(7));
+        assert_eq!(aggregator.imbalance(), 0.0);
+    }
+
+    #[rstest]
+    fn test_imbalance_bar_aggregator_updates_expected_imbalance() {
+        let bar_type = BarType::from("AUD/USD.SIM-2-TICK_IMBALANCE-LAST-INTERNAL");
+        let mut aggregator = ImbalanceBarAggregator::new(bar_type, 0).unwrap();
+        let trades = [
+            trade("1.00000", "1", AggressorSide::Buyer, 1),
+            trade("1.00000", "1", AggressorSide::Seller, 2),
+            trade("1.00000", "1", AggressorSide::Seller, 3),
+            trade("1.00000", "1", AggressorSide::Seller, 4),
+        ];
+
+        handle_trades(&mut aggregator, &trades);
+
+        // Four ticks per bar with an imbalance of -0.5 per tick
+        assert_eq!(aggregator.expected_imbalance(), 2.0);
+
+        let trades = [
+            trade("1.00000", "1", AggressorSide::Buyer, 5),
+            trade("1.00000", "1", AggressorSide::Buyer, 6),
+        ];
+        let bars = handle_trades(&mut aggregator, &trades);
+
+        assert_eq!(bars.len(), 1);
+        assert!(aggregator.expected_imbalance() < 2.0);
+    }
+
+    #[rstest]
+    fn test_tick_runs_bar_aggregator() {
+        let bar_type = BarType::from("AUD/USD.SIM-2-TICK_RUNS-LAST-INTERNAL");
+        let mut aggregator = RunsBarAggregator::new(bar_type, 0).unwrap();
+        let trades = [
+            trade("1.00000", "1", AggressorSide::Buyer, 1),
+            trade("1.00000", "1", AggressorSide::Seller, 2),
+            trade("1.00000", "1", AggressorSide::Buyer, 3),
+            trade("1.00000", "1", AggressorSide::Buyer, 4),
+        ];
+
+        let bars = handle_trades(&mut aggregator, &trades);
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].ts_event, 3);
+        assert_eq!(bars[0].volume, Quantity::from(3));
+        // Three ticks per bar, two thirds of them buys of one
+        assert_eq!(aggregator.expected_run(), 2.0);
+        assert_eq!(aggregator.buy_run(), 1.0);
+        assert_eq!(aggregator.sell_run(), 0.0);
+    }
+
+    #[rstest]
+    #[case("0-TICK-LAST")]
+    #[case("0-VOLUME-LAST")]
+    #[case("0-VALUE-LAST")]
+    #[case("0-VOLUME_IMBALANCE-LAST")]
+    #[case("0-VALUE_RUNS-LAST")]
+    #[case("0-SECOND-LAST")]
+    fn test_create_bar_aggregator_with_zero_step(#[case] spec: &str) {
+        let bar_type = BarType::from(format!("AUD/USD.SIM-{spec}-INTERNAL").as_str());
+        let mut clock = TestClock::new();
+
+        assert!(create_bar_aggregator(bar_type, 0, &mut clock, 0).is_err());
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_with_test_clock() {
+        let bar_type = BarType::from("AUD/USD.SIM-1-SECOND-BID-INTERNAL");
+        let mut clock = TestClock::new();
+        let mut aggregator = create_bar_aggregator(bar_type, 0, &mut clock, 500_000_000).unwrap();
+        let quote = QuoteTick::new(
+            bar_type.instrument_id,
+            Price::from("1.00000"),
+            Price::from("1.00002"),
+            Quantity::from(1),
+            Quantity::from(2),
+            600_000_000,
+            600_000_000,
+        )
+        .unwrap();
+
+        assert!(aggregator.handle_quote_tick(&quote).is_empty());
+        let events = clock.advance_time(2_000_000_000, true);
+        let bars: Vec<Bar> = events
+            .iter()
+            .filter_map(|event| aggregator.on_time_event(event))
+            .collect();
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].ts_event, 1_000_000_000);
+        assert_eq!(bars[0].close, Price::from("1.00000"));
+        assert_eq!(bars[0].volume, Quantity::from(1));
+        assert_eq!(bars[1].ts_event, 2_000_000_000);
+        assert_eq!(bars[1].volume, Quantity::from(0));
+
+        aggregator.stop(&mut clock);
+        assert_eq!(clock.timer_count(), 0);
+    }
+
+    #[rstest]
+    #[case("1-MILLISECOND-LAST", 1_000_000)]
+    #[case("5-SECOND-LAST", 5_000_000_000)]
+    #[case("1-MINUTE-LAST", 60_000_000_000)]
+    #[case("4-HOUR-LAST", 14_400_000_000_000)]
+    #[case("1-DAY-LAST", 86_400_000_000_000)]
+    fn test_get_bar_interval_ns(#[case] spec: &str, #[case] expected: u64) {
+        let bar_type = BarType::from(format!("AUD/USD.SIM-{spec}-INTERNAL").as_str());
+
+        assert_eq!(get_bar_interval_ns(&bar_type.spec).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("1-MONTH-LAST")]
+    #[case("100-TICK-LAST")]
+    fn test_get_bar_interval_ns_when_not_fixed(#[case] spec: &str) {
+        let bar_type = BarType::from(format!("AUD/USD.SIM-{spec}-INTERNAL").as_str());
+
+        assert!(get_bar_interval_ns(&bar_type.spec).is_err());
+    }
+}
@@ -0,0 +1,747 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The data engine, which manages data subscriptions and fans data out over the `MessageBus`.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    ops::Deref,
+    rc::Rc,
+};
+
+use anyhow::{bail, Result};
+use nautilus_common::{
+    clock::Clock,
+    msgbus::{self, MessageBus},
+    timer::TimeEvent,
+};
+use nautilus_core::time::AtomicTime;
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+        Data,
+    },
+    enums::{AggregationSource, BookType, PriceType},
+    identifiers::instrument_id::InstrumentId,
+    instruments::{any::InstrumentAny, synthetic::SyntheticInstrument},
+    orderbook::any::OrderBookAny,
+    types::quantity::Quantity,
+};
+
+use crate::aggregation::{create_bar_aggregator, BarAggregator};
+
+/// Returns the topic for instruments with the given `instrument_id`.
+#[must_use]
+pub fn get_instrument_topic(instrument_id: &InstrumentId) -> String {
+    format!(
+        "data.instrument.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    )
+}
+
+/// Returns the topic for quotes of the given `instrument_id`.
+#[must_use]
+pub fn get_quotes_topic(instrument_id: &InstrumentId) -> String {
+    format!(
+        "data.quotes.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    )
+}
+
+/// Returns the topic for trades of the given `instrument_id`.
+#[must_use]
+pub fn get_trades_topic(instrument_id: &InstrumentId) -> String {
+    format!(
+        "data.trades.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    )
+}
+
+/// Returns the topic for order book deltas of the given `instrument_id`.
+#[must_use]
+pub fn get_book_deltas_topic(instrument_id: &InstrumentId) -> String {
+    format!(
+        "data.book.deltas.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    )
+}
+
+/// Returns the topic for order book depth snapshots of the given `instrument_id`.
+#[must_use]
+pub fn get_book_depth_topic(instrument_id: &InstrumentId) -> String {
+    format!(
+        "data.book.depth.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    )
+}
+
+/// Returns the topic for bars of the given `bar_type`.
+#[must_use]
+pub fn get_bars_topic(bar_type: &BarType) -> String {
+    format!("data.bars.{bar_type}")
+}
+
+/// Returns the topic for custom data registered under the given `type_name`.
+#[must_use]
+pub fn get_custom_data_topic(type_name: &str) -> String {
+    format!("data.custom.{type_name}")
+}
+
+/// Provides a data engine, which manages data subscriptions and publishes data on
+/// the `MessageBus`.
+///
+/// Each [`Data`] variant is published as its concrete type on the topic for its
+/// instrument, bar type or custom data type (see the `get_*_topic` functions), when
+/// subscribed to (custom data is always published). While processing data the engine
+/// also:
+/// - maintains an order book per instrument subscribed to book deltas or depth.
+/// - drives a bar aggregator per subscribed `INTERNAL` bar type, publishing the bars built.
+/// - prices subscribed synthetic instruments from the quotes of their components.
+///
+/// Time bar aggregators set timers on the engine's clock, whose events must be passed
+/// to [`DataEngine::on_time_event`].
+pub struct DataEngine<C>
+where
+    C: Clock + Deref<Target = AtomicTime>,
+{
+    clock: C,
+    msgbus: Rc<RefCell<MessageBus>>,
+    instruments: HashMap<InstrumentId, InstrumentAny>,
+    synthetics: HashMap<InstrumentId, SyntheticInstrument>,
+    books: HashMap<InstrumentId, OrderBookAny>,
+    last_quotes: HashMap<InstrumentId, QuoteTick>,
+    bar_aggregators: HashMap<BarType, Box<dyn BarAggregator>>,
+    subscribed_quotes: HashSet<InstrumentId>,
+    subscribed_trades: HashSet<InstrumentId>,
+    subscribed_book_deltas: HashSet<InstrumentId>,
+    subscribed_book_depth: HashSet<InstrumentId>,
+    subscribed_bars: HashSet<BarType>,
+}
+
+impl<C> DataEngine<C>
+where
+    C: Clock + Deref<Target = AtomicTime>,
+{
+    /// Initializes a new [`DataEngine`] instance.
+    #[must_use]
+    pub fn new(clock: C, msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self {
+            clock,
+            msgbus,
+            instruments: HashMap::new(),
+            synthetics: HashMap::new(),
+            books: HashMap::new(),
+            last_quotes: HashMap::new(),
+            bar_aggregators: HashMap::new(),
+            subscribed_quotes: HashSet::new(),
+            subscribed_trades: HashSet::new(),
+            subscribed_book_deltas: HashSet::new(),
+            subscribed_book_depth: HashSet::new(),
+            subscribed_bars: HashSet::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    #[must_use]
+    pub fn clock_mut(&mut self) -> &mut C {
+        &mut self.clock
+    }
+
+    /// Adds the given `instrument` to the engine, publishing it on the instrument topic.
+    pub fn add_instrument(&mut self, instrument: InstrumentAny) {
+        let topic = get_instrument_topic(&instrument.id());
+        msgbus::publish(&self.msgbus, &topic, &instrument);
+        self.instruments.insert(instrument.id(), instrument);
+    }
+
+    /// Adds the given `synthetic` instrument to the engine.
+    pub fn add_synthetic(&mut self, synthetic: SyntheticInstrument) {
+        self.synthetics.insert(synthetic.id, synthetic);
+    }
+
+    #[must_use]
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<&InstrumentAny> {
+        self.instruments.get(instrument_id)
+    }
+
+    #[must_use]
+    pub fn synthetic(&self, instrument_id: &InstrumentId) -> Option<&SyntheticInstrument> {
+        self.synthetics.get(instrument_id)
+    }
+
+    #[must_use]
+    pub fn order_book(&self, instrument_id: &InstrumentId) -> Option<&OrderBookAny> {
+        self.books.get(instrument_id)
+    }
+
+    #[must_use]
+    pub fn subscribed_quotes(&self) -> Vec<InstrumentId> {
+        self.subscribed_quotes.iter().copied().collect()
+    }
+
+    #[must_use]
+    pub fn subscribed_trades(&self) -> Vec<InstrumentId> {
+        self.subscribed_trades.iter().copied().collect()
+    }
+
+    #[must_use]
+    pub fn subscribed_order_book_deltas(&self) -> Vec<InstrumentId> {
+        self.subscribed_book_deltas.iter().copied().collect()
+    }
+
+    #[must_use]
+    pub fn subscribed_order_book_depth(&self) -> Vec<InstrumentId> {
+        self.subscribed_book_depth.iter().copied().collect()
+    }
+
+    #[must_use]
+    pub fn subscribed_bars(&self) -> Vec<BarType> {
+        self.subscribed_bars.iter().copied().collect()
+    }
+
+    /// Subscribes to quotes for the given `instrument_id`.
+    ///
+    /// Quotes for a synthetic instrument are computed from its component quotes, so the
+    /// synthetic must have been added to the engine.
+    pub fn subscribe_quotes(&mut self, instrument_id: InstrumentId) -> Result<()> {
+        self.check_instrument_known(&instrument_id)?;
+        self.subscribed_quotes.insert(instrument_id);
+        Ok(())
+    }
+
+    pub fn unsubscribe_quotes(&mut self, instrument_id: &InstrumentId) {
+        self.subscribed_quotes.remove(instrument_id);
+    }
+
+    /// Subscribes to trades for the given `instrument_id`.
+    pub fn subscribe_trades(&mut self, instrument_id: InstrumentId) -> Result<()> {
+        self.check_instrument_known(&instrument_id)?;
+        self.subscribed_trades.insert(instrument_id);
+        Ok(())
+    }
+
+    pub fn unsubscribe_trades(&mut self, instrument_id: &InstrumentId) {
+        self.subscribed_trades.remove(instrument_id);
+    }
+
+    /// Subscribes to order book deltas for the given `instrument_id`, maintaining an order
+    /// book of the given `book_type` from them.
+    pub fn subscribe_order_book_deltas(
+        &mut self,
+        instrument_id: InstrumentId,
+        book_type: BookType,
+    ) -> Result<()> {
+        self.check_instrument_known(&instrument_id)?;
+        if let Some(book) = self.books.get(&instrument_id) {
+            if book.book_type() != book_type {
+                bail!(
+                    "Cannot subscribe to {book_type} deltas for {instrument_id}, \
+                    a {} book is already maintained",
+                    book.book_type()
+                );
+            }
+        }
+
+        self.books
+            .entry(instrument_id)
+            .or_insert_with(|| OrderBookAny::new(instrument_id, book_type));
+        self.subscribed_book_deltas.insert(instrument_id);
+        Ok(())
+    }
+
+    pub fn unsubscribe_order_book_deltas(&mut self, instrument_id: &InstrumentId) {
+        self.subscribed_book_deltas.remove(instrument_id);
+        self.remove_book_if_unsubscribed(instrument_id);
+    }
+
+    /// Subscribes to order book depth snapshots for the given `instrument_id`, maintaining
+    /// an `L2_MBP` order book from them (unless a book is already maintained).
+    pub fn subscribe_order_book_depth(&mut self, instrument_id: InstrumentId) -> Result<()> {
+        self.check_instrument_known(&instrument_id)?;
+        self.books
+            .entry(instrument_id)
+            .or_insert_with(|| OrderBookAny::new(instrument_id, BookType::L2_MBP));
+        self.subscribed_book_depth.insert(instrument_id);
+        Ok(())
+    }
+
+    pub fn unsubscribe_order_book_depth(&mut self, instrument_id: &InstrumentId) {
+        self.subscribed_book_depth.remove(instrument_id);
+        self.remove_book_if_unsubscribed(instrument_id);
+    }
+
+    /// Subscribes to bars of the given `bar_type`.
+    ///
+    /// For `INTERNAL` bar types an aggregator is created, which builds the bars from the
+    /// instrument's quotes (or trades for `LAST` prices) as they are processed.
+    pub fn subscribe_bars(&mut self, bar_type: BarType) -> Result<()> {
+        if self.subscribed_bars.contains(&bar_type) {
+            return Ok(());
+        }
+
+        if bar_type.aggregation_source == AggregationSource::Internal {
+            let size_precision = match self.instruments.get(&bar_type.instrument_id) {
+                Some(instrument) => instrument.size_precision(),
+                None => match self.synthetics.get(&bar_type.instrument_id) {
+                    Some(_) => 0, // Synthetic quotes have unit sizes
+                    None => bail!("Cannot aggregate {bar_type} bars, instrument not found"),
+                },
+            };
+            let ts_now = self.clock.get_time_ns();
+            let aggregator =
+                create_bar_aggregator(bar_type, size_precision, &mut self.clock, ts_now)?;
+            self.bar_aggregators.insert(bar_type, aggregator);
+        }
+
+        self.subscribed_bars.insert(bar_type);
+        Ok(())
+    }
+
+    pub fn unsubscribe_bars(&mut self, bar_type: &BarType) {
+        self.subscribed_bars.remove(bar_type);
+        if let Some(mut aggregator) = self.bar_aggregators.remove(bar_type) {
+            aggregator.stop(&mut self.clock);
+        }
+    }
+
+    /// Processes the given `data`, updating the engine's order books and bar aggregators,
+    /// then publishing it (and any data derived from it) on the message bus when subscribed.
+    pub fn process(&mut self, data: Data) -> Result<()> {
+        match data {
+            Data::Delta(delta) => {
+                if let Some(book) = self.books.get_mut(&delta.instrument_id) {
+                    book.apply_delta(delta);
+                }
+                if self.subscribed_book_deltas.contains(&delta.instrument_id) {
+                    self.publish(&get_book_deltas_topic(&delta.instrument_id), &delta);
+                }
+            }
+            Data::Deltas(deltas) => {
+                if let Some(book) = self.books.get_mut(&deltas.instrument_id) {
+                    book.apply_deltas((*deltas).clone());
+                }
+                if self.subscribed_book_deltas.contains(&deltas.instrument_id) {
+                    self.publish(
+                        &get_book_deltas_topic(&deltas.instrument_id),
+                        deltas.deref(),
+                    );
+                }
+            }
+            Data::Depth10(depth) => {
+                if let Some(book) = self.books.get_mut(&depth.instrument_id) {
+                    book.apply_depth(depth);
+                }
+                if self.subscribed_book_depth.contains(&depth.instrument_id) {
+                    self.publish(&get_book_depth_topic(&depth.instrument_id), &depth);
+                }
+            }
+            Data::Quote(quote) => {
+                self.handle_quote(&quote);
+                self.update_synthetics(&quote)?;
+            }
+            Data::Trade(trade) => self.handle_trade(&trade),
+            Data::Bar(bar) => {
+                if self.subscribed_bars.contains(&bar.bar_type) {
+                    self.publish(&get_bars_topic(&bar.bar_type), &bar);
+                }
+            }
+            Data::Custom(custom) => {
+                self.publish(&get_custom_data_topic(custom.type_name()), &custom);
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles the given time `event`, publishing any bar built by the time bar aggregator
+    /// which set the timer.
+    pub fn on_time_event(&mut self, event: &TimeEvent) {
+        let bars: Vec<Bar> = self
+            .bar_aggregators
+            .values_mut()
+            .filter_map(|aggregator| aggregator.on_time_event(event))
+            .collect();
+
+        for bar in bars {
+            self.publish(&get_bars_topic(&bar.bar_type), &bar);
+        }
+    }
+
+    fn check_instrument_known(&self, instrument_id: &InstrumentId) -> Result<()> {
+        if instrument_id.is_synthetic() && !self.synthetics.contains_key(instrument_id) {
+            bail!("Synthetic instrument {instrument_id} not found");
+        }
+        Ok(())
+    }
+
+    fn remove_book_if_unsubscribed(&mut self, instrument_id: &InstrumentId) {
+        if !self.subscribed_book_deltas.contains(instrument_id)
+            && !self.subscribed_book_depth.contains(instrument_id)
+        {
+            self.books.remove(instrument_id);
+        }
+    }
+
+    fn publish<T: 'static>(&self, topic: &str, message: &T) {
+        msgbus::publish(&self.msgbus, topic, message);
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.last_quotes.insert(quote.instrument_id, *quote);
+
+        if let Some(book) = self.books.get_mut(&quote.instrument_id) {
+            if book.book_type() == BookType::L1_MBP {
+                // Only top-of-book is maintained, which the quote fully describes
+                let _ = book.update_quote_tick(quote);
+            }
+        }
+
+        let bars: Vec<Bar> = self
+            .bar_aggregators
+            .values_mut()
+            .filter(|aggregator| {
+                let bar_type = aggregator.bar_type();
+                bar_type.instrument_id == quote.instrument_id
+                    && bar_type.spec.price_type != PriceType::Last
+            })
+            .flat_map(|aggregator| aggregator.handle_quote_tick(quote))
+            .collect();
+
+        if self.subscribed_quotes.contains(&quote.instrument_id) {
+            self.publish(&get_quotes_topic(&quote.instrument_id), quote);
+        }
+        for bar in bars {
+            self.publish(&get_bars_topic(&bar.bar_type), &bar);
+        }
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        let bars: Vec<Bar> = self
+            .bar_aggregators
+            .values_mut()
+            .filter(|aggregator| {
+                let bar_type = aggregator.bar_type();
+                bar_type.instrument_id == trade.instrument_id
+                    && bar_type.spec.price_type == PriceType::Last
+            })
+            .flat_map(|aggregator| aggregator.handle_trade_tick(trade))
+            .collect();
+
+        if self.subscribed_trades.contains(&trade.instrument_id) {
+            self.publish(&get_trades_topic(&trade.instrument_id), trade);
+        }
+        for bar in bars {
+            self.publish(&get_bars_topic(&bar.bar_type), &bar);
+        }
+    }
+
+    fn update_synthetics(&mut self, quote: &QuoteTick) -> Result<()> {
+        let mut synthetic_quotes = Vec::new();
+
+        'synthetics: for synthetic in self.synthetics.values_mut() {
+            if !self.subscribed_quotes.contains(&synthetic.id)
+                || !synthetic.components.contains(&quote.instrument_id)
+            {
+                continue;
+            }
+
+            let mut bids = Vec::with_capacity(synthetic.components.len());
+            let mut asks = Vec::with_capacity(synthetic.components.len());
+            for component_id in &synthetic.components {
+                match self.last_quotes.get(component_id) {
+                    Some(component) => {
+                        bids.push(component.bid_price.as_f64());
+                        asks.push(component.ask_price.as_f64());
+                    }
+                    None => continue 'synthetics, // Not yet priced
+                }
+            }
+
+            let bid_price = synthetic.calculate(&bids)?;
+            let ask_price = synthetic.calculate(&asks)?;
+            let size = Quantity::new(1.0, 0)?;
+            synthetic_quotes.push(QuoteTick::new(
+                synthetic.id,
+                bid_price,
+                ask_price,
+                size,
+                size,
+                quote.ts_event,
+                quote.ts_init,
+            )?);
+        }
+
+        for synthetic_quote in synthetic_quotes {
+            self.handle_quote(&synthetic_quote);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use nautilus_common::{clock::TestClock, handlers::MessageHandler};
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        data::{
+            custom::{stubs::FundingRate, CustomData},
+            delta::OrderBookDelta,
+            order::BookOrder,
+        },
+        enums::{AggressorSide, BookAction, OrderSide},
+        identifiers::{symbol::Symbol, trade_id::TradeId, trader_id::TraderId},
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        types::price::Price,
+    };
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+
+    fn engine(instrument: CurrencyPair) -> DataEngine<TestClock> {
+        let msgbus =
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap();
+        let mut engine = DataEngine::new(TestClock::new(), Rc::new(RefCell::new(msgbus)));
+        engine.add_instrument(InstrumentAny::CurrencyPair(instrument));
+        engine
+    }
+
+    /// Subscribes a handler to `topic` which records the messages of type `T` received.
+    fn subscribe<T: Clone + Send + 'static>(
+        engine: &DataEngine<TestClock>,
+        topic: &str,
+    ) -> Arc<Mutex<Vec<T>>> {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let handler = MessageHandler::with_typed_callback(Ustr::from(topic), move |m: &T| {
+            sink.lock().unwrap().push(m.clone());
+        });
+        engine.msgbus.borrow_mut().subscribe(topic, handler, None);
+        received
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            ts,
+            ts,
+        )
+        .unwrap()
+    }
+
+    fn trade(instrument_id: InstrumentId, price: &str, ts: u64) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from(price),
+            Quantity::from(100_000),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            ts,
+            ts,
+        )
+    }
+
+    #[rstest]
+    fn test_publishes_quotes_and_trades_on_topics(audusd_sim: CurrencyPair) {
+        let mut engine = engine(audusd_sim);
+        engine.subscribe_quotes(audusd_sim.id).unwrap();
+        engine.subscribe_trades(audusd_sim.id).unwrap();
+        let quotes = subscribe::<QuoteTick>(&engine, "data.quotes.SIM.*");
+        let trades = subscribe::<TradeTick>(&engine, "data.trades.SIM.AUD/USD");
+        let quote = quote(audusd_sim.id, "1.00000", "1.00001", 1);
+        let trade = trade(audusd_sim.id, "1.00000", 2);
+
+        engine.process(Data::Quote(quote)).unwrap();
+        engine.process(Data::Trade(trade)).unwrap();
+
+        assert_eq!(*quotes.lock().unwrap(), vec![quote]);
+        assert_eq!(*trades.lock().unwrap(), vec![trade]);
+    }
+
+    #[rstest]
+    fn test_does_not_publish_unsubscribed_data(audusd_sim: CurrencyPair) {
+        let mut engine = engine(audusd_sim);
+        engine.subscribe_quotes(audusd_sim.id).unwrap();
+        let quotes = subscribe::<QuoteTick>(&engine, "data.quotes.SIM.*");
+        let trades = subscribe::<TradeTick>(&engine, "data.trades.SIM.*");
+
+        engine.unsubscribe_quotes(&audusd_sim.id);
+        engine
+            .process(Data::Quote(quote(audusd_sim.id, "1.00000", "1.00001", 1)))
+            .unwrap();
+        engine
+            .process(Data::Trade(trade(audusd_sim.id, "1.00000", 2)))
+            .unwrap();
+
+        assert!(quotes.lock().unwrap().is_empty());
+        assert!(trades.lock().unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_publishes_custom_data_on_topic(audusd_sim: CurrencyPair) {
+        let mut engine = engine(audusd_sim);
+        let received = subscribe::<CustomData>(&engine, "data.custom.FundingRate");
+        let funding = FundingRate {
+            instrument_id: audusd_sim.id,
+            rate: 0.0001,
+            ts_event: 1,
+            ts_init: 1,
+        };
+
+        engine
+            .process(Data::Custom(CustomData::new(funding)))
+            .unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].downcast_ref::<FundingRate>(), Some(&funding));
+    }
+
+    #[rstest]
+    fn test_maintains_order_book_from_deltas(audusd_sim: CurrencyPair) {
+        let mut engine = engine(audusd_sim);
+        engine
+            .subscribe_order_book_deltas(audusd_sim.id, BookType::L2_MBP)
+            .unwrap();
+        let deltas = subscribe::<OrderBookDelta>(&engine, "data.book.deltas.SIM.AUD/USD");
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            1,
+        );
+        let delta = OrderBookDelta::new(audusd_sim.id, BookAction::Add, order, 0, 1, 1, 1);
+
+        engine.process(Data::Delta(delta)).unwrap();
+
+        let book = engine.order_book(&audusd_sim.id).unwrap();
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.00000")));
+        assert_eq!(deltas.lock().unwrap().len(), 1);
+        assert!(engine
+            .subscribe_order_book_deltas(audusd_sim.id, BookType::L3_MBO)
+            .is_err());
+
+        engine.unsubscribe_order_book_deltas(&audusd_sim.id);
+
+        assert!(engine.order_book(&audusd_sim.id).is_none());
+    }
+
+    #[rstest]
+    fn test_aggregates_internal_bars_from_trades(audusd_sim: CurrencyPair) {
+        let mut engine = engine(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-2-TICK-LAST-INTERNAL");
+        engine.subscribe_bars(bar_type).unwrap();
+        let bars = subscribe::<Bar>(&engine, &get_bars_topic(&bar_type));
+
+        for ts in 1..=5 {
+            engine
+                .process(Data::Trade(trade(audusd_sim.id, "1.00000", ts)))
+                .unwrap();
+        }
+        // Quotes do not update bars aggregated from `LAST` prices
+        engine
+            .process(Data::Quote(quote(audusd_sim.id, "1.00000", "1.00001", 6)))
+            .unwrap();
+
+        let bars = bars.lock().unwrap();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[1].ts_event, 4);
+        assert_eq!(bars[1].volume, Quantity::from(200_000));
+    }
+
+    #[rstest]
+    fn test_aggregates_time_bars_on_time_events(audusd_sim: CurrencyPair) {
+        let mut engine = engine(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-1-SECOND-BID-INTERNAL");
+        engine.subscribe_bars(bar_type).unwrap();
+        let bars = subscribe::<Bar>(&engine, "data.bars.*");
+
+        engine
+            .process(Data::Quote(quote(audusd_sim.id, "1.00000", "1.00001", 1)))
+            .unwrap();
+        for event in engine.clock_mut().advance_time(1_000_000_000, true) {
+            engine.on_time_event(&event);
+        }
+
+        assert_eq!(bars.lock().unwrap().len(), 1);
+        assert_eq!(bars.lock().unwrap()[0].close, Price::from("1.00000"));
+
+        engine.unsubscribe_bars(&bar_type);
+
+        assert_eq!(engine.clock().timer_count(), 0);
+        assert!(engine.subscribed_bars().is_empty());
+    }
+
+    #[rstest]
+    fn test_subscribe_bars_for_unknown_instrument() {
+        let msgbus =
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap();
+        let mut engine = DataEngine::new(TestClock::new(), Rc::new(RefCell::new(msgbus)));
+
+        let result = engine.subscribe_bars(BarType::from("AUD/USD.SIM-1-TICK-LAST-INTERNAL"));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_prices_synthetic_from_component_quotes(audusd_sim: CurrencyPair) {
+        let mut engine = engine(audusd_sim);
+        let btc_id = InstrumentId::from("BTC.BINANCE");
+        let ltc_id = InstrumentId::from("LTC.BINANCE");
+        let synthetic = SyntheticInstrument::new(
+            Symbol::new("BTC-LTC").unwrap(),
+            2,
+            vec![btc_id, ltc_id],
+            "(BTC.BINANCE + LTC.BINANCE) / 2".to_string(),
+            0,
+            0,
+        )
+        .unwrap();
+        let synthetic_id = synthetic.id;
+        engine.add_synthetic(synthetic);
+        engine.subscribe_quotes(synthetic_id).unwrap();
+        let quotes = subscribe::<QuoteTick>(&engine, &get_quotes_topic(&synthetic_id));
+
+        engine
+            .process(Data::Quote(quote(btc_id, "100.00", "100.02", 1)))
+            .unwrap();
+        assert!(quotes.lock().unwrap().is_empty()); // Not all components priced
+
+        engine
+            .process(Data::Quote(quote(ltc_id, "200.00", "200.04", 2)))
+            .unwrap();
+
+        let quotes = quotes.lock().unwrap();
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0].bid_price, Price::from("150.00"));
+        assert_eq!(quotes[0].ask_price, Price::from("150.03"));
+        assert_eq!(quotes[0].ts_event, 2);
+    }
+}
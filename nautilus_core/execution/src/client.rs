@@ -0,0 +1,35 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use anyhow::Result;
+use nautilus_model::{
+    enums::OmsType,
+    identifiers::{client_id::ClientId, venue::Venue},
+};
+
+use crate::messages::{
+    cancel::CancelOrder, cancel_all::CancelAllOrders, modify::ModifyOrder, submit::SubmitOrder,
+};
+
+/// Represents a client which sends order commands to a trading venue.
+pub trait ExecutionClient {
+    fn client_id(&self) -> ClientId;
+    fn venue(&self) -> Venue;
+    fn oms_type(&self) -> OmsType;
+    fn submit_order(&mut self, command: &SubmitOrder) -> Result<()>;
+    fn modify_order(&mut self, command: &ModifyOrder) -> Result<()>;
+    fn cancel_order(&mut self, command: &CancelOrder) -> Result<()>;
+    fn cancel_all_orders(&mut self, command: &CancelAllOrders) -> Result<()>;
+}
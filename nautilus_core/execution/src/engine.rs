@@ -0,0 +1,725 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::IndexMap;
+use nautilus_common::generators::position_id::PositionIdGenerator;
+use nautilus_core::time::AtomicTime;
+use nautilus_model::{
+    enums::OmsType,
+    events::{
+        order::{event::OrderEvent, filled::OrderFilled},
+        position::{
+            changed::PositionChanged, closed::PositionClosed, opened::PositionOpened, PositionEvent,
+        },
+    },
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trader_id::TraderId, venue::Venue,
+    },
+    instruments::Instrument,
+    orders::any::OrderAny,
+    position::Position,
+    types::money::Money,
+};
+
+use crate::{client::ExecutionClient, messages::TradingCommand};
+
+/// Provides an execution engine, which routes order commands to execution clients
+/// and processes the resulting order events.
+///
+/// Commands are routed to the client registered for the command's `ClientId`, then
+/// to the client routed for the instrument's venue, then to the default client.
+///
+/// Order events are applied to the engine's orders, and fills open, update and
+/// close positions according to the `OmsType` of the strategy (or of the client):
+/// - `NETTING`: one position per instrument and strategy.
+/// - `HEDGING`: a position per position ID, generating new IDs where the venue
+///   does not provide them.
+pub struct ExecutionEngine {
+    clock: &'static AtomicTime,
+    clients: IndexMap<ClientId, Box<dyn ExecutionClient>>,
+    default_client: Option<ClientId>,
+    routing_map: HashMap<Venue, ClientId>,
+    oms_overrides: HashMap<StrategyId, OmsType>,
+    instruments: HashMap<InstrumentId, Box<dyn Instrument>>,
+    orders: IndexMap<ClientOrderId, OrderAny>,
+    positions: IndexMap<PositionId, Position>,
+    position_id_generator: PositionIdGenerator,
+    position_events: Vec<PositionEvent>,
+}
+
+impl ExecutionEngine {
+    /// Initializes a new [`ExecutionEngine`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, clock: &'static AtomicTime) -> Self {
+        Self {
+            clock,
+            clients: IndexMap::new(),
+            default_client: None,
+            routing_map: HashMap::new(),
+            oms_overrides: HashMap::new(),
+            instruments: HashMap::new(),
+            orders: IndexMap::new(),
+            positions: IndexMap::new(),
+            position_id_generator: PositionIdGenerator::new(trader_id, clock),
+            position_events: Vec::new(),
+        }
+    }
+
+    /// Registers the given execution `client`, routing commands for its venue to it
+    /// (unless the venue is already routed).
+    pub fn register_client(&mut self, client: Box<dyn ExecutionClient>) -> Result<()> {
+        let client_id = client.client_id();
+        if self.clients.contains_key(&client_id) {
+            bail!("Execution client {client_id} already registered");
+        }
+
+        self.routing_map.entry(client.venue()).or_insert(client_id);
+        self.clients.insert(client_id, client);
+        Ok(())
+    }
+
+    /// Registers the given execution `client` as the default client, for commands
+    /// which cannot otherwise be routed.
+    pub fn register_default_client(&mut self, client: Box<dyn ExecutionClient>) -> Result<()> {
+        let client_id = client.client_id();
+        self.register_client(client)?;
+        self.default_client = Some(client_id);
+        Ok(())
+    }
+
+    /// Routes commands for the given `venue` to the registered client with `client_id`.
+    pub fn register_venue_routing(&mut self, client_id: ClientId, venue: Venue) -> Result<()> {
+        if !self.clients.contains_key(&client_id) {
+            bail!("Execution client {client_id} not registered");
+        }
+
+        self.routing_map.insert(venue, client_id);
+        Ok(())
+    }
+
+    /// Registers the given `oms_type` for the strategy, overriding the client's `OmsType`.
+    pub fn register_oms_type(&mut self, strategy_id: StrategyId, oms_type: OmsType) {
+        self.oms_overrides.insert(strategy_id, oms_type);
+    }
+
+    pub fn add_instrument(&mut self, instrument: Box<dyn Instrument>) {
+        self.instruments.insert(instrument.id(), instrument);
+    }
+
+    #[must_use]
+    pub fn client_ids(&self) -> Vec<ClientId> {
+        self.clients.keys().copied().collect()
+    }
+
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<&OrderAny> {
+        self.orders.get(client_order_id)
+    }
+
+    #[must_use]
+    pub fn orders(&self) -> Vec<&OrderAny> {
+        self.orders.values().collect()
+    }
+
+    #[must_use]
+    pub fn position(&self, position_id: &PositionId) -> Option<&Position> {
+        self.positions.get(position_id)
+    }
+
+    /// Returns the open positions, optionally filtered by `instrument_id`.
+    #[must_use]
+    pub fn positions_open(&self, instrument_id: Option<&InstrumentId>) -> Vec<&Position> {
+        self.positions
+            .values()
+            .filter(|position| position.is_open())
+            .filter(|position| instrument_id.map_or(true, |id| position.instrument_id == *id))
+            .collect()
+    }
+
+    /// Returns the closed positions, optionally filtered by `instrument_id`.
+    #[must_use]
+    pub fn positions_closed(&self, instrument_id: Option<&InstrumentId>) -> Vec<&Position> {
+        self.positions
+            .values()
+            .filter(|position| position.is_closed())
+            .filter(|position| instrument_id.map_or(true, |id| position.instrument_id == *id))
+            .collect()
+    }
+
+    /// Drains the position events published since the last drain.
+    pub fn drain_position_events(&mut self) -> Vec<PositionEvent> {
+        self.position_events.drain(..).collect()
+    }
+
+    /// Routes the given `command` to its execution client.
+    ///
+    /// The order of a `SubmitOrder` command is added to the engine once the client accepts it.
+    pub fn execute(&mut self, command: TradingCommand) -> Result<()> {
+        let venue = command.instrument_id().venue;
+        let client_id = if self.clients.contains_key(&command.client_id()) {
+            command.client_id()
+        } else {
+            self.routing_map
+                .get(&venue)
+                .or(self.default_client.as_ref())
+                .copied()
+                .ok_or_else(|| anyhow!("No execution client found for {command:?}"))?
+        };
+        let client = self
+            .clients
+            .get_mut(&client_id)
+            .expect("Routed client should be registered");
+
+        match &command {
+            TradingCommand::SubmitOrder(submit) => {
+                let client_order_id = submit.order.client_order_id();
+                if self.orders.contains_key(&client_order_id) {
+                    bail!("Order {client_order_id} already exists");
+                }
+                client.submit_order(submit)?;
+                self.orders.insert(client_order_id, submit.order.clone());
+                Ok(())
+            }
+            TradingCommand::ModifyOrder(modify) => client.modify_order(modify),
+            TradingCommand::CancelOrder(cancel) => client.cancel_order(cancel),
+            TradingCommand::CancelAllOrders(cancel_all) => client.cancel_all_orders(cancel_all),
+        }
+    }
+
+    /// Processes the given order `event`, applying it to its order and handling
+    /// any fill against the positions.
+    pub fn process(&mut self, event: OrderEvent) -> Result<()> {
+        let client_order_id = event.client_order_id();
+        let order = self
+            .orders
+            .get_mut(&client_order_id)
+            .ok_or_else(|| anyhow!("Order {client_order_id} not found"))?;
+
+        let (fill, is_partial) = match event {
+            OrderEvent::OrderFilled(fill) => (fill, false),
+            OrderEvent::OrderPartiallyFilled(fill) => (fill, true),
+            event => {
+                order.apply(event)?;
+                return Ok(());
+            }
+        };
+
+        let oms_type = self.oms_type(&fill);
+        let mut fill = fill;
+        fill.position_id = Some(self.determine_position_id(&fill, oms_type));
+
+        let order = self
+            .orders
+            .get_mut(&client_order_id)
+            .expect("Order should exist");
+        if is_partial {
+            order.apply(OrderEvent::OrderPartiallyFilled(fill))?;
+        } else {
+            order.apply(OrderEvent::OrderFilled(fill))?;
+        }
+
+        self.handle_fill(fill, oms_type)
+    }
+
+    fn oms_type(&self, fill: &OrderFilled) -> OmsType {
+        let oms_type = self
+            .oms_overrides
+            .get(&fill.strategy_id)
+            .copied()
+            .or_else(|| {
+                self.routing_map
+                    .get(&fill.instrument_id.venue)
+                    .or(self.default_client.as_ref())
+                    .and_then(|client_id| self.clients.get(client_id))
+                    .map(|client| client.oms_type())
+            });
+
+        match oms_type {
+            Some(OmsType::Hedging) => OmsType::Hedging,
+            _ => OmsType::Netting,
+        }
+    }
+
+    fn determine_position_id(&mut self, fill: &OrderFilled, oms_type: OmsType) -> PositionId {
+        match oms_type {
+            OmsType::Hedging => fill
+                .position_id
+                .or_else(|| {
+                    self.orders
+                        .get(&fill.client_order_id)
+                        .and_then(|order| order.position_id())
+                })
+                .unwrap_or_else(|| self.position_id_generator.generate(fill.strategy_id, false)),
+            _ => PositionId::from(format!("{}-{}", fill.instrument_id, fill.strategy_id).as_str()),
+        }
+    }
+
+    fn handle_fill(&mut self, fill: OrderFilled, oms_type: OmsType) -> Result<()> {
+        let position_id = fill.position_id.expect("Position ID should be assigned");
+        let Some(position) = self.positions.get(&position_id) else {
+            return self.open_position(fill);
+        };
+
+        if position.is_closed() {
+            self.open_position(fill)
+        } else if position.is_opposite_side(fill.order_side) && fill.last_qty > position.quantity {
+            self.flip_position(fill, oms_type)
+        } else {
+            self.update_position(fill);
+            Ok(())
+        }
+    }
+
+    fn open_position(&mut self, fill: OrderFilled) -> Result<()> {
+        let position_id = fill.position_id.expect("Position ID should be assigned");
+        if let Some(position) = self.positions.get_mut(&position_id) {
+            // Reopens a closed position
+            position.apply(&fill);
+        } else {
+            let instrument = self
+                .instruments
+                .get(&fill.instrument_id)
+                .ok_or_else(|| anyhow!("Instrument {} not found", fill.instrument_id))?;
+            let position = Position::new(instrument.as_ref(), fill)?;
+            self.positions.insert(position_id, position);
+        }
+
+        let position = &self.positions[&position_id];
+        let event = PositionOpened::create(position, &fill, self.clock.get_time_ns());
+        self.position_events
+            .push(PositionEvent::PositionOpened(event));
+        Ok(())
+    }
+
+    fn update_position(&mut self, fill: OrderFilled) {
+        let position_id = fill.position_id.expect("Position ID should be assigned");
+        let position = self
+            .positions
+            .get_mut(&position_id)
+            .expect("Position should exist");
+        position.apply(&fill);
+
+        let ts_init = self.clock.get_time_ns();
+        let event = if position.is_closed() {
+            PositionEvent::PositionClosed(PositionClosed::create(position, &fill, ts_init))
+        } else {
+            PositionEvent::PositionChanged(PositionChanged::create(position, &fill, ts_init))
+        };
+        self.position_events.push(event);
+    }
+
+    /// Splits a fill which flips the side of a position into a fill closing the
+    /// position, and a fill opening a position on the other side.
+    fn flip_position(&mut self, fill: OrderFilled, oms_type: OmsType) -> Result<()> {
+        let position_id = fill.position_id.expect("Position ID should be assigned");
+        let position_qty = self.positions[&position_id].quantity;
+        let difference = fill.last_qty - position_qty;
+        let close_ratio = position_qty.as_f64() / fill.last_qty.as_f64();
+        let split_commission = |ratio: f64| {
+            fill.commission
+                .map(|commission| Money::new(commission.as_f64() * ratio, commission.currency))
+                .transpose()
+        };
+
+        let mut fill_close = fill;
+        fill_close.last_qty = position_qty;
+        fill_close.commission = split_commission(close_ratio)?;
+        self.update_position(fill_close);
+
+        let flip_position_id = match oms_type {
+            OmsType::Hedging => self.position_id_generator.generate(fill.strategy_id, true),
+            _ => position_id,
+        };
+
+        let mut fill_open = fill;
+        fill_open.last_qty = difference;
+        fill_open.commission = split_commission(1.0 - close_ratio)?;
+        fill_open.position_id = Some(flip_position_id);
+        self.open_position(fill_open)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        enums::{OrderSide, OrderType, PositionSide},
+        events::order::{
+            accepted::OrderAcceptedBuilder, filled::OrderFilledBuilder,
+            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+        },
+        identifiers::trade_id::TradeId,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::messages::{
+        cancel::CancelOrder, cancel_all::CancelAllOrders, modify::ModifyOrder, submit::SubmitOrder,
+    };
+
+    struct StubClient {
+        client_id: ClientId,
+        venue: Venue,
+        oms_type: OmsType,
+        commands: Arc<Mutex<Vec<String>>>,
+        reject_submits: bool,
+    }
+
+    impl StubClient {
+        fn record(&self, command: &str, client_order_id: impl std::fmt::Display) {
+            self.commands
+                .lock()
+                .unwrap()
+                .push(format!("{} {command} {client_order_id}", self.client_id));
+        }
+    }
+
+    impl ExecutionClient for StubClient {
+        fn client_id(&self) -> ClientId {
+            self.client_id
+        }
+
+        fn venue(&self) -> Venue {
+            self.venue
+        }
+
+        fn oms_type(&self) -> OmsType {
+            self.oms_type
+        }
+
+        fn submit_order(&mut self, command: &SubmitOrder) -> Result<()> {
+            if self.reject_submits {
+                bail!("Submit rejected");
+            }
+            self.record("SUBMIT", command.client_order_id);
+            Ok(())
+        }
+
+        fn modify_order(&mut self, command: &ModifyOrder) -> Result<()> {
+            self.record("MODIFY", command.client_order_id);
+            Ok(())
+        }
+
+        fn cancel_order(&mut self, command: &CancelOrder) -> Result<()> {
+            self.record("CANCEL", command.client_order_id);
+            Ok(())
+        }
+
+        fn cancel_all_orders(&mut self, command: &CancelAllOrders) -> Result<()> {
+            self.record("CANCEL_ALL", command.instrument_id);
+            Ok(())
+        }
+    }
+
+    #[fixture]
+    fn time() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, 0)))
+    }
+
+    fn stub_client(
+        client_id: &str,
+        venue: &str,
+        oms_type: OmsType,
+        commands: &Arc<Mutex<Vec<String>>>,
+    ) -> Box<StubClient> {
+        Box::new(StubClient {
+            client_id: ClientId::from(client_id),
+            venue: Venue::from(venue),
+            oms_type,
+            commands: commands.clone(),
+            reject_submits: false,
+        })
+    }
+
+    fn engine(
+        instrument: CurrencyPair,
+        oms_type: OmsType,
+        time: &'static AtomicTime,
+    ) -> ExecutionEngine {
+        let mut engine = ExecutionEngine::new(TraderId::default(), time);
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        engine
+            .register_client(stub_client("SIM", "SIM", oms_type, &commands))
+            .unwrap();
+        engine.add_instrument(Box::new(instrument));
+        engine
+    }
+
+    fn submit(
+        instrument_id: InstrumentId,
+        client_id: &str,
+        client_order_id: &str,
+        side: OrderSide,
+    ) -> TradingCommand {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .order_type(OrderType::Market)
+            .order_side(side)
+            .quantity(Quantity::from("200000"))
+            .build()
+            .unwrap();
+        let order = OrderAny::from(init);
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from(client_id),
+            order.strategy_id(),
+            instrument_id,
+            order,
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        TradingCommand::SubmitOrder(command)
+    }
+
+    /// Submits an order through the engine, and processes its acceptance.
+    fn accepted_order(
+        engine: &mut ExecutionEngine,
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        side: OrderSide,
+    ) {
+        engine
+            .execute(submit(instrument_id, "SIM", client_order_id, side))
+            .unwrap();
+        let client_order_id = ClientOrderId::from(client_order_id);
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(client_order_id)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .client_order_id(client_order_id)
+            .build()
+            .unwrap();
+        engine
+            .process(OrderEvent::OrderSubmitted(submitted))
+            .unwrap();
+        engine.process(OrderEvent::OrderAccepted(accepted)).unwrap();
+    }
+
+    fn fill(
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        side: OrderSide,
+        last_qty: &str,
+        trade_id: &str,
+    ) -> OrderEvent {
+        let fill = OrderFilledBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .trade_id(TradeId::from(trade_id))
+            .order_side(side)
+            .last_qty(Quantity::from(last_qty))
+            .last_px(Price::from("1.00000"))
+            .build()
+            .unwrap();
+        OrderEvent::OrderFilled(fill)
+    }
+
+    fn event_names(engine: &mut ExecutionEngine) -> Vec<&'static str> {
+        engine
+            .drain_position_events()
+            .iter()
+            .map(|event| match event {
+                PositionEvent::PositionOpened(_) => "OPENED",
+                PositionEvent::PositionChanged(_) => "CHANGED",
+                PositionEvent::PositionClosed(_) => "CLOSED",
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_execute_routes_commands(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        let mut engine = ExecutionEngine::new(TraderId::default(), time);
+        engine
+            .register_client(stub_client("SIM", "SIM", OmsType::Netting, &commands))
+            .unwrap();
+        engine
+            .register_default_client(stub_client("OTHER", "OTHER", OmsType::Netting, &commands))
+            .unwrap();
+        let other_id = InstrumentId::from("AUD/USD.IDEALPRO");
+
+        engine
+            .execute(submit(audusd_sim.id, "UNKNOWN", "O-1", OrderSide::Buy))
+            .unwrap();
+        engine
+            .execute(submit(audusd_sim.id, "OTHER", "O-2", OrderSide::Buy))
+            .unwrap();
+        engine
+            .execute(submit(other_id, "UNKNOWN", "O-3", OrderSide::Buy))
+            .unwrap();
+
+        assert_eq!(
+            *commands.lock().unwrap(),
+            vec!["SIM SUBMIT O-1", "OTHER SUBMIT O-2", "OTHER SUBMIT O-3"]
+        );
+        assert_eq!(engine.orders().len(), 3);
+    }
+
+    #[rstest]
+    fn test_execute_without_client(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = ExecutionEngine::new(TraderId::default(), time);
+
+        let result = engine.execute(submit(audusd_sim.id, "SIM", "O-1", OrderSide::Buy));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_execute_failed_submit_does_not_add_order(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        let mut engine = ExecutionEngine::new(TraderId::default(), time);
+        let mut client = stub_client("SIM", "SIM", OmsType::Netting, &commands);
+        client.reject_submits = true;
+        engine.register_client(client).unwrap();
+
+        let result = engine.execute(submit(audusd_sim.id, "SIM", "O-1", OrderSide::Buy));
+
+        assert!(result.is_err());
+        assert!(engine.order(&ClientOrderId::from("O-1")).is_none());
+    }
+
+    #[rstest]
+    fn test_register_client_twice(time: &'static AtomicTime) {
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        let mut engine = ExecutionEngine::new(TraderId::default(), time);
+        engine
+            .register_client(stub_client("SIM", "SIM", OmsType::Netting, &commands))
+            .unwrap();
+
+        let result = engine.register_client(stub_client("SIM", "SIM", OmsType::Netting, &commands));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_netting_position_lifecycle(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = engine(audusd_sim, OmsType::Netting, time);
+        accepted_order(&mut engine, audusd_sim.id, "O-1", OrderSide::Buy);
+        accepted_order(&mut engine, audusd_sim.id, "O-2", OrderSide::Sell);
+
+        engine
+            .process(fill(audusd_sim.id, "O-1", OrderSide::Buy, "200000", "T-1"))
+            .unwrap();
+        engine
+            .process(fill(audusd_sim.id, "O-2", OrderSide::Sell, "100000", "T-2"))
+            .unwrap();
+        engine
+            .process(fill(audusd_sim.id, "O-2", OrderSide::Sell, "100000", "T-3"))
+            .unwrap();
+
+        let position_id = PositionId::from("AUD/USD.SIM-S-001");
+        let position = engine.position(&position_id).unwrap();
+        assert!(position.is_closed());
+        assert_eq!(
+            engine
+                .order(&ClientOrderId::from("O-1"))
+                .unwrap()
+                .position_id(),
+            Some(position_id)
+        );
+        assert_eq!(
+            event_names(&mut engine),
+            vec!["OPENED", "CHANGED", "CLOSED"]
+        );
+        assert!(engine.positions_open(None).is_empty());
+        assert_eq!(engine.positions_closed(Some(&audusd_sim.id)).len(), 1);
+    }
+
+    #[rstest]
+    fn test_netting_position_flip(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = engine(audusd_sim, OmsType::Netting, time);
+        accepted_order(&mut engine, audusd_sim.id, "O-1", OrderSide::Buy);
+        accepted_order(&mut engine, audusd_sim.id, "O-2", OrderSide::Sell);
+
+        engine
+            .process(fill(audusd_sim.id, "O-1", OrderSide::Buy, "100000", "T-1"))
+            .unwrap();
+        engine
+            .process(fill(audusd_sim.id, "O-2", OrderSide::Sell, "150000", "T-2"))
+            .unwrap();
+
+        let positions = engine.positions_open(None);
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].side, PositionSide::Short);
+        assert_eq!(positions[0].quantity, Quantity::from("50000"));
+        assert_eq!(event_names(&mut engine), vec!["OPENED", "CLOSED", "OPENED"]);
+    }
+
+    #[rstest]
+    fn test_hedging_opens_position_per_order(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = engine(audusd_sim, OmsType::Hedging, time);
+        accepted_order(&mut engine, audusd_sim.id, "O-1", OrderSide::Buy);
+        accepted_order(&mut engine, audusd_sim.id, "O-2", OrderSide::Sell);
+
+        engine
+            .process(fill(audusd_sim.id, "O-1", OrderSide::Buy, "100000", "T-1"))
+            .unwrap();
+        engine
+            .process(fill(audusd_sim.id, "O-2", OrderSide::Sell, "100000", "T-2"))
+            .unwrap();
+
+        let positions = engine.positions_open(Some(&audusd_sim.id));
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[0].side, PositionSide::Long);
+        assert_eq!(positions[1].side, PositionSide::Short);
+        assert_eq!(event_names(&mut engine), vec!["OPENED", "OPENED"]);
+    }
+
+    #[rstest]
+    fn test_oms_type_override(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = engine(audusd_sim, OmsType::Hedging, time);
+        engine.register_oms_type(StrategyId::default(), OmsType::Netting);
+        accepted_order(&mut engine, audusd_sim.id, "O-1", OrderSide::Buy);
+        accepted_order(&mut engine, audusd_sim.id, "O-2", OrderSide::Buy);
+
+        engine
+            .process(fill(audusd_sim.id, "O-1", OrderSide::Buy, "100000", "T-1"))
+            .unwrap();
+        engine
+            .process(fill(audusd_sim.id, "O-2", OrderSide::Buy, "100000", "T-2"))
+            .unwrap();
+
+        let positions = engine.positions_open(None);
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].quantity, Quantity::from("200000"));
+    }
+
+    #[rstest]
+    fn test_process_for_unknown_order(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = engine(audusd_sim, OmsType::Netting, time);
+
+        let result = engine.process(fill(audusd_sim.id, "O-1", OrderSide::Buy, "100000", "T-1"));
+
+        assert!(result.is_err());
+    }
+}
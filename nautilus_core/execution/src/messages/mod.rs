@@ -0,0 +1,92 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod cancel;
+pub mod cancel_all;
+pub mod modify;
+pub mod submit;
+
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use nautilus_model::identifiers::{
+    client_id::ClientId, instrument_id::InstrumentId, strategy_id::StrategyId, trader_id::TraderId,
+};
+use serde::{Deserialize, Serialize};
+
+use self::{
+    cancel::CancelOrder, cancel_all::CancelAllOrders, modify::ModifyOrder, submit::SubmitOrder,
+};
+
+/// Represents a command to an execution client or simulated venue.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum TradingCommand {
+    SubmitOrder(SubmitOrder),
+    ModifyOrder(ModifyOrder),
+    CancelOrder(CancelOrder),
+    CancelAllOrders(CancelAllOrders),
+}
+
+impl TradingCommand {
+    #[must_use]
+    pub fn trader_id(&self) -> TraderId {
+        match self {
+            Self::SubmitOrder(command) => command.trader_id,
+            Self::ModifyOrder(command) => command.trader_id,
+            Self::CancelOrder(command) => command.trader_id,
+            Self::CancelAllOrders(command) => command.trader_id,
+        }
+    }
+
+    #[must_use]
+    pub fn client_id(&self) -> ClientId {
+        match self {
+            Self::SubmitOrder(command) => command.client_id,
+            Self::ModifyOrder(command) => command.client_id,
+            Self::CancelOrder(command) => command.client_id,
+            Self::CancelAllOrders(command) => command.client_id,
+        }
+    }
+
+    #[must_use]
+    pub fn strategy_id(&self) -> StrategyId {
+        match self {
+            Self::SubmitOrder(command) => command.strategy_id,
+            Self::ModifyOrder(command) => command.strategy_id,
+            Self::CancelOrder(command) => command.strategy_id,
+            Self::CancelAllOrders(command) => command.strategy_id,
+        }
+    }
+
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::SubmitOrder(command) => command.instrument_id,
+            Self::ModifyOrder(command) => command.instrument_id,
+            Self::CancelOrder(command) => command.instrument_id,
+            Self::CancelAllOrders(command) => command.instrument_id,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::SubmitOrder(command) => command.ts_init,
+            Self::ModifyOrder(command) => command.ts_init,
+            Self::CancelOrder(command) => command.ts_init,
+            Self::CancelAllOrders(command) => command.ts_init,
+        }
+    }
+}
+
+impl Serializable for TradingCommand {}
@@ -23,7 +23,7 @@ use nautilus_model::{
 use pyo3::prelude::*;
 
 use crate::{
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, InputKind, MovingAverage},
     ratio::efficiency_ratio::EfficiencyRatio,
 };
 
@@ -82,6 +82,10 @@ impl Indicator for AdaptiveMovingAverage {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
     fn handle_quote_tick(&mut self, tick: &QuoteTick) {
         self.update_raw(tick.extract_price(self.price_type).into());
     }
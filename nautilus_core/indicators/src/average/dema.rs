@@ -24,7 +24,7 @@ use pyo3::prelude::*;
 
 use crate::{
     average::ema::ExponentialMovingAverage,
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, InputKind, MovingAverage},
 };
 
 /// The Double Exponential Moving Average attempts to a smoother average with less
@@ -65,6 +65,10 @@ impl Indicator for DoubleExponentialMovingAverage {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
     fn handle_quote_tick(&mut self, quote: &QuoteTick) {
         self.update_raw(quote.extract_price(self.price_type).into());
     }
@@ -24,7 +24,7 @@ use pyo3::prelude::*;
 
 use crate::{
     average::wma::WeightedMovingAverage,
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, InputKind, MovingAverage},
 };
 
 /// An indicator which calculates a Hull Moving Average (HMA) across a rolling
@@ -64,6 +64,10 @@ impl Indicator for HullMovingAverage {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
     fn handle_quote_tick(&mut self, quote: &QuoteTick) {
         self.update_raw(quote.extract_price(self.price_type).into());
     }
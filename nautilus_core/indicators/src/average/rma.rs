@@ -22,7 +22,7 @@ use nautilus_model::{
 };
 use pyo3::prelude::*;
 
-use crate::indicator::{Indicator, MovingAverage};
+use crate::indicator::{Indicator, InputKind, MovingAverage};
 
 #[repr(C)]
 #[derive(Debug)]
@@ -56,6 +56,10 @@ impl Indicator for WilderMovingAverage {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
     fn handle_quote_tick(&mut self, quote: &QuoteTick) {
         self.update_raw(quote.extract_price(self.price_type).into());
     }
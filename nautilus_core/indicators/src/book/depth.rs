@@ -0,0 +1,265 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{depth::OrderBookDepth10, quote::QuoteTick},
+    orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp, level::Level},
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which calculates the imbalance of resting size across the top
+/// `num_levels` of the book, from -1 (all size on the ask side) to 1 (all size on the
+/// bid side).
+///
+/// The size at each level is weighted by `decay` raised to the level index (with the
+/// top level weighted 1), so a `decay` below 1 emphasizes levels nearer the top.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct DepthImbalance {
+    pub num_levels: usize,
+    pub decay: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for DepthImbalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.num_levels, self.decay)
+    }
+}
+
+impl Indicator for DepthImbalance {
+    fn name(&self) -> String {
+        stringify!(DepthImbalance).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[
+            InputKind::Depth,
+            InputKind::BookMbo,
+            InputKind::BookMbp,
+            InputKind::QuoteTick,
+        ]
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let bid_sizes: Vec<f64> = depth.bids.iter().map(|order| order.size.as_f64()).collect();
+        let ask_sizes: Vec<f64> = depth.asks.iter().map(|order| order.size.as_f64()).collect();
+        self.update_raw(&bid_sizes, &ask_sizes);
+    }
+
+    fn handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        self.update_raw(
+            &level_sizes(book.bids(), self.num_levels),
+            &level_sizes(book.asks(), self.num_levels),
+        );
+    }
+
+    fn handle_book_mbp(&mut self, book: &OrderBookMbp) {
+        self.update_raw(
+            &level_sizes(book.bids(), self.num_levels),
+            &level_sizes(book.asks(), self.num_levels),
+        );
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(&[quote.bid_size.as_f64()], &[quote.ask_size.as_f64()]);
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl DepthImbalance {
+    pub fn new(num_levels: usize, decay: Option<f64>) -> Result<Self> {
+        if num_levels == 0 {
+            bail!("Condition failed: `num_levels` must be positive");
+        }
+        let decay = decay.unwrap_or(1.0);
+        if decay <= 0.0 || decay > 1.0 {
+            bail!("Condition failed: `decay` must be in the range (0, 1], was {decay}");
+        }
+
+        Ok(Self {
+            num_levels,
+            decay,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+        })
+    }
+
+    /// Updates the indicator with the sizes of each book level, ordered from the top of
+    /// the book (levels beyond `num_levels` are ignored).
+    pub fn update_raw(&mut self, bid_sizes: &[f64], ask_sizes: &[f64]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let bid_depth = self.weighted_depth(bid_sizes);
+        let ask_depth = self.weighted_depth(ask_sizes);
+        let total = bid_depth + ask_depth;
+
+        if bid_depth > 0.0 && ask_depth > 0.0 {
+            self.value = (bid_depth - ask_depth) / total;
+            self.initialized = true;
+        }
+        // No market yet
+    }
+
+    fn weighted_depth(&self, sizes: &[f64]) -> f64 {
+        let mut weight = 1.0;
+        let mut depth = 0.0;
+        for size in sizes.iter().take(self.num_levels) {
+            depth += weight * size;
+            weight *= self.decay;
+        }
+        depth
+    }
+}
+
+fn level_sizes<'a>(levels: impl Iterator<Item = &'a Level>, num_levels: usize) -> Vec<f64> {
+    levels.take(num_levels).map(Level::size).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::depth::stubs::stub_depth10,
+        identifiers::instrument_id::InstrumentId,
+        stubs::{stub_order_book_mbp, stub_order_book_mbp_appl_xnas},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn bid_heavy_book() -> OrderBookMbp {
+        stub_order_book_mbp(
+            InstrumentId::from("AAPL.XNAS"),
+            101.0,
+            100.0,
+            100.0,
+            200.0, // <-- Larger bid side
+            2,
+            0.01,
+            0,
+            100.0,
+            10,
+        )
+    }
+
+    #[rstest]
+    fn test_initialized() {
+        let imbalance = DepthImbalance::new(5, None).unwrap();
+        let display_str = format!("{imbalance}");
+        assert_eq!(display_str, "DepthImbalance(5,1)");
+        assert_eq!(imbalance.value, 0.0);
+        assert!(!imbalance.has_inputs);
+        assert!(!imbalance.initialized);
+    }
+
+    #[rstest]
+    #[case(0, None)]
+    #[case(5, Some(0.0))]
+    #[case(5, Some(1.5))]
+    fn test_new_with_invalid_params_returns_error(
+        #[case] num_levels: usize,
+        #[case] decay: Option<f64>,
+    ) {
+        assert!(DepthImbalance::new(num_levels, decay).is_err());
+    }
+
+    #[rstest]
+    fn test_balanced_book() {
+        let mut imbalance = DepthImbalance::new(5, None).unwrap();
+        imbalance.handle_book_mbp(&stub_order_book_mbp_appl_xnas());
+
+        assert_eq!(imbalance.count, 1);
+        assert_eq!(imbalance.value, 0.0);
+        assert!(imbalance.initialized);
+    }
+
+    #[rstest]
+    fn test_bid_heavy_book_over_levels() {
+        let mut imbalance = DepthImbalance::new(3, None).unwrap();
+        imbalance.handle_book_mbp(&bid_heavy_book());
+
+        // Bids of 200 + 300 + 400 against asks of 100 + 200 + 300
+        assert_eq!(imbalance.value, 0.2);
+    }
+
+    #[rstest]
+    fn test_bid_heavy_book_with_decay() {
+        let mut imbalance = DepthImbalance::new(3, Some(0.5)).unwrap();
+        imbalance.handle_book_mbp(&bid_heavy_book());
+
+        // Bids of 200 + 150 + 100 against asks of 100 + 100 + 75
+        assert!((imbalance.value - 175.0 / 725.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_depth() {
+        let mut imbalance = DepthImbalance::new(10, None).unwrap();
+        imbalance.handle_depth(&stub_depth10());
+
+        assert_eq!(imbalance.value, 0.0);
+        assert!(imbalance.initialized);
+    }
+
+    #[rstest]
+    fn test_one_sided_book_is_not_initialized() {
+        let mut imbalance = DepthImbalance::new(3, None).unwrap();
+        imbalance.update_raw(&[100.0], &[]);
+
+        assert!(imbalance.has_inputs);
+        assert!(!imbalance.initialized);
+        assert_eq!(imbalance.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut imbalance = DepthImbalance::new(3, None).unwrap();
+        imbalance.handle_book_mbp(&bid_heavy_book());
+        imbalance.reset();
+
+        assert_eq!(imbalance.count, 0);
+        assert_eq!(imbalance.value, 0.0);
+        assert!(!imbalance.initialized);
+        assert!(!imbalance.has_inputs);
+    }
+}
@@ -22,7 +22,7 @@ use nautilus_model::{
 };
 use pyo3::prelude::*;
 
-use crate::indicator::Indicator;
+use crate::indicator::{Indicator, InputKind};
 
 #[repr(C)]
 #[derive(Debug)]
@@ -53,6 +53,10 @@ impl Indicator for BookImbalanceRatio {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::BookMbo, InputKind::BookMbp]
+    }
+
     fn handle_book_mbo(&mut self, book: &OrderBookMbo) {
         self.update(book.best_bid_size(), book.best_ask_size());
     }
@@ -0,0 +1,234 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{depth::OrderBookDepth10, quote::QuoteTick},
+    orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp},
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which calculates the micro-price of the top of the book.
+///
+/// The micro-price weights each side's best price by the opposite side's size, so the
+/// value leans toward the side more likely to be traded through next.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct MicroPrice {
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for MicroPrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.name())
+    }
+}
+
+impl Indicator for MicroPrice {
+    fn name(&self) -> String {
+        stringify!(MicroPrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[
+            InputKind::Depth,
+            InputKind::BookMbo,
+            InputKind::BookMbp,
+            InputKind::QuoteTick,
+        ]
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bid, ask) = (depth.bids[0], depth.asks[0]);
+        self.update_raw(
+            bid.price.as_f64(),
+            bid.size.as_f64(),
+            ask.price.as_f64(),
+            ask.size.as_f64(),
+        );
+    }
+
+    fn handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        if let (Some(bid_price), Some(bid_size), Some(ask_price), Some(ask_size)) = (
+            book.best_bid_price(),
+            book.best_bid_size(),
+            book.best_ask_price(),
+            book.best_ask_size(),
+        ) {
+            self.update_raw(
+                bid_price.as_f64(),
+                bid_size.as_f64(),
+                ask_price.as_f64(),
+                ask_size.as_f64(),
+            );
+        }
+    }
+
+    fn handle_book_mbp(&mut self, book: &OrderBookMbp) {
+        if let (Some(bid_price), Some(bid_size), Some(ask_price), Some(ask_size)) = (
+            book.best_bid_price(),
+            book.best_bid_size(),
+            book.best_ask_price(),
+            book.best_ask_size(),
+        ) {
+            self.update_raw(
+                bid_price.as_f64(),
+                bid_size.as_f64(),
+                ask_price.as_f64(),
+                ask_size.as_f64(),
+            );
+        }
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(
+            quote.bid_price.as_f64(),
+            quote.bid_size.as_f64(),
+            quote.ask_price.as_f64(),
+            quote.ask_size.as_f64(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MicroPrice {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, bid_price: f64, bid_size: f64, ask_price: f64, ask_size: f64) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let total_size = bid_size + ask_size;
+        if total_size > 0.0 {
+            self.value = (bid_price * ask_size + ask_price * bid_size) / total_size;
+            self.initialized = true;
+        }
+        // No resting size to weight by
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::depth::stubs::stub_depth10, identifiers::instrument_id::InstrumentId,
+        stubs::stub_order_book_mbp,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let micro_price = MicroPrice::new().unwrap();
+        let display_str = format!("{micro_price}");
+        assert_eq!(display_str, "MicroPrice()");
+        assert_eq!(micro_price.value, 0.0);
+        assert!(!micro_price.has_inputs);
+        assert!(!micro_price.initialized);
+    }
+
+    #[rstest]
+    #[case(100.0, 100.0, 100.0)] // Balanced sizes gives the mid
+    #[case(300.0, 100.0, 100.75)] // Heavy bid leans toward the ask
+    #[case(100.0, 300.0, 100.25)] // Heavy ask leans toward the bid
+    fn test_value_with_sizes(#[case] bid_size: f64, #[case] ask_size: f64, #[case] expected: f64) {
+        let mut micro_price = MicroPrice::new().unwrap();
+        micro_price.update_raw(100.0, bid_size, 101.0, ask_size);
+
+        assert!(micro_price.initialized);
+        assert_eq!(micro_price.value, expected);
+    }
+
+    #[rstest]
+    fn test_empty_sizes_does_not_initialize() {
+        let mut micro_price = MicroPrice::new().unwrap();
+        micro_price.update_raw(100.0, 0.0, 101.0, 0.0);
+
+        assert!(micro_price.has_inputs);
+        assert!(!micro_price.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_depth() {
+        let mut micro_price = MicroPrice::new().unwrap();
+        micro_price.handle_depth(&stub_depth10());
+
+        assert_eq!(micro_price.value, 99.5);
+    }
+
+    #[rstest]
+    fn test_handle_book_mbp() {
+        let book = stub_order_book_mbp(
+            InstrumentId::from("AAPL.XNAS"),
+            101.0,
+            100.0,
+            100.0,
+            300.0,
+            2,
+            0.01,
+            0,
+            100.0,
+            1,
+        );
+        let mut micro_price = MicroPrice::new().unwrap();
+        micro_price.handle_book_mbp(&book);
+
+        assert_eq!(micro_price.value, 100.75);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut micro_price = MicroPrice::new().unwrap();
+        micro_price.update_raw(100.0, 100.0, 101.0, 100.0);
+        micro_price.reset();
+
+        assert_eq!(micro_price.count, 0);
+        assert_eq!(micro_price.value, 0.0);
+        assert!(!micro_price.has_inputs);
+        assert!(!micro_price.initialized);
+    }
+}
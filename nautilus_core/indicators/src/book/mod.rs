@@ -13,4 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod depth;
 pub mod imbalance;
+pub mod micro_price;
+pub mod ofi;
+pub mod queue;
+pub mod spread;
+pub mod weighted_mid;
@@ -0,0 +1,359 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{
+        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, quote::QuoteTick,
+    },
+    orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp},
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// The best bid and ask prices and sizes.
+#[derive(Clone, Copy, Debug)]
+struct TopOfBook {
+    bid_price: f64,
+    bid_size: f64,
+    ask_price: f64,
+    ask_size: f64,
+}
+
+/// An indicator which calculates the order flow imbalance (OFI) of the top of the book,
+/// summed across a rolling window of `period` book events.
+///
+/// Each event contributes the change in resting bid size less the change in resting ask
+/// size at the best prices (following Cont, Kukanov and Stoikov), so positive values
+/// indicate buying pressure. Order book deltas are applied to an internal book, with
+/// each batch of `OrderBookDeltas` treated as a single event.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct OrderFlowImbalance {
+    pub period: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    events: VecDeque<f64>,
+    previous: Option<TopOfBook>,
+    book: Option<OrderBookMbp>,
+    has_inputs: bool,
+}
+
+impl Display for OrderFlowImbalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for OrderFlowImbalance {
+    fn name(&self) -> String {
+        stringify!(OrderFlowImbalance).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[
+            InputKind::Delta,
+            InputKind::Deltas,
+            InputKind::Depth,
+            InputKind::BookMbo,
+            InputKind::BookMbp,
+            InputKind::QuoteTick,
+        ]
+    }
+
+    fn handle_delta(&mut self, delta: &OrderBookDelta) {
+        let book = self
+            .book
+            .get_or_insert_with(|| OrderBookMbp::new(delta.instrument_id, false));
+        book.apply_delta(*delta);
+        self.update_from_book();
+    }
+
+    fn handle_deltas(&mut self, deltas: &OrderBookDeltas) {
+        let book = self
+            .book
+            .get_or_insert_with(|| OrderBookMbp::new(deltas.instrument_id, false));
+        book.apply_deltas(deltas.clone());
+        self.update_from_book();
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bid, ask) = (depth.bids[0], depth.asks[0]);
+        self.update_raw(
+            bid.price.as_f64(),
+            bid.size.as_f64(),
+            ask.price.as_f64(),
+            ask.size.as_f64(),
+        );
+    }
+
+    fn handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        if let (Some(bid_price), Some(bid_size), Some(ask_price), Some(ask_size)) = (
+            book.best_bid_price(),
+            book.best_bid_size(),
+            book.best_ask_price(),
+            book.best_ask_size(),
+        ) {
+            self.update_raw(
+                bid_price.as_f64(),
+                bid_size.as_f64(),
+                ask_price.as_f64(),
+                ask_size.as_f64(),
+            );
+        }
+    }
+
+    fn handle_book_mbp(&mut self, book: &OrderBookMbp) {
+        if let (Some(bid_price), Some(bid_size), Some(ask_price), Some(ask_size)) = (
+            book.best_bid_price(),
+            book.best_bid_size(),
+            book.best_ask_price(),
+            book.best_ask_size(),
+        ) {
+            self.update_raw(
+                bid_price.as_f64(),
+                bid_size.as_f64(),
+                ask_price.as_f64(),
+                ask_size.as_f64(),
+            );
+        }
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(
+            quote.bid_price.as_f64(),
+            quote.bid_size.as_f64(),
+            quote.ask_price.as_f64(),
+            quote.ask_size.as_f64(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.events.clear();
+        self.previous = None;
+        self.book = None;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl OrderFlowImbalance {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            bail!("Condition failed: `period` must be positive");
+        }
+
+        Ok(Self {
+            period,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            events: VecDeque::with_capacity(period),
+            previous: None,
+            book: None,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, bid_price: f64, bid_size: f64, ask_price: f64, ask_size: f64) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let current = TopOfBook {
+            bid_price,
+            bid_size,
+            ask_price,
+            ask_size,
+        };
+
+        if let Some(previous) = self.previous {
+            let mut event = 0.0;
+            if current.bid_price >= previous.bid_price {
+                event += current.bid_size;
+            }
+            if current.bid_price <= previous.bid_price {
+                event -= previous.bid_size;
+            }
+            if current.ask_price <= previous.ask_price {
+                event -= current.ask_size;
+            }
+            if current.ask_price >= previous.ask_price {
+                event += previous.ask_size;
+            }
+
+            if self.events.len() == self.period {
+                self.events.pop_front();
+            }
+            self.events.push_back(event);
+            self.value = self.events.iter().sum();
+
+            if !self.initialized && self.events.len() == self.period {
+                self.initialized = true;
+            }
+        }
+
+        self.previous = Some(current);
+    }
+
+    fn update_from_book(&mut self) {
+        let top = self.book.as_ref().and_then(|book| {
+            Some((
+                book.best_bid_price()?,
+                book.best_bid_size()?,
+                book.best_ask_price()?,
+                book.best_ask_size()?,
+            ))
+        });
+
+        if let Some((bid_price, bid_size, ask_price, ask_size)) = top {
+            self.update_raw(
+                bid_price.as_f64(),
+                bid_size.as_f64(),
+                ask_price.as_f64(),
+                ask_size.as_f64(),
+            );
+        }
+        // No market yet
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{deltas::stubs::stub_deltas, order::BookOrder},
+        enums::{BookAction, OrderSide},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let ofi = OrderFlowImbalance::new(3).unwrap();
+        let display_str = format!("{ofi}");
+        assert_eq!(display_str, "OrderFlowImbalance(3)");
+        assert_eq!(ofi.value, 0.0);
+        assert!(!ofi.has_inputs);
+        assert!(!ofi.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_zero_period_returns_error() {
+        assert!(OrderFlowImbalance::new(0).is_err());
+    }
+
+    #[rstest]
+    fn test_first_input_sets_reference_only() {
+        let mut ofi = OrderFlowImbalance::new(3).unwrap();
+        ofi.update_raw(99.0, 100.0, 100.0, 100.0);
+
+        assert!(ofi.has_inputs);
+        assert_eq!(ofi.value, 0.0);
+    }
+
+    #[rstest]
+    #[case(99.0, 150.0, 100.0, 100.0, 50.0)] // Bid size added
+    #[case(99.5, 10.0, 100.0, 100.0, 10.0)] // Bid price improved
+    #[case(98.0, 500.0, 100.0, 100.0, -100.0)] // Bid level consumed
+    #[case(99.0, 100.0, 100.0, 40.0, 60.0)] // Ask size removed
+    #[case(99.0, 100.0, 99.5, 10.0, -10.0)] // Ask price improved
+    fn test_event_values(
+        #[case] bid_price: f64,
+        #[case] bid_size: f64,
+        #[case] ask_price: f64,
+        #[case] ask_size: f64,
+        #[case] expected: f64,
+    ) {
+        let mut ofi = OrderFlowImbalance::new(3).unwrap();
+        ofi.update_raw(99.0, 100.0, 100.0, 100.0);
+        ofi.update_raw(bid_price, bid_size, ask_price, ask_size);
+
+        assert_eq!(ofi.value, expected);
+    }
+
+    #[rstest]
+    fn test_value_sums_rolling_window() {
+        let mut ofi = OrderFlowImbalance::new(2).unwrap();
+        ofi.update_raw(99.0, 100.0, 100.0, 100.0);
+        ofi.update_raw(99.0, 150.0, 100.0, 100.0); // +50
+        assert!(!ofi.initialized);
+
+        ofi.update_raw(99.0, 170.0, 100.0, 100.0); // +20
+        assert!(ofi.initialized);
+        assert_eq!(ofi.value, 70.0);
+
+        ofi.update_raw(99.0, 170.0, 100.0, 130.0); // -30
+        assert_eq!(ofi.value, -10.0);
+    }
+
+    #[rstest]
+    fn test_handle_deltas() {
+        let mut ofi = OrderFlowImbalance::new(3).unwrap();
+        let deltas = stub_deltas();
+        ofi.handle_deltas(&deltas);
+
+        // Add to the best bid (99.00 for 100)
+        let delta = OrderBookDelta::new(
+            deltas.instrument_id,
+            BookAction::Update,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("99.00"),
+                Quantity::from("250"),
+                4,
+            ),
+            0,
+            1,
+            3,
+            4,
+        );
+        ofi.handle_delta(&delta);
+
+        assert_eq!(ofi.count, 2);
+        assert_eq!(ofi.value, 150.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut ofi = OrderFlowImbalance::new(3).unwrap();
+        ofi.handle_deltas(&stub_deltas());
+        ofi.update_raw(99.0, 150.0, 100.0, 100.0);
+        ofi.reset();
+
+        assert_eq!(ofi.count, 0);
+        assert_eq!(ofi.value, 0.0);
+        assert!(!ofi.initialized);
+        assert!(!ofi.has_inputs);
+    }
+}
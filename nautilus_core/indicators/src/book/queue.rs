@@ -0,0 +1,427 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{order::OrderId, trade::TradeTick},
+    enums::AggressorSide,
+    orderbook::{book_mbo::OrderBookMbo, level::Level},
+    types::price::Price,
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// The resting orders at the top level of one side of the book.
+#[derive(Clone, Debug)]
+struct QueueSnapshot {
+    price: Price,
+    orders: HashMap<OrderId, f64>,
+    size: f64,
+}
+
+impl QueueSnapshot {
+    fn from_level(level: &Level) -> Self {
+        let orders: HashMap<OrderId, f64> = level
+            .orders
+            .iter()
+            .map(|(order_id, order)| (*order_id, order.size.as_f64()))
+            .collect();
+        Self {
+            price: level.price.value,
+            size: orders.values().sum(),
+            orders,
+        }
+    }
+
+    /// Returns the size removed from this queue, given the current level at the same
+    /// price (if it still exists).
+    fn removed_size(&self, level: Option<&Level>) -> f64 {
+        self.orders
+            .iter()
+            .map(|(order_id, size)| {
+                let remaining = level
+                    .and_then(|level| level.orders.get(order_id))
+                    .map_or(0.0, |order| order.size.as_f64());
+                (size - remaining).max(0.0)
+            })
+            .sum()
+    }
+}
+
+/// An indicator which measures how quickly the queues at the best bid and ask are
+/// depleted, from market-by-order (MBO) book updates.
+///
+/// For each side, the depletion rate is the fraction of the previous top-of-book queue
+/// which was removed by the next update, and the cancel rate is the fraction removed
+/// beyond the size traded against that side in between (from trade ticks). Both rates
+/// are averaged over the last `period` updates.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct QueueDepletion {
+    pub period: usize,
+    pub bid_depletion_rate: f64,
+    pub ask_depletion_rate: f64,
+    pub bid_cancel_rate: f64,
+    pub ask_cancel_rate: f64,
+    pub count: usize,
+    pub initialized: bool,
+    bid_depletions: VecDeque<f64>,
+    ask_depletions: VecDeque<f64>,
+    bid_cancels: VecDeque<f64>,
+    ask_cancels: VecDeque<f64>,
+    prev_bid_queue: Option<QueueSnapshot>,
+    prev_ask_queue: Option<QueueSnapshot>,
+    traded_bid_size: f64,
+    traded_ask_size: f64,
+    has_inputs: bool,
+}
+
+impl Display for QueueDepletion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for QueueDepletion {
+    fn name(&self) -> String {
+        stringify!(QueueDepletion).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::BookMbo, InputKind::TradeTick]
+    }
+
+    fn handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        let (bid_removed, bid_queue_size) = match &self.prev_bid_queue {
+            Some(prev) => (
+                prev.removed_size(book.bids().find(|l| l.price.value == prev.price)),
+                prev.size,
+            ),
+            None => (0.0, 0.0),
+        };
+        let (ask_removed, ask_queue_size) = match &self.prev_ask_queue {
+            Some(prev) => (
+                prev.removed_size(book.asks().find(|l| l.price.value == prev.price)),
+                prev.size,
+            ),
+            None => (0.0, 0.0),
+        };
+
+        self.update_raw(
+            bid_removed,
+            self.traded_bid_size,
+            bid_queue_size,
+            ask_removed,
+            self.traded_ask_size,
+            ask_queue_size,
+        );
+
+        self.prev_bid_queue = book.bids().next().map(QueueSnapshot::from_level);
+        self.prev_ask_queue = book.asks().next().map(QueueSnapshot::from_level);
+        self.traded_bid_size = 0.0;
+        self.traded_ask_size = 0.0;
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        match trade.aggressor_side {
+            AggressorSide::Buyer => self.traded_ask_size += trade.size.as_f64(),
+            AggressorSide::Seller => self.traded_bid_size += trade.size.as_f64(),
+            AggressorSide::NoAggressor => {} // Cannot attribute to a side
+        }
+    }
+
+    fn reset(&mut self) {
+        self.bid_depletions.clear();
+        self.ask_depletions.clear();
+        self.bid_cancels.clear();
+        self.ask_cancels.clear();
+        self.prev_bid_queue = None;
+        self.prev_ask_queue = None;
+        self.traded_bid_size = 0.0;
+        self.traded_ask_size = 0.0;
+        self.bid_depletion_rate = 0.0;
+        self.ask_depletion_rate = 0.0;
+        self.bid_cancel_rate = 0.0;
+        self.ask_cancel_rate = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl QueueDepletion {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            bail!("Condition failed: `period` must be positive");
+        }
+
+        Ok(Self {
+            period,
+            bid_depletion_rate: 0.0,
+            ask_depletion_rate: 0.0,
+            bid_cancel_rate: 0.0,
+            ask_cancel_rate: 0.0,
+            count: 0,
+            initialized: false,
+            bid_depletions: VecDeque::with_capacity(period),
+            ask_depletions: VecDeque::with_capacity(period),
+            bid_cancels: VecDeque::with_capacity(period),
+            ask_cancels: VecDeque::with_capacity(period),
+            prev_bid_queue: None,
+            prev_ask_queue: None,
+            traded_bid_size: 0.0,
+            traded_ask_size: 0.0,
+            has_inputs: false,
+        })
+    }
+
+    /// Updates the indicator with the size removed from, and traded against, each side's
+    /// previous top-of-book queue of `queue_size` (a side with an empty queue is skipped).
+    pub fn update_raw(
+        &mut self,
+        bid_removed: f64,
+        bid_traded: f64,
+        bid_queue_size: f64,
+        ask_removed: f64,
+        ask_traded: f64,
+        ask_queue_size: f64,
+    ) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        if bid_queue_size > 0.0 {
+            push_rates(
+                &mut self.bid_depletions,
+                &mut self.bid_cancels,
+                self.period,
+                bid_removed,
+                bid_traded,
+                bid_queue_size,
+            );
+            self.bid_depletion_rate = mean(&self.bid_depletions);
+            self.bid_cancel_rate = mean(&self.bid_cancels);
+        }
+
+        if ask_queue_size > 0.0 {
+            push_rates(
+                &mut self.ask_depletions,
+                &mut self.ask_cancels,
+                self.period,
+                ask_removed,
+                ask_traded,
+                ask_queue_size,
+            );
+            self.ask_depletion_rate = mean(&self.ask_depletions);
+            self.ask_cancel_rate = mean(&self.ask_cancels);
+        }
+
+        if !self.initialized
+            && self.bid_depletions.len() == self.period
+            && self.ask_depletions.len() == self.period
+        {
+            self.initialized = true;
+        }
+    }
+}
+
+fn push_rates(
+    depletions: &mut VecDeque<f64>,
+    cancels: &mut VecDeque<f64>,
+    period: usize,
+    removed: f64,
+    traded: f64,
+    queue_size: f64,
+) {
+    if depletions.len() == period {
+        depletions.pop_front();
+        cancels.pop_front();
+    }
+    depletions.push_back(removed / queue_size);
+    cancels.push_back((removed - traded).max(0.0) / queue_size);
+}
+
+fn mean(values: &VecDeque<f64>) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::order::BookOrder, enums::OrderSide, identifiers::instrument_id::InstrumentId,
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::trade_tick;
+
+    fn bid_order(size: &str, order_id: OrderId) -> BookOrder {
+        BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(size),
+            order_id,
+        )
+    }
+
+    fn stub_book() -> OrderBookMbo {
+        let mut book = OrderBookMbo::new(InstrumentId::from("AAPL.XNAS"));
+        book.add(bid_order("100", 1), 0, 1);
+        book.add(bid_order("100", 2), 0, 2);
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("101.00"),
+                Quantity::from("100"),
+                3,
+            ),
+            0,
+            3,
+        );
+        book
+    }
+
+    #[rstest]
+    fn test_initialized() {
+        let queue = QueueDepletion::new(3).unwrap();
+        let display_str = format!("{queue}");
+        assert_eq!(display_str, "QueueDepletion(3)");
+        assert_eq!(queue.bid_depletion_rate, 0.0);
+        assert_eq!(queue.ask_cancel_rate, 0.0);
+        assert!(!queue.has_inputs);
+        assert!(!queue.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_zero_period_returns_error() {
+        assert!(QueueDepletion::new(0).is_err());
+    }
+
+    #[rstest]
+    fn test_first_book_sets_reference_only() {
+        let mut queue = QueueDepletion::new(1).unwrap();
+        queue.handle_book_mbo(&stub_book());
+
+        assert!(queue.has_inputs);
+        assert!(!queue.initialized);
+        assert_eq!(queue.bid_depletion_rate, 0.0);
+    }
+
+    #[rstest]
+    fn test_cancelled_order_depletes_queue() {
+        let mut queue = QueueDepletion::new(1).unwrap();
+        let mut book = stub_book();
+        queue.handle_book_mbo(&book);
+
+        book.delete(bid_order("100", 1), 0, 4);
+        queue.handle_book_mbo(&book);
+
+        assert!(queue.initialized);
+        assert_eq!(queue.bid_depletion_rate, 0.5);
+        assert_eq!(queue.bid_cancel_rate, 0.5);
+        assert_eq!(queue.ask_depletion_rate, 0.0);
+        assert_eq!(queue.ask_cancel_rate, 0.0);
+    }
+
+    #[rstest]
+    fn test_traded_size_is_not_counted_as_cancelled() {
+        let mut queue = QueueDepletion::new(1).unwrap();
+        let mut book = stub_book();
+        book.delete(bid_order("100", 1), 0, 4);
+        queue.handle_book_mbo(&book);
+
+        let mut trade = trade_tick();
+        trade.aggressor_side = AggressorSide::Seller;
+        trade.size = Quantity::from("50");
+        queue.handle_trade_tick(&trade);
+
+        book.update(bid_order("50", 2), 0, 5);
+        queue.handle_book_mbo(&book);
+
+        assert_eq!(queue.bid_depletion_rate, 0.5);
+        assert_eq!(queue.bid_cancel_rate, 0.0);
+    }
+
+    #[rstest]
+    fn test_rates_average_over_period() {
+        let mut queue = QueueDepletion::new(2).unwrap();
+        let mut book = stub_book();
+        queue.handle_book_mbo(&book);
+
+        book.delete(bid_order("100", 1), 0, 4);
+        queue.handle_book_mbo(&book);
+        assert!(!queue.initialized);
+
+        let mut trade = trade_tick();
+        trade.aggressor_side = AggressorSide::Seller;
+        trade.size = Quantity::from("50");
+        queue.handle_trade_tick(&trade);
+
+        book.update(bid_order("50", 2), 0, 5);
+        queue.handle_book_mbo(&book);
+
+        assert!(queue.initialized);
+        assert_eq!(queue.bid_depletion_rate, 0.5);
+        assert_eq!(queue.bid_cancel_rate, 0.25);
+    }
+
+    #[rstest]
+    fn test_level_removed_fully_depletes_queue() {
+        let mut queue = QueueDepletion::new(1).unwrap();
+        let mut book = stub_book();
+        queue.handle_book_mbo(&book);
+
+        book.delete(bid_order("100", 1), 0, 4);
+        book.delete(bid_order("100", 2), 0, 5);
+        queue.handle_book_mbo(&book);
+
+        assert_eq!(queue.bid_depletion_rate, 1.0);
+        assert_eq!(queue.bid_cancel_rate, 1.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut queue = QueueDepletion::new(1).unwrap();
+        let mut book = stub_book();
+        queue.handle_book_mbo(&book);
+        book.delete(bid_order("100", 1), 0, 4);
+        queue.handle_book_mbo(&book);
+        queue.reset();
+
+        assert_eq!(queue.count, 0);
+        assert_eq!(queue.bid_depletion_rate, 0.0);
+        assert_eq!(queue.bid_cancel_rate, 0.0);
+        assert!(!queue.has_inputs);
+        assert!(!queue.initialized);
+    }
+}
@@ -0,0 +1,254 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{depth::OrderBookDepth10, quote::QuoteTick},
+    orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp},
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which provides statistics of the bid-ask spread across a rolling window
+/// of `capacity` updates.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct SpreadAnalyzer {
+    pub capacity: usize,
+    pub current: f64,
+    pub average: f64,
+    pub min: f64,
+    pub max: f64,
+    pub std: f64,
+    pub count: usize,
+    pub initialized: bool,
+    spreads: VecDeque<f64>,
+    has_inputs: bool,
+}
+
+impl Display for SpreadAnalyzer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.capacity)
+    }
+}
+
+impl Indicator for SpreadAnalyzer {
+    fn name(&self) -> String {
+        stringify!(SpreadAnalyzer).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[
+            InputKind::Depth,
+            InputKind::BookMbo,
+            InputKind::BookMbp,
+            InputKind::QuoteTick,
+        ]
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        self.update_raw(depth.bids[0].price.as_f64(), depth.asks[0].price.as_f64());
+    }
+
+    fn handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        if let (Some(bid), Some(ask)) = (book.best_bid_price(), book.best_ask_price()) {
+            self.update_raw(bid.as_f64(), ask.as_f64());
+        }
+    }
+
+    fn handle_book_mbp(&mut self, book: &OrderBookMbp) {
+        if let (Some(bid), Some(ask)) = (book.best_bid_price(), book.best_ask_price()) {
+            self.update_raw(bid.as_f64(), ask.as_f64());
+        }
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.bid_price.as_f64(), quote.ask_price.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.spreads.clear();
+        self.current = 0.0;
+        self.average = 0.0;
+        self.min = 0.0;
+        self.max = 0.0;
+        self.std = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl SpreadAnalyzer {
+    pub fn new(capacity: usize) -> Result<Self> {
+        if capacity == 0 {
+            bail!("Condition failed: `capacity` must be positive");
+        }
+
+        Ok(Self {
+            capacity,
+            current: 0.0,
+            average: 0.0,
+            min: 0.0,
+            max: 0.0,
+            std: 0.0,
+            count: 0,
+            initialized: false,
+            spreads: VecDeque::with_capacity(capacity),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, bid: f64, ask: f64) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let spread = ask - bid;
+        self.current = spread;
+
+        if self.spreads.len() == self.capacity {
+            self.spreads.pop_front();
+        }
+        self.spreads.push_back(spread);
+
+        let n = self.spreads.len() as f64;
+        self.average = self.spreads.iter().sum::<f64>() / n;
+        self.min = self.spreads.iter().copied().fold(f64::INFINITY, f64::min);
+        self.max = self
+            .spreads
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let variance = self
+            .spreads
+            .iter()
+            .map(|spread| (spread - self.average).powi(2))
+            .sum::<f64>()
+            / n;
+        self.std = variance.sqrt();
+
+        if !self.initialized && self.spreads.len() == self.capacity {
+            self.initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::depth::stubs::stub_depth10, stubs::stub_order_book_mbp_appl_xnas};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::quote_tick;
+
+    #[rstest]
+    fn test_initialized() {
+        let analyzer = SpreadAnalyzer::new(3).unwrap();
+        let display_str = format!("{analyzer}");
+        assert_eq!(display_str, "SpreadAnalyzer(3)");
+        assert_eq!(analyzer.current, 0.0);
+        assert_eq!(analyzer.average, 0.0);
+        assert!(!analyzer.has_inputs);
+        assert!(!analyzer.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_zero_capacity_returns_error() {
+        assert!(SpreadAnalyzer::new(0).is_err());
+    }
+
+    #[rstest]
+    fn test_statistics_over_window() {
+        let mut analyzer = SpreadAnalyzer::new(3).unwrap();
+        analyzer.update_raw(100.0, 101.0);
+        analyzer.update_raw(100.0, 102.0);
+        assert!(!analyzer.initialized);
+
+        analyzer.update_raw(100.0, 103.0);
+        assert!(analyzer.initialized);
+        assert_eq!(analyzer.current, 3.0);
+        assert_eq!(analyzer.average, 2.0);
+        assert_eq!(analyzer.min, 1.0);
+        assert_eq!(analyzer.max, 3.0);
+        assert!((analyzer.std - (2.0_f64 / 3.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_window_drops_oldest_spread() {
+        let mut analyzer = SpreadAnalyzer::new(2).unwrap();
+        analyzer.update_raw(100.0, 104.0);
+        analyzer.update_raw(100.0, 102.0);
+        analyzer.update_raw(100.0, 102.0);
+
+        assert_eq!(analyzer.average, 2.0);
+        assert_eq!(analyzer.max, 2.0);
+        assert_eq!(analyzer.std, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick() {
+        let mut analyzer = SpreadAnalyzer::new(2).unwrap();
+        analyzer.handle_quote_tick(&quote_tick("1500.0", "1502.0"));
+        analyzer.handle_quote_tick(&quote_tick("1500.0", "1501.0"));
+
+        assert_eq!(analyzer.current, 1.0);
+        assert_eq!(analyzer.average, 1.5);
+        assert!(analyzer.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_book_mbp() {
+        let mut analyzer = SpreadAnalyzer::new(1).unwrap();
+        analyzer.handle_book_mbp(&stub_order_book_mbp_appl_xnas());
+
+        assert!((analyzer.current - 1.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_depth() {
+        let mut analyzer = SpreadAnalyzer::new(1).unwrap();
+        analyzer.handle_depth(&stub_depth10());
+
+        assert_eq!(analyzer.current, 1.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut analyzer = SpreadAnalyzer::new(2).unwrap();
+        analyzer.update_raw(100.0, 101.0);
+        analyzer.reset();
+
+        assert_eq!(analyzer.count, 0);
+        assert_eq!(analyzer.current, 0.0);
+        assert_eq!(analyzer.average, 0.0);
+        assert!(!analyzer.has_inputs);
+        assert!(!analyzer.initialized);
+    }
+}
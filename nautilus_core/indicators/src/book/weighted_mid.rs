@@ -0,0 +1,235 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{depth::OrderBookDepth10, quote::QuoteTick},
+    orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp, level::Level},
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which calculates the mid-point of the size-weighted average bid and ask
+/// prices across the top `num_levels` of the book.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct WeightedMid {
+    pub num_levels: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for WeightedMid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.num_levels)
+    }
+}
+
+impl Indicator for WeightedMid {
+    fn name(&self) -> String {
+        stringify!(WeightedMid).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[
+            InputKind::Depth,
+            InputKind::BookMbo,
+            InputKind::BookMbp,
+            InputKind::QuoteTick,
+        ]
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let bids: Vec<(f64, f64)> = depth
+            .bids
+            .iter()
+            .map(|order| (order.price.as_f64(), order.size.as_f64()))
+            .collect();
+        let asks: Vec<(f64, f64)> = depth
+            .asks
+            .iter()
+            .map(|order| (order.price.as_f64(), order.size.as_f64()))
+            .collect();
+        self.update_raw(&bids, &asks);
+    }
+
+    fn handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        self.update_raw(
+            &level_prices_and_sizes(book.bids(), self.num_levels),
+            &level_prices_and_sizes(book.asks(), self.num_levels),
+        );
+    }
+
+    fn handle_book_mbp(&mut self, book: &OrderBookMbp) {
+        self.update_raw(
+            &level_prices_and_sizes(book.bids(), self.num_levels),
+            &level_prices_and_sizes(book.asks(), self.num_levels),
+        );
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(
+            &[(quote.bid_price.as_f64(), quote.bid_size.as_f64())],
+            &[(quote.ask_price.as_f64(), quote.ask_size.as_f64())],
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl WeightedMid {
+    pub fn new(num_levels: usize) -> Result<Self> {
+        if num_levels == 0 {
+            bail!("Condition failed: `num_levels` must be positive");
+        }
+
+        Ok(Self {
+            num_levels,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+        })
+    }
+
+    /// Updates the indicator with the `(price, size)` of each book level, ordered from
+    /// the top of the book (levels beyond `num_levels` are ignored).
+    pub fn update_raw(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        if let (Some(bid_vwap), Some(ask_vwap)) = (self.vwap(bids), self.vwap(asks)) {
+            self.value = (bid_vwap + ask_vwap) / 2.0;
+            self.initialized = true;
+        }
+        // No market yet
+    }
+
+    fn vwap(&self, levels: &[(f64, f64)]) -> Option<f64> {
+        let mut notional = 0.0;
+        let mut size = 0.0;
+        for (level_price, level_size) in levels.iter().take(self.num_levels) {
+            notional += level_price * level_size;
+            size += level_size;
+        }
+        (size > 0.0).then(|| notional / size)
+    }
+}
+
+fn level_prices_and_sizes<'a>(
+    levels: impl Iterator<Item = &'a Level>,
+    num_levels: usize,
+) -> Vec<(f64, f64)> {
+    levels
+        .take(num_levels)
+        .map(|level| (level.price.value.as_f64(), level.size()))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::depth::stubs::stub_depth10, stubs::stub_order_book_mbp_appl_xnas};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let weighted_mid = WeightedMid::new(5).unwrap();
+        let display_str = format!("{weighted_mid}");
+        assert_eq!(display_str, "WeightedMid(5)");
+        assert_eq!(weighted_mid.value, 0.0);
+        assert!(!weighted_mid.has_inputs);
+        assert!(!weighted_mid.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_zero_levels_returns_error() {
+        assert!(WeightedMid::new(0).is_err());
+    }
+
+    #[rstest]
+    fn test_value_over_levels() {
+        let mut weighted_mid = WeightedMid::new(2).unwrap();
+        weighted_mid.update_raw(
+            &[(100.0, 100.0), (99.0, 300.0), (98.0, 1_000.0)],
+            &[(101.0, 100.0), (102.0, 100.0), (103.0, 1_000.0)],
+        );
+
+        // Bid VWAP of 99.25 and ask VWAP of 101.5
+        assert_eq!(weighted_mid.value, 100.375);
+        assert!(weighted_mid.initialized);
+    }
+
+    #[rstest]
+    fn test_one_sided_book_is_not_initialized() {
+        let mut weighted_mid = WeightedMid::new(2).unwrap();
+        weighted_mid.update_raw(&[(100.0, 100.0)], &[]);
+
+        assert!(weighted_mid.has_inputs);
+        assert!(!weighted_mid.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_book_mbp() {
+        let mut weighted_mid = WeightedMid::new(3).unwrap();
+        weighted_mid.handle_book_mbp(&stub_order_book_mbp_appl_xnas());
+
+        // Symmetric book about the 100.50 mid
+        assert!((weighted_mid.value - 100.5).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_depth() {
+        let mut weighted_mid = WeightedMid::new(10).unwrap();
+        weighted_mid.handle_depth(&stub_depth10());
+
+        assert!((weighted_mid.value - 99.5).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut weighted_mid = WeightedMid::new(2).unwrap();
+        weighted_mid.update_raw(&[(100.0, 100.0)], &[(101.0, 100.0)]);
+        weighted_mid.reset();
+
+        assert_eq!(weighted_mid.count, 0);
+        assert_eq!(weighted_mid.value, 0.0);
+        assert!(!weighted_mid.has_inputs);
+        assert!(!weighted_mid.initialized);
+    }
+}
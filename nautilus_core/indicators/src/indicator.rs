@@ -25,11 +25,31 @@ use nautilus_model::{
 
 const IMPL_ERR: &str = "is not implemented for";
 
+/// A kind of market data an [`Indicator`] can handle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    Delta,
+    Deltas,
+    Depth,
+    BookMbo,
+    BookMbp,
+    QuoteTick,
+    TradeTick,
+    Bar,
+}
+
 #[allow(unused_variables)]
 pub trait Indicator {
     fn name(&self) -> String;
     fn has_inputs(&self) -> bool;
     fn initialized(&self) -> bool;
+    /// Returns the kinds of market data the indicator implements a `handle_*` method for.
+    ///
+    /// Defaults to none, in which case the indicator can't be registered with an
+    /// [`IndicatorRegistry`](crate::registry::IndicatorRegistry).
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[]
+    }
     fn handle_delta(&mut self, delta: &OrderBookDelta) {
         // Eventually change this to log an error
         panic!("`handle_delta` {} `{}`", IMPL_ERR, self.name());
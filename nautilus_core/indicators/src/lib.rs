@@ -17,8 +17,11 @@ pub mod average;
 pub mod book;
 pub mod indicator;
 pub mod momentum;
+pub mod pipeline;
 pub mod ratio;
+pub mod registry;
 pub mod volatility;
+pub mod volume;
 
 #[cfg(test)]
 mod stubs;
@@ -0,0 +1,239 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+    momentum::dm::DirectionalMovement,
+    volatility::atr::AverageTrueRange,
+};
+
+/// An indicator which calculates the Average Directional Index (ADX), the moving average
+/// of the directional index (DX), along with the positive and negative directional
+/// indicators (+DI and -DI) from which the DX is derived.
+///
+/// The ADX measures the strength of a trend regardless of its direction, and
+/// initializes once the DX itself has `period` inputs.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct AverageDirectionalIndex {
+    pub period: usize,
+    pub ma_type: MovingAverageType,
+    pub plus_di: f64,
+    pub minus_di: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    dm: DirectionalMovement,
+    atr: AverageTrueRange,
+    adx_ma: Box<dyn MovingAverage + Send + 'static>,
+    has_inputs: bool,
+}
+
+impl Display for AverageDirectionalIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.ma_type)
+    }
+}
+
+impl Indicator for AverageDirectionalIndex {
+    fn name(&self) -> String {
+        stringify!(AverageDirectionalIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.dm.reset();
+        self.atr.reset();
+        self.adx_ma.reset();
+        self.plus_di = 0.0;
+        self.minus_di = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl AverageDirectionalIndex {
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Wilder);
+        Ok(Self {
+            period,
+            ma_type,
+            plus_di: 0.0,
+            minus_di: 0.0,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            dm: DirectionalMovement::new(period, Some(ma_type))?,
+            atr: AverageTrueRange::new(period, Some(ma_type), Some(true), None)?,
+            adx_ma: MovingAverageFactory::create(ma_type, period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        self.dm.update_raw(high, low);
+        self.atr.update_raw(high, low, close);
+
+        if self.atr.value > 0.0 {
+            self.plus_di = 100.0 * self.dm.pos / self.atr.value;
+            self.minus_di = 100.0 * self.dm.neg / self.atr.value;
+        }
+
+        if self.dm.initialized() {
+            let di_sum = self.plus_di + self.minus_di;
+            let dx = if di_sum > 0.0 {
+                100.0 * (self.plus_di - self.minus_di).abs() / di_sum
+            } else {
+                0.0
+            };
+            self.adx_ma.update_raw(dx);
+            self.value = self.adx_ma.value();
+        }
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.adx_ma.initialized() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, momentum::adx::AverageDirectionalIndex, stubs::*};
+
+    #[rstest]
+    fn test_adx_initialized(adx_10: AverageDirectionalIndex) {
+        let display_str = format!("{adx_10}");
+        assert_eq!(display_str, "AverageDirectionalIndex(10,WILDER)");
+        assert_eq!(adx_10.period, 10);
+        assert!(!adx_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut adx_10: AverageDirectionalIndex) {
+        // The DX is only averaged once the directional movement is initialized
+        for i in 0..18 {
+            adx_10.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i) + 0.5);
+        }
+        assert!(!adx_10.initialized);
+
+        adx_10.update_raw(19.0, 18.0, 18.5);
+        assert!(adx_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_rising_inputs_returns_maximum_trend_strength(
+        mut adx_10: AverageDirectionalIndex,
+    ) {
+        for i in 0..20 {
+            adx_10.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i) + 0.5);
+        }
+        assert!(adx_10.plus_di > 0.0);
+        assert_eq!(adx_10.minus_di, 0.0);
+        assert!((adx_10.value - 100.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_value_with_flat_inputs_returns_zero(mut adx_10: AverageDirectionalIndex) {
+        for _ in 0..20 {
+            adx_10.update_raw(2.0, 1.0, 1.5);
+        }
+        assert_eq!(adx_10.plus_di, 0.0);
+        assert_eq!(adx_10.minus_di, 0.0);
+        assert_eq!(adx_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut adx_10: AverageDirectionalIndex, quote_tick: QuoteTick) {
+        adx_10.handle_quote_tick(&quote_tick);
+        assert!(adx_10.has_inputs());
+        assert_eq!(adx_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut adx_10: AverageDirectionalIndex, trade_tick: TradeTick) {
+        adx_10.handle_trade_tick(&trade_tick);
+        assert!(adx_10.has_inputs());
+        assert_eq!(adx_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut adx_10: AverageDirectionalIndex, bar_ethusdt_binance_minute_bid: Bar) {
+        adx_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(adx_10.has_inputs());
+        assert_eq!(adx_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut adx_10: AverageDirectionalIndex) {
+        for i in 0..20 {
+            adx_10.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i) + 0.5);
+        }
+        adx_10.reset();
+
+        assert!(!adx_10.has_inputs());
+        assert!(!adx_10.initialized);
+        assert_eq!(adx_10.count, 0);
+        assert_eq!(adx_10.value, 0.0);
+        assert_eq!(adx_10.plus_di, 0.0);
+    }
+}
@@ -25,7 +25,7 @@ use nautilus_model::{
 };
 use pyo3::prelude::*;
 
-use crate::indicator::Indicator;
+use crate::indicator::{Indicator, InputKind};
 
 /// The Aroon Oscillator calculates the Aroon Up and Aroon Down indicators to
 /// determine if an instrument is trending, and the strength of the trend.
@@ -63,6 +63,10 @@ impl Indicator for AroonOscillator {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
     fn handle_quote_tick(&mut self, tick: &QuoteTick) {
         let price = tick.extract_price(PriceType::Mid).into();
         self.update_raw(price, price);
@@ -0,0 +1,244 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+};
+
+/// An indicator which calculates the Commodity Channel Index (CCI), a momentum oscillator
+/// which measures the deviation of the typical price from its moving average, scaled by
+/// the mean absolute deviation.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct CommodityChannelIndex {
+    pub period: usize,
+    pub scalar: f64,
+    pub ma_type: MovingAverageType,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    ma: Box<dyn MovingAverage + Send + 'static>,
+    prices: VecDeque<f64>,
+    has_inputs: bool,
+}
+
+impl Display for CommodityChannelIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.period,
+            self.scalar,
+            self.ma_type
+        )
+    }
+}
+
+impl Indicator for CommodityChannelIndex {
+    fn name(&self) -> String {
+        stringify!(CommodityChannelIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.prices.clear();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl CommodityChannelIndex {
+    pub fn new(
+        period: usize,
+        scalar: Option<f64>,
+        ma_type: Option<MovingAverageType>,
+    ) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+        Ok(Self {
+            period,
+            scalar: scalar.unwrap_or(0.015),
+            ma_type,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            ma: MovingAverageFactory::create(ma_type, period),
+            prices: VecDeque::with_capacity(period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical_price = (high + low + close) / 3.0;
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(typical_price);
+        self.ma.update_raw(typical_price);
+
+        let mean = self.ma.value();
+        let mad = self
+            .prices
+            .iter()
+            .map(|price| (price - mean).abs())
+            .sum::<f64>()
+            / self.prices.len() as f64;
+
+        if self.ma.initialized() && mad != 0.0 {
+            self.value = (typical_price - mean) / (self.scalar * mad);
+        }
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.ma.initialized() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, momentum::cci::CommodityChannelIndex, stubs::*};
+
+    #[rstest]
+    fn test_cci_initialized(cci_10: CommodityChannelIndex) {
+        let display_str = format!("{cci_10}");
+        assert_eq!(display_str, "CommodityChannelIndex(10,0.015,SIMPLE)");
+        assert_eq!(cci_10.period, 10);
+        assert!(!cci_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut cci_10: CommodityChannelIndex) {
+        for i in 0..9 {
+            cci_10.update_raw(f64::from(i), f64::from(i), f64::from(i));
+        }
+        assert!(!cci_10.initialized);
+
+        cci_10.update_raw(9.0, 9.0, 9.0);
+        assert!(cci_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_rising_inputs_returns_expected_value() {
+        let mut cci = CommodityChannelIndex::new(3, None, None).unwrap();
+        cci.update_raw(1.0, 1.0, 1.0);
+        cci.update_raw(2.0, 2.0, 2.0);
+        cci.update_raw(3.0, 3.0, 3.0);
+
+        // Typical price 3 vs a mean of 2, with a mean absolute deviation of 2/3
+        assert!((cci.value - 100.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_value_with_falling_inputs_is_negative(mut cci_10: CommodityChannelIndex) {
+        for i in (0..10).rev() {
+            cci_10.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i) + 0.5);
+        }
+        assert!(cci_10.value < 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_constant_inputs_returns_zero(mut cci_10: CommodityChannelIndex) {
+        for _ in 0..10 {
+            cci_10.update_raw(1.0, 1.0, 1.0);
+        }
+        assert_eq!(cci_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut cci_10: CommodityChannelIndex, quote_tick: QuoteTick) {
+        cci_10.handle_quote_tick(&quote_tick);
+        assert!(cci_10.has_inputs());
+        assert_eq!(cci_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut cci_10: CommodityChannelIndex, trade_tick: TradeTick) {
+        cci_10.handle_trade_tick(&trade_tick);
+        assert!(cci_10.has_inputs());
+        assert_eq!(cci_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut cci_10: CommodityChannelIndex, bar_ethusdt_binance_minute_bid: Bar) {
+        cci_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(cci_10.has_inputs());
+        assert_eq!(cci_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut cci_10: CommodityChannelIndex) {
+        for i in 0..10 {
+            cci_10.update_raw(f64::from(i), f64::from(i), f64::from(i));
+        }
+        cci_10.reset();
+
+        assert!(!cci_10.has_inputs());
+        assert!(!cci_10.initialized);
+        assert_eq!(cci_10.count, 0);
+        assert_eq!(cci_10.value, 0.0);
+    }
+}
@@ -21,7 +21,7 @@ use pyo3::prelude::*;
 
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, InputKind, MovingAverage},
 };
 
 #[repr(C)]
@@ -58,6 +58,10 @@ impl Indicator for ChandeMomentumOscillator {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
     fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
         // Function body intentionally left blank.
     }
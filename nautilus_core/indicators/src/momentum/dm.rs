@@ -0,0 +1,239 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+};
+
+/// An indicator which calculates the positive and negative directional movement, two
+/// oscillators capturing upward and downward trend movement.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct DirectionalMovement {
+    pub period: usize,
+    pub ma_type: MovingAverageType,
+    pub pos: f64,
+    pub neg: f64,
+    pub count: usize,
+    pub initialized: bool,
+    pos_ma: Box<dyn MovingAverage + Send + 'static>,
+    neg_ma: Box<dyn MovingAverage + Send + 'static>,
+    previous_high: f64,
+    previous_low: f64,
+    has_inputs: bool,
+}
+
+impl Display for DirectionalMovement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.ma_type)
+    }
+}
+
+impl Indicator for DirectionalMovement {
+    fn name(&self) -> String {
+        stringify!(DirectionalMovement).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into());
+    }
+
+    fn reset(&mut self) {
+        self.pos_ma.reset();
+        self.neg_ma.reset();
+        self.previous_high = 0.0;
+        self.previous_low = 0.0;
+        self.pos = 0.0;
+        self.neg = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl DirectionalMovement {
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+        Ok(Self {
+            period,
+            ma_type,
+            pos: 0.0,
+            neg: 0.0,
+            count: 0,
+            initialized: false,
+            pos_ma: MovingAverageFactory::create(ma_type, period),
+            neg_ma: MovingAverageFactory::create(ma_type, period),
+            previous_high: 0.0,
+            previous_low: 0.0,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        if !self.has_inputs {
+            self.previous_high = high;
+            self.previous_low = low;
+        }
+
+        let up = high - self.previous_high;
+        let down = self.previous_low - low;
+
+        self.pos_ma
+            .update_raw(if up > down && up > 0.0 { up } else { 0.0 });
+        self.neg_ma
+            .update_raw(if down > up && down > 0.0 { down } else { 0.0 });
+        self.pos = self.pos_ma.value();
+        self.neg = self.neg_ma.value();
+
+        self.previous_high = high;
+        self.previous_low = low;
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.neg_ma.initialized() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{
+        average::MovingAverageType, indicator::Indicator, momentum::dm::DirectionalMovement,
+        stubs::*,
+    };
+
+    #[rstest]
+    fn test_dm_initialized(dm_10: DirectionalMovement) {
+        let display_str = format!("{dm_10}");
+        assert_eq!(display_str, "DirectionalMovement(10,EXPONENTIAL)");
+        assert_eq!(dm_10.period, 10);
+        assert!(!dm_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut dm_10: DirectionalMovement) {
+        for i in 0..9 {
+            dm_10.update_raw(f64::from(i) + 1.0, f64::from(i));
+        }
+        assert!(!dm_10.initialized);
+
+        dm_10.update_raw(10.0, 9.0);
+        assert!(dm_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_one_input_returns_zero(mut dm_10: DirectionalMovement) {
+        dm_10.update_raw(2.0, 1.0);
+
+        assert_eq!(dm_10.pos, 0.0);
+        assert_eq!(dm_10.neg, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_simple_moving_average_returns_expected_value() {
+        let mut dm = DirectionalMovement::new(3, Some(MovingAverageType::Simple)).unwrap();
+        dm.update_raw(2.0, 1.0);
+        dm.update_raw(3.0, 1.5); // Up move of 1
+        dm.update_raw(2.5, 0.5); // Down move of 1
+
+        assert_eq!(dm.pos, 1.0 / 3.0);
+        assert_eq!(dm.neg, 1.0 / 3.0);
+    }
+
+    #[rstest]
+    fn test_value_with_rising_inputs_has_no_negative_movement(mut dm_10: DirectionalMovement) {
+        for i in 0..10 {
+            dm_10.update_raw(f64::from(i) + 1.0, f64::from(i));
+        }
+        assert!(dm_10.pos > 0.0);
+        assert_eq!(dm_10.neg, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut dm_10: DirectionalMovement, quote_tick: QuoteTick) {
+        dm_10.handle_quote_tick(&quote_tick);
+        assert!(dm_10.has_inputs());
+        assert_eq!(dm_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut dm_10: DirectionalMovement, trade_tick: TradeTick) {
+        dm_10.handle_trade_tick(&trade_tick);
+        assert!(dm_10.has_inputs());
+        assert_eq!(dm_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut dm_10: DirectionalMovement, bar_ethusdt_binance_minute_bid: Bar) {
+        dm_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(dm_10.has_inputs());
+        assert_eq!(dm_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut dm_10: DirectionalMovement) {
+        dm_10.update_raw(2.0, 1.0);
+        dm_10.update_raw(3.0, 1.5);
+        dm_10.reset();
+
+        assert!(!dm_10.has_inputs());
+        assert!(!dm_10.initialized);
+        assert_eq!(dm_10.count, 0);
+        assert_eq!(dm_10.pos, 0.0);
+        assert_eq!(dm_10.neg, 0.0);
+    }
+}
@@ -0,0 +1,279 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+    momentum::roc::RateOfChange,
+};
+
+/// The default rate of change periods for [`KnowSureThing`].
+pub const KST_ROC_PERIODS: [usize; 4] = [10, 15, 20, 30];
+
+/// The default moving average periods for [`KnowSureThing`].
+pub const KST_MA_PERIODS: [usize; 4] = [10, 10, 10, 15];
+
+/// The default signal line period for [`KnowSureThing`].
+pub const KST_SIGNAL_PERIOD: usize = 9;
+
+/// An oscillator which calculates the Know Sure Thing (KST), the weighted sum of four
+/// smoothed rates of change (in percent) with increasing periods, weighted 1 to 4, along
+/// with a signal line (a moving average of the KST).
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct KnowSureThing {
+    pub roc_periods: [usize; 4],
+    pub ma_periods: [usize; 4],
+    pub signal_period: usize,
+    pub ma_type: MovingAverageType,
+    pub value: f64,
+    pub signal: f64,
+    pub count: usize,
+    pub initialized: bool,
+    rocs: Vec<RateOfChange>,
+    mas: Vec<Box<dyn MovingAverage + Send + 'static>>,
+    signal_ma: Box<dyn MovingAverage + Send + 'static>,
+    has_inputs: bool,
+}
+
+impl Display for KnowSureThing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({:?},{:?},{},{})",
+            self.name(),
+            self.roc_periods,
+            self.ma_periods,
+            self.signal_period,
+            self.ma_type
+        )
+    }
+}
+
+impl Indicator for KnowSureThing {
+    fn name(&self) -> String {
+        stringify!(KnowSureThing).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(PriceType::Mid).into());
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        for roc in &mut self.rocs {
+            roc.reset();
+        }
+        for ma in &mut self.mas {
+            ma.reset();
+        }
+        self.signal_ma.reset();
+        self.value = 0.0;
+        self.signal = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl KnowSureThing {
+    pub fn new(
+        roc_periods: Option<[usize; 4]>,
+        ma_periods: Option<[usize; 4]>,
+        signal_period: Option<usize>,
+        ma_type: Option<MovingAverageType>,
+    ) -> Result<Self> {
+        let roc_periods = roc_periods.unwrap_or(KST_ROC_PERIODS);
+        let ma_periods = ma_periods.unwrap_or(KST_MA_PERIODS);
+        let signal_period = signal_period.unwrap_or(KST_SIGNAL_PERIOD);
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+
+        let rocs = roc_periods
+            .iter()
+            .map(|period| RateOfChange::new(*period, Some(false)))
+            .collect::<Result<Vec<_>>>()?;
+        let mas = ma_periods
+            .iter()
+            .map(|period| MovingAverageFactory::create(ma_type, *period))
+            .collect();
+
+        Ok(Self {
+            roc_periods,
+            ma_periods,
+            signal_period,
+            ma_type,
+            value: 0.0,
+            signal: 0.0,
+            count: 0,
+            initialized: false,
+            rocs,
+            mas,
+            signal_ma: MovingAverageFactory::create(ma_type, signal_period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        for (roc, ma) in self.rocs.iter_mut().zip(self.mas.iter_mut()) {
+            roc.update_raw(close);
+            // Only smooth rates of change across a full window
+            if roc.initialized {
+                ma.update_raw(100.0 * roc.value);
+            }
+        }
+
+        self.count += 1;
+        self.has_inputs = true;
+
+        if self.mas.iter().all(|ma| ma.initialized()) {
+            self.value = self
+                .mas
+                .iter()
+                .enumerate()
+                .map(|(i, ma)| (i + 1) as f64 * ma.value())
+                .sum();
+            self.signal_ma.update_raw(self.value);
+            self.signal = self.signal_ma.value();
+
+            if !self.initialized && self.signal_ma.initialized() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, momentum::kst::KnowSureThing, stubs::*};
+
+    #[rstest]
+    fn test_kst_initialized(kst: KnowSureThing) {
+        let display_str = format!("{kst}");
+        assert_eq!(
+            display_str,
+            "KnowSureThing([10, 15, 20, 30],[10, 10, 10, 15],9,SIMPLE)"
+        );
+        assert!(!kst.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_roc_period_returns_error() {
+        assert!(KnowSureThing::new(Some([1, 2, 3, 4]), None, None, None).is_err());
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut kst: KnowSureThing) {
+        // The slowest component needs 30 + 15 - 1 inputs, then the signal needs 9 values
+        for i in 1..52 {
+            kst.update_raw(f64::from(i));
+        }
+        assert!(!kst.initialized);
+
+        kst.update_raw(52.0);
+        assert!(kst.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_constant_inputs_returns_zero(mut kst: KnowSureThing) {
+        for _ in 0..60 {
+            kst.update_raw(1.0);
+        }
+        assert_eq!(kst.value, 0.0);
+        assert_eq!(kst.signal, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_simple_parameters_returns_expected_value() {
+        let mut kst =
+            KnowSureThing::new(Some([2, 2, 2, 2]), Some([1, 1, 1, 1]), Some(1), None).unwrap();
+        kst.update_raw(100.0);
+        kst.update_raw(110.0);
+
+        // Each component is a 10% rate of change, weighted 1 + 2 + 3 + 4
+        assert!((kst.value - 100.0).abs() < 1e-9);
+        assert!((kst.signal - 100.0).abs() < 1e-9);
+        assert!(kst.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut kst: KnowSureThing, quote_tick: QuoteTick) {
+        kst.handle_quote_tick(&quote_tick);
+        assert!(kst.has_inputs());
+        assert_eq!(kst.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut kst: KnowSureThing, trade_tick: TradeTick) {
+        kst.handle_trade_tick(&trade_tick);
+        assert!(kst.has_inputs());
+        assert_eq!(kst.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut kst: KnowSureThing, bar_ethusdt_binance_minute_bid: Bar) {
+        kst.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(kst.has_inputs());
+        assert_eq!(kst.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut kst: KnowSureThing) {
+        for i in 1..60 {
+            kst.update_raw(f64::from(i));
+        }
+        kst.reset();
+
+        assert!(!kst.has_inputs());
+        assert!(!kst.initialized);
+        assert_eq!(kst.count, 0);
+        assert_eq!(kst.value, 0.0);
+        assert_eq!(kst.signal, 0.0);
+    }
+}
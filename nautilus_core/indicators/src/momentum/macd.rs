@@ -0,0 +1,287 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+};
+
+/// An indicator which calculates the difference between a fast and a slow moving average
+/// (MACD), along with a signal line (a moving average of the MACD) and their histogram.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct MovingAverageConvergenceDivergence {
+    pub fast_period: usize,
+    pub slow_period: usize,
+    pub signal_period: usize,
+    pub ma_type: MovingAverageType,
+    pub value: f64,
+    pub signal: f64,
+    pub histogram: f64,
+    pub count: usize,
+    pub initialized: bool,
+    fast_ma: Box<dyn MovingAverage + Send + 'static>,
+    slow_ma: Box<dyn MovingAverage + Send + 'static>,
+    signal_ma: Box<dyn MovingAverage + Send + 'static>,
+    has_inputs: bool,
+}
+
+impl Display for MovingAverageConvergenceDivergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{})",
+            self.name(),
+            self.fast_period,
+            self.slow_period,
+            self.signal_period,
+            self.ma_type
+        )
+    }
+}
+
+impl Indicator for MovingAverageConvergenceDivergence {
+    fn name(&self) -> String {
+        stringify!(MovingAverageConvergenceDivergence).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(PriceType::Mid).into());
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self.value = 0.0;
+        self.signal = 0.0;
+        self.histogram = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MovingAverageConvergenceDivergence {
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: Option<usize>,
+        ma_type: Option<MovingAverageType>,
+    ) -> Result<Self> {
+        if slow_period <= fast_period {
+            bail!(
+                "Condition failed: `slow_period` must be greater than `fast_period`, was {slow_period} <= {fast_period}"
+            );
+        }
+        let signal_period = signal_period.unwrap_or(9);
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+
+        Ok(Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            ma_type,
+            value: 0.0,
+            signal: 0.0,
+            histogram: 0.0,
+            count: 0,
+            initialized: false,
+            fast_ma: MovingAverageFactory::create(ma_type, fast_period),
+            slow_ma: MovingAverageFactory::create(ma_type, slow_period),
+            signal_ma: MovingAverageFactory::create(ma_type, signal_period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        self.fast_ma.update_raw(close);
+        self.slow_ma.update_raw(close);
+        self.value = self.fast_ma.value() - self.slow_ma.value();
+
+        self.signal_ma.update_raw(self.value);
+        self.signal = self.signal_ma.value();
+        self.histogram = self.value - self.signal;
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.fast_ma.initialized()
+                && self.slow_ma.initialized()
+                && self.signal_ma.initialized()
+            {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{
+        average::MovingAverageType, indicator::Indicator,
+        momentum::macd::MovingAverageConvergenceDivergence, stubs::*,
+    };
+
+    #[rstest]
+    fn test_macd_initialized(macd_12_26: MovingAverageConvergenceDivergence) {
+        let display_str = format!("{macd_12_26}");
+        assert_eq!(
+            display_str,
+            "MovingAverageConvergenceDivergence(12,26,9,EXPONENTIAL)"
+        );
+        assert_eq!(macd_12_26.fast_period, 12);
+        assert_eq!(macd_12_26.slow_period, 26);
+        assert!(!macd_12_26.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_slow_period_not_greater_than_fast_returns_error() {
+        assert!(MovingAverageConvergenceDivergence::new(26, 26, None, None).is_err());
+        assert!(MovingAverageConvergenceDivergence::new(26, 12, None, None).is_err());
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(
+        mut macd_12_26: MovingAverageConvergenceDivergence,
+    ) {
+        for i in 0..25 {
+            macd_12_26.update_raw(f64::from(i));
+        }
+        assert!(!macd_12_26.initialized);
+
+        macd_12_26.update_raw(25.0);
+        assert!(macd_12_26.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_constant_inputs_returns_zero(
+        mut macd_12_26: MovingAverageConvergenceDivergence,
+    ) {
+        for _ in 0..30 {
+            macd_12_26.update_raw(1.0);
+        }
+        assert!(macd_12_26.value.abs() < 1e-12);
+        assert!(macd_12_26.signal.abs() < 1e-12);
+        assert!(macd_12_26.histogram.abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_value_with_simple_moving_averages_returns_expected_value() {
+        let mut macd =
+            MovingAverageConvergenceDivergence::new(2, 3, Some(2), Some(MovingAverageType::Simple))
+                .unwrap();
+        macd.update_raw(1.0);
+        macd.update_raw(2.0);
+        macd.update_raw(3.0);
+
+        assert!(macd.initialized);
+        assert_eq!(macd.value, 0.5);
+        assert_eq!(macd.signal, 0.25);
+        assert_eq!(macd.histogram, 0.25);
+    }
+
+    #[rstest]
+    fn test_value_with_rising_inputs_is_positive(
+        mut macd_12_26: MovingAverageConvergenceDivergence,
+    ) {
+        for i in 0..40 {
+            macd_12_26.update_raw(f64::from(i));
+        }
+        assert!(macd_12_26.value > 0.0);
+        assert!(macd_12_26.signal > 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(
+        mut macd_12_26: MovingAverageConvergenceDivergence,
+        quote_tick: QuoteTick,
+    ) {
+        macd_12_26.handle_quote_tick(&quote_tick);
+        assert!(macd_12_26.has_inputs());
+        assert_eq!(macd_12_26.count, 1);
+        assert_eq!(macd_12_26.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(
+        mut macd_12_26: MovingAverageConvergenceDivergence,
+        trade_tick: TradeTick,
+    ) {
+        macd_12_26.handle_trade_tick(&trade_tick);
+        assert!(macd_12_26.has_inputs());
+        assert_eq!(macd_12_26.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(
+        mut macd_12_26: MovingAverageConvergenceDivergence,
+        bar_ethusdt_binance_minute_bid: Bar,
+    ) {
+        macd_12_26.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(macd_12_26.has_inputs());
+        assert_eq!(macd_12_26.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut macd_12_26: MovingAverageConvergenceDivergence) {
+        for i in 0..30 {
+            macd_12_26.update_raw(f64::from(i));
+        }
+        macd_12_26.reset();
+
+        assert!(!macd_12_26.has_inputs());
+        assert!(!macd_12_26.initialized);
+        assert_eq!(macd_12_26.count, 0);
+        assert_eq!(macd_12_26.value, 0.0);
+        assert_eq!(macd_12_26.signal, 0.0);
+    }
+}
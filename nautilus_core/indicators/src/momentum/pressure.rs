@@ -0,0 +1,230 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+    volatility::atr::AverageTrueRange,
+};
+
+/// An indicator which calculates the relative volume (multiple of average volume) to move
+/// the market across a relative range (multiple of ATR).
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct Pressure {
+    pub period: usize,
+    pub ma_type: MovingAverageType,
+    pub atr_floor: f64,
+    pub value: f64,
+    pub value_cumulative: f64,
+    pub count: usize,
+    pub initialized: bool,
+    atr: AverageTrueRange,
+    average_volume: Box<dyn MovingAverage + Send + 'static>,
+    has_inputs: bool,
+}
+
+impl Display for Pressure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.period,
+            self.ma_type,
+            self.atr_floor
+        )
+    }
+}
+
+impl Indicator for Pressure {
+    fn name(&self) -> String {
+        stringify!(Pressure).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, _tick: &TradeTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+            (&bar.volume).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.average_volume.reset();
+        self.value = 0.0;
+        self.value_cumulative = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl Pressure {
+    pub fn new(
+        period: usize,
+        ma_type: Option<MovingAverageType>,
+        atr_floor: Option<f64>,
+    ) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+        let atr_floor = atr_floor.unwrap_or(0.0);
+        Ok(Self {
+            period,
+            ma_type,
+            atr_floor,
+            value: 0.0,
+            value_cumulative: 0.0,
+            count: 0,
+            initialized: false,
+            atr: AverageTrueRange::new(
+                period,
+                Some(MovingAverageType::Exponential),
+                Some(true),
+                Some(atr_floor),
+            )?,
+            average_volume: MovingAverageFactory::create(ma_type, period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        self.atr.update_raw(high, low, close);
+        self.average_volume.update_raw(volume);
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.atr.initialized {
+                self.initialized = true;
+            }
+        }
+
+        if self.average_volume.value() == 0.0 || self.atr.value == 0.0 {
+            self.value = 0.0;
+            return;
+        }
+
+        let relative_volume = volume / self.average_volume.value();
+        let buy_pressure = ((close - low) / self.atr.value) * relative_volume;
+        let sell_pressure = ((high - close) / self.atr.value) * relative_volume;
+
+        self.value = buy_pressure - sell_pressure;
+        self.value_cumulative += self.value;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::bar::Bar;
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, momentum::pressure::Pressure, stubs::*};
+
+    #[rstest]
+    fn test_pressure_initialized(pressure_10: Pressure) {
+        let display_str = format!("{pressure_10}");
+        assert_eq!(display_str, "Pressure(10,EXPONENTIAL,0)");
+        assert_eq!(pressure_10.period, 10);
+        assert!(!pressure_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut pressure_10: Pressure) {
+        for i in 0..9 {
+            pressure_10.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i) + 0.5, 100.0);
+        }
+        assert!(!pressure_10.initialized);
+
+        pressure_10.update_raw(10.0, 9.0, 9.5, 100.0);
+        assert!(pressure_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_balanced_close_returns_zero(mut pressure_10: Pressure) {
+        pressure_10.update_raw(2.0, 1.0, 1.5, 100.0);
+
+        assert_eq!(pressure_10.value, 0.0);
+        assert_eq!(pressure_10.value_cumulative, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_close_at_high_returns_expected_value(mut pressure_10: Pressure) {
+        pressure_10.update_raw(2.0, 1.0, 1.5, 100.0);
+        pressure_10.update_raw(3.0, 2.0, 3.0, 100.0);
+
+        // The ATR moves from 1.0 towards a true range of 1.5, with an alpha of 2/11
+        let atr = 12.0 / 11.0;
+        assert!((pressure_10.value - 1.0 / atr).abs() < 1e-9);
+        assert!((pressure_10.value_cumulative - 1.0 / atr).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_value_with_zero_volume_returns_zero(mut pressure_10: Pressure) {
+        pressure_10.update_raw(3.0, 2.0, 3.0, 0.0);
+        assert_eq!(pressure_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut pressure_10: Pressure, bar_ethusdt_binance_minute_bid: Bar) {
+        pressure_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(pressure_10.has_inputs());
+        assert_eq!(pressure_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut pressure_10: Pressure) {
+        pressure_10.update_raw(2.0, 1.0, 1.5, 100.0);
+        pressure_10.update_raw(3.0, 2.0, 3.0, 100.0);
+        pressure_10.reset();
+
+        assert!(!pressure_10.has_inputs());
+        assert!(!pressure_10.initialized);
+        assert_eq!(pressure_10.count, 0);
+        assert_eq!(pressure_10.value, 0.0);
+        assert_eq!(pressure_10.value_cumulative, 0.0);
+    }
+}
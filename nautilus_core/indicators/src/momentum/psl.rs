@@ -0,0 +1,211 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+};
+
+/// An oscillator which calculates the Psychological Line, the percentage of inputs which
+/// close above the previous close across a rolling window.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct PsychologicalLine {
+    pub period: usize,
+    pub ma_type: MovingAverageType,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    ma: Box<dyn MovingAverage + Send + 'static>,
+    previous_close: f64,
+    has_inputs: bool,
+}
+
+impl Display for PsychologicalLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.ma_type)
+    }
+}
+
+impl Indicator for PsychologicalLine {
+    fn name(&self) -> String {
+        stringify!(PsychologicalLine).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(PriceType::Mid).into());
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.previous_close = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl PsychologicalLine {
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+        Ok(Self {
+            period,
+            ma_type,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            ma: MovingAverageFactory::create(ma_type, period),
+            previous_close: 0.0,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        if !self.has_inputs {
+            self.previous_close = close;
+        }
+
+        let rising = close > self.previous_close;
+        self.ma.update_raw(if rising { 1.0 } else { 0.0 });
+        self.value = 100.0 * self.ma.value();
+        self.previous_close = close;
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.ma.initialized() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, momentum::psl::PsychologicalLine, stubs::*};
+
+    #[rstest]
+    fn test_psl_initialized(psl_10: PsychologicalLine) {
+        let display_str = format!("{psl_10}");
+        assert_eq!(display_str, "PsychologicalLine(10,SIMPLE)");
+        assert_eq!(psl_10.period, 10);
+        assert!(!psl_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut psl_10: PsychologicalLine) {
+        for i in 0..9 {
+            psl_10.update_raw(f64::from(i));
+        }
+        assert!(!psl_10.initialized);
+
+        psl_10.update_raw(9.0);
+        assert!(psl_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_one_input_returns_zero(mut psl_10: PsychologicalLine) {
+        psl_10.update_raw(1.0);
+        assert_eq!(psl_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_alternating_inputs_returns_expected_value(mut psl_10: PsychologicalLine) {
+        for i in 0..11 {
+            psl_10.update_raw(f64::from(i % 2));
+        }
+        // Five of the last ten inputs closed above the previous close
+        assert_eq!(psl_10.value, 50.0);
+    }
+
+    #[rstest]
+    fn test_value_with_rising_inputs_returns_expected_value(mut psl_10: PsychologicalLine) {
+        for i in 0..11 {
+            psl_10.update_raw(f64::from(i));
+        }
+        assert_eq!(psl_10.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut psl_10: PsychologicalLine, quote_tick: QuoteTick) {
+        psl_10.handle_quote_tick(&quote_tick);
+        assert!(psl_10.has_inputs());
+        assert_eq!(psl_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut psl_10: PsychologicalLine, trade_tick: TradeTick) {
+        psl_10.handle_trade_tick(&trade_tick);
+        assert!(psl_10.has_inputs());
+        assert_eq!(psl_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut psl_10: PsychologicalLine, bar_ethusdt_binance_minute_bid: Bar) {
+        psl_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(psl_10.has_inputs());
+        assert_eq!(psl_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut psl_10: PsychologicalLine) {
+        for i in 0..11 {
+            psl_10.update_raw(f64::from(i));
+        }
+        psl_10.reset();
+
+        assert!(!psl_10.has_inputs());
+        assert!(!psl_10.initialized);
+        assert_eq!(psl_10.count, 0);
+        assert_eq!(psl_10.value, 0.0);
+    }
+}
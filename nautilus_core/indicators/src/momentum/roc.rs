@@ -0,0 +1,221 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which calculates the rate of change of price across a rolling window,
+/// as either a simple or a log return.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct RateOfChange {
+    pub period: usize,
+    pub use_log: bool,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    prices: VecDeque<f64>,
+    has_inputs: bool,
+}
+
+impl Display for RateOfChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.use_log)
+    }
+}
+
+impl Indicator for RateOfChange {
+    fn name(&self) -> String {
+        stringify!(RateOfChange).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(PriceType::Mid).into());
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.prices.clear();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl RateOfChange {
+    pub fn new(period: usize, use_log: Option<bool>) -> Result<Self> {
+        if period < 2 {
+            bail!("Condition failed: `period` must be at least 2, was {period}");
+        }
+
+        Ok(Self {
+            period,
+            use_log: use_log.unwrap_or(false),
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            prices: VecDeque::with_capacity(period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, price: f64) {
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(price);
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.prices.len() >= self.period {
+                self.initialized = true;
+            }
+        }
+
+        let first = self.prices[0];
+        self.value = if self.use_log {
+            (price / first).ln()
+        } else {
+            (price - first) / first
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, momentum::roc::RateOfChange, stubs::*};
+
+    #[rstest]
+    fn test_roc_initialized(roc_10: RateOfChange) {
+        let display_str = format!("{roc_10}");
+        assert_eq!(display_str, "RateOfChange(10,false)");
+        assert_eq!(roc_10.period, 10);
+        assert!(!roc_10.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_period_returns_error() {
+        assert!(RateOfChange::new(1, None).is_err());
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut roc_10: RateOfChange) {
+        for i in 1..10 {
+            roc_10.update_raw(f64::from(i));
+        }
+        assert!(!roc_10.initialized);
+
+        roc_10.update_raw(10.0);
+        assert!(roc_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_one_input_returns_zero(mut roc_10: RateOfChange) {
+        roc_10.update_raw(1.0);
+        assert_eq!(roc_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_full_window_returns_expected_value(mut roc_10: RateOfChange) {
+        for i in 1..=11 {
+            roc_10.update_raw(f64::from(i));
+        }
+        // Compared against the first price in the window (2.0)
+        assert_eq!(roc_10.value, 4.5);
+    }
+
+    #[rstest]
+    fn test_value_with_log_returns_expected_value() {
+        let mut roc = RateOfChange::new(3, Some(true)).unwrap();
+        roc.update_raw(1.0);
+        roc.update_raw(2.0);
+        roc.update_raw(4.0);
+
+        assert_eq!(roc.value, 4.0f64.ln());
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut roc_10: RateOfChange, quote_tick: QuoteTick) {
+        roc_10.handle_quote_tick(&quote_tick);
+        assert!(roc_10.has_inputs());
+        assert_eq!(roc_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut roc_10: RateOfChange, trade_tick: TradeTick) {
+        roc_10.handle_trade_tick(&trade_tick);
+        assert!(roc_10.has_inputs());
+        assert_eq!(roc_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut roc_10: RateOfChange, bar_ethusdt_binance_minute_bid: Bar) {
+        roc_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(roc_10.has_inputs());
+        assert_eq!(roc_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut roc_10: RateOfChange) {
+        for i in 1..=10 {
+            roc_10.update_raw(f64::from(i));
+        }
+        roc_10.reset();
+
+        assert!(!roc_10.has_inputs());
+        assert!(!roc_10.initialized);
+        assert_eq!(roc_10.count, 0);
+        assert_eq!(roc_10.value, 0.0);
+    }
+}
@@ -24,7 +24,7 @@ use pyo3::prelude::*;
 
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, InputKind, MovingAverage},
 };
 
 /// An indicator which calculates a relative strength index (RSI) across a rolling window.
@@ -63,6 +63,10 @@ impl Indicator for RelativeStrengthIndex {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
     fn handle_quote_tick(&mut self, quote: &QuoteTick) {
         self.update_raw(quote.extract_price(PriceType::Mid).into());
     }
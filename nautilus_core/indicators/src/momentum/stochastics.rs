@@ -0,0 +1,258 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An oscillator which can indicate when an asset may be over bought or over sold, from
+/// the position of the close within the high-low range of a rolling window (%K), and
+/// the same ratio summed over the last `period_d` inputs (%D).
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct Stochastics {
+    pub period_k: usize,
+    pub period_d: usize,
+    pub value_k: f64,
+    pub value_d: f64,
+    pub count: usize,
+    pub initialized: bool,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    c_sub_l: VecDeque<f64>,
+    h_sub_l: VecDeque<f64>,
+    has_inputs: bool,
+}
+
+impl Display for Stochastics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period_k, self.period_d)
+    }
+}
+
+impl Indicator for Stochastics {
+    fn name(&self) -> String {
+        stringify!(Stochastics).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+        self.c_sub_l.clear();
+        self.h_sub_l.clear();
+        self.value_k = 0.0;
+        self.value_d = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl Stochastics {
+    pub fn new(period_k: usize, period_d: usize) -> Result<Self> {
+        if period_k == 0 {
+            bail!("Condition failed: `period_k` must be positive, was {period_k}");
+        }
+        if period_d == 0 {
+            bail!("Condition failed: `period_d` must be positive, was {period_d}");
+        }
+
+        Ok(Self {
+            period_k,
+            period_d,
+            value_k: 0.0,
+            value_d: 0.0,
+            count: 0,
+            initialized: false,
+            highs: VecDeque::with_capacity(period_k),
+            lows: VecDeque::with_capacity(period_k),
+            c_sub_l: VecDeque::with_capacity(period_d),
+            h_sub_l: VecDeque::with_capacity(period_d),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        if self.highs.len() == self.period_k {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.highs.len() == self.period_k {
+                self.initialized = true;
+            }
+        }
+
+        let k_max_high = self.highs.iter().copied().fold(f64::MIN, f64::max);
+        let k_min_low = self.lows.iter().copied().fold(f64::MAX, f64::min);
+
+        if self.c_sub_l.len() == self.period_d {
+            self.c_sub_l.pop_front();
+            self.h_sub_l.pop_front();
+        }
+        self.c_sub_l.push_back(close - k_min_low);
+        self.h_sub_l.push_back(k_max_high - k_min_low);
+
+        if k_max_high == k_min_low {
+            return; // Divide by zero guard
+        }
+
+        self.value_k = 100.0 * ((close - k_min_low) / (k_max_high - k_min_low));
+        self.value_d =
+            100.0 * (self.c_sub_l.iter().sum::<f64>() / self.h_sub_l.iter().sum::<f64>());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, momentum::stochastics::Stochastics, stubs::*};
+
+    #[rstest]
+    fn test_stochastics_initialized(stochastics_10_3: Stochastics) {
+        let display_str = format!("{stochastics_10_3}");
+        assert_eq!(display_str, "Stochastics(10,3)");
+        assert_eq!(stochastics_10_3.period_k, 10);
+        assert_eq!(stochastics_10_3.period_d, 3);
+        assert!(!stochastics_10_3.initialized);
+    }
+
+    #[rstest]
+    #[case(0, 3)]
+    #[case(10, 0)]
+    fn test_new_with_zero_period_returns_error(#[case] period_k: usize, #[case] period_d: usize) {
+        assert!(Stochastics::new(period_k, period_d).is_err());
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut stochastics_10_3: Stochastics) {
+        for i in 0..9 {
+            stochastics_10_3.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i) + 0.5);
+        }
+        assert!(!stochastics_10_3.initialized);
+
+        stochastics_10_3.update_raw(10.0, 9.0, 9.5);
+        assert!(stochastics_10_3.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_one_input_returns_expected_value(mut stochastics_10_3: Stochastics) {
+        stochastics_10_3.update_raw(2.0, 1.0, 1.5);
+
+        assert_eq!(stochastics_10_3.value_k, 50.0);
+        assert_eq!(stochastics_10_3.value_d, 50.0);
+    }
+
+    #[rstest]
+    fn test_value_with_rising_inputs_returns_expected_value(mut stochastics_10_3: Stochastics) {
+        stochastics_10_3.update_raw(2.0, 1.0, 1.5);
+        stochastics_10_3.update_raw(3.0, 2.0, 2.5);
+        stochastics_10_3.update_raw(4.0, 3.0, 3.5);
+
+        assert!((stochastics_10_3.value_k - 250.0 / 3.0).abs() < 1e-12);
+        assert_eq!(stochastics_10_3.value_d, 75.0);
+    }
+
+    #[rstest]
+    fn test_value_with_flat_range_is_unchanged(mut stochastics_10_3: Stochastics) {
+        stochastics_10_3.update_raw(1.0, 1.0, 1.0);
+
+        assert!(stochastics_10_3.has_inputs());
+        assert_eq!(stochastics_10_3.value_k, 0.0);
+        assert_eq!(stochastics_10_3.value_d, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut stochastics_10_3: Stochastics, quote_tick: QuoteTick) {
+        stochastics_10_3.handle_quote_tick(&quote_tick);
+        assert!(stochastics_10_3.has_inputs());
+        assert_eq!(stochastics_10_3.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut stochastics_10_3: Stochastics, trade_tick: TradeTick) {
+        stochastics_10_3.handle_trade_tick(&trade_tick);
+        assert!(stochastics_10_3.has_inputs());
+        assert_eq!(stochastics_10_3.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut stochastics_10_3: Stochastics, bar_ethusdt_binance_minute_bid: Bar) {
+        stochastics_10_3.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        // Close 1522 within a range of 1495 to 1550
+        assert!((stochastics_10_3.value_k - 100.0 * 27.0 / 55.0).abs() < 1e-12);
+        assert_eq!(stochastics_10_3.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut stochastics_10_3: Stochastics) {
+        stochastics_10_3.update_raw(2.0, 1.0, 1.5);
+        stochastics_10_3.reset();
+
+        assert!(!stochastics_10_3.has_inputs());
+        assert!(!stochastics_10_3.initialized);
+        assert_eq!(stochastics_10_3.count, 0);
+        assert_eq!(stochastics_10_3.value_k, 0.0);
+        assert_eq!(stochastics_10_3.value_d, 0.0);
+    }
+}
@@ -0,0 +1,226 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An oscillator which calculates Williams %R, the position of the close relative to the
+/// highest high across a rolling window, from 0 (at the high) to -100 (at the low).
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct WilliamsPercentR {
+    pub period: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    has_inputs: bool,
+}
+
+impl Display for WilliamsPercentR {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for WilliamsPercentR {
+    fn name(&self) -> String {
+        stringify!(WilliamsPercentR).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl WilliamsPercentR {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            bail!("Condition failed: `period` must be positive, was {period}");
+        }
+
+        Ok(Self {
+            period,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            highs: VecDeque::with_capacity(period),
+            lows: VecDeque::with_capacity(period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        if self.highs.len() == self.period {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.highs.len() == self.period {
+                self.initialized = true;
+            }
+        }
+
+        let highest_high = self.highs.iter().copied().fold(f64::MIN, f64::max);
+        let lowest_low = self.lows.iter().copied().fold(f64::MAX, f64::min);
+
+        if highest_high == lowest_low {
+            return; // Divide by zero guard
+        }
+
+        self.value = -100.0 * (highest_high - close) / (highest_high - lowest_low);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, momentum::willr::WilliamsPercentR, stubs::*};
+
+    #[rstest]
+    fn test_willr_initialized(willr_10: WilliamsPercentR) {
+        let display_str = format!("{willr_10}");
+        assert_eq!(display_str, "WilliamsPercentR(10)");
+        assert_eq!(willr_10.period, 10);
+        assert!(!willr_10.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_zero_period_returns_error() {
+        assert!(WilliamsPercentR::new(0).is_err());
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut willr_10: WilliamsPercentR) {
+        for i in 0..9 {
+            willr_10.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i) + 0.5);
+        }
+        assert!(!willr_10.initialized);
+
+        willr_10.update_raw(10.0, 9.0, 9.5);
+        assert!(willr_10.initialized);
+    }
+
+    #[rstest]
+    #[case(4.0, 0.0)]
+    #[case(1.0, -100.0)]
+    #[case(3.25, -25.0)]
+    fn test_value_returns_expected_value(
+        mut willr_10: WilliamsPercentR,
+        #[case] close: f64,
+        #[case] expected: f64,
+    ) {
+        willr_10.update_raw(2.0, 1.0, 1.5);
+        willr_10.update_raw(4.0, 2.0, close);
+
+        assert_eq!(willr_10.value, expected);
+    }
+
+    #[rstest]
+    fn test_value_with_flat_range_is_unchanged(mut willr_10: WilliamsPercentR) {
+        willr_10.update_raw(1.0, 1.0, 1.0);
+
+        assert!(willr_10.has_inputs());
+        assert_eq!(willr_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut willr_10: WilliamsPercentR, quote_tick: QuoteTick) {
+        willr_10.handle_quote_tick(&quote_tick);
+        assert!(willr_10.has_inputs());
+        assert_eq!(willr_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut willr_10: WilliamsPercentR, trade_tick: TradeTick) {
+        willr_10.handle_trade_tick(&trade_tick);
+        assert!(willr_10.has_inputs());
+        assert_eq!(willr_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut willr_10: WilliamsPercentR, bar_ethusdt_binance_minute_bid: Bar) {
+        willr_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        // Close 1522 within a range of 1495 to 1550
+        assert!((willr_10.value + 100.0 * 28.0 / 55.0).abs() < 1e-12);
+        assert_eq!(willr_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut willr_10: WilliamsPercentR) {
+        willr_10.update_raw(2.0, 1.0, 1.5);
+        willr_10.reset();
+
+        assert!(!willr_10.has_inputs());
+        assert!(!willr_10.initialized);
+        assert_eq!(willr_10.count, 0);
+        assert_eq!(willr_10.value, 0.0);
+    }
+}
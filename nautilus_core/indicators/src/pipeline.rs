@@ -0,0 +1,388 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
+        quote::QuoteTick, trade::TradeTick,
+    },
+    orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp},
+};
+
+use crate::indicator::{Indicator, InputKind};
+
+/// A type-erased source indicator of a pipeline, with accessors for its output value and
+/// the count of inputs it has handled.
+trait PipelineSource {
+    fn indicator(&self) -> &dyn Indicator;
+    fn indicator_mut(&mut self) -> &mut dyn Indicator;
+    fn value(&self) -> f64;
+    fn count(&self) -> usize;
+}
+
+struct Source<I, V, C> {
+    indicator: I,
+    value: V,
+    count: C,
+}
+
+impl<I, V, C> PipelineSource for Source<I, V, C>
+where
+    I: Indicator,
+    V: Fn(&I) -> f64,
+    C: Fn(&I) -> usize,
+{
+    fn indicator(&self) -> &dyn Indicator {
+        &self.indicator
+    }
+
+    fn indicator_mut(&mut self) -> &mut dyn Indicator {
+        &mut self.indicator
+    }
+
+    fn value(&self) -> f64 {
+        (self.value)(&self.indicator)
+    }
+
+    fn count(&self) -> usize {
+        (self.count)(&self.indicator)
+    }
+}
+
+/// A type-erased indicator within a pipeline, with accessors for its output value and
+/// for updating it from an upstream value.
+trait PipelineNode {
+    fn indicator(&self) -> &dyn Indicator;
+    fn indicator_mut(&mut self) -> &mut dyn Indicator;
+    fn value(&self) -> f64;
+    fn update(&mut self, value: f64);
+}
+
+struct Node<I, V, U> {
+    indicator: I,
+    value: V,
+    update: U,
+}
+
+impl<I, V, U> PipelineNode for Node<I, V, U>
+where
+    I: Indicator,
+    V: Fn(&I) -> f64,
+    U: FnMut(&mut I, f64),
+{
+    fn indicator(&self) -> &dyn Indicator {
+        &self.indicator
+    }
+
+    fn indicator_mut(&mut self) -> &mut dyn Indicator {
+        &mut self.indicator
+    }
+
+    fn value(&self) -> f64 {
+        (self.value)(&self.indicator)
+    }
+
+    fn update(&mut self, value: f64) {
+        (self.update)(&mut self.indicator, value);
+    }
+}
+
+/// Provides a chain of indicators where each stage is updated with the output value of
+/// the stage before it (e.g. an RSI of an EMA).
+///
+/// Market data is handled by the source indicator only. Following each update of the
+/// source (i.e. data which increments its count), the output of every initialized stage
+/// is passed to the next stage, so a stage begins receiving values once all upstream
+/// stages have warmed up. The pipeline is itself an `Indicator`, and so can be registered
+/// and composed like any other.
+pub struct IndicatorPipeline {
+    source: Box<dyn PipelineSource>,
+    stages: Vec<Box<dyn PipelineNode>>,
+}
+
+impl Debug for IndicatorPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Display for IndicatorPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = std::iter::once(self.source.indicator())
+            .chain(self.stages.iter().map(|stage| stage.indicator()))
+            .map(Indicator::name)
+            .collect();
+        write!(f, "{}({})", self.name(), names.join(" -> "))
+    }
+}
+
+impl Indicator for IndicatorPipeline {
+    fn name(&self) -> String {
+        stringify!(IndicatorPipeline).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.source.indicator().has_inputs()
+    }
+
+    fn initialized(&self) -> bool {
+        self.stages.last().map_or_else(
+            || self.source.indicator().initialized(),
+            |stage| stage.indicator().initialized(),
+        )
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        self.source.indicator().input_kinds()
+    }
+
+    fn handle_delta(&mut self, delta: &OrderBookDelta) {
+        self.handle(|source| source.handle_delta(delta));
+    }
+
+    fn handle_deltas(&mut self, deltas: &OrderBookDeltas) {
+        self.handle(|source| source.handle_deltas(deltas));
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        self.handle(|source| source.handle_depth(depth));
+    }
+
+    fn handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        self.handle(|source| source.handle_book_mbo(book));
+    }
+
+    fn handle_book_mbp(&mut self, book: &OrderBookMbp) {
+        self.handle(|source| source.handle_book_mbp(book));
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.handle(|source| source.handle_quote_tick(quote));
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.handle(|source| source.handle_trade_tick(trade));
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.handle(|source| source.handle_bar(bar));
+    }
+
+    fn reset(&mut self) {
+        self.source.indicator_mut().reset();
+        for stage in &mut self.stages {
+            stage.indicator_mut().reset();
+        }
+    }
+}
+
+impl IndicatorPipeline {
+    /// Creates a new pipeline from the `source` indicator which handles market data, the
+    /// function returning its output value, and the function returning its count of
+    /// inputs handled.
+    pub fn new<I, V, C>(source: I, value: V, count: C) -> Self
+    where
+        I: Indicator + 'static,
+        V: Fn(&I) -> f64 + 'static,
+        C: Fn(&I) -> usize + 'static,
+    {
+        Self {
+            source: Box::new(Source {
+                indicator: source,
+                value,
+                count,
+            }),
+            stages: Vec::new(),
+        }
+    }
+
+    /// Appends a stage to the pipeline, with the function updating the `indicator` from
+    /// the upstream value and the function returning its output value.
+    #[must_use]
+    pub fn then<I, U, V>(mut self, indicator: I, update: U, value: V) -> Self
+    where
+        I: Indicator + 'static,
+        U: FnMut(&mut I, f64) + 'static,
+        V: Fn(&I) -> f64 + 'static,
+    {
+        self.stages.push(Box::new(Node {
+            indicator,
+            value,
+            update,
+        }));
+        self
+    }
+
+    /// Returns the output value of the final stage.
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.stages
+            .last()
+            .map_or_else(|| self.source.value(), |stage| stage.value())
+    }
+
+    /// Returns the output values of the source and each stage, in pipeline order.
+    #[must_use]
+    pub fn values(&self) -> Vec<f64> {
+        std::iter::once(self.source.value())
+            .chain(self.stages.iter().map(|stage| stage.value()))
+            .collect()
+    }
+
+    fn handle(&mut self, handler: impl FnOnce(&mut dyn Indicator)) {
+        let count = self.source.count();
+        handler(self.source.indicator_mut());
+        if self.source.count() != count {
+            self.propagate();
+        }
+    }
+
+    fn propagate(&mut self) {
+        if !self.source.indicator().initialized() {
+            return;
+        }
+
+        let mut value = self.source.value();
+        for stage in &mut self.stages {
+            stage.update(value);
+            if !stage.indicator().initialized() {
+                break; // Downstream stages wait for this stage to warm up
+            }
+            value = stage.value();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        average::sma::SimpleMovingAverage,
+        indicator::MovingAverage,
+        momentum::rsi::RelativeStrengthIndex,
+        stubs::{bar_ethusdt_binance_minute_bid, quote_tick},
+        volume::vwap::VolumeWeightedAveragePrice,
+    };
+
+    fn sma_of_sma() -> IndicatorPipeline {
+        IndicatorPipeline::new(
+            SimpleMovingAverage::new(2, None).unwrap(),
+            |sma: &SimpleMovingAverage| sma.value,
+            |sma: &SimpleMovingAverage| sma.count,
+        )
+        .then(
+            SimpleMovingAverage::new(2, None).unwrap(),
+            |sma: &mut SimpleMovingAverage, value| sma.update_raw(value),
+            |sma: &SimpleMovingAverage| sma.value,
+        )
+    }
+
+    #[rstest]
+    fn test_pipeline_initialized() {
+        let pipeline = sma_of_sma();
+        let display_str = format!("{pipeline}");
+        assert_eq!(
+            display_str,
+            "IndicatorPipeline(SimpleMovingAverage -> SimpleMovingAverage)"
+        );
+        assert_eq!(pipeline.value(), 0.0);
+        assert!(!pipeline.has_inputs());
+        assert!(!pipeline.initialized());
+    }
+
+    #[rstest]
+    fn test_stage_waits_for_source_to_initialize() {
+        let mut pipeline = sma_of_sma();
+        pipeline.handle_bar(&bar_ethusdt_binance_minute_bid("1.0"));
+
+        assert!(pipeline.has_inputs());
+        assert!(!pipeline.initialized());
+        assert_eq!(pipeline.values(), vec![1.0, 0.0]);
+    }
+
+    #[rstest]
+    fn test_values_propagate_through_stages() {
+        let mut pipeline = sma_of_sma();
+        for close in ["1.0", "2.0", "3.0", "4.0"] {
+            pipeline.handle_bar(&bar_ethusdt_binance_minute_bid(close));
+        }
+
+        // Source outputs 1.5, 2.5 and 3.5 once initialized
+        assert!(pipeline.initialized());
+        assert_eq!(pipeline.values(), vec![3.5, 3.0]);
+        assert_eq!(pipeline.value(), 3.0);
+    }
+
+    #[rstest]
+    fn test_rsi_of_sma() {
+        let mut pipeline = IndicatorPipeline::new(
+            SimpleMovingAverage::new(2, None).unwrap(),
+            |sma: &SimpleMovingAverage| sma.value,
+            |sma: &SimpleMovingAverage| sma.count,
+        )
+        .then(
+            RelativeStrengthIndex::new(2, None).unwrap(),
+            |rsi: &mut RelativeStrengthIndex, value| rsi.update_raw(value),
+            |rsi: &RelativeStrengthIndex| rsi.value,
+        );
+        for close in ["1.0", "2.0", "3.0", "4.0", "5.0"] {
+            pipeline.handle_bar(&bar_ethusdt_binance_minute_bid(close));
+        }
+
+        // Steadily rising input
+        assert!(pipeline.initialized());
+        assert_eq!(pipeline.value(), 1.0);
+    }
+
+    #[rstest]
+    fn test_input_ignored_by_source_is_not_propagated() {
+        let mut pipeline = IndicatorPipeline::new(
+            VolumeWeightedAveragePrice::new(None).unwrap(),
+            |vwap: &VolumeWeightedAveragePrice| vwap.value,
+            |vwap: &VolumeWeightedAveragePrice| vwap.count,
+        )
+        .then(
+            SimpleMovingAverage::new(2, None).unwrap(),
+            |sma: &mut SimpleMovingAverage, value| sma.update_raw(value),
+            |sma: &SimpleMovingAverage| sma.value,
+        );
+        pipeline.handle_bar(&bar_ethusdt_binance_minute_bid("1.0"));
+        pipeline.handle_quote_tick(&quote_tick("1500.0", "1502.0"));
+
+        // The VWAP ignores quotes, so the stage has still only been updated once
+        assert!(!pipeline.initialized());
+        assert_eq!(pipeline.values(), vec![1.0, 1.0]);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut pipeline = sma_of_sma();
+        for close in ["1.0", "2.0", "3.0"] {
+            pipeline.handle_bar(&bar_ethusdt_binance_minute_bid(close));
+        }
+        pipeline.reset();
+
+        assert!(!pipeline.has_inputs());
+        assert!(!pipeline.initialized());
+        assert_eq!(pipeline.values(), vec![0.0, 0.0]);
+    }
+}
@@ -0,0 +1,121 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::python::to_pyvalue_err;
+use nautilus_model::{data::trade::TradeTick, orderbook::book_mbo::OrderBookMbo};
+use pyo3::prelude::*;
+
+use crate::{book::queue::QueueDepletion, indicator::Indicator};
+
+#[pymethods]
+impl QueueDepletion {
+    #[new]
+    fn py_new(period: usize) -> PyResult<Self> {
+        Self::new(period).map_err(to_pyvalue_err)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "period")]
+    fn py_period(&self) -> usize {
+        self.period
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "bid_depletion_rate")]
+    fn py_bid_depletion_rate(&self) -> f64 {
+        self.bid_depletion_rate
+    }
+
+    #[getter]
+    #[pyo3(name = "ask_depletion_rate")]
+    fn py_ask_depletion_rate(&self) -> f64 {
+        self.ask_depletion_rate
+    }
+
+    #[getter]
+    #[pyo3(name = "bid_cancel_rate")]
+    fn py_bid_cancel_rate(&self) -> f64 {
+        self.bid_cancel_rate
+    }
+
+    #[getter]
+    #[pyo3(name = "ask_cancel_rate")]
+    fn py_ask_cancel_rate(&self) -> f64 {
+        self.ask_cancel_rate
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "handle_book_mbo")]
+    fn py_handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        self.handle_book_mbo(book);
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn py_handle_trade_tick(&mut self, tick: &TradeTick) {
+        self.handle_trade_tick(tick);
+    }
+
+    #[pyo3(name = "update_raw")]
+    fn py_update_raw(
+        &mut self,
+        bid_removed: f64,
+        bid_traded: f64,
+        bid_queue_size: f64,
+        ask_removed: f64,
+        ask_traded: f64,
+        ask_queue_size: f64,
+    ) {
+        self.update_raw(
+            bid_removed,
+            bid_traded,
+            bid_queue_size,
+            ask_removed,
+            ask_traded,
+            ask_queue_size,
+        );
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
@@ -0,0 +1,125 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::python::to_pyvalue_err;
+use nautilus_model::{
+    data::{depth::OrderBookDepth10, quote::QuoteTick},
+    orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp},
+};
+use pyo3::prelude::*;
+
+use crate::{book::spread::SpreadAnalyzer, indicator::Indicator};
+
+#[pymethods]
+impl SpreadAnalyzer {
+    #[new]
+    fn py_new(capacity: usize) -> PyResult<Self> {
+        Self::new(capacity).map_err(to_pyvalue_err)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "capacity")]
+    fn py_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "current")]
+    fn py_current(&self) -> f64 {
+        self.current
+    }
+
+    #[getter]
+    #[pyo3(name = "average")]
+    fn py_average(&self) -> f64 {
+        self.average
+    }
+
+    #[getter]
+    #[pyo3(name = "min")]
+    fn py_min(&self) -> f64 {
+        self.min
+    }
+
+    #[getter]
+    #[pyo3(name = "max")]
+    fn py_max(&self) -> f64 {
+        self.max
+    }
+
+    #[getter]
+    #[pyo3(name = "std")]
+    fn py_std(&self) -> f64 {
+        self.std
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "handle_depth")]
+    fn py_handle_depth(&mut self, depth: &OrderBookDepth10) {
+        self.handle_depth(depth);
+    }
+
+    #[pyo3(name = "handle_book_mbo")]
+    fn py_handle_book_mbo(&mut self, book: &OrderBookMbo) {
+        self.handle_book_mbo(book);
+    }
+
+    #[pyo3(name = "handle_book_mbp")]
+    fn py_handle_book_mbp(&mut self, book: &OrderBookMbp) {
+        self.handle_book_mbp(book);
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn py_handle_quote_tick(&mut self, tick: &QuoteTick) {
+        self.handle_quote_tick(tick);
+    }
+
+    #[pyo3(name = "update_raw")]
+    fn py_update_raw(&mut self, bid: f64, ask: f64) {
+        self.update_raw(bid, ask);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
@@ -20,6 +20,7 @@ pub mod book;
 pub mod momentum;
 pub mod ratio;
 pub mod volatility;
+pub mod volume;
 
 #[pymodule]
 pub fn indicators(_: Python<'_>, m: &PyModule) -> PyResult<()> {
@@ -31,14 +32,44 @@ pub fn indicators(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::average::hma::HullMovingAverage>()?;
     m.add_class::<crate::average::rma::WilderMovingAverage>()?;
     // book
+    m.add_class::<crate::book::depth::DepthImbalance>()?;
     m.add_class::<crate::book::imbalance::BookImbalanceRatio>()?;
+    m.add_class::<crate::book::micro_price::MicroPrice>()?;
+    m.add_class::<crate::book::ofi::OrderFlowImbalance>()?;
+    m.add_class::<crate::book::queue::QueueDepletion>()?;
+    m.add_class::<crate::book::spread::SpreadAnalyzer>()?;
+    m.add_class::<crate::book::weighted_mid::WeightedMid>()?;
     // ratio
     m.add_class::<crate::ratio::efficiency_ratio::EfficiencyRatio>()?;
     // momentum
     m.add_class::<crate::momentum::rsi::RelativeStrengthIndex>()?;
     m.add_class::<crate::momentum::aroon::AroonOscillator>()?;
     m.add_class::<crate::momentum::cmo::ChandeMomentumOscillator>()?;
+    m.add_class::<crate::momentum::macd::MovingAverageConvergenceDivergence>()?;
+    m.add_class::<crate::momentum::stochastics::Stochastics>()?;
+    m.add_class::<crate::momentum::cci::CommodityChannelIndex>()?;
+    m.add_class::<crate::momentum::roc::RateOfChange>()?;
+    m.add_class::<crate::momentum::willr::WilliamsPercentR>()?;
+    m.add_class::<crate::momentum::dm::DirectionalMovement>()?;
+    m.add_class::<crate::momentum::adx::AverageDirectionalIndex>()?;
+    m.add_class::<crate::momentum::kst::KnowSureThing>()?;
+    m.add_class::<crate::momentum::psl::PsychologicalLine>()?;
+    m.add_class::<crate::momentum::pressure::Pressure>()?;
     // volatility
     m.add_class::<crate::volatility::atr::AverageTrueRange>()?;
+    m.add_class::<crate::volatility::bb::BollingerBands>()?;
+    m.add_class::<crate::volatility::dc::DonchianChannel>()?;
+    m.add_class::<crate::volatility::hv::HistoricalVolatility>()?;
+    m.add_class::<crate::volatility::hv::VolatilityEstimator>()?;
+    m.add_class::<crate::volatility::kc::KeltnerChannel>()?;
+    m.add_class::<crate::volatility::vhf::VerticalHorizontalFilter>()?;
+    // volume
+    m.add_class::<crate::volume::vwap::VolumeWeightedAveragePrice>()?;
+    m.add_class::<crate::volume::rvwap::RollingVolumeWeightedAveragePrice>()?;
+    m.add_class::<crate::volume::obv::OnBalanceVolume>()?;
+    m.add_class::<crate::volume::vwma::VolumeWeightedMovingAverage>()?;
+    m.add_class::<crate::volume::kvo::KlingerVolumeOscillator>()?;
+    m.add_class::<crate::volume::mfi::MoneyFlowIndex>()?;
+    m.add_class::<crate::volume::ad::AccumulationDistribution>()?;
     Ok(())
 }
@@ -13,6 +13,16 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod adx;
 pub mod aroon;
+pub mod cci;
 pub mod cmo;
+pub mod dm;
+pub mod kst;
+pub mod macd;
+pub mod pressure;
+pub mod psl;
+pub mod roc;
 pub mod rsi;
+pub mod stochastics;
+pub mod willr;
@@ -14,3 +14,8 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod atr;
+pub mod bb;
+pub mod dc;
+pub mod hv;
+pub mod kc;
+pub mod vhf;
@@ -22,7 +22,7 @@ use nautilus_model::{
 };
 use pyo3::prelude::*;
 
-use crate::indicator::Indicator;
+use crate::indicator::{Indicator, InputKind};
 
 /// An indicator which calculates the efficiency ratio across a rolling window.
 /// The Kaufman Efficiency measures the ratio of the relative market speed in
@@ -58,6 +58,10 @@ impl Indicator for EfficiencyRatio {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
     fn handle_quote_tick(&mut self, quote: &QuoteTick) {
         self.update_raw(quote.extract_price(self.price_type).into());
     }
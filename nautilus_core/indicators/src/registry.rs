@@ -0,0 +1,569 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{cell::RefCell, collections::HashMap, fmt::Display, hash::Hash, rc::Rc};
+
+use anyhow::{bail, Context, Result};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::OrderBookDepth10,
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    identifiers::instrument_id::InstrumentId,
+    orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp},
+};
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which can be shared between its owner and an [`IndicatorRegistry`].
+pub type SharedIndicator = Rc<RefCell<dyn Indicator>>;
+
+/// Provides a registry of indicators subscribed to market data streams.
+///
+/// Indicators are registered against a `BarType` (for bars) or an `InstrumentId` (for
+/// ticks and order book data), and are fed each item of data in registration order.
+/// The same indicator may be registered against several streams.
+///
+/// An indicator may only be registered for a stream if it handles at least one of the
+/// stream's kinds of data (see [`Indicator::input_kinds`]), and is then only passed the
+/// data it handles (e.g. an indicator handling only `OrderBookMbo` ignores `OrderBookMbp`
+/// updates).
+#[derive(Default)]
+pub struct IndicatorRegistry {
+    bar_indicators: HashMap<BarType, Vec<SharedIndicator>>,
+    quote_indicators: HashMap<InstrumentId, Vec<SharedIndicator>>,
+    trade_indicators: HashMap<InstrumentId, Vec<SharedIndicator>>,
+    delta_indicators: HashMap<InstrumentId, Vec<SharedIndicator>>,
+    depth_indicators: HashMap<InstrumentId, Vec<SharedIndicator>>,
+    book_indicators: HashMap<InstrumentId, Vec<SharedIndicator>>,
+}
+
+impl IndicatorRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register_for_bars(
+        &mut self,
+        bar_type: BarType,
+        indicator: SharedIndicator,
+    ) -> Result<()> {
+        register(
+            &mut self.bar_indicators,
+            bar_type,
+            &[InputKind::Bar],
+            indicator,
+        )
+    }
+
+    pub fn register_for_quote_ticks(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: SharedIndicator,
+    ) -> Result<()> {
+        register(
+            &mut self.quote_indicators,
+            instrument_id,
+            &[InputKind::QuoteTick],
+            indicator,
+        )
+    }
+
+    pub fn register_for_trade_ticks(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: SharedIndicator,
+    ) -> Result<()> {
+        register(
+            &mut self.trade_indicators,
+            instrument_id,
+            &[InputKind::TradeTick],
+            indicator,
+        )
+    }
+
+    /// Registers the `indicator` for both single `OrderBookDelta` and batched
+    /// `OrderBookDeltas` updates.
+    pub fn register_for_deltas(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: SharedIndicator,
+    ) -> Result<()> {
+        register(
+            &mut self.delta_indicators,
+            instrument_id,
+            &[InputKind::Delta, InputKind::Deltas],
+            indicator,
+        )
+    }
+
+    pub fn register_for_depth(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: SharedIndicator,
+    ) -> Result<()> {
+        register(
+            &mut self.depth_indicators,
+            instrument_id,
+            &[InputKind::Depth],
+            indicator,
+        )
+    }
+
+    /// Registers the `indicator` for both `OrderBookMbo` and `OrderBookMbp` updates.
+    pub fn register_for_book(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: SharedIndicator,
+    ) -> Result<()> {
+        register(
+            &mut self.book_indicators,
+            instrument_id,
+            &[InputKind::BookMbo, InputKind::BookMbp],
+            indicator,
+        )
+    }
+
+    /// Removes the `indicator` from every stream it is registered for.
+    pub fn deregister(&mut self, indicator: &SharedIndicator) {
+        deregister(&mut self.bar_indicators, indicator);
+        deregister(&mut self.quote_indicators, indicator);
+        deregister(&mut self.trade_indicators, indicator);
+        deregister(&mut self.delta_indicators, indicator);
+        deregister(&mut self.depth_indicators, indicator);
+        deregister(&mut self.book_indicators, indicator);
+    }
+
+    /// Returns the indicators registered for the `bar_type`, in registration order.
+    #[must_use]
+    pub fn indicators_for_bars(&self, bar_type: &BarType) -> &[SharedIndicator] {
+        self.bar_indicators
+            .get(bar_type)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns every registered indicator (once each).
+    #[must_use]
+    pub fn indicators(&self) -> Vec<SharedIndicator> {
+        let mut indicators: Vec<SharedIndicator> = Vec::new();
+        for indicator in self
+            .bar_indicators
+            .values()
+            .chain(self.quote_indicators.values())
+            .chain(self.trade_indicators.values())
+            .chain(self.delta_indicators.values())
+            .chain(self.depth_indicators.values())
+            .chain(self.book_indicators.values())
+            .flatten()
+        {
+            if !indicators.iter().any(|seen| Rc::ptr_eq(seen, indicator)) {
+                indicators.push(indicator.clone());
+            }
+        }
+        indicators
+    }
+
+    pub fn handle_bar(&self, bar: &Bar) -> Result<()> {
+        feed(
+            &self.bar_indicators,
+            &bar.bar_type,
+            InputKind::Bar,
+            |indicator| indicator.handle_bar(bar),
+        )
+    }
+
+    pub fn handle_quote_tick(&self, quote: &QuoteTick) -> Result<()> {
+        feed(
+            &self.quote_indicators,
+            &quote.instrument_id,
+            InputKind::QuoteTick,
+            |indicator| indicator.handle_quote_tick(quote),
+        )
+    }
+
+    pub fn handle_trade_tick(&self, trade: &TradeTick) -> Result<()> {
+        feed(
+            &self.trade_indicators,
+            &trade.instrument_id,
+            InputKind::TradeTick,
+            |indicator| indicator.handle_trade_tick(trade),
+        )
+    }
+
+    pub fn handle_delta(&self, delta: &OrderBookDelta) -> Result<()> {
+        feed(
+            &self.delta_indicators,
+            &delta.instrument_id,
+            InputKind::Delta,
+            |indicator| indicator.handle_delta(delta),
+        )
+    }
+
+    pub fn handle_deltas(&self, deltas: &OrderBookDeltas) -> Result<()> {
+        feed(
+            &self.delta_indicators,
+            &deltas.instrument_id,
+            InputKind::Deltas,
+            |indicator| indicator.handle_deltas(deltas),
+        )
+    }
+
+    pub fn handle_depth(&self, depth: &OrderBookDepth10) -> Result<()> {
+        feed(
+            &self.depth_indicators,
+            &depth.instrument_id,
+            InputKind::Depth,
+            |indicator| indicator.handle_depth(depth),
+        )
+    }
+
+    pub fn handle_book_mbo(&self, book: &OrderBookMbo) -> Result<()> {
+        feed(
+            &self.book_indicators,
+            &book.instrument_id,
+            InputKind::BookMbo,
+            |indicator| indicator.handle_book_mbo(book),
+        )
+    }
+
+    pub fn handle_book_mbp(&self, book: &OrderBookMbp) -> Result<()> {
+        feed(
+            &self.book_indicators,
+            &book.instrument_id,
+            InputKind::BookMbp,
+            |indicator| indicator.handle_book_mbp(book),
+        )
+    }
+
+    /// Warms up the registered indicators from historical `bars`, which must be in
+    /// ascending `ts_init` order.
+    ///
+    /// Stops at the first bar any indicator fails to handle.
+    pub fn warm_up(&self, bars: &[Bar]) -> Result<()> {
+        if let Some(pair) = bars
+            .windows(2)
+            .find(|pair| pair[1].ts_init < pair[0].ts_init)
+        {
+            bail!(
+                "Condition failed: `bars` must be in ascending `ts_init` order, was {} after {}",
+                pair[1].ts_init,
+                pair[0].ts_init,
+            );
+        }
+
+        for bar in bars {
+            self.handle_bar(bar)
+                .with_context(|| format!("Failed to warm up from bar at {}", bar.ts_init))?;
+        }
+        Ok(())
+    }
+
+    /// Resets every registered indicator.
+    pub fn reset(&self) -> Result<()> {
+        let mut errors = Vec::new();
+        for indicator in self.indicators() {
+            match indicator.try_borrow_mut() {
+                Ok(mut indicator) => indicator.reset(),
+                Err(e) => errors.push(format!("`{}`: {e}", indicator_name(&indicator))),
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            bail!("Error resetting indicators: {}", errors.join("; "))
+        }
+    }
+}
+
+fn register<K: Eq + Hash + Display>(
+    map: &mut HashMap<K, Vec<SharedIndicator>>,
+    key: K,
+    kinds: &[InputKind],
+    indicator: SharedIndicator,
+) -> Result<()> {
+    let input_kinds = indicator
+        .try_borrow()
+        .with_context(|| format!("Failed to register indicator for {key}"))?
+        .input_kinds();
+    if !input_kinds.iter().any(|kind| kinds.contains(kind)) {
+        bail!(
+            "Indicator `{}` does not handle {kinds:?} for {key}",
+            indicator_name(&indicator),
+        );
+    }
+    if map
+        .get(&key)
+        .is_some_and(|indicators| indicators.iter().any(|i| Rc::ptr_eq(i, &indicator)))
+    {
+        bail!(
+            "Indicator `{}` already registered for {key}",
+            indicator_name(&indicator),
+        );
+    }
+    map.entry(key).or_default().push(indicator);
+    Ok(())
+}
+
+fn deregister<K>(map: &mut HashMap<K, Vec<SharedIndicator>>, indicator: &SharedIndicator) {
+    for indicators in map.values_mut() {
+        indicators.retain(|existing| !Rc::ptr_eq(existing, indicator));
+    }
+    map.retain(|_, indicators| !indicators.is_empty());
+}
+
+fn feed<K: Eq + Hash + Display>(
+    map: &HashMap<K, Vec<SharedIndicator>>,
+    key: &K,
+    kind: InputKind,
+    handler: impl Fn(&mut dyn Indicator),
+) -> Result<()> {
+    let Some(indicators) = map.get(key) else {
+        return Ok(()); // No indicators registered for this stream
+    };
+
+    let mut errors = Vec::new();
+    for indicator in indicators {
+        let Ok(mut indicator) = indicator.try_borrow_mut() else {
+            errors.push(format!(
+                "`{}` is already borrowed",
+                indicator_name(indicator)
+            ));
+            continue;
+        };
+        if indicator.input_kinds().contains(&kind) {
+            handler(&mut *indicator);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        bail!("Error handling {kind:?} for {key}: {}", errors.join("; "))
+    }
+}
+
+fn indicator_name(indicator: &SharedIndicator) -> String {
+    indicator
+        .try_borrow()
+        .map_or_else(|_| "<borrowed>".to_string(), |indicator| indicator.name())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::PriceType;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        average::{ema::ExponentialMovingAverage, sma::SimpleMovingAverage},
+        book::imbalance::BookImbalanceRatio,
+        indicator::MovingAverage,
+        pipeline::IndicatorPipeline,
+        stubs::{bar_ethusdt_binance_minute_bid, quote_tick},
+    };
+
+    fn bar(close: &str, ts_init: u64) -> Bar {
+        let mut bar = bar_ethusdt_binance_minute_bid(close);
+        bar.ts_init = ts_init;
+        bar
+    }
+
+    fn shared_sma(period: usize) -> Rc<RefCell<SimpleMovingAverage>> {
+        Rc::new(RefCell::new(
+            SimpleMovingAverage::new(period, None).unwrap(),
+        ))
+    }
+
+    #[rstest]
+    fn test_handle_bar_feeds_registered_indicators() {
+        let mut registry = IndicatorRegistry::new();
+        let sma = shared_sma(2);
+        let ema = Rc::new(RefCell::new(
+            ExponentialMovingAverage::new(2, None).unwrap(),
+        ));
+        let bar_type = bar("1.0", 1).bar_type;
+        registry.register_for_bars(bar_type, sma.clone()).unwrap();
+        registry.register_for_bars(bar_type, ema.clone()).unwrap();
+
+        registry.handle_bar(&bar("1.0", 1)).unwrap();
+        registry.handle_bar(&bar("2.0", 2)).unwrap();
+
+        assert_eq!(registry.indicators_for_bars(&bar_type).len(), 2);
+        assert_eq!(sma.borrow().value, 1.5);
+        assert!(sma.borrow().initialized());
+        assert!(ema.borrow().has_inputs());
+    }
+
+    #[rstest]
+    fn test_handle_bar_for_other_bar_type_is_ignored() {
+        let mut registry = IndicatorRegistry::new();
+        let sma = shared_sma(2);
+        registry
+            .register_for_bars(bar("1.0", 1).bar_type, sma.clone())
+            .unwrap();
+
+        let mut other_bar = bar("1.0", 1);
+        other_bar.bar_type.spec.step = 5;
+        registry.handle_bar(&other_bar).unwrap();
+
+        assert!(!sma.borrow().has_inputs());
+    }
+
+    #[rstest]
+    fn test_register_twice_for_same_stream_returns_error() {
+        let mut registry = IndicatorRegistry::new();
+        let sma = shared_sma(2);
+        let bar_type = bar("1.0", 1).bar_type;
+        registry.register_for_bars(bar_type, sma.clone()).unwrap();
+
+        assert!(registry.register_for_bars(bar_type, sma.clone()).is_err());
+        assert!(registry
+            .register_for_quote_ticks(bar_type.instrument_id, sma)
+            .is_ok());
+    }
+
+    #[rstest]
+    fn test_register_for_unsupported_stream_returns_error() {
+        let mut registry = IndicatorRegistry::new();
+        let imbalance = Rc::new(RefCell::new(BookImbalanceRatio::new().unwrap()));
+        let bar_type = bar("1.0", 1).bar_type;
+
+        let error = registry
+            .register_for_bars(bar_type, imbalance.clone())
+            .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("`BookImbalanceRatio` does not handle [Bar]"));
+        assert!(registry.indicators().is_empty());
+        assert!(registry
+            .register_for_book(bar_type.instrument_id, imbalance)
+            .is_ok());
+    }
+
+    #[rstest]
+    fn test_borrowed_indicator_returns_error() {
+        let mut registry = IndicatorRegistry::new();
+        let sma = shared_sma(2);
+        let bar_type = bar("1.0", 1).bar_type;
+        registry.register_for_bars(bar_type, sma.clone()).unwrap();
+
+        let _borrowed = sma.borrow();
+
+        assert!(registry.handle_bar(&bar("1.0", 1)).is_err());
+        assert!(registry.reset().is_err());
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick_feeds_pipeline() {
+        let mut registry = IndicatorRegistry::new();
+        let pipeline = Rc::new(RefCell::new(
+            IndicatorPipeline::new(
+                SimpleMovingAverage::new(1, Some(PriceType::Mid)).unwrap(),
+                |sma: &SimpleMovingAverage| sma.value,
+                |sma: &SimpleMovingAverage| sma.count,
+            )
+            .then(
+                SimpleMovingAverage::new(2, None).unwrap(),
+                |sma: &mut SimpleMovingAverage, value| sma.update_raw(value),
+                |sma: &SimpleMovingAverage| sma.value,
+            ),
+        ));
+        let quote = quote_tick("1500.0", "1502.0");
+        registry
+            .register_for_quote_ticks(quote.instrument_id, pipeline.clone())
+            .unwrap();
+
+        registry.handle_quote_tick(&quote).unwrap();
+        registry
+            .handle_quote_tick(&quote_tick("1502.0", "1504.0"))
+            .unwrap();
+
+        // Mid prices of 1501 and 1503
+        assert!(pipeline.borrow().initialized());
+        assert_eq!(pipeline.borrow().value(), 1502.0);
+    }
+
+    #[rstest]
+    fn test_warm_up_from_bars() {
+        let mut registry = IndicatorRegistry::new();
+        let sma = shared_sma(3);
+        let bars = [bar("1.0", 1), bar("2.0", 2), bar("6.0", 3)];
+        registry
+            .register_for_bars(bars[0].bar_type, sma.clone())
+            .unwrap();
+
+        registry.warm_up(&bars).unwrap();
+
+        assert!(sma.borrow().initialized());
+        assert_eq!(sma.borrow().value, 3.0);
+    }
+
+    #[rstest]
+    fn test_warm_up_with_unordered_bars_returns_error() {
+        let mut registry = IndicatorRegistry::new();
+        let sma = shared_sma(2);
+        let bars = [bar("1.0", 2), bar("2.0", 1)];
+        registry
+            .register_for_bars(bars[0].bar_type, sma.clone())
+            .unwrap();
+
+        assert!(registry.warm_up(&bars).is_err());
+        assert!(!sma.borrow().has_inputs());
+    }
+
+    #[rstest]
+    fn test_deregister() {
+        let mut registry = IndicatorRegistry::new();
+        let sma: SharedIndicator = shared_sma(2);
+        let bar_type = bar("1.0", 1).bar_type;
+        registry.register_for_bars(bar_type, sma.clone()).unwrap();
+        registry
+            .register_for_quote_ticks(bar_type.instrument_id, sma.clone())
+            .unwrap();
+
+        registry.deregister(&sma);
+        registry.handle_bar(&bar("1.0", 1)).unwrap();
+
+        assert!(registry.indicators().is_empty());
+        assert!(!sma.borrow().has_inputs());
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut registry = IndicatorRegistry::new();
+        let sma = shared_sma(2);
+        let bar_type = bar("1.0", 1).bar_type;
+        registry.register_for_bars(bar_type, sma.clone()).unwrap();
+        registry
+            .register_for_quote_ticks(bar_type.instrument_id, sma.clone())
+            .unwrap();
+        registry.handle_bar(&bar("1.0", 1)).unwrap();
+
+        registry.reset().unwrap();
+
+        assert_eq!(registry.indicators().len(), 1);
+        assert!(!sma.borrow().has_inputs());
+    }
+}
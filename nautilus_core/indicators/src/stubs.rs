@@ -31,8 +31,22 @@ use crate::{
         ema::ExponentialMovingAverage, hma::HullMovingAverage, rma::WilderMovingAverage,
         sma::SimpleMovingAverage, wma::WeightedMovingAverage, MovingAverageType,
     },
-    momentum::{cmo::ChandeMomentumOscillator, rsi::RelativeStrengthIndex},
+    momentum::{
+        adx::AverageDirectionalIndex, cci::CommodityChannelIndex, cmo::ChandeMomentumOscillator,
+        dm::DirectionalMovement, kst::KnowSureThing, macd::MovingAverageConvergenceDivergence,
+        pressure::Pressure, psl::PsychologicalLine, roc::RateOfChange, rsi::RelativeStrengthIndex,
+        stochastics::Stochastics, willr::WilliamsPercentR,
+    },
     ratio::efficiency_ratio::EfficiencyRatio,
+    volatility::{
+        bb::BollingerBands, dc::DonchianChannel, hv::HistoricalVolatility, kc::KeltnerChannel,
+        vhf::VerticalHorizontalFilter,
+    },
+    volume::{
+        ad::AccumulationDistribution, kvo::KlingerVolumeOscillator, mfi::MoneyFlowIndex,
+        obv::OnBalanceVolume, rvwap::RollingVolumeWeightedAveragePrice,
+        vwap::VolumeWeightedAveragePrice, vwma::VolumeWeightedMovingAverage,
+    },
 };
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -154,3 +168,119 @@ pub fn rsi_10() -> RelativeStrengthIndex {
 pub fn cmo_10() -> ChandeMomentumOscillator {
     ChandeMomentumOscillator::new(10, Some(MovingAverageType::Wilder)).unwrap()
 }
+
+#[fixture]
+pub fn macd_12_26() -> MovingAverageConvergenceDivergence {
+    MovingAverageConvergenceDivergence::new(12, 26, None, None).unwrap()
+}
+
+#[fixture]
+pub fn stochastics_10_3() -> Stochastics {
+    Stochastics::new(10, 3).unwrap()
+}
+
+#[fixture]
+pub fn cci_10() -> CommodityChannelIndex {
+    CommodityChannelIndex::new(10, None, None).unwrap()
+}
+
+#[fixture]
+pub fn roc_10() -> RateOfChange {
+    RateOfChange::new(10, None).unwrap()
+}
+
+#[fixture]
+pub fn willr_10() -> WilliamsPercentR {
+    WilliamsPercentR::new(10).unwrap()
+}
+
+#[fixture]
+pub fn dm_10() -> DirectionalMovement {
+    DirectionalMovement::new(10, None).unwrap()
+}
+
+#[fixture]
+pub fn adx_10() -> AverageDirectionalIndex {
+    AverageDirectionalIndex::new(10, None).unwrap()
+}
+
+#[fixture]
+pub fn kst() -> KnowSureThing {
+    KnowSureThing::new(None, None, None, None).unwrap()
+}
+
+#[fixture]
+pub fn psl_10() -> PsychologicalLine {
+    PsychologicalLine::new(10, None).unwrap()
+}
+
+#[fixture]
+pub fn pressure_10() -> Pressure {
+    Pressure::new(10, None, None).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Volatility
+////////////////////////////////////////////////////////////////////////////////
+#[fixture]
+pub fn bb_10() -> BollingerBands {
+    BollingerBands::new(10, 2.0, None).unwrap()
+}
+
+#[fixture]
+pub fn kc_10() -> KeltnerChannel {
+    KeltnerChannel::new(10, 2.0, None, None, None, None).unwrap()
+}
+
+#[fixture]
+pub fn dc_10() -> DonchianChannel {
+    DonchianChannel::new(10).unwrap()
+}
+
+#[fixture]
+pub fn vhf_10() -> VerticalHorizontalFilter {
+    VerticalHorizontalFilter::new(10, None).unwrap()
+}
+
+#[fixture]
+pub fn hv_10() -> HistoricalVolatility {
+    HistoricalVolatility::new(10, None, None).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Volume
+////////////////////////////////////////////////////////////////////////////////
+#[fixture]
+pub fn vwap() -> VolumeWeightedAveragePrice {
+    VolumeWeightedAveragePrice::new(None).unwrap()
+}
+
+#[fixture]
+pub fn rvwap_10() -> RollingVolumeWeightedAveragePrice {
+    RollingVolumeWeightedAveragePrice::new(10).unwrap()
+}
+
+#[fixture]
+pub fn obv_10() -> OnBalanceVolume {
+    OnBalanceVolume::new(10).unwrap()
+}
+
+#[fixture]
+pub fn vwma_10() -> VolumeWeightedMovingAverage {
+    VolumeWeightedMovingAverage::new(10, None).unwrap()
+}
+
+#[fixture]
+pub fn kvo_34_55_13() -> KlingerVolumeOscillator {
+    KlingerVolumeOscillator::new(34, 55, 13, None).unwrap()
+}
+
+#[fixture]
+pub fn mfi_10() -> MoneyFlowIndex {
+    MoneyFlowIndex::new(10).unwrap()
+}
+
+#[fixture]
+pub fn ad() -> AccumulationDistribution {
+    AccumulationDistribution::new().unwrap()
+}
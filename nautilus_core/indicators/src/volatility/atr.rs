@@ -21,7 +21,7 @@ use pyo3::prelude::*;
 
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, InputKind, MovingAverage},
 };
 
 /// An indicator which calculates a Average True Range (ATR) across a rolling window.
@@ -68,6 +68,10 @@ impl Indicator for AverageTrueRange {
         self.initialized
     }
 
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::Bar]
+    }
+
     fn handle_bar(&mut self, bar: &Bar) {
         self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
     }
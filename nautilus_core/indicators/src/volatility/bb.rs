@@ -0,0 +1,249 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+};
+
+/// An indicator which calculates Bollinger Bands, placed `k` standard deviations
+/// above and below a moving average of the typical price across a rolling window.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct BollingerBands {
+    pub period: usize,
+    pub k: f64,
+    pub ma_type: MovingAverageType,
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+    pub count: usize,
+    pub initialized: bool,
+    ma: Box<dyn MovingAverage + Send + 'static>,
+    prices: VecDeque<f64>,
+    has_inputs: bool,
+}
+
+impl Display for BollingerBands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.period,
+            self.k,
+            self.ma_type
+        )
+    }
+}
+
+impl Indicator for BollingerBands {
+    fn name(&self) -> String {
+        stringify!(BollingerBands).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.prices.clear();
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BollingerBands {
+    pub fn new(period: usize, k: f64, ma_type: Option<MovingAverageType>) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+        Ok(Self {
+            period,
+            k,
+            ma_type,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            count: 0,
+            initialized: false,
+            ma: MovingAverageFactory::create(ma_type, period),
+            prices: VecDeque::with_capacity(period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical = (high + low + close) / 3.0;
+
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(typical);
+        self.ma.update_raw(typical);
+
+        // The deviation is taken around the moving average (rather than the window mean)
+        let mean = self.ma.value();
+        let variance = self
+            .prices
+            .iter()
+            .map(|price| (price - mean).powi(2))
+            .sum::<f64>()
+            / self.prices.len() as f64;
+        let std = variance.sqrt();
+
+        self.upper = mean + self.k * std;
+        self.middle = mean;
+        self.lower = mean - self.k * std;
+
+        self.increment_count();
+    }
+
+    fn increment_count(&mut self) {
+        self.count += 1;
+
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.count >= self.period {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volatility::bb::BollingerBands};
+
+    #[rstest]
+    fn test_name_returns_expected_string(bb_10: BollingerBands) {
+        assert_eq!(bb_10.name(), "BollingerBands");
+        assert_eq!(format!("{bb_10}"), "BollingerBands(10,2,SIMPLE)");
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut bb_10: BollingerBands) {
+        for i in 1..=10 {
+            assert!(!bb_10.initialized);
+            bb_10.update_raw(f64::from(i), f64::from(i), f64::from(i));
+        }
+
+        assert!(bb_10.initialized);
+        assert_eq!(bb_10.count, 10);
+    }
+
+    #[rstest]
+    fn test_value_with_constant_inputs_collapses_bands(mut bb_10: BollingerBands) {
+        for _ in 0..10 {
+            bb_10.update_raw(1.0, 1.0, 1.0);
+        }
+
+        assert_eq!(bb_10.upper, 1.0);
+        assert_eq!(bb_10.middle, 1.0);
+        assert_eq!(bb_10.lower, 1.0);
+    }
+
+    #[rstest]
+    fn test_value_with_various_inputs_returns_expected_value(mut bb_10: BollingerBands) {
+        for i in 1..=10 {
+            bb_10.update_raw(f64::from(i), f64::from(i), f64::from(i));
+        }
+
+        // Population standard deviation of 1..=10 is sqrt(8.25)
+        let std = 8.25_f64.sqrt();
+        assert_eq!(bb_10.middle, 5.5);
+        assert!((bb_10.upper - (5.5 + 2.0 * std)).abs() < 1e-12);
+        assert!((bb_10.lower - (5.5 - 2.0 * std)).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut bb_10: BollingerBands, quote_tick: QuoteTick) {
+        bb_10.handle_quote_tick(&quote_tick);
+
+        assert!(bb_10.has_inputs());
+        assert_eq!(bb_10.middle, 1501.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut bb_10: BollingerBands, trade_tick: TradeTick) {
+        bb_10.handle_trade_tick(&trade_tick);
+
+        assert!(bb_10.has_inputs());
+        assert_eq!(bb_10.middle, 1500.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut bb_10: BollingerBands, bar_ethusdt_binance_minute_bid: Bar) {
+        bb_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(bb_10.has_inputs());
+        assert_eq!(bb_10.middle, (1550.0 + 1495.0 + 1522.0) / 3.0);
+    }
+
+    #[rstest]
+    fn test_reset(mut bb_10: BollingerBands) {
+        bb_10.update_raw(1.0, 1.0, 1.0);
+        bb_10.reset();
+
+        assert!(!bb_10.has_inputs());
+        assert!(!bb_10.initialized);
+        assert_eq!(bb_10.count, 0);
+        assert_eq!(bb_10.middle, 0.0);
+    }
+}
@@ -0,0 +1,214 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which calculates Donchian Channels, the highest high and lowest low
+/// across a rolling window (with the middle channel halfway between them).
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct DonchianChannel {
+    pub period: usize,
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+    pub count: usize,
+    pub initialized: bool,
+    upper_prices: VecDeque<f64>,
+    lower_prices: VecDeque<f64>,
+    has_inputs: bool,
+}
+
+impl Display for DonchianChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for DonchianChannel {
+    fn name(&self) -> String {
+        stringify!(DonchianChannel).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into());
+    }
+
+    fn reset(&mut self) {
+        self.upper_prices.clear();
+        self.lower_prices.clear();
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl DonchianChannel {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            count: 0,
+            initialized: false,
+            upper_prices: VecDeque::with_capacity(period),
+            lower_prices: VecDeque::with_capacity(period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        if self.upper_prices.len() == self.period {
+            self.upper_prices.pop_front();
+        }
+        if self.lower_prices.len() == self.period {
+            self.lower_prices.pop_front();
+        }
+        self.upper_prices.push_back(high);
+        self.lower_prices.push_back(low);
+
+        self.upper = self.upper_prices.iter().copied().fold(f64::MIN, f64::max);
+        self.lower = self.lower_prices.iter().copied().fold(f64::MAX, f64::min);
+        self.middle = (self.upper + self.lower) / 2.0;
+
+        self.increment_count();
+    }
+
+    fn increment_count(&mut self) {
+        self.count += 1;
+
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.count >= self.period {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volatility::dc::DonchianChannel};
+
+    #[rstest]
+    fn test_name_returns_expected_string(dc_10: DonchianChannel) {
+        assert_eq!(dc_10.name(), "DonchianChannel");
+        assert_eq!(format!("{dc_10}"), "DonchianChannel(10)");
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut dc_10: DonchianChannel) {
+        for i in 1..=10 {
+            assert!(!dc_10.initialized);
+            dc_10.update_raw(f64::from(i) + 1.0, f64::from(i));
+        }
+
+        assert!(dc_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_rolling_window_returns_expected_value(mut dc_10: DonchianChannel) {
+        for i in 1..=15 {
+            dc_10.update_raw(f64::from(i) + 1.0, f64::from(i));
+        }
+
+        // The window holds the last ten inputs (6..=15)
+        assert_eq!(dc_10.upper, 16.0);
+        assert_eq!(dc_10.lower, 6.0);
+        assert_eq!(dc_10.middle, 11.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut dc_10: DonchianChannel, quote_tick: QuoteTick) {
+        dc_10.handle_quote_tick(&quote_tick);
+
+        assert!(dc_10.has_inputs());
+        assert_eq!(dc_10.upper, 1501.0);
+        assert_eq!(dc_10.lower, 1501.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut dc_10: DonchianChannel, trade_tick: TradeTick) {
+        dc_10.handle_trade_tick(&trade_tick);
+
+        assert!(dc_10.has_inputs());
+        assert_eq!(dc_10.middle, 1500.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut dc_10: DonchianChannel, bar_ethusdt_binance_minute_bid: Bar) {
+        dc_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert_eq!(dc_10.upper, 1550.0);
+        assert_eq!(dc_10.lower, 1495.0);
+        assert_eq!(dc_10.middle, 1522.5);
+    }
+
+    #[rstest]
+    fn test_reset(mut dc_10: DonchianChannel) {
+        dc_10.update_raw(2.0, 1.0);
+        dc_10.reset();
+
+        assert!(!dc_10.has_inputs());
+        assert!(!dc_10.initialized);
+        assert_eq!(dc_10.count, 0);
+        assert_eq!(dc_10.upper, 0.0);
+    }
+}
@@ -0,0 +1,451 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
+
+use crate::indicator::{Indicator, InputKind};
+
+/// The estimator used by [`HistoricalVolatility`].
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub enum VolatilityEstimator {
+    /// The sample standard deviation of log close-to-close returns.
+    CloseToClose,
+    /// Parkinson's estimator, from the high-low range.
+    Parkinson,
+    /// The Garman-Klass estimator, from the high-low range and open-close returns.
+    GarmanKlass,
+    /// The Yang-Zhang estimator, combining overnight (close-to-open), open-close and
+    /// Rogers-Satchell variances, which is robust to opening jumps and drift.
+    YangZhang,
+}
+
+impl VolatilityEstimator {
+    /// Returns whether the estimator uses the close of the bar before the window.
+    #[must_use]
+    pub fn uses_previous_close(self) -> bool {
+        matches!(self, Self::CloseToClose | Self::YangZhang)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct OhlcPrices {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+}
+
+/// An indicator which estimates the historical volatility of prices across a rolling
+/// window of bars, scaled by `annualization` (the number of bars per year, or 1.0 for
+/// the volatility per bar).
+///
+/// Ticks only provide a single price, so for the range based estimators (Parkinson,
+/// Garman-Klass and Yang-Zhang) the indicator should be fed bars.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct HistoricalVolatility {
+    pub period: usize,
+    pub estimator: VolatilityEstimator,
+    pub annualization: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    bars: VecDeque<OhlcPrices>,
+    has_inputs: bool,
+}
+
+impl std::fmt::Display for HistoricalVolatility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.period,
+            self.estimator,
+            self.annualization
+        )
+    }
+}
+
+impl Indicator for HistoricalVolatility {
+    fn name(&self) -> String {
+        stringify!(HistoricalVolatility).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price, price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.open).into(),
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.bars.clear();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl HistoricalVolatility {
+    pub fn new(
+        period: usize,
+        estimator: Option<VolatilityEstimator>,
+        annualization: Option<f64>,
+    ) -> Result<Self> {
+        if period < 2 {
+            bail!("Condition failed: `period` must be at least 2, was {period}");
+        }
+        let annualization = annualization.unwrap_or(1.0);
+        if annualization <= 0.0 {
+            bail!("Condition failed: `annualization` must be positive, was {annualization}");
+        }
+
+        Ok(Self {
+            period,
+            estimator: estimator.unwrap_or(VolatilityEstimator::CloseToClose),
+            annualization,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            bars: VecDeque::with_capacity(period + 1),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, open: f64, high: f64, low: f64, close: f64) {
+        if self.bars.len() == self.period + 1 {
+            self.bars.pop_front();
+        }
+        self.bars.push_back(OhlcPrices {
+            open,
+            high,
+            low,
+            close,
+        });
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            let required = if self.estimator.uses_previous_close() {
+                self.period + 1
+            } else {
+                self.period
+            };
+            if self.bars.len() >= required {
+                self.initialized = true;
+            }
+        }
+
+        if self.initialized {
+            let variance = match self.estimator {
+                VolatilityEstimator::CloseToClose => self.close_to_close_variance(),
+                VolatilityEstimator::Parkinson => self.parkinson_variance(),
+                VolatilityEstimator::GarmanKlass => self.garman_klass_variance(),
+                VolatilityEstimator::YangZhang => self.yang_zhang_variance(),
+            };
+            self.value = (variance.max(0.0) * self.annualization).sqrt();
+        }
+    }
+
+    /// Returns the bars in the window, excluding any bar kept only for its close.
+    fn window(&self) -> impl Iterator<Item = &OhlcPrices> {
+        self.bars.iter().skip(self.bars.len() - self.period)
+    }
+
+    /// Returns the (previous close, bar) pairs in the window.
+    fn window_with_previous_close(&self) -> impl Iterator<Item = (f64, &OhlcPrices)> {
+        self.bars
+            .iter()
+            .zip(self.bars.iter().skip(1))
+            .map(|(previous, bar)| (previous.close, bar))
+    }
+
+    fn close_to_close_variance(&self) -> f64 {
+        let returns: Vec<f64> = self
+            .window_with_previous_close()
+            .map(|(previous_close, bar)| (bar.close / previous_close).ln())
+            .collect();
+        sample_variance(&returns)
+    }
+
+    fn parkinson_variance(&self) -> f64 {
+        let sum: f64 = self
+            .window()
+            .map(|bar| (bar.high / bar.low).ln().powi(2))
+            .sum();
+        sum / (4.0 * self.period as f64 * std::f64::consts::LN_2)
+    }
+
+    fn garman_klass_variance(&self) -> f64 {
+        let close_open_factor = 2.0f64.mul_add(std::f64::consts::LN_2, -1.0);
+        let sum: f64 = self
+            .window()
+            .map(|bar| {
+                let high_low = (bar.high / bar.low).ln();
+                let close_open = (bar.close / bar.open).ln();
+                0.5 * high_low.powi(2) - close_open_factor * close_open.powi(2)
+            })
+            .sum();
+        sum / self.period as f64
+    }
+
+    fn yang_zhang_variance(&self) -> f64 {
+        let overnight: Vec<f64> = self
+            .window_with_previous_close()
+            .map(|(previous_close, bar)| (bar.open / previous_close).ln())
+            .collect();
+        let open_close: Vec<f64> = self
+            .window()
+            .map(|bar| (bar.close / bar.open).ln())
+            .collect();
+        let rogers_satchell = self
+            .window()
+            .map(|bar| {
+                let high_close = (bar.high / bar.close).ln();
+                let high_open = (bar.high / bar.open).ln();
+                let low_close = (bar.low / bar.close).ln();
+                let low_open = (bar.low / bar.open).ln();
+                high_close.mul_add(high_open, low_close * low_open)
+            })
+            .sum::<f64>()
+            / self.period as f64;
+
+        let n = self.period as f64;
+        let k = 0.34 / (1.34 + (n + 1.0) / (n - 1.0));
+        sample_variance(&overnight) + k * sample_variance(&open_close) + (1.0 - k) * rogers_satchell
+    }
+}
+
+/// Returns the sample variance (with Bessel's correction) of the given `values`.
+fn sample_variance(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / (n - 1.0)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{
+        indicator::Indicator,
+        stubs::*,
+        volatility::hv::{HistoricalVolatility, VolatilityEstimator},
+    };
+
+    /// Bars alternating between two shapes, so every estimator has a known value.
+    fn update_alternating(hv: &mut HistoricalVolatility, count: usize) {
+        for i in 0..count {
+            if i % 2 == 0 {
+                hv.update_raw(100.0, 110.0, 100.0, 110.0);
+            } else {
+                hv.update_raw(110.0, 110.0, 100.0, 100.0);
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_name_returns_expected_string(hv_10: HistoricalVolatility) {
+        assert_eq!(hv_10.name(), "HistoricalVolatility");
+        assert_eq!(
+            format!("{hv_10}"),
+            "HistoricalVolatility(10,CLOSE_TO_CLOSE,1)"
+        );
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_period_returns_error() {
+        assert!(HistoricalVolatility::new(1, None, None).is_err());
+        assert!(HistoricalVolatility::new(10, None, Some(0.0)).is_err());
+    }
+
+    #[rstest]
+    #[case(VolatilityEstimator::CloseToClose, 11)]
+    #[case(VolatilityEstimator::Parkinson, 10)]
+    #[case(VolatilityEstimator::GarmanKlass, 10)]
+    #[case(VolatilityEstimator::YangZhang, 11)]
+    fn test_initialized_with_required_inputs_returns_true(
+        #[case] estimator: VolatilityEstimator,
+        #[case] required: usize,
+    ) {
+        let mut hv = HistoricalVolatility::new(10, Some(estimator), None).unwrap();
+        update_alternating(&mut hv, required - 1);
+        assert!(!hv.initialized);
+
+        update_alternating(&mut hv, 1);
+        assert!(hv.initialized);
+    }
+
+    #[rstest]
+    fn test_close_to_close_value(mut hv_10: HistoricalVolatility) {
+        for _ in 0..11 {
+            hv_10.update_raw(100.0, 100.0, 100.0, 100.0);
+        }
+        assert_eq!(hv_10.value, 0.0);
+
+        update_alternating(&mut hv_10, 11);
+
+        // Returns alternate +/- ln(1.1), with a mean of zero
+        let r = 1.1f64.ln();
+        let expected = (10.0 * r * r / 9.0).sqrt();
+        assert!((hv_10.value - expected).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_parkinson_value() {
+        let mut hv =
+            HistoricalVolatility::new(10, Some(VolatilityEstimator::Parkinson), None).unwrap();
+        update_alternating(&mut hv, 10);
+
+        let r = 1.1f64.ln();
+        let expected = (r * r / (4.0 * std::f64::consts::LN_2)).sqrt();
+        assert!((hv.value - expected).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_garman_klass_value() {
+        let mut hv =
+            HistoricalVolatility::new(10, Some(VolatilityEstimator::GarmanKlass), None).unwrap();
+        update_alternating(&mut hv, 10);
+
+        // Every bar closes at its high or low, so ln(C/O)^2 equals ln(H/L)^2
+        let r = 1.1f64.ln();
+        let expected = ((0.5 - (2.0 * std::f64::consts::LN_2 - 1.0)) * r * r).sqrt();
+        assert!((hv.value - expected).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_yang_zhang_value() {
+        let mut hv =
+            HistoricalVolatility::new(10, Some(VolatilityEstimator::YangZhang), None).unwrap();
+        update_alternating(&mut hv, 11);
+
+        // Each bar opens at the previous close (no overnight variance), and the
+        // Rogers-Satchell term is zero when bars close at their high or low
+        let r = 1.1f64.ln();
+        let k = 0.34 / (1.34 + 11.0 / 9.0);
+        let expected = (k * 10.0 * r * r / 9.0).sqrt();
+        assert!((hv.value - expected).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_annualization_scales_value() {
+        let mut hv = HistoricalVolatility::new(10, None, Some(252.0)).unwrap();
+        let mut unscaled = HistoricalVolatility::new(10, None, None).unwrap();
+        update_alternating(&mut hv, 11);
+        update_alternating(&mut unscaled, 11);
+
+        assert!((hv.value - unscaled.value * 252.0f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut hv_10: HistoricalVolatility, quote_tick: QuoteTick) {
+        hv_10.handle_quote_tick(&quote_tick);
+
+        assert!(hv_10.has_inputs());
+        assert_eq!(hv_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut hv_10: HistoricalVolatility, trade_tick: TradeTick) {
+        hv_10.handle_trade_tick(&trade_tick);
+
+        assert!(hv_10.has_inputs());
+        assert_eq!(hv_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut hv_10: HistoricalVolatility, bar_ethusdt_binance_minute_bid: Bar) {
+        hv_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(hv_10.has_inputs());
+        assert_eq!(hv_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut hv_10: HistoricalVolatility) {
+        update_alternating(&mut hv_10, 11);
+        hv_10.reset();
+
+        assert!(!hv_10.has_inputs());
+        assert!(!hv_10.initialized);
+        assert_eq!(hv_10.count, 0);
+        assert_eq!(hv_10.value, 0.0);
+    }
+}
@@ -0,0 +1,247 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+    volatility::atr::AverageTrueRange,
+};
+
+/// An indicator which calculates Keltner Channels, placed `k_multiplier` average true
+/// ranges above and below a moving average of the typical price.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct KeltnerChannel {
+    pub period: usize,
+    pub k_multiplier: f64,
+    pub ma_type: MovingAverageType,
+    pub ma_type_atr: MovingAverageType,
+    pub use_previous: bool,
+    pub atr_floor: f64,
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+    pub count: usize,
+    pub initialized: bool,
+    ma: Box<dyn MovingAverage + Send + 'static>,
+    atr: AverageTrueRange,
+    has_inputs: bool,
+}
+
+impl Display for KeltnerChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{},{},{})",
+            self.name(),
+            self.period,
+            self.k_multiplier,
+            self.ma_type,
+            self.ma_type_atr,
+            self.use_previous,
+            self.atr_floor,
+        )
+    }
+}
+
+impl Indicator for KeltnerChannel {
+    fn name(&self) -> String {
+        stringify!(KeltnerChannel).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = quote.extract_price(PriceType::Mid).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.atr.reset();
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl KeltnerChannel {
+    pub fn new(
+        period: usize,
+        k_multiplier: f64,
+        ma_type: Option<MovingAverageType>,
+        ma_type_atr: Option<MovingAverageType>,
+        use_previous: Option<bool>,
+        atr_floor: Option<f64>,
+    ) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+        let ma_type_atr = ma_type_atr.unwrap_or(MovingAverageType::Simple);
+        let use_previous = use_previous.unwrap_or(true);
+        let atr_floor = atr_floor.unwrap_or(0.0);
+        Ok(Self {
+            period,
+            k_multiplier,
+            ma_type,
+            ma_type_atr,
+            use_previous,
+            atr_floor,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            count: 0,
+            initialized: false,
+            ma: MovingAverageFactory::create(ma_type, period),
+            atr: AverageTrueRange::new(
+                period,
+                Some(ma_type_atr),
+                Some(use_previous),
+                Some(atr_floor),
+            )?,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical = (high + low + close) / 3.0;
+
+        self.ma.update_raw(typical);
+        self.atr.update_raw(high, low, close);
+
+        let middle = self.ma.value();
+        let offset = self.atr.value * self.k_multiplier;
+        self.upper = middle + offset;
+        self.middle = middle;
+        self.lower = middle - offset;
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.ma.initialized() && self.atr.initialized {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volatility::kc::KeltnerChannel};
+
+    #[rstest]
+    fn test_name_returns_expected_string(kc_10: KeltnerChannel) {
+        assert_eq!(kc_10.name(), "KeltnerChannel");
+        assert_eq!(
+            format!("{kc_10}"),
+            "KeltnerChannel(10,2,EXPONENTIAL,SIMPLE,true,0)"
+        );
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut kc_10: KeltnerChannel) {
+        for i in 1..=10 {
+            assert!(!kc_10.initialized);
+            kc_10.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i) + 0.5);
+        }
+
+        assert!(kc_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_constant_range_returns_expected_value(mut kc_10: KeltnerChannel) {
+        for _ in 0..10 {
+            kc_10.update_raw(2.0, 1.0, 1.5);
+        }
+
+        // Typical price is 1.5 and the true range is 1.0
+        assert!((kc_10.middle - 1.5).abs() < 1e-12);
+        assert!((kc_10.upper - 3.5).abs() < 1e-12);
+        assert!((kc_10.lower + 0.5).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut kc_10: KeltnerChannel, quote_tick: QuoteTick) {
+        kc_10.handle_quote_tick(&quote_tick);
+
+        assert!(kc_10.has_inputs());
+        assert!((kc_10.middle - 1501.0).abs() < 1e-9);
+        assert_eq!(kc_10.upper, kc_10.middle);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut kc_10: KeltnerChannel, trade_tick: TradeTick) {
+        kc_10.handle_trade_tick(&trade_tick);
+
+        assert!(kc_10.has_inputs());
+        assert!((kc_10.middle - 1500.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut kc_10: KeltnerChannel, bar_ethusdt_binance_minute_bid: Bar) {
+        kc_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(kc_10.has_inputs());
+        assert!(kc_10.upper > kc_10.middle);
+        assert!(kc_10.lower < kc_10.middle);
+    }
+
+    #[rstest]
+    fn test_reset(mut kc_10: KeltnerChannel) {
+        kc_10.update_raw(2.0, 1.0, 1.5);
+        kc_10.reset();
+
+        assert!(!kc_10.has_inputs());
+        assert!(!kc_10.initialized);
+        assert_eq!(kc_10.count, 0);
+        assert_eq!(kc_10.upper, 0.0);
+    }
+}
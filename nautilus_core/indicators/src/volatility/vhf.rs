@@ -0,0 +1,237 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+};
+
+/// An indicator which calculates the Vertical Horizontal Filter (VHF), the range of
+/// closing prices across a rolling window relative to the sum of their changes, to
+/// determine whether prices are trending or in a congestion phase.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct VerticalHorizontalFilter {
+    pub period: usize,
+    pub ma_type: MovingAverageType,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    ma: Box<dyn MovingAverage + Send + 'static>,
+    prices: VecDeque<f64>,
+    previous_close: f64,
+    has_inputs: bool,
+}
+
+impl Display for VerticalHorizontalFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.ma_type)
+    }
+}
+
+impl Indicator for VerticalHorizontalFilter {
+    fn name(&self) -> String {
+        stringify!(VerticalHorizontalFilter).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::QuoteTick, InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(PriceType::Mid).into());
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.prices.clear();
+        self.previous_close = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl VerticalHorizontalFilter {
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+        Ok(Self {
+            period,
+            ma_type,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            ma: MovingAverageFactory::create(ma_type, period),
+            prices: VecDeque::with_capacity(period),
+            previous_close: 0.0,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        if !self.has_inputs {
+            self.previous_close = close;
+        }
+
+        if self.prices.len() == self.period {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(close);
+        self.ma.update_raw((close - self.previous_close).abs());
+        self.previous_close = close;
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.ma.initialized() {
+                self.initialized = true;
+            }
+        }
+
+        if self.initialized {
+            let max_price = self.prices.iter().copied().fold(f64::MIN, f64::max);
+            let min_price = self.prices.iter().copied().fold(f64::MAX, f64::min);
+            let ma_value = self.ma.value();
+            self.value = if ma_value == 0.0 {
+                0.0 // Prices have not changed across the window
+            } else {
+                (max_price - min_price) / self.period as f64 / ma_value
+            };
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volatility::vhf::VerticalHorizontalFilter};
+
+    #[rstest]
+    fn test_name_returns_expected_string(vhf_10: VerticalHorizontalFilter) {
+        assert_eq!(vhf_10.name(), "VerticalHorizontalFilter");
+        assert_eq!(format!("{vhf_10}"), "VerticalHorizontalFilter(10,SIMPLE)");
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut vhf_10: VerticalHorizontalFilter) {
+        for i in 1..=10 {
+            assert!(!vhf_10.initialized);
+            vhf_10.update_raw(f64::from(i));
+        }
+
+        assert!(vhf_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_trending_inputs_returns_one(mut vhf_10: VerticalHorizontalFilter) {
+        for i in 1..=10 {
+            vhf_10.update_raw(f64::from(i));
+        }
+
+        assert_eq!(vhf_10.value, 1.0);
+    }
+
+    #[rstest]
+    fn test_value_with_oscillating_inputs_returns_expected_value(
+        mut vhf_10: VerticalHorizontalFilter,
+    ) {
+        for i in 0..10 {
+            vhf_10.update_raw(if i % 2 == 0 { 1.0 } else { 2.0 });
+        }
+
+        // The range is 1.0 while the closes moved 9.0 in total
+        assert!((vhf_10.value - 1.0 / 9.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_value_with_constant_inputs_returns_zero(mut vhf_10: VerticalHorizontalFilter) {
+        for _ in 0..10 {
+            vhf_10.update_raw(1.0);
+        }
+
+        assert!(vhf_10.initialized);
+        assert_eq!(vhf_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick(mut vhf_10: VerticalHorizontalFilter, quote_tick: QuoteTick) {
+        vhf_10.handle_quote_tick(&quote_tick);
+
+        assert!(vhf_10.has_inputs());
+        assert_eq!(vhf_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut vhf_10: VerticalHorizontalFilter, trade_tick: TradeTick) {
+        vhf_10.handle_trade_tick(&trade_tick);
+
+        assert!(vhf_10.has_inputs());
+        assert_eq!(vhf_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut vhf_10: VerticalHorizontalFilter, bar_ethusdt_binance_minute_bid: Bar) {
+        vhf_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(vhf_10.has_inputs());
+        assert_eq!(vhf_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut vhf_10: VerticalHorizontalFilter) {
+        vhf_10.update_raw(1.0);
+        vhf_10.reset();
+
+        assert!(!vhf_10.has_inputs());
+        assert!(!vhf_10.initialized);
+        assert_eq!(vhf_10.count, 0);
+        assert_eq!(vhf_10.value, 0.0);
+    }
+}
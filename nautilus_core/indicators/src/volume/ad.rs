@@ -0,0 +1,213 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::AggressorSide,
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which calculates the cumulative Accumulation/Distribution line, the
+/// running sum of volume weighted by the close location value (where the close sits
+/// within the high-low range, from -1 at the low to 1 at the high).
+///
+/// Trades carry no range, so their size is weighted by the aggressor side instead (1 for
+/// a buyer, -1 for a seller and 0 for no aggressor).
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct AccumulationDistribution {
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for AccumulationDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.name())
+    }
+}
+
+impl Indicator for AccumulationDistribution {
+    fn name(&self) -> String {
+        stringify!(AccumulationDistribution).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let multiplier = match trade.aggressor_side {
+            AggressorSide::Buyer => 1.0,
+            AggressorSide::Seller => -1.0,
+            AggressorSide::NoAggressor => 0.0,
+        };
+        self.accumulate(multiplier, (&trade.size).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+            (&bar.volume).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl AccumulationDistribution {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        let range = high - low;
+        let close_location_value = if range > 0.0 {
+            ((close - low) - (high - close)) / range
+        } else {
+            0.0
+        };
+        self.accumulate(close_location_value, volume);
+    }
+
+    fn accumulate(&mut self, multiplier: f64, volume: f64) {
+        self.value += multiplier * volume;
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            self.initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{bar::Bar, trade::TradeTick},
+        enums::AggressorSide,
+    };
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volume::ad::AccumulationDistribution};
+
+    #[rstest]
+    fn test_ad_initialized(ad: AccumulationDistribution) {
+        let display_str = format!("{ad}");
+        assert_eq!(display_str, "AccumulationDistribution()");
+        assert!(!ad.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_one_input_returns_true(mut ad: AccumulationDistribution) {
+        ad.update_raw(2.0, 1.0, 1.5, 10.0);
+        assert!(ad.initialized);
+    }
+
+    #[rstest]
+    #[case(2.0, 10.0)]
+    #[case(1.0, -10.0)]
+    #[case(1.5, 0.0)]
+    #[case(1.75, 5.0)]
+    fn test_value_returns_expected_value(
+        mut ad: AccumulationDistribution,
+        #[case] close: f64,
+        #[case] expected: f64,
+    ) {
+        ad.update_raw(2.0, 1.0, close, 10.0);
+        assert_eq!(ad.value, expected);
+    }
+
+    #[rstest]
+    fn test_value_accumulates(mut ad: AccumulationDistribution) {
+        ad.update_raw(2.0, 1.0, 2.0, 10.0);
+        ad.update_raw(2.0, 1.0, 1.75, 20.0);
+        ad.update_raw(1.0, 1.0, 1.0, 50.0);
+
+        assert_eq!(ad.value, 20.0);
+        assert_eq!(ad.count, 3);
+    }
+
+    #[rstest]
+    #[case(AggressorSide::Buyer, 1.0)]
+    #[case(AggressorSide::Seller, -1.0)]
+    #[case(AggressorSide::NoAggressor, 0.0)]
+    fn test_handle_trade_tick(
+        mut ad: AccumulationDistribution,
+        mut trade_tick: TradeTick,
+        #[case] aggressor_side: AggressorSide,
+        #[case] expected: f64,
+    ) {
+        trade_tick.aggressor_side = aggressor_side;
+        ad.handle_trade_tick(&trade_tick);
+
+        assert!(ad.has_inputs());
+        assert_eq!(ad.value, expected);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut ad: AccumulationDistribution, bar_ethusdt_binance_minute_bid: Bar) {
+        ad.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        // Close 1522 within a range of 1495 to 1550
+        let expected = (27.0 - 28.0) / 55.0 * 100_000.0;
+        assert!((ad.value - expected).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_reset(mut ad: AccumulationDistribution) {
+        ad.update_raw(2.0, 1.0, 2.0, 10.0);
+        ad.reset();
+
+        assert!(!ad.has_inputs());
+        assert!(!ad.initialized);
+        assert_eq!(ad.count, 0);
+        assert_eq!(ad.value, 0.0);
+    }
+}
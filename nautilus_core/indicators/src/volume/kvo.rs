@@ -0,0 +1,255 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::{bail, Result};
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+};
+
+/// An indicator which calculates the Klinger Volume Oscillator, designed to predict price
+/// reversals by comparing volume to price.
+///
+/// Volume is signed by the direction of the typical price, and the value is the signal
+/// line (a moving average) of the difference between its fast and slow moving averages.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct KlingerVolumeOscillator {
+    pub fast_period: usize,
+    pub slow_period: usize,
+    pub signal_period: usize,
+    pub ma_type: MovingAverageType,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    fast_ma: Box<dyn MovingAverage + Send + 'static>,
+    slow_ma: Box<dyn MovingAverage + Send + 'static>,
+    signal_ma: Box<dyn MovingAverage + Send + 'static>,
+    previous_hlc3: f64,
+    has_inputs: bool,
+}
+
+impl Display for KlingerVolumeOscillator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{})",
+            self.name(),
+            self.fast_period,
+            self.slow_period,
+            self.signal_period,
+            self.ma_type
+        )
+    }
+}
+
+impl Indicator for KlingerVolumeOscillator {
+    fn name(&self) -> String {
+        stringify!(KlingerVolumeOscillator).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price, (&trade.size).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+            (&bar.volume).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self.previous_hlc3 = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl KlingerVolumeOscillator {
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        ma_type: Option<MovingAverageType>,
+    ) -> Result<Self> {
+        if slow_period <= fast_period {
+            bail!(
+                "Condition failed: `slow_period` must be greater than `fast_period`, was {slow_period} <= {fast_period}"
+            );
+        }
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+
+        Ok(Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            ma_type,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            fast_ma: MovingAverageFactory::create(ma_type, fast_period),
+            slow_ma: MovingAverageFactory::create(ma_type, slow_period),
+            signal_ma: MovingAverageFactory::create(ma_type, signal_period),
+            previous_hlc3: 0.0,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        let hlc3 = (high + low + close) / 3.0;
+        if !self.has_inputs {
+            self.previous_hlc3 = hlc3;
+        }
+
+        let signed_volume = if hlc3 > self.previous_hlc3 {
+            volume
+        } else if hlc3 < self.previous_hlc3 {
+            -volume
+        } else {
+            0.0
+        };
+        self.fast_ma.update_raw(signed_volume);
+        self.slow_ma.update_raw(signed_volume);
+
+        if self.slow_ma.initialized() {
+            self.signal_ma
+                .update_raw(self.fast_ma.value() - self.slow_ma.value());
+            self.value = self.signal_ma.value();
+        }
+
+        self.previous_hlc3 = hlc3;
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.signal_ma.initialized() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{
+        average::MovingAverageType, indicator::Indicator, stubs::*,
+        volume::kvo::KlingerVolumeOscillator,
+    };
+
+    #[rstest]
+    fn test_kvo_initialized(kvo_34_55_13: KlingerVolumeOscillator) {
+        let display_str = format!("{kvo_34_55_13}");
+        assert_eq!(display_str, "KlingerVolumeOscillator(34,55,13,EXPONENTIAL)");
+        assert!(!kvo_34_55_13.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_slow_period_not_greater_than_fast_returns_error() {
+        assert!(KlingerVolumeOscillator::new(55, 34, 13, None).is_err());
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(
+        mut kvo_34_55_13: KlingerVolumeOscillator,
+    ) {
+        // The signal line starts once the slow average is initialized
+        for i in 0..66 {
+            kvo_34_55_13.update_raw(f64::from(i) + 1.0, f64::from(i), f64::from(i), 10.0);
+        }
+        assert!(!kvo_34_55_13.initialized);
+
+        kvo_34_55_13.update_raw(67.0, 66.0, 66.0, 10.0);
+        assert!(kvo_34_55_13.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_simple_moving_averages_returns_expected_value() {
+        let mut kvo =
+            KlingerVolumeOscillator::new(1, 2, 1, Some(MovingAverageType::Simple)).unwrap();
+        kvo.update_raw(1.0, 1.0, 1.0, 10.0);
+        kvo.update_raw(2.0, 2.0, 2.0, 10.0);
+        kvo.update_raw(1.0, 1.0, 1.0, 20.0);
+
+        assert!(kvo.initialized);
+        assert_eq!(kvo.value, -15.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut kvo_34_55_13: KlingerVolumeOscillator, trade_tick: TradeTick) {
+        kvo_34_55_13.handle_trade_tick(&trade_tick);
+
+        assert!(kvo_34_55_13.has_inputs());
+        assert_eq!(kvo_34_55_13.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(
+        mut kvo_34_55_13: KlingerVolumeOscillator,
+        bar_ethusdt_binance_minute_bid: Bar,
+    ) {
+        kvo_34_55_13.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(kvo_34_55_13.has_inputs());
+        assert_eq!(kvo_34_55_13.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut kvo_34_55_13: KlingerVolumeOscillator) {
+        kvo_34_55_13.update_raw(2.0, 1.0, 1.5, 10.0);
+        kvo_34_55_13.reset();
+
+        assert!(!kvo_34_55_13.has_inputs());
+        assert!(!kvo_34_55_13.initialized);
+        assert_eq!(kvo_34_55_13.count, 0);
+        assert_eq!(kvo_34_55_13.value, 0.0);
+    }
+}
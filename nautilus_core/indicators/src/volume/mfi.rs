@@ -0,0 +1,231 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::Result;
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An oscillator which calculates the Money Flow Index (MFI), a volume weighted RSI of
+/// the typical price across a rolling window, from 0 to 100.
+///
+/// The first input only sets the reference typical price, so the indicator initializes
+/// once it holds `period` money flows.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct MoneyFlowIndex {
+    pub period: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    positive_flows: VecDeque<f64>,
+    negative_flows: VecDeque<f64>,
+    previous_typical_price: f64,
+    has_inputs: bool,
+}
+
+impl Display for MoneyFlowIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for MoneyFlowIndex {
+    fn name(&self) -> String {
+        stringify!(MoneyFlowIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let price = (&trade.price).into();
+        self.update_raw(price, price, price, (&trade.size).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+            (&bar.volume).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.positive_flows.clear();
+        self.negative_flows.clear();
+        self.previous_typical_price = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MoneyFlowIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            positive_flows: VecDeque::with_capacity(period),
+            negative_flows: VecDeque::with_capacity(period),
+            previous_typical_price: 0.0,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64, volume: f64) {
+        let typical_price = (high + low + close) / 3.0;
+
+        if self.has_inputs {
+            let money_flow = typical_price * volume;
+            let (positive, negative) = if typical_price > self.previous_typical_price {
+                (money_flow, 0.0)
+            } else if typical_price < self.previous_typical_price {
+                (0.0, money_flow)
+            } else {
+                (0.0, 0.0)
+            };
+
+            if self.positive_flows.len() == self.period {
+                self.positive_flows.pop_front();
+                self.negative_flows.pop_front();
+            }
+            self.positive_flows.push_back(positive);
+            self.negative_flows.push_back(negative);
+
+            let positive_sum: f64 = self.positive_flows.iter().sum();
+            let negative_sum: f64 = self.negative_flows.iter().sum();
+            let total = positive_sum + negative_sum;
+            if total > 0.0 {
+                self.value = 100.0 * positive_sum / total;
+            }
+        }
+
+        self.previous_typical_price = typical_price;
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.positive_flows.len() == self.period {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volume::mfi::MoneyFlowIndex};
+
+    #[rstest]
+    fn test_mfi_initialized(mfi_10: MoneyFlowIndex) {
+        let display_str = format!("{mfi_10}");
+        assert_eq!(display_str, "MoneyFlowIndex(10)");
+        assert_eq!(mfi_10.period, 10);
+        assert!(!mfi_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut mfi_10: MoneyFlowIndex) {
+        for i in 0..10 {
+            mfi_10.update_raw(f64::from(i), f64::from(i), f64::from(i), 10.0);
+        }
+        assert!(!mfi_10.initialized);
+
+        mfi_10.update_raw(10.0, 10.0, 10.0, 10.0);
+        assert!(mfi_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_with_one_input_returns_zero(mut mfi_10: MoneyFlowIndex) {
+        mfi_10.update_raw(2.0, 1.0, 1.5, 10.0);
+        assert_eq!(mfi_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_returns_expected_value(mut mfi_10: MoneyFlowIndex) {
+        mfi_10.update_raw(1.0, 1.0, 1.0, 10.0);
+        mfi_10.update_raw(2.0, 2.0, 2.0, 30.0); // Positive flow of 60
+        mfi_10.update_raw(1.0, 1.0, 1.0, 20.0); // Negative flow of 20
+
+        assert_eq!(mfi_10.value, 75.0);
+    }
+
+    #[rstest]
+    fn test_value_with_rising_inputs_returns_maximum(mut mfi_10: MoneyFlowIndex) {
+        for i in 0..11 {
+            mfi_10.update_raw(f64::from(i), f64::from(i), f64::from(i), 10.0);
+        }
+        assert_eq!(mfi_10.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut mfi_10: MoneyFlowIndex, trade_tick: TradeTick) {
+        mfi_10.handle_trade_tick(&trade_tick);
+
+        assert!(mfi_10.has_inputs());
+        assert_eq!(mfi_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut mfi_10: MoneyFlowIndex, bar_ethusdt_binance_minute_bid: Bar) {
+        mfi_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(mfi_10.has_inputs());
+        assert_eq!(mfi_10.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut mfi_10: MoneyFlowIndex) {
+        mfi_10.update_raw(1.0, 1.0, 1.0, 10.0);
+        mfi_10.update_raw(2.0, 2.0, 2.0, 30.0);
+        mfi_10.reset();
+
+        assert!(!mfi_10.has_inputs());
+        assert!(!mfi_10.initialized);
+        assert_eq!(mfi_10.count, 0);
+        assert_eq!(mfi_10.value, 0.0);
+    }
+}
@@ -0,0 +1,236 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::Result;
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::AggressorSide,
+};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which calculates the momentum of relative positive or negative volume
+/// (On-Balance Volume), across a rolling window of `period` inputs (or all inputs for a
+/// `period` of zero).
+///
+/// Bars add their volume when closing above the open and subtract it when closing below,
+/// while trades add or subtract their size according to the aggressor side.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct OnBalanceVolume {
+    pub period: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    obv: VecDeque<f64>,
+    has_inputs: bool,
+}
+
+impl Display for OnBalanceVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for OnBalanceVolume {
+    fn name(&self) -> String {
+        stringify!(OnBalanceVolume).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let size: f64 = (&trade.size).into();
+        self.update_signed_volume(match trade.aggressor_side {
+            AggressorSide::Buyer => size,
+            AggressorSide::Seller => -size,
+            AggressorSide::NoAggressor => 0.0,
+        });
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.open).into(),
+            (&bar.close).into(),
+            (&bar.volume).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.obv.clear();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl OnBalanceVolume {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            obv: VecDeque::with_capacity(period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, open: f64, close: f64, volume: f64) {
+        let signed_volume = if close > open {
+            volume
+        } else if close < open {
+            -volume
+        } else {
+            0.0
+        };
+        self.update_signed_volume(signed_volume);
+    }
+
+    /// Updates the indicator with the given `signed_volume` (positive for buying volume
+    /// and negative for selling volume).
+    pub fn update_signed_volume(&mut self, signed_volume: f64) {
+        if self.period > 0 && self.obv.len() == self.period {
+            self.obv.pop_front();
+        }
+        self.obv.push_back(signed_volume);
+        self.value = self.obv.iter().sum();
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.obv.len() >= self.period.max(1) {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{bar::Bar, trade::TradeTick},
+        enums::AggressorSide,
+    };
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volume::obv::OnBalanceVolume};
+
+    #[rstest]
+    fn test_obv_initialized(obv_10: OnBalanceVolume) {
+        let display_str = format!("{obv_10}");
+        assert_eq!(display_str, "OnBalanceVolume(10)");
+        assert_eq!(obv_10.period, 10);
+        assert!(!obv_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut obv_10: OnBalanceVolume) {
+        for _ in 0..9 {
+            obv_10.update_raw(1.0, 2.0, 10.0);
+        }
+        assert!(!obv_10.initialized);
+
+        obv_10.update_raw(1.0, 2.0, 10.0);
+        assert!(obv_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_no_window_after_one_input() {
+        let mut obv = OnBalanceVolume::new(0).unwrap();
+        obv.update_raw(1.0, 2.0, 10.0);
+        assert!(obv.initialized);
+    }
+
+    #[rstest]
+    fn test_value_returns_expected_value(mut obv_10: OnBalanceVolume) {
+        obv_10.update_raw(1.0, 2.0, 10.0);
+        obv_10.update_raw(2.0, 1.0, 4.0);
+        obv_10.update_raw(1.0, 1.0, 100.0);
+
+        assert_eq!(obv_10.value, 6.0);
+    }
+
+    #[rstest]
+    fn test_value_with_rolling_window_drops_oldest_volume() {
+        let mut obv = OnBalanceVolume::new(2).unwrap();
+        obv.update_raw(1.0, 2.0, 10.0);
+        obv.update_raw(2.0, 1.0, 4.0);
+        obv.update_raw(2.0, 1.0, 4.0);
+
+        assert_eq!(obv.value, -8.0);
+    }
+
+    #[rstest]
+    #[case(AggressorSide::Buyer, 1.0)]
+    #[case(AggressorSide::Seller, -1.0)]
+    #[case(AggressorSide::NoAggressor, 0.0)]
+    fn test_handle_trade_tick(
+        mut obv_10: OnBalanceVolume,
+        mut trade_tick: TradeTick,
+        #[case] aggressor_side: AggressorSide,
+        #[case] expected: f64,
+    ) {
+        trade_tick.aggressor_side = aggressor_side;
+        obv_10.handle_trade_tick(&trade_tick);
+
+        assert!(obv_10.has_inputs());
+        assert_eq!(obv_10.value, expected);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut obv_10: OnBalanceVolume, bar_ethusdt_binance_minute_bid: Bar) {
+        obv_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(obv_10.has_inputs());
+        assert_eq!(obv_10.value, 100_000.0);
+    }
+
+    #[rstest]
+    fn test_reset(mut obv_10: OnBalanceVolume) {
+        obv_10.update_raw(1.0, 2.0, 10.0);
+        obv_10.reset();
+
+        assert!(!obv_10.has_inputs());
+        assert!(!obv_10.initialized);
+        assert_eq!(obv_10.count, 0);
+        assert_eq!(obv_10.value, 0.0);
+    }
+}
@@ -0,0 +1,223 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use anyhow::{bail, Result};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// An indicator which calculates the volume weighted average price (VWAP) across a
+/// rolling time window of `window_ns` nanoseconds.
+///
+/// The indicator initializes once its inputs span a full window.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct RollingVolumeWeightedAveragePrice {
+    pub window_ns: u64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    inputs: VecDeque<(UnixNanos, f64, f64)>,
+    first_ts: Option<UnixNanos>,
+    has_inputs: bool,
+}
+
+impl Display for RollingVolumeWeightedAveragePrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.window_ns)
+    }
+}
+
+impl Indicator for RollingVolumeWeightedAveragePrice {
+    fn name(&self) -> String {
+        stringify!(RollingVolumeWeightedAveragePrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into(), (&trade.size).into(), trade.ts_event);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into(), (&bar.volume).into(), bar.ts_init);
+    }
+
+    fn reset(&mut self) {
+        self.inputs.clear();
+        self.first_ts = None;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl RollingVolumeWeightedAveragePrice {
+    pub fn new(window_ns: u64) -> Result<Self> {
+        if window_ns == 0 {
+            bail!("Condition failed: `window_ns` must be positive");
+        }
+
+        Ok(Self {
+            window_ns,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            inputs: VecDeque::new(),
+            first_ts: None,
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, price: f64, volume: f64, ts: UnixNanos) {
+        self.inputs.push_back((ts, price, volume));
+        while let Some((front_ts, _, _)) = self.inputs.front() {
+            if *front_ts + self.window_ns > ts {
+                break;
+            }
+            self.inputs.pop_front();
+        }
+
+        let first_ts = *self.first_ts.get_or_insert(ts);
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if ts - first_ts >= self.window_ns {
+                self.initialized = true;
+            }
+        }
+
+        let (price_volume, volume_total) = self
+            .inputs
+            .iter()
+            .fold((0.0, 0.0), |(pv, v), (_, price, volume)| {
+                (pv + price * volume, v + volume)
+            });
+
+        if volume_total > 0.0 {
+            self.value = price_volume / volume_total;
+        } else if self.count == 1 {
+            self.value = price;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volume::rvwap::RollingVolumeWeightedAveragePrice};
+
+    #[rstest]
+    fn test_rvwap_initialized(rvwap_10: RollingVolumeWeightedAveragePrice) {
+        let display_str = format!("{rvwap_10}");
+        assert_eq!(display_str, "RollingVolumeWeightedAveragePrice(10)");
+        assert!(!rvwap_10.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_zero_window_returns_error() {
+        assert!(RollingVolumeWeightedAveragePrice::new(0).is_err());
+    }
+
+    #[rstest]
+    fn test_initialized_with_full_window_returns_true(
+        mut rvwap_10: RollingVolumeWeightedAveragePrice,
+    ) {
+        rvwap_10.update_raw(100.0, 10.0, 5);
+        rvwap_10.update_raw(100.0, 10.0, 14);
+        assert!(!rvwap_10.initialized);
+
+        rvwap_10.update_raw(100.0, 10.0, 15);
+        assert!(rvwap_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_excludes_inputs_outside_window(mut rvwap_10: RollingVolumeWeightedAveragePrice) {
+        rvwap_10.update_raw(100.0, 10.0, 0);
+        rvwap_10.update_raw(110.0, 30.0, 5);
+        assert_eq!(rvwap_10.value, 107.5);
+
+        // The first input drops out of the window
+        rvwap_10.update_raw(120.0, 10.0, 10);
+        assert_eq!(rvwap_10.value, 112.5);
+    }
+
+    #[rstest]
+    fn test_value_with_zero_volume_returns_price(mut rvwap_10: RollingVolumeWeightedAveragePrice) {
+        rvwap_10.update_raw(100.0, 0.0, 0);
+        assert_eq!(rvwap_10.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(
+        mut rvwap_10: RollingVolumeWeightedAveragePrice,
+        trade_tick: TradeTick,
+    ) {
+        rvwap_10.handle_trade_tick(&trade_tick);
+
+        assert!(rvwap_10.has_inputs());
+        assert_eq!(rvwap_10.value, 1500.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(
+        mut rvwap_10: RollingVolumeWeightedAveragePrice,
+        bar_ethusdt_binance_minute_bid: Bar,
+    ) {
+        rvwap_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(rvwap_10.has_inputs());
+        assert_eq!(rvwap_10.value, 1522.0);
+    }
+
+    #[rstest]
+    fn test_reset(mut rvwap_10: RollingVolumeWeightedAveragePrice) {
+        rvwap_10.update_raw(100.0, 10.0, 0);
+        rvwap_10.reset();
+
+        assert!(!rvwap_10.has_inputs());
+        assert!(!rvwap_10.initialized);
+        assert_eq!(rvwap_10.count, 0);
+        assert_eq!(rvwap_10.value, 0.0);
+    }
+}
@@ -0,0 +1,245 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::{bail, Result};
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, time::UnixNanos};
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use pyo3::prelude::*;
+
+use crate::indicator::{Indicator, InputKind};
+
+/// The number of nanoseconds in one day.
+pub const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// An indicator which calculates the volume weighted average price (VWAP) anchored to a
+/// daily session.
+///
+/// The session resets each day at `session_offset_ns` nanoseconds after midnight UTC
+/// (zero resets at midnight UTC), so inputs before and after the reset time fall into
+/// different sessions.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct VolumeWeightedAveragePrice {
+    pub session_offset_ns: u64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    session: Option<u64>,
+    price_volume: f64,
+    volume_total: f64,
+    has_inputs: bool,
+}
+
+impl Display for VolumeWeightedAveragePrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.session_offset_ns)
+    }
+}
+
+impl Indicator for VolumeWeightedAveragePrice {
+    fn name(&self) -> String {
+        stringify!(VolumeWeightedAveragePrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into(), (&trade.size).into(), trade.ts_event);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into(), (&bar.volume).into(), bar.ts_init);
+    }
+
+    fn reset(&mut self) {
+        self.session = None;
+        self.price_volume = 0.0;
+        self.volume_total = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl VolumeWeightedAveragePrice {
+    pub fn new(session_offset_ns: Option<u64>) -> Result<Self> {
+        let session_offset_ns = session_offset_ns.unwrap_or(0);
+        if session_offset_ns >= NANOSECONDS_IN_DAY {
+            bail!(
+                "Condition failed: `session_offset_ns` must be less than one day, was {session_offset_ns}"
+            );
+        }
+
+        Ok(Self {
+            session_offset_ns,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            session: None,
+            price_volume: 0.0,
+            volume_total: 0.0,
+            has_inputs: false,
+        })
+    }
+
+    /// Returns the index of the session containing the given `ts`.
+    #[must_use]
+    pub fn session_index(&self, ts: UnixNanos) -> u64 {
+        (ts + NANOSECONDS_IN_DAY - self.session_offset_ns) / NANOSECONDS_IN_DAY
+    }
+
+    pub fn update_raw(&mut self, price: f64, volume: f64, ts: UnixNanos) {
+        let session = self.session_index(ts);
+        if self.session != Some(session) {
+            self.session = Some(session);
+            self.price_volume = 0.0;
+            self.volume_total = 0.0;
+            self.value = price;
+        }
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            self.initialized = true;
+        }
+
+        if volume == 0.0 {
+            return;
+        }
+
+        self.price_volume += price * volume;
+        self.volume_total += volume;
+        self.value = self.price_volume / self.volume_total;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
+    use nautilus_model::data::{bar::Bar, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{
+        indicator::Indicator,
+        stubs::*,
+        volume::vwap::{VolumeWeightedAveragePrice, NANOSECONDS_IN_DAY},
+    };
+
+    const HOUR: u64 = 3_600 * NANOSECONDS_IN_SECOND;
+
+    #[rstest]
+    fn test_vwap_initialized(vwap: VolumeWeightedAveragePrice) {
+        let display_str = format!("{vwap}");
+        assert_eq!(display_str, "VolumeWeightedAveragePrice(0)");
+        assert!(!vwap.initialized);
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_session_offset_returns_error() {
+        assert!(VolumeWeightedAveragePrice::new(Some(NANOSECONDS_IN_DAY)).is_err());
+    }
+
+    #[rstest]
+    fn test_value_with_one_input_returns_price(mut vwap: VolumeWeightedAveragePrice) {
+        vwap.update_raw(100.0, 10.0, 0);
+
+        assert!(vwap.initialized);
+        assert_eq!(vwap.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_value_with_multiple_inputs_returns_expected_value(
+        mut vwap: VolumeWeightedAveragePrice,
+    ) {
+        vwap.update_raw(100.0, 10.0, HOUR);
+        vwap.update_raw(110.0, 30.0, 2 * HOUR);
+        vwap.update_raw(120.0, 0.0, 3 * HOUR);
+
+        assert_eq!(vwap.value, 107.5);
+        assert_eq!(vwap.count, 3);
+    }
+
+    #[rstest]
+    fn test_value_resets_at_midnight(mut vwap: VolumeWeightedAveragePrice) {
+        vwap.update_raw(100.0, 10.0, 23 * HOUR);
+        vwap.update_raw(110.0, 10.0, 25 * HOUR);
+
+        assert_eq!(vwap.value, 110.0);
+    }
+
+    #[rstest]
+    fn test_value_resets_at_session_offset() {
+        // Sessions reset at 13:30 UTC
+        let mut vwap = VolumeWeightedAveragePrice::new(Some(13 * HOUR + HOUR / 2)).unwrap();
+        vwap.update_raw(100.0, 10.0, 13 * HOUR);
+        vwap.update_raw(110.0, 10.0, 23 * HOUR);
+        assert_eq!(vwap.value, 110.0);
+
+        // Crossing midnight stays within the same session
+        vwap.update_raw(120.0, 10.0, 25 * HOUR);
+        assert_eq!(vwap.value, 115.0);
+
+        vwap.update_raw(130.0, 10.0, 38 * HOUR);
+        assert_eq!(vwap.value, 130.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut vwap: VolumeWeightedAveragePrice, trade_tick: TradeTick) {
+        vwap.handle_trade_tick(&trade_tick);
+
+        assert!(vwap.has_inputs());
+        assert_eq!(vwap.value, 1500.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut vwap: VolumeWeightedAveragePrice, bar_ethusdt_binance_minute_bid: Bar) {
+        vwap.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(vwap.has_inputs());
+        assert_eq!(vwap.value, 1522.0);
+    }
+
+    #[rstest]
+    fn test_reset(mut vwap: VolumeWeightedAveragePrice) {
+        vwap.update_raw(100.0, 10.0, 0);
+        vwap.reset();
+
+        assert!(!vwap.has_inputs());
+        assert!(!vwap.initialized);
+        assert_eq!(vwap.count, 0);
+        assert_eq!(vwap.value, 0.0);
+    }
+}
@@ -0,0 +1,205 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use pyo3::prelude::*;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, InputKind, MovingAverage},
+};
+
+/// An indicator which calculates a volume weighted moving average (VWMA), the ratio of
+/// the moving average of price times volume to the moving average of volume.
+#[repr(C)]
+#[derive(Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct VolumeWeightedMovingAverage {
+    pub period: usize,
+    pub ma_type: MovingAverageType,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    price_volume_ma: Box<dyn MovingAverage + Send + 'static>,
+    volume_ma: Box<dyn MovingAverage + Send + 'static>,
+    has_inputs: bool,
+}
+
+impl Display for VolumeWeightedMovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.ma_type)
+    }
+}
+
+impl Indicator for VolumeWeightedMovingAverage {
+    fn name(&self) -> String {
+        stringify!(VolumeWeightedMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn input_kinds(&self) -> &'static [InputKind] {
+        &[InputKind::TradeTick, InputKind::Bar]
+    }
+
+    fn handle_quote_tick(&mut self, _tick: &QuoteTick) {
+        // Function body intentionally left blank.
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into(), (&trade.size).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into(), (&bar.volume).into());
+    }
+
+    fn reset(&mut self) {
+        self.price_volume_ma.reset();
+        self.volume_ma.reset();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl VolumeWeightedMovingAverage {
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Result<Self> {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+        Ok(Self {
+            period,
+            ma_type,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            price_volume_ma: MovingAverageFactory::create(ma_type, period),
+            volume_ma: MovingAverageFactory::create(ma_type, period),
+            has_inputs: false,
+        })
+    }
+
+    pub fn update_raw(&mut self, price: f64, volume: f64) {
+        self.price_volume_ma.update_raw(price * volume);
+        self.volume_ma.update_raw(volume);
+
+        if self.volume_ma.value() != 0.0 {
+            self.value = self.price_volume_ma.value() / self.volume_ma.value();
+        }
+
+        self.count += 1;
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.volume_ma.initialized() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{bar::Bar, trade::TradeTick};
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volume::vwma::VolumeWeightedMovingAverage};
+
+    #[rstest]
+    fn test_vwma_initialized(vwma_10: VolumeWeightedMovingAverage) {
+        let display_str = format!("{vwma_10}");
+        assert_eq!(display_str, "VolumeWeightedMovingAverage(10,SIMPLE)");
+        assert_eq!(vwma_10.period, 10);
+        assert!(!vwma_10.initialized);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(
+        mut vwma_10: VolumeWeightedMovingAverage,
+    ) {
+        for i in 0..9 {
+            vwma_10.update_raw(f64::from(i), 10.0);
+        }
+        assert!(!vwma_10.initialized);
+
+        vwma_10.update_raw(9.0, 10.0);
+        assert!(vwma_10.initialized);
+    }
+
+    #[rstest]
+    fn test_value_returns_expected_value(mut vwma_10: VolumeWeightedMovingAverage) {
+        vwma_10.update_raw(100.0, 10.0);
+        vwma_10.update_raw(110.0, 30.0);
+
+        assert_eq!(vwma_10.value, 107.5);
+    }
+
+    #[rstest]
+    fn test_value_with_rolling_window_returns_expected_value() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2, None).unwrap();
+        vwma.update_raw(100.0, 10.0);
+        vwma.update_raw(110.0, 30.0);
+        vwma.update_raw(120.0, 10.0);
+
+        assert_eq!(vwma.value, 112.5);
+    }
+
+    #[rstest]
+    fn test_value_with_zero_volume_is_unchanged(mut vwma_10: VolumeWeightedMovingAverage) {
+        vwma_10.update_raw(100.0, 0.0);
+        assert_eq!(vwma_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_tick(mut vwma_10: VolumeWeightedMovingAverage, trade_tick: TradeTick) {
+        vwma_10.handle_trade_tick(&trade_tick);
+
+        assert!(vwma_10.has_inputs());
+        assert_eq!(vwma_10.value, 1500.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(
+        mut vwma_10: VolumeWeightedMovingAverage,
+        bar_ethusdt_binance_minute_bid: Bar,
+    ) {
+        vwma_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(vwma_10.has_inputs());
+        assert_eq!(vwma_10.value, 1522.0);
+    }
+
+    #[rstest]
+    fn test_reset(mut vwma_10: VolumeWeightedMovingAverage) {
+        vwma_10.update_raw(100.0, 10.0);
+        vwma_10.reset();
+
+        assert!(!vwma_10.has_inputs());
+        assert!(!vwma_10.initialized);
+        assert_eq!(vwma_10.count, 0);
+        assert_eq!(vwma_10.value, 0.0);
+    }
+}
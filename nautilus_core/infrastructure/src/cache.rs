@@ -15,9 +15,22 @@
 
 use std::{collections::HashMap, sync::mpsc::Receiver};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use nautilus_core::uuid::UUID4;
 use nautilus_model::identifiers::trader_id::TraderId;
+use serde::{de::DeserializeOwned, Serialize};
+
+pub mod memory;
+pub mod state;
+
+/// The payload encoding used when a database config has no `encoding` entry.
+pub const DEFAULT_ENCODING: &str = "msgpack";
+
+const DELIMITER: char = ':';
+const INSTRUMENTS: &str = "instruments";
+const ACCOUNTS: &str = "accounts";
+const ORDERS: &str = "orders";
+const POSITIONS: &str = "positions";
 
 /// A type of database operation.
 #[derive(Clone, Debug)]
@@ -55,22 +68,98 @@ impl DatabaseCommand {
 ///
 /// Delete operations may need a `payload` to target specific values.
 pub trait CacheDatabase {
-    type DatabaseType;
-
     fn new(
         trader_id: TraderId,
         instance_id: UUID4,
         config: HashMap<String, serde_json::Value>,
-    ) -> Result<Self::DatabaseType>;
+    ) -> Result<Self>
+    where
+        Self: Sized;
+    /// Returns the trader key which prefixes the keys returned by [`CacheDatabase::keys`].
+    fn trader_key(&self) -> String;
     fn flushdb(&mut self) -> Result<()>;
     fn keys(&mut self, pattern: &str) -> Result<Vec<String>>;
     fn read(&mut self, key: &str) -> Result<Vec<Vec<u8>>>;
     fn insert(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()>;
     fn update(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()>;
     fn delete(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()>;
+    /// Returns the encoding of the payloads read from and written to the database.
+    fn encoding(&self) -> &str {
+        DEFAULT_ENCODING
+    }
     fn handle_messages(
         rx: Receiver<DatabaseCommand>,
         trader_key: String,
         config: HashMap<String, serde_json::Value>,
-    );
+    ) where
+        Self: Sized;
+}
+
+/// Returns the payload encoding (`msgpack` or `json`) from the database `config`.
+#[must_use]
+pub fn get_encoding(config: &HashMap<String, serde_json::Value>) -> String {
+    config
+        .get("encoding")
+        .and_then(|v| v.as_str())
+        .unwrap_or(DEFAULT_ENCODING)
+        .to_string()
+}
+
+/// Encodes the given `value` as a payload with the `encoding`.
+pub fn encode_payload<T: Serialize>(encoding: &str, value: &T) -> Result<Vec<u8>> {
+    match encoding {
+        "msgpack" => rmp_serde::to_vec_named(value)
+            .map_err(|e| anyhow!("Failed to serialize msgpack `payload`: {e}")),
+        "json" => serde_json::to_vec(value)
+            .map_err(|e| anyhow!("Failed to serialize json `payload`: {e}")),
+        _ => Err(anyhow!("Unsupported encoding: {encoding}")),
+    }
+}
+
+/// Decodes the given `payload` with the `encoding`.
+pub fn decode_payload<T: DeserializeOwned>(encoding: &str, payload: &[u8]) -> Result<T> {
+    match encoding {
+        "msgpack" => rmp_serde::from_slice(payload)
+            .map_err(|e| anyhow!("Failed to deserialize msgpack `payload`: {e}")),
+        "json" => serde_json::from_slice(payload)
+            .map_err(|e| anyhow!("Failed to deserialize json `payload`: {e}")),
+        _ => Err(anyhow!("Unsupported encoding: {encoding}")),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::account::{
+        state::AccountState, stubs::cash_account_state_million_usd,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_encoding_default() {
+        assert_eq!(get_encoding(&HashMap::new()), "msgpack");
+    }
+
+    #[rstest]
+    #[case("msgpack")]
+    #[case("json")]
+    fn test_encode_decode_payload_account_state(#[case] encoding: &str) {
+        let state = cash_account_state_million_usd();
+
+        let payload = encode_payload(encoding, &state).unwrap();
+        let decoded: AccountState = decode_payload(encoding, &payload).unwrap();
+
+        assert_eq!(decoded.account_id, state.account_id);
+        assert_eq!(decoded.balances, state.balances);
+    }
+
+    #[rstest]
+    fn test_decode_payload_unsupported_encoding() {
+        let result: Result<AccountState> = decode_payload("bson", b"{}");
+        assert!(result.is_err());
+    }
 }
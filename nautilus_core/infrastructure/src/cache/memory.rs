@@ -0,0 +1,967 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_accounting::account::any::AccountAny;
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    events::account::state::AccountState,
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, venue::Venue,
+    },
+    instruments::any::InstrumentAny,
+    orderbook::any::OrderBookAny,
+    orders::any::OrderAny,
+    position::Position,
+};
+
+use super::{
+    encode_payload, state::CacheState, CacheDatabase, ACCOUNTS, DEFAULT_ENCODING, DELIMITER,
+    INSTRUMENTS, ORDERS, POSITIONS,
+};
+
+/// Configuration for a [`Cache`].
+#[derive(Clone, Debug)]
+pub struct CacheConfig {
+    /// The maximum number of quotes and trades held for each instrument.
+    pub tick_capacity: usize,
+    /// If the database is flushed when the cache is created.
+    pub flush_on_start: bool,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            tick_capacity: 10_000,
+            flush_on_start: false,
+        }
+    }
+}
+
+/// The indexes of the cached orders and positions.
+#[derive(Debug, Default)]
+struct CacheIndex {
+    venue_account: HashMap<Venue, AccountId>,
+    venue_orders: HashMap<Venue, HashSet<ClientOrderId>>,
+    instrument_orders: HashMap<InstrumentId, HashSet<ClientOrderId>>,
+    strategy_orders: HashMap<StrategyId, HashSet<ClientOrderId>>,
+    orders_open: HashSet<ClientOrderId>,
+    orders_closed: HashSet<ClientOrderId>,
+    order_position: HashMap<ClientOrderId, PositionId>,
+    venue_positions: HashMap<Venue, HashSet<PositionId>>,
+    instrument_positions: HashMap<InstrumentId, HashSet<PositionId>>,
+    strategy_positions: HashMap<StrategyId, HashSet<PositionId>>,
+    positions_open: HashSet<PositionId>,
+    positions_closed: HashSet<PositionId>,
+}
+
+/// Provides an in-memory cache of the trading state: instruments, accounts, orders,
+/// positions, the latest market data and order books.
+///
+/// Orders and positions are indexed by venue, instrument and strategy, and by whether
+/// they are open or closed.
+///
+/// When a [`CacheDatabase`] is provided, instruments, accounts, orders and positions
+/// are written through to it with the database encoding, and can be loaded back with
+/// [`Cache::load`]:
+/// - `instruments:{id}`: the instrument.
+/// - `accounts:{id}`: the `AccountState` events of the account.
+/// - `orders:{id}`: the `OrderEvent`s of the order.
+/// - `positions:{id}`: the `OrderFilled` events of the position.
+pub struct Cache {
+    config: CacheConfig,
+    database: Option<Box<dyn CacheDatabase>>,
+    encoding: String,
+    instruments: HashMap<InstrumentId, InstrumentAny>,
+    accounts: HashMap<AccountId, AccountAny>,
+    orders: IndexMap<ClientOrderId, OrderAny>,
+    positions: IndexMap<PositionId, Position>,
+    quotes: HashMap<InstrumentId, VecDeque<QuoteTick>>,
+    trades: HashMap<InstrumentId, VecDeque<TradeTick>>,
+    books: HashMap<InstrumentId, OrderBookAny>,
+    index: CacheIndex,
+}
+
+impl Cache {
+    /// Initializes a new [`Cache`] instance, with an optional `database` to write through to.
+    pub fn new(config: CacheConfig, database: Option<Box<dyn CacheDatabase>>) -> Result<Self> {
+        let encoding = database
+            .as_ref()
+            .map_or(DEFAULT_ENCODING, |database| database.encoding())
+            .to_string();
+        let mut cache = Self {
+            config,
+            database,
+            encoding,
+            instruments: HashMap::new(),
+            accounts: HashMap::new(),
+            orders: IndexMap::new(),
+            positions: IndexMap::new(),
+            quotes: HashMap::new(),
+            trades: HashMap::new(),
+            books: HashMap::new(),
+            index: CacheIndex::default(),
+        };
+
+        if cache.config.flush_on_start {
+            if let Some(database) = cache.database.as_mut() {
+                database.flushdb()?;
+            }
+        }
+
+        Ok(cache)
+    }
+
+    /// Loads the instruments, accounts, orders and positions from the database,
+    /// replacing the cached state and rebuilding the indexes.
+    pub fn load(&mut self) -> Result<()> {
+        let Some(database) = self.database.as_mut() else {
+            bail!("No database to load from");
+        };
+
+        let state = CacheState::load(database.as_mut())?;
+
+        self.clear();
+        self.instruments = state.instruments;
+        for account in state.accounts.into_values() {
+            self.index_account(&account);
+            self.accounts.insert(account.id(), account);
+        }
+        let mut orders: Vec<_> = state.orders.into_values().collect();
+        orders.sort_by_key(|order| order.client_order_id());
+        for order in orders {
+            self.index_order(&order);
+            self.orders.insert(order.client_order_id(), order);
+        }
+        let mut positions: Vec<_> = state.positions.into_values().collect();
+        positions.sort_by_key(|position| position.id);
+        for position in positions {
+            self.index_position(&position);
+            self.positions.insert(position.id, position);
+        }
+
+        Ok(())
+    }
+
+    /// Clears all cached state (the database is not modified).
+    pub fn clear(&mut self) {
+        self.instruments.clear();
+        self.accounts.clear();
+        self.orders.clear();
+        self.positions.clear();
+        self.quotes.clear();
+        self.trades.clear();
+        self.books.clear();
+        self.index = CacheIndex::default();
+    }
+
+    // -- COMMANDS --------------------------------------------------------------------------------
+
+    /// Adds the given `instrument`, replacing any instrument with the same ID.
+    pub fn add_instrument(&mut self, instrument: InstrumentAny) -> Result<()> {
+        let key = format!("{INSTRUMENTS}{DELIMITER}{}", instrument.id());
+        let payload = vec![encode_payload(&self.encoding, &instrument)?];
+        self.write(&key, payload, false)?;
+        self.instruments.insert(instrument.id(), instrument);
+        Ok(())
+    }
+
+    /// Adds the given `account`, which must not already be cached.
+    pub fn add_account(&mut self, account: AccountAny) -> Result<()> {
+        let account_id = account.id();
+        if self.accounts.contains_key(&account_id) {
+            bail!("Account {account_id} already exists");
+        }
+
+        let key = format!("{ACCOUNTS}{DELIMITER}{account_id}");
+        let payload = account
+            .events()
+            .iter()
+            .map(|value| encode_payload(&self.encoding, &value))
+            .collect::<Result<Vec<_>>>()?;
+        self.write(&key, payload, false)?;
+
+        self.index_account(&account);
+        self.accounts.insert(account_id, account);
+        Ok(())
+    }
+
+    /// Applies the given account `event` to its cached account.
+    pub fn update_account(&mut self, event: AccountState) -> Result<()> {
+        let account_id = event.account_id;
+        let Some(account) = self.accounts.get_mut(&account_id) else {
+            bail!("Account {account_id} not found");
+        };
+
+        let payload = vec![encode_payload(&self.encoding, &event)?];
+        account.apply(event);
+
+        let key = format!("{ACCOUNTS}{DELIMITER}{account_id}");
+        self.write(&key, payload, true)
+    }
+
+    /// Adds the given `order`, which must not already be cached, with the ID of the
+    /// position it is for (if known).
+    pub fn add_order(&mut self, order: OrderAny, position_id: Option<PositionId>) -> Result<()> {
+        let client_order_id = order.client_order_id();
+        if self.orders.contains_key(&client_order_id) {
+            bail!("Order {client_order_id} already exists");
+        }
+
+        let key = format!("{ORDERS}{DELIMITER}{client_order_id}");
+        let payload = order
+            .events()
+            .into_iter()
+            .map(|value| encode_payload(&self.encoding, &value))
+            .collect::<Result<Vec<_>>>()?;
+        self.write(&key, payload, false)?;
+
+        self.index_order(&order);
+        if let Some(position_id) = position_id {
+            self.index
+                .order_position
+                .insert(client_order_id, position_id);
+        }
+        self.orders.insert(client_order_id, order);
+        Ok(())
+    }
+
+    /// Replaces the cached order with the given `order`, writing through the events
+    /// applied to it since it was last cached.
+    pub fn update_order(&mut self, order: OrderAny) -> Result<()> {
+        let client_order_id = order.client_order_id();
+        let Some(cached) = self.orders.get(&client_order_id) else {
+            bail!("Order {client_order_id} not found");
+        };
+
+        let key = format!("{ORDERS}{DELIMITER}{client_order_id}");
+        let payload = order
+            .events()
+            .into_iter()
+            .skip(cached.events().len())
+            .map(|value| encode_payload(&self.encoding, &value))
+            .collect::<Result<Vec<_>>>()?;
+        self.write(&key, payload, true)?;
+
+        self.index_order(&order);
+        self.orders.insert(client_order_id, order);
+        Ok(())
+    }
+
+    /// Adds the given `position`, which must not already be cached.
+    pub fn add_position(&mut self, position: Position) -> Result<()> {
+        let position_id = position.id;
+        if self.positions.contains_key(&position_id) {
+            bail!("Position {position_id} already exists");
+        }
+
+        let key = format!("{POSITIONS}{DELIMITER}{position_id}");
+        let payload = position
+            .events
+            .iter()
+            .map(|value| encode_payload(&self.encoding, &value))
+            .collect::<Result<Vec<_>>>()?;
+        self.write(&key, payload, false)?;
+
+        self.index_position(&position);
+        self.positions.insert(position_id, position);
+        Ok(())
+    }
+
+    /// Replaces the cached position with the given `position`, writing through the fills
+    /// applied to it since it was last cached.
+    pub fn update_position(&mut self, position: Position) -> Result<()> {
+        let position_id = position.id;
+        let Some(cached) = self.positions.get(&position_id) else {
+            bail!("Position {position_id} not found");
+        };
+
+        // A flat position clears its fills when reopened, so match them by trade ID
+        let key = format!("{POSITIONS}{DELIMITER}{position_id}");
+        let payload = position
+            .events
+            .iter()
+            .filter(|fill| !cached.trade_ids.contains(&fill.trade_id))
+            .map(|value| encode_payload(&self.encoding, &value))
+            .collect::<Result<Vec<_>>>()?;
+        self.write(&key, payload, true)?;
+
+        self.index_position(&position);
+        self.positions.insert(position_id, position);
+        Ok(())
+    }
+
+    pub fn add_quote(&mut self, quote: QuoteTick) {
+        let quotes = self.quotes.entry(quote.instrument_id).or_default();
+        quotes.push_front(quote);
+        quotes.truncate(self.config.tick_capacity);
+    }
+
+    pub fn add_trade(&mut self, trade: TradeTick) {
+        let trades = self.trades.entry(trade.instrument_id).or_default();
+        trades.push_front(trade);
+        trades.truncate(self.config.tick_capacity);
+    }
+
+    /// Adds the given order `book`, replacing any book for the same instrument.
+    pub fn add_order_book(&mut self, book: OrderBookAny) {
+        self.books.insert(book.instrument_id(), book);
+    }
+
+    // -- QUERIES ---------------------------------------------------------------------------------
+
+    #[must_use]
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<&InstrumentAny> {
+        self.instruments.get(instrument_id)
+    }
+
+    /// Returns the instruments, optionally filtered by `venue`.
+    #[must_use]
+    pub fn instruments(&self, venue: Option<&Venue>) -> Vec<&InstrumentAny> {
+        self.instruments
+            .values()
+            .filter(|instrument| venue.map_or(true, |venue| instrument.venue() == *venue))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn account(&self, account_id: &AccountId) -> Option<&AccountAny> {
+        self.accounts.get(account_id)
+    }
+
+    #[must_use]
+    pub fn account_for_venue(&self, venue: &Venue) -> Option<&AccountAny> {
+        self.index
+            .venue_account
+            .get(venue)
+            .and_then(|account_id| self.accounts.get(account_id))
+    }
+
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<&OrderAny> {
+        self.orders.get(client_order_id)
+    }
+
+    /// Returns the orders matching all the given filters, in the order they were added.
+    #[must_use]
+    pub fn orders(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+    ) -> Vec<&OrderAny> {
+        let ids = self.order_ids(venue, instrument_id, strategy_id);
+        self.get_orders(ids)
+    }
+
+    /// Returns the open orders matching all the given filters, in the order they were added.
+    #[must_use]
+    pub fn orders_open(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+    ) -> Vec<&OrderAny> {
+        let ids = self.order_ids(venue, instrument_id, strategy_id);
+        self.get_orders(ids.intersection(&self.index.orders_open).copied().collect())
+    }
+
+    /// Returns the closed orders matching all the given filters, in the order they were added.
+    #[must_use]
+    pub fn orders_closed(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+    ) -> Vec<&OrderAny> {
+        let ids = self.order_ids(venue, instrument_id, strategy_id);
+        self.get_orders(
+            ids.intersection(&self.index.orders_closed)
+                .copied()
+                .collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn position(&self, position_id: &PositionId) -> Option<&Position> {
+        self.positions.get(position_id)
+    }
+
+    #[must_use]
+    pub fn position_for_order(&self, client_order_id: &ClientOrderId) -> Option<&Position> {
+        self.index
+            .order_position
+            .get(client_order_id)
+            .and_then(|position_id| self.positions.get(position_id))
+    }
+
+    /// Returns the positions matching all the given filters, in the order they were added.
+    #[must_use]
+    pub fn positions(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+    ) -> Vec<&Position> {
+        let ids = self.position_ids(venue, instrument_id, strategy_id);
+        self.get_positions(ids)
+    }
+
+    /// Returns the open positions matching all the given filters, in the order they were added.
+    #[must_use]
+    pub fn positions_open(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+    ) -> Vec<&Position> {
+        let ids = self.position_ids(venue, instrument_id, strategy_id);
+        self.get_positions(
+            ids.intersection(&self.index.positions_open)
+                .copied()
+                .collect(),
+        )
+    }
+
+    /// Returns the closed positions matching all the given filters, in the order they were added.
+    #[must_use]
+    pub fn positions_closed(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+    ) -> Vec<&Position> {
+        let ids = self.position_ids(venue, instrument_id, strategy_id);
+        self.get_positions(
+            ids.intersection(&self.index.positions_closed)
+                .copied()
+                .collect(),
+        )
+    }
+
+    /// Returns the latest quote for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn quote(&self, instrument_id: &InstrumentId) -> Option<&QuoteTick> {
+        self.quotes.get(instrument_id).and_then(VecDeque::front)
+    }
+
+    /// Returns the cached quotes for the given `instrument_id`, latest first.
+    #[must_use]
+    pub fn quotes(&self, instrument_id: &InstrumentId) -> Vec<QuoteTick> {
+        self.quotes
+            .get(instrument_id)
+            .map(|quotes| quotes.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the latest trade for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn trade(&self, instrument_id: &InstrumentId) -> Option<&TradeTick> {
+        self.trades.get(instrument_id).and_then(VecDeque::front)
+    }
+
+    /// Returns the cached trades for the given `instrument_id`, latest first.
+    #[must_use]
+    pub fn trades(&self, instrument_id: &InstrumentId) -> Vec<TradeTick> {
+        self.trades
+            .get(instrument_id)
+            .map(|trades| trades.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn order_book(&self, instrument_id: &InstrumentId) -> Option<&OrderBookAny> {
+        self.books.get(instrument_id)
+    }
+
+    pub fn order_book_mut(&mut self, instrument_id: &InstrumentId) -> Option<&mut OrderBookAny> {
+        self.books.get_mut(instrument_id)
+    }
+
+    // -- INTERNAL --------------------------------------------------------------------------------
+
+    fn write(&mut self, key: &str, payload: Vec<Vec<u8>>, update: bool) -> Result<()> {
+        let Some(database) = self.database.as_mut() else {
+            return Ok(());
+        };
+
+        if update {
+            for value in payload {
+                database.update(key.to_string(), Some(vec![value]))?;
+            }
+            return Ok(());
+        }
+
+        // Database list collections store one value per insert, later values are appended
+        let mut iter = payload.into_iter();
+        if let Some(first) = iter.next() {
+            database.insert(key.to_string(), Some(vec![first]))?;
+        }
+        for value in iter {
+            database.update(key.to_string(), Some(vec![value]))?;
+        }
+        Ok(())
+    }
+
+    fn index_account(&mut self, account: &AccountAny) {
+        let venue = Venue::from(account.id().get_issuer().as_str());
+        self.index.venue_account.insert(venue, account.id());
+    }
+
+    fn index_order(&mut self, order: &OrderAny) {
+        let client_order_id = order.client_order_id();
+        let instrument_id = order.instrument_id();
+        let index = &mut self.index;
+        index
+            .venue_orders
+            .entry(instrument_id.venue)
+            .or_default()
+            .insert(client_order_id);
+        index
+            .instrument_orders
+            .entry(instrument_id)
+            .or_default()
+            .insert(client_order_id);
+        index
+            .strategy_orders
+            .entry(order.strategy_id())
+            .or_default()
+            .insert(client_order_id);
+        if let Some(position_id) = order.position_id() {
+            index.order_position.insert(client_order_id, position_id);
+        }
+
+        index.orders_open.remove(&client_order_id);
+        index.orders_closed.remove(&client_order_id);
+        if order.is_open() {
+            index.orders_open.insert(client_order_id);
+        } else if order.is_closed() {
+            index.orders_closed.insert(client_order_id);
+        }
+    }
+
+    fn index_position(&mut self, position: &Position) {
+        let position_id = position.id;
+        let index = &mut self.index;
+        index
+            .venue_positions
+            .entry(position.instrument_id.venue)
+            .or_default()
+            .insert(position_id);
+        index
+            .instrument_positions
+            .entry(position.instrument_id)
+            .or_default()
+            .insert(position_id);
+        index
+            .strategy_positions
+            .entry(position.strategy_id)
+            .or_default()
+            .insert(position_id);
+        for client_order_id in position.client_order_ids() {
+            index.order_position.insert(client_order_id, position_id);
+        }
+
+        if position.is_open() {
+            index.positions_closed.remove(&position_id);
+            index.positions_open.insert(position_id);
+        } else {
+            index.positions_open.remove(&position_id);
+            index.positions_closed.insert(position_id);
+        }
+    }
+
+    fn order_ids(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+    ) -> HashSet<ClientOrderId> {
+        let filters = [
+            venue.map(|venue| self.index.venue_orders.get(venue)),
+            instrument_id.map(|instrument_id| self.index.instrument_orders.get(instrument_id)),
+            strategy_id.map(|strategy_id| self.index.strategy_orders.get(strategy_id)),
+        ];
+        intersect(self.orders.keys(), &filters)
+    }
+
+    fn position_ids(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+    ) -> HashSet<PositionId> {
+        let filters = [
+            venue.map(|venue| self.index.venue_positions.get(venue)),
+            instrument_id.map(|instrument_id| self.index.instrument_positions.get(instrument_id)),
+            strategy_id.map(|strategy_id| self.index.strategy_positions.get(strategy_id)),
+        ];
+        intersect(self.positions.keys(), &filters)
+    }
+
+    fn get_orders(&self, ids: HashSet<ClientOrderId>) -> Vec<&OrderAny> {
+        let mut orders: Vec<_> = ids
+            .iter()
+            .filter_map(|id| self.orders.get_full(id))
+            .collect();
+        orders.sort_unstable_by_key(|(index, _, _)| *index);
+        orders.into_iter().map(|(_, _, order)| order).collect()
+    }
+
+    fn get_positions(&self, ids: HashSet<PositionId>) -> Vec<&Position> {
+        let mut positions: Vec<_> = ids
+            .iter()
+            .filter_map(|id| self.positions.get_full(id))
+            .collect();
+        positions.sort_unstable_by_key(|(index, _, _)| *index);
+        positions
+            .into_iter()
+            .map(|(_, _, position)| position)
+            .collect()
+    }
+}
+
+/// Returns the IDs in all the given index `filters`, where a `None` filter matches
+/// all IDs, and a filter with no index entry matches none.
+fn intersect<'a, T>(
+    all: impl Iterator<Item = &'a T>,
+    filters: &[Option<Option<&HashSet<T>>>],
+) -> HashSet<T>
+where
+    T: Copy + Eq + std::hash::Hash + 'a,
+{
+    let mut ids: Option<HashSet<T>> = None;
+    for filter in filters.iter().flatten() {
+        let Some(filter) = filter else {
+            return HashSet::new();
+        };
+        ids = Some(match ids {
+            Some(ids) => ids.intersection(filter).copied().collect(),
+            None => (*filter).clone(),
+        });
+    }
+    ids.unwrap_or_else(|| all.copied().collect())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, sync::mpsc::Receiver};
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        enums::OrderType,
+        events::{
+            account::stubs::cash_account_state_million_usd,
+            order::{
+                accepted::OrderAcceptedBuilder, canceled::OrderCanceledBuilder, event::OrderEvent,
+                initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            },
+        },
+        identifiers::trader_id::TraderId,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        stubs::test_position_long,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::cache::DatabaseCommand;
+
+    type Store = Rc<RefCell<HashMap<String, Vec<Vec<u8>>>>>;
+
+    /// A list-based database which prefixes keys like the Redis database.
+    struct TestDatabase {
+        store: Store,
+        encoding: &'static str,
+    }
+
+    impl CacheDatabase for TestDatabase {
+        fn new(
+            _trader_id: TraderId,
+            _instance_id: UUID4,
+            _config: HashMap<String, serde_json::Value>,
+        ) -> Result<Self> {
+            Ok(Self {
+                store: Store::default(),
+                encoding: DEFAULT_ENCODING,
+            })
+        }
+
+        fn trader_key(&self) -> String {
+            "trader-TESTER-001".to_string()
+        }
+
+        fn flushdb(&mut self) -> Result<()> {
+            self.store.borrow_mut().clear();
+            Ok(())
+        }
+
+        fn keys(&mut self, pattern: &str) -> Result<Vec<String>> {
+            let prefix = pattern.trim_end_matches('*');
+            Ok(self
+                .store
+                .borrow()
+                .keys()
+                .map(|key| format!("{}{DELIMITER}{key}", self.trader_key()))
+                .filter(|key| key.starts_with(prefix))
+                .collect())
+        }
+
+        fn read(&mut self, key: &str) -> Result<Vec<Vec<u8>>> {
+            Ok(self.store.borrow().get(key).cloned().unwrap_or_default())
+        }
+
+        fn insert(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+            self.store
+                .borrow_mut()
+                .insert(key, payload.unwrap_or_default());
+            Ok(())
+        }
+
+        fn update(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+            let mut store = self.store.borrow_mut();
+            let Some(values) = store.get_mut(&key) else {
+                bail!("Key {key} not found");
+            };
+            values.extend(payload.unwrap_or_default());
+            Ok(())
+        }
+
+        fn delete(&mut self, key: String, _payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+            self.store.borrow_mut().remove(&key);
+            Ok(())
+        }
+
+        fn encoding(&self) -> &str {
+            self.encoding
+        }
+
+        fn handle_messages(
+            _rx: Receiver<DatabaseCommand>,
+            _trader_key: String,
+            _config: HashMap<String, serde_json::Value>,
+        ) {
+        }
+    }
+
+    fn cache_with_store(store: &Store, encoding: &'static str) -> Cache {
+        let database = TestDatabase {
+            store: store.clone(),
+            encoding,
+        };
+        Cache::new(CacheConfig::default(), Some(Box::new(database))).unwrap()
+    }
+
+    fn order(client_order_id: &str, instrument_id: &str, strategy_id: &str) -> OrderAny {
+        let init = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .instrument_id(InstrumentId::from(instrument_id))
+            .strategy_id(StrategyId::from(strategy_id))
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap();
+        OrderAny::from(init)
+    }
+
+    fn accept(order: &mut OrderAny) {
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(order.client_order_id())
+            .strategy_id(order.strategy_id())
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .client_order_id(order.client_order_id())
+            .strategy_id(order.strategy_id())
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+    }
+
+    #[rstest]
+    fn test_orders_queries_with_filters() {
+        let mut cache = Cache::new(CacheConfig::default(), None).unwrap();
+        let mut order1 = order("O-1", "AUD/USD.SIM", "S-001");
+        let order2 = order("O-2", "ETHUSDT.BINANCE", "S-001");
+        let order3 = order("O-3", "AUD/USD.SIM", "S-002");
+        accept(&mut order1);
+
+        cache.add_order(order1, None).unwrap();
+        cache.add_order(order2, None).unwrap();
+        cache.add_order(order3, None).unwrap();
+
+        let ids = |orders: Vec<&OrderAny>| {
+            orders
+                .iter()
+                .map(|order| order.client_order_id().to_string())
+                .collect::<Vec<_>>()
+        };
+        let venue = Venue::from("SIM");
+        let strategy_id = StrategyId::from("S-001");
+
+        assert_eq!(ids(cache.orders(None, None, None)), ["O-1", "O-2", "O-3"]);
+        assert_eq!(ids(cache.orders(Some(&venue), None, None)), ["O-1", "O-3"]);
+        assert_eq!(
+            ids(cache.orders(Some(&venue), None, Some(&strategy_id))),
+            ["O-1"]
+        );
+        assert_eq!(ids(cache.orders_open(None, None, None)), ["O-1"]);
+        assert!(cache
+            .orders(Some(&Venue::from("XNAS")), None, None)
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_update_order_moves_between_open_and_closed() {
+        let mut cache = Cache::new(CacheConfig::default(), None).unwrap();
+        let mut order = order("O-1", "AUD/USD.SIM", "S-001");
+        accept(&mut order);
+        cache.add_order(order.clone(), None).unwrap();
+        assert_eq!(cache.orders_open(None, None, None).len(), 1);
+
+        let canceled = OrderCanceledBuilder::default()
+            .client_order_id(order.client_order_id())
+            .strategy_id(order.strategy_id())
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderCanceled(canceled)).unwrap();
+        cache.update_order(order).unwrap();
+
+        assert!(cache.orders_open(None, None, None).is_empty());
+        assert_eq!(cache.orders_closed(None, None, None).len(), 1);
+    }
+
+    #[rstest]
+    fn test_add_order_when_already_exists() {
+        let mut cache = Cache::new(CacheConfig::default(), None).unwrap();
+        cache
+            .add_order(order("O-1", "AUD/USD.SIM", "S-001"), None)
+            .unwrap();
+
+        let result = cache.add_order(order("O-1", "AUD/USD.SIM", "S-001"), None);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_quotes_are_bounded_by_capacity(audusd_sim: CurrencyPair) {
+        let config = CacheConfig {
+            tick_capacity: 2,
+            ..Default::default()
+        };
+        let mut cache = Cache::new(config, None).unwrap();
+        for ts in 1..=3 {
+            let quote = QuoteTick::new(
+                audusd_sim.id,
+                Price::from("1.00000"),
+                Price::from("1.00001"),
+                Quantity::from(1),
+                Quantity::from(1),
+                ts,
+                ts,
+            )
+            .unwrap();
+            cache.add_quote(quote);
+        }
+
+        let quotes = cache.quotes(&audusd_sim.id);
+
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].ts_event, 3);
+        assert_eq!(cache.quote(&audusd_sim.id).unwrap().ts_event, 3);
+    }
+
+    #[rstest]
+    #[case("msgpack")]
+    #[case("json")]
+    fn test_write_through_and_load(
+        audusd_sim: CurrencyPair,
+        test_position_long: Position,
+        #[case] encoding: &'static str,
+    ) {
+        let store = Store::default();
+        let mut cache = cache_with_store(&store, encoding);
+        let account = AccountAny::from_events(vec![cash_account_state_million_usd()]).unwrap();
+        let mut order = order("O-1", "AUD/USD.SIM", "S-001");
+
+        cache
+            .add_instrument(InstrumentAny::from(audusd_sim))
+            .unwrap();
+        cache.add_account(account).unwrap();
+        cache.add_order(order.clone(), None).unwrap();
+        accept(&mut order);
+        cache.update_order(order).unwrap();
+        cache.add_position(test_position_long.clone()).unwrap();
+
+        let mut loaded = cache_with_store(&store, encoding);
+        loaded.load().unwrap();
+
+        let venue = Venue::from("SIM");
+        assert!(loaded.instrument(&audusd_sim.id).is_some());
+        assert!(loaded.account_for_venue(&venue).is_some());
+        let order = loaded.order(&ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(order.events().len(), 3);
+        assert_eq!(loaded.orders_open(Some(&venue), None, None).len(), 1);
+        assert_eq!(
+            loaded
+                .positions_open(None, Some(&audusd_sim.id), None)
+                .len(),
+            1
+        );
+        assert!(loaded.position(&test_position_long.id).is_some());
+    }
+
+    #[rstest]
+    fn test_update_order_writes_all_new_events() {
+        let store = Store::default();
+        let mut cache = cache_with_store(&store, "json");
+        let mut order = order("O-1", "AUD/USD.SIM", "S-001");
+        cache.add_order(order.clone(), None).unwrap();
+
+        accept(&mut order);
+        cache.update_order(order.clone()).unwrap();
+        cache.update_order(order).unwrap();
+
+        assert_eq!(store.borrow()["orders:O-1"].len(), 3);
+    }
+
+    #[rstest]
+    fn test_update_position_writes_only_new_fills(test_position_long: Position) {
+        let store = Store::default();
+        let mut cache = cache_with_store(&store, "json");
+        let key = format!("{POSITIONS}{DELIMITER}{}", test_position_long.id);
+        cache.add_position(test_position_long.clone()).unwrap();
+
+        cache.update_position(test_position_long.clone()).unwrap();
+        cache.update_position(test_position_long.clone()).unwrap();
+
+        assert_eq!(store.borrow()[&key].len(), test_position_long.events.len());
+    }
+
+    #[rstest]
+    fn test_load_without_database() {
+        let mut cache = Cache::new(CacheConfig::default(), None).unwrap();
+
+        assert!(cache.load().is_err());
+    }
+}
@@ -0,0 +1,272 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use nautilus_accounting::account::any::AccountAny;
+use nautilus_model::{
+    events::{
+        account::state::AccountState,
+        order::{event::OrderEvent, filled::OrderFilled},
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId,
+    },
+    instruments::any::InstrumentAny,
+    orders::any::OrderAny,
+    position::Position,
+};
+
+use super::{decode_payload, CacheDatabase, ACCOUNTS, DELIMITER, INSTRUMENTS, ORDERS, POSITIONS};
+
+/// An inconsistency found between the objects of a reloaded [`CacheState`].
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum CacheIntegrityError {
+    #[error("Order {client_order_id} references instrument {instrument_id} which was not found")]
+    OrderInstrumentNotFound {
+        client_order_id: ClientOrderId,
+        instrument_id: InstrumentId,
+    },
+    #[error("Order {client_order_id} references account {account_id} which was not found")]
+    OrderAccountNotFound {
+        client_order_id: ClientOrderId,
+        account_id: AccountId,
+    },
+    #[error("Order {client_order_id} references position {position_id} which was not found")]
+    OrderPositionNotFound {
+        client_order_id: ClientOrderId,
+        position_id: PositionId,
+    },
+    #[error("Position {position_id} references order {client_order_id} which was not found")]
+    PositionOrderNotFound {
+        position_id: PositionId,
+        client_order_id: ClientOrderId,
+    },
+}
+
+/// The trading state reloaded from a cache database.
+#[derive(Debug, Default)]
+pub struct CacheState {
+    pub instruments: HashMap<InstrumentId, InstrumentAny>,
+    pub accounts: HashMap<AccountId, AccountAny>,
+    pub orders: HashMap<ClientOrderId, OrderAny>,
+    pub positions: HashMap<PositionId, Position>,
+}
+
+impl CacheState {
+    /// Loads the state written through to the given `database` by a
+    /// [`Cache`](super::memory::Cache), decoding each payload with the database encoding.
+    ///
+    /// Accounts, orders and positions are rebuilt from their events, so positions
+    /// are loaded after the instruments they are for.
+    pub fn load(database: &mut dyn CacheDatabase) -> Result<Self> {
+        let encoding = database.encoding().to_string();
+        let mut state = Self::default();
+
+        for key in collection_keys(database, INSTRUMENTS)? {
+            let payload = database.read(&key)?;
+            let instrument: InstrumentAny =
+                decode_payload(&encoding, last_payload(&key, &payload)?)
+                    .map_err(|e| anyhow!("Failed to load '{key}': {e}"))?;
+            state.instruments.insert(instrument.id(), instrument);
+        }
+
+        for key in collection_keys(database, ACCOUNTS)? {
+            let account = database
+                .read(&key)?
+                .iter()
+                .map(|payload| decode_payload::<AccountState>(&encoding, payload))
+                .collect::<Result<Vec<_>>>()
+                .and_then(AccountAny::from_events)
+                .map_err(|e| anyhow!("Failed to load '{key}': {e}"))?;
+            state.accounts.insert(account.id(), account);
+        }
+
+        for key in collection_keys(database, ORDERS)? {
+            let order = database
+                .read(&key)?
+                .iter()
+                .map(|payload| decode_payload::<OrderEvent>(&encoding, payload))
+                .collect::<Result<Vec<_>>>()
+                .and_then(|events| OrderAny::from_events(events).map_err(Into::into))
+                .map_err(|e| anyhow!("Failed to load '{key}': {e}"))?;
+            state.orders.insert(order.client_order_id(), order);
+        }
+
+        for key in collection_keys(database, POSITIONS)? {
+            let fills = database
+                .read(&key)?
+                .iter()
+                .map(|payload| decode_payload::<OrderFilled>(&encoding, payload))
+                .collect::<Result<Vec<_>>>()
+                .map_err(|e| anyhow!("Failed to load '{key}': {e}"))?;
+            let Some((first, rest)) = fills.split_first() else {
+                bail!("No payload for {key}");
+            };
+            let Some(instrument) = state.instruments.get(&first.instrument_id) else {
+                bail!(
+                    "Failed to load '{key}': instrument {} not found",
+                    first.instrument_id
+                );
+            };
+            let mut position = Position::new(&**instrument, *first)?;
+            for fill in rest {
+                position.apply(fill);
+            }
+            state.positions.insert(position.id, position);
+        }
+
+        Ok(state)
+    }
+
+    /// Checks the state for orphaned orders and positions, returning every issue found.
+    ///
+    /// An order is orphaned when its instrument, account or position is missing, and
+    /// a position is orphaned when any of the orders which filled it are missing.
+    #[must_use]
+    pub fn check_integrity(&self) -> Vec<CacheIntegrityError> {
+        let mut errors = Vec::new();
+
+        for (client_order_id, order) in &self.orders {
+            let instrument_id = order.instrument_id();
+            if !self.instruments.contains_key(&instrument_id) {
+                errors.push(CacheIntegrityError::OrderInstrumentNotFound {
+                    client_order_id: *client_order_id,
+                    instrument_id,
+                });
+            }
+            if let Some(account_id) = order.account_id() {
+                if !self.accounts.contains_key(&account_id) {
+                    errors.push(CacheIntegrityError::OrderAccountNotFound {
+                        client_order_id: *client_order_id,
+                        account_id,
+                    });
+                }
+            }
+            if let Some(position_id) = order.position_id() {
+                if !self.positions.contains_key(&position_id) {
+                    errors.push(CacheIntegrityError::OrderPositionNotFound {
+                        client_order_id: *client_order_id,
+                        position_id,
+                    });
+                }
+            }
+        }
+
+        for (position_id, position) in &self.positions {
+            for client_order_id in position.client_order_ids() {
+                if !self.orders.contains_key(&client_order_id) {
+                    errors.push(CacheIntegrityError::PositionOrderNotFound {
+                        position_id: *position_id,
+                        client_order_id,
+                    });
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+/// Returns the keys of the given `collection` for the database trader, without the
+/// trader key prefix.
+fn collection_keys(database: &mut dyn CacheDatabase, collection: &str) -> Result<Vec<String>> {
+    let prefix = format!("{}{DELIMITER}", database.trader_key());
+    let mut keys: Vec<String> = database
+        .keys(&format!("{prefix}{collection}{DELIMITER}*"))?
+        .into_iter()
+        .filter_map(|key| key.strip_prefix(&prefix).map(str::to_string))
+        .collect();
+    keys.sort();
+    keys.dedup();
+    Ok(keys)
+}
+
+fn last_payload<'a>(key: &str, payload: &'a [Vec<u8>]) -> Result<&'a [u8]> {
+    match payload.last() {
+        Some(value) => Ok(value),
+        None => bail!("No payload for {key}"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        events::order::initialized::OrderInitializedBuilder,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        stubs::test_position_long,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_check_integrity_when_consistent(
+        audusd_sim: CurrencyPair,
+        test_position_long: Position,
+    ) {
+        let mut state = CacheState::default();
+        state
+            .instruments
+            .insert(audusd_sim.id, InstrumentAny::from(audusd_sim));
+        for client_order_id in test_position_long.client_order_ids() {
+            let init = OrderInitializedBuilder::default()
+                .client_order_id(client_order_id)
+                .instrument_id(audusd_sim.id)
+                .build()
+                .unwrap();
+            state.orders.insert(client_order_id, OrderAny::from(init));
+        }
+        state
+            .positions
+            .insert(test_position_long.id, test_position_long);
+
+        assert!(state.check_integrity().is_empty());
+    }
+
+    #[rstest]
+    fn test_check_integrity_flags_orphans(test_position_long: Position) {
+        let mut state = CacheState::default();
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let order = OrderAny::from(init);
+        let client_order_id = order.client_order_id();
+        let instrument_id = order.instrument_id();
+        state.orders.insert(client_order_id, order);
+        let opening_order_id = test_position_long.opening_order_id;
+        let position_id = test_position_long.id;
+        state.positions.insert(position_id, test_position_long);
+
+        let errors = state.check_integrity();
+
+        assert_eq!(
+            errors,
+            vec![
+                CacheIntegrityError::OrderInstrumentNotFound {
+                    client_order_id,
+                    instrument_id,
+                },
+                CacheIntegrityError::PositionOrderNotFound {
+                    position_id,
+                    client_order_id: opening_order_id,
+                },
+            ]
+        );
+    }
+}
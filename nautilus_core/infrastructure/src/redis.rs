@@ -28,7 +28,9 @@ use pyo3::prelude::*;
 use redis::{Commands, Connection, Pipeline};
 use serde_json::json;
 
-use crate::cache::{CacheDatabase, DatabaseCommand, DatabaseOperation};
+use crate::cache::{
+    get_encoding, state::CacheState, CacheDatabase, DatabaseCommand, DatabaseOperation,
+};
 
 // Error constants
 const CHANNEL_TX_FAILED: &str = "Failed to send to channel";
@@ -71,13 +73,12 @@ const INDEX_POSITIONS_CLOSED: &str = "index:positions_closed";
 pub struct RedisCacheDatabase {
     pub trader_id: TraderId,
     trader_key: String,
+    encoding: String,
     conn: Connection,
     tx: Sender<DatabaseCommand>,
 }
 
 impl CacheDatabase for RedisCacheDatabase {
-    type DatabaseType = RedisCacheDatabase;
-
     fn new(
         trader_id: TraderId,
         instance_id: UUID4,
@@ -90,6 +91,7 @@ impl CacheDatabase for RedisCacheDatabase {
         let (tx, rx) = channel::<DatabaseCommand>();
         let trader_key = get_trader_key(trader_id, instance_id, &config);
         let trader_key_clone = trader_key.clone();
+        let encoding = get_encoding(&config);
 
         thread::spawn(move || {
             Self::handle_messages(rx, trader_key_clone, config);
@@ -98,11 +100,16 @@ impl CacheDatabase for RedisCacheDatabase {
         Ok(RedisCacheDatabase {
             trader_id,
             trader_key,
+            encoding,
             conn,
             tx,
         })
     }
 
+    fn trader_key(&self) -> String {
+        self.trader_key.clone()
+    }
+
     fn flushdb(&mut self) -> Result<()> {
         match redis::cmd(FLUSHDB).query::<()>(&mut self.conn) {
             Ok(_) => Ok(()),
@@ -160,6 +167,10 @@ impl CacheDatabase for RedisCacheDatabase {
         }
     }
 
+    fn encoding(&self) -> &str {
+        &self.encoding
+    }
+
     fn handle_messages(
         rx: Receiver<DatabaseCommand>,
         trader_key: String,
@@ -196,6 +207,17 @@ impl CacheDatabase for RedisCacheDatabase {
     }
 }
 
+impl RedisCacheDatabase {
+    /// Loads the full trading state written through by a
+    /// [`Cache`](crate::cache::memory::Cache), decoding each payload with the configured
+    /// encoding.
+    ///
+    /// Use [`CacheState::check_integrity`] to find orphaned orders and positions.
+    pub fn load_state(&mut self) -> Result<CacheState> {
+        CacheState::load(self)
+    }
+}
+
 fn drain_buffer(conn: &mut Connection, trader_key: &str, buffer: &mut VecDeque<DatabaseCommand>) {
     let mut pipe = redis::pipe();
     pipe.atomic();
@@ -553,31 +575,6 @@ fn get_index_key(key: &str) -> Result<&str> {
         .ok_or_else(|| anyhow!("Invalid `key`, missing a '{DELIMITER}' delimiter, was {key}"))
 }
 
-// This function can be used when we handle cache serialization in Rust
-#[allow(dead_code)]
-fn get_encoding(config: &HashMap<String, serde_json::Value>) -> String {
-    config
-        .get("encoding")
-        .and_then(|v| v.as_str())
-        .unwrap_or("msgpack")
-        .to_string()
-}
-
-// This function can be used when we handle cache serialization in Rust
-#[allow(dead_code)]
-fn deserialize_payload(
-    encoding: &str,
-    payload: &[u8],
-) -> Result<HashMap<String, serde_json::Value>> {
-    match encoding {
-        "msgpack" => rmp_serde::from_slice(payload)
-            .map_err(|e| anyhow!("Failed to deserialize msgpack `payload`: {e}")),
-        "json" => serde_json::from_slice(payload)
-            .map_err(|e| anyhow!("Failed to deserialize json `payload`: {e}")),
-        _ => Err(anyhow!("Unsupported encoding: {encoding}")),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
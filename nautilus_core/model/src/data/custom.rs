@@ -0,0 +1,165 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{any::Any, fmt::Debug, sync::Arc};
+
+use nautilus_core::time::UnixNanos;
+
+use super::HasTsInit;
+use crate::identifiers::instrument_id::InstrumentId;
+
+/// A user-defined data type (such as a signal, news item or funding rate) which can flow
+/// through the platform alongside market data.
+pub trait CustomDataTrait: HasTsInit + Debug + Send + Sync + 'static {
+    /// Returns the name the type is registered under (used to look up its codecs).
+    fn type_name(&self) -> &'static str;
+
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    fn ts_event(&self) -> UnixNanos;
+
+    /// Returns the instrument ID the data relates to, if any.
+    fn instrument_id(&self) -> Option<InstrumentId> {
+        None
+    }
+
+    /// Returns the data as `Any`, so it can be downcast to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Represents user-defined data carried by the [`Data`](super::Data) enum.
+///
+/// The data is shared behind a boxed `Arc`, keeping this a thin pointer which is cheap
+/// to clone.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct CustomData(Box<Arc<dyn CustomDataTrait>>);
+
+impl CustomData {
+    #[must_use]
+    pub fn new<T: CustomDataTrait>(data: T) -> Self {
+        Self(Box::new(Arc::new(data)))
+    }
+
+    /// Returns the registered type name of the data.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.0.type_name()
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        self.0.ts_event()
+    }
+
+    /// Returns the instrument ID the data relates to, if any.
+    #[must_use]
+    pub fn instrument_id(&self) -> Option<InstrumentId> {
+        self.0.instrument_id()
+    }
+
+    /// Returns a reference to the data if it is of type `T`.
+    #[must_use]
+    pub fn downcast_ref<T: CustomDataTrait>(&self) -> Option<&T> {
+        self.0.as_any().downcast_ref::<T>()
+    }
+}
+
+impl HasTsInit for CustomData {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.0.get_ts_init()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "stubs")]
+pub mod stubs {
+    use std::any::Any;
+
+    use nautilus_core::time::UnixNanos;
+
+    use super::CustomDataTrait;
+    use crate::{data::HasTsInit, identifiers::instrument_id::InstrumentId};
+
+    /// A custom data type for tests, representing a funding rate update.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct FundingRate {
+        pub instrument_id: InstrumentId,
+        pub rate: f64,
+        pub ts_event: UnixNanos,
+        pub ts_init: UnixNanos,
+    }
+
+    impl FundingRate {
+        pub const TYPE_NAME: &'static str = "FundingRate";
+    }
+
+    impl HasTsInit for FundingRate {
+        fn get_ts_init(&self) -> UnixNanos {
+            self.ts_init
+        }
+    }
+
+    impl CustomDataTrait for FundingRate {
+        fn type_name(&self) -> &'static str {
+            Self::TYPE_NAME
+        }
+
+        fn ts_event(&self) -> UnixNanos {
+            self.ts_event
+        }
+
+        fn instrument_id(&self) -> Option<InstrumentId> {
+            Some(self.instrument_id)
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{stubs::FundingRate, *};
+    use crate::data::Data;
+
+    #[rstest]
+    fn test_custom_data() {
+        let funding = FundingRate {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            rate: 0.0001,
+            ts_event: 1,
+            ts_init: 2,
+        };
+        let data = Data::from(CustomData::new(funding));
+
+        assert_eq!(data.get_ts_init(), 2);
+        let Data::Custom(custom) = data else {
+            panic!("Expected custom data");
+        };
+        assert_eq!(custom.type_name(), "FundingRate");
+        assert_eq!(custom.ts_event(), 1);
+        assert_eq!(custom.instrument_id(), Some(funding.instrument_id));
+        assert_eq!(custom.downcast_ref::<FundingRate>(), Some(&funding));
+    }
+}
@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod bar;
+pub mod custom;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
@@ -24,8 +25,8 @@ pub mod trade;
 use nautilus_core::time::UnixNanos;
 
 use self::{
-    bar::Bar, delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
-    quote::QuoteTick, trade::TradeTick,
+    bar::Bar, custom::CustomData, delta::OrderBookDelta, deltas::OrderBookDeltas,
+    depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
 };
 use crate::ffi::data::deltas::OrderBookDeltas_API;
 
@@ -39,6 +40,7 @@ pub enum Data {
     Quote(QuoteTick),
     Trade(TradeTick),
     Bar(Bar),
+    Custom(CustomData),
 }
 
 pub trait HasTsInit {
@@ -54,6 +56,7 @@ impl HasTsInit for Data {
             Self::Quote(q) => q.ts_init,
             Self::Trade(t) => t.ts_init,
             Self::Bar(b) => b.ts_init,
+            Self::Custom(c) => c.get_ts_init(),
         }
     }
 }
@@ -135,6 +138,12 @@ impl From<Bar> for Data {
     }
 }
 
+impl From<CustomData> for Data {
+    fn from(value: CustomData) -> Self {
+        Self::Custom(value)
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn data_clone(data: &Data) -> Data {
     data.clone()
@@ -16,7 +16,7 @@
 use std::fmt::{Display, Formatter};
 
 use anyhow::Result;
-use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_core::{serialization::Serializable, time::UnixNanos, uuid::UUID4};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -90,6 +90,8 @@ impl Display for AccountState {
     }
 }
 
+impl Serializable for AccountState {}
+
 impl PartialEq for AccountState {
     fn eq(&self, other: &Self) -> bool {
         self.account_id == other.account_id
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::time::UnixNanos;
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -118,4 +118,53 @@ impl OrderEvent {
             Self::OrderFilled(e) => e.ts_event,
         }
     }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::OrderInitialized(e) => e.ts_init,
+            Self::OrderDenied(e) => e.ts_init,
+            Self::OrderEmulated(e) => e.ts_init,
+            Self::OrderReleased(e) => e.ts_init,
+            Self::OrderSubmitted(e) => e.ts_init,
+            Self::OrderAccepted(e) => e.ts_init,
+            Self::OrderRejected(e) => e.ts_init,
+            Self::OrderCanceled(e) => e.ts_init,
+            Self::OrderExpired(e) => e.ts_init,
+            Self::OrderTriggered(e) => e.ts_init,
+            Self::OrderPendingUpdate(e) => e.ts_init,
+            Self::OrderPendingCancel(e) => e.ts_init,
+            Self::OrderModifyRejected(e) => e.ts_init,
+            Self::OrderCancelRejected(e) => e.ts_init,
+            Self::OrderUpdated(e) => e.ts_init,
+            Self::OrderPartiallyFilled(e) => e.ts_init,
+            Self::OrderFilled(e) => e.ts_init,
+        }
+    }
+
+    /// Sets the UNIX timestamp (nanoseconds) when the event was initialized, such as
+    /// when an event generated at a venue is received after some latency.
+    pub fn set_ts_init(&mut self, ts_init: UnixNanos) {
+        match self {
+            Self::OrderInitialized(e) => e.ts_init = ts_init,
+            Self::OrderDenied(e) => e.ts_init = ts_init,
+            Self::OrderEmulated(e) => e.ts_init = ts_init,
+            Self::OrderReleased(e) => e.ts_init = ts_init,
+            Self::OrderSubmitted(e) => e.ts_init = ts_init,
+            Self::OrderAccepted(e) => e.ts_init = ts_init,
+            Self::OrderRejected(e) => e.ts_init = ts_init,
+            Self::OrderCanceled(e) => e.ts_init = ts_init,
+            Self::OrderExpired(e) => e.ts_init = ts_init,
+            Self::OrderTriggered(e) => e.ts_init = ts_init,
+            Self::OrderPendingUpdate(e) => e.ts_init = ts_init,
+            Self::OrderPendingCancel(e) => e.ts_init = ts_init,
+            Self::OrderModifyRejected(e) => e.ts_init = ts_init,
+            Self::OrderCancelRejected(e) => e.ts_init = ts_init,
+            Self::OrderUpdated(e) => e.ts_init = ts_init,
+            Self::OrderPartiallyFilled(e) => e.ts_init = ts_init,
+            Self::OrderFilled(e) => e.ts_init = ts_init,
+        }
+    }
 }
+
+impl Serializable for OrderEvent {}
@@ -13,8 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use crate::events::position::{
-    changed::PositionChanged, closed::PositionClosed, opened::PositionOpened,
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    events::position::{changed::PositionChanged, closed::PositionClosed, opened::PositionOpened},
+    identifiers::{instrument_id::InstrumentId, position_id::PositionId},
+    types::{price::Price, quantity::Quantity},
 };
 
 pub mod changed;
@@ -23,8 +28,59 @@ pub mod opened;
 
 pub mod state;
 
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum PositionEvent {
     PositionOpened(PositionOpened),
     PositionChanged(PositionChanged),
     PositionClosed(PositionClosed),
 }
+
+impl PositionEvent {
+    #[must_use]
+    pub fn position_id(&self) -> PositionId {
+        match self {
+            Self::PositionOpened(e) => e.position_id,
+            Self::PositionChanged(e) => e.position_id,
+            Self::PositionClosed(e) => e.position_id,
+        }
+    }
+
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::PositionOpened(e) => e.instrument_id,
+            Self::PositionChanged(e) => e.instrument_id,
+            Self::PositionClosed(e) => e.instrument_id,
+        }
+    }
+
+    /// Returns the quantity and price of the fill which triggered the event.
+    #[must_use]
+    pub fn last_fill(&self) -> (Quantity, Price) {
+        match self {
+            Self::PositionOpened(e) => (e.last_qty, e.last_px),
+            Self::PositionChanged(e) => (e.last_qty, e.last_px),
+            Self::PositionClosed(e) => (e.last_qty, e.last_px),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::PositionOpened(e) => e.ts_event,
+            Self::PositionChanged(e) => e.ts_event,
+            Self::PositionClosed(e) => e.ts_event,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::PositionOpened(e) => e.ts_init,
+            Self::PositionChanged(e) => e.ts_init,
+            Self::PositionClosed(e) => e.ts_init,
+        }
+    }
+}
+
+impl Serializable for PositionEvent {}
@@ -14,18 +14,21 @@
 // -------------------------------------------------------------------------------------------------
 
 use nautilus_core::time::UnixNanos;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     enums::{OrderSide, PositionSide},
+    events::order::filled::OrderFilled,
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         position_id::PositionId, strategy_id::StrategyId, trader_id::TraderId,
     },
+    position::Position,
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
 
 #[repr(C)]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct PositionOpened {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -44,3 +47,28 @@ pub struct PositionOpened {
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
 }
+
+impl PositionOpened {
+    /// Creates a new [`PositionOpened`] event for the given `position` opened by the given `fill`.
+    #[must_use]
+    pub fn create(position: &Position, fill: &OrderFilled, ts_init: UnixNanos) -> Self {
+        Self {
+            trader_id: position.trader_id,
+            strategy_id: position.strategy_id,
+            instrument_id: position.instrument_id,
+            position_id: position.id,
+            account_id: position.account_id,
+            opening_order_id: position.opening_order_id,
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            currency: position.quote_currency,
+            avg_px_open: position.avg_px_open,
+            ts_event: fill.ts_event,
+            ts_init,
+        }
+    }
+}
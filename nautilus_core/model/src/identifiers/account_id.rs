@@ -49,6 +49,13 @@ impl AccountId {
             value: Ustr::from(s),
         })
     }
+
+    /// Returns the account issuer, which is the part of the value before the hyphen.
+    #[must_use]
+    pub fn get_issuer(&self) -> Ustr {
+        // SAFETY: Account ID is guaranteed to contain a hyphen
+        Ustr::from(self.value.split('-').next().unwrap())
+    }
 }
 
 impl Default for AccountId {
@@ -113,4 +120,9 @@ mod tests {
     fn test_string_reprs(account_ib: AccountId) {
         assert_eq!(account_ib.to_string(), "IB-1234567890");
     }
+
+    #[rstest]
+    fn test_get_issuer(account_ib: AccountId) {
+        assert_eq!(account_ib.get_issuer(), Ustr::from("IB"));
+    }
 }
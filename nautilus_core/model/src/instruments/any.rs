@@ -0,0 +1,135 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
+    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
+};
+
+/// Wraps any concrete instrument type so that instruments can be held in homogeneous
+/// collections, and serialized.
+///
+/// Dereferences to `dyn Instrument`, so the full [`Instrument`] interface is available directly.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum InstrumentAny {
+    CryptoFuture(CryptoFuture),
+    CryptoPerpetual(CryptoPerpetual),
+    CurrencyPair(CurrencyPair),
+    Equity(Equity),
+    FuturesContract(FuturesContract),
+    FuturesSpread(FuturesSpread),
+    OptionsContract(OptionsContract),
+    OptionsSpread(OptionsSpread),
+}
+
+impl Deref for InstrumentAny {
+    type Target = dyn Instrument;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::CryptoFuture(inst) => inst,
+            Self::CryptoPerpetual(inst) => inst,
+            Self::CurrencyPair(inst) => inst,
+            Self::Equity(inst) => inst,
+            Self::FuturesContract(inst) => inst,
+            Self::FuturesSpread(inst) => inst,
+            Self::OptionsContract(inst) => inst,
+            Self::OptionsSpread(inst) => inst,
+        }
+    }
+}
+
+impl From<CryptoFuture> for InstrumentAny {
+    fn from(inst: CryptoFuture) -> Self {
+        Self::CryptoFuture(inst)
+    }
+}
+
+impl From<CryptoPerpetual> for InstrumentAny {
+    fn from(inst: CryptoPerpetual) -> Self {
+        Self::CryptoPerpetual(inst)
+    }
+}
+
+impl From<CurrencyPair> for InstrumentAny {
+    fn from(inst: CurrencyPair) -> Self {
+        Self::CurrencyPair(inst)
+    }
+}
+
+impl From<Equity> for InstrumentAny {
+    fn from(inst: Equity) -> Self {
+        Self::Equity(inst)
+    }
+}
+
+impl From<FuturesContract> for InstrumentAny {
+    fn from(inst: FuturesContract) -> Self {
+        Self::FuturesContract(inst)
+    }
+}
+
+impl From<FuturesSpread> for InstrumentAny {
+    fn from(inst: FuturesSpread) -> Self {
+        Self::FuturesSpread(inst)
+    }
+}
+
+impl From<OptionsContract> for InstrumentAny {
+    fn from(inst: OptionsContract) -> Self {
+        Self::OptionsContract(inst)
+    }
+}
+
+impl From<OptionsSpread> for InstrumentAny {
+    fn from(inst: OptionsSpread) -> Self {
+        Self::OptionsSpread(inst)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{enums::InstrumentClass, instruments::stubs::*};
+
+    #[rstest]
+    fn test_instrument_any_deref(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::from(audusd_sim);
+
+        assert_eq!(instrument.id(), audusd_sim.id);
+        assert_eq!(instrument.instrument_class(), InstrumentClass::Spot);
+    }
+
+    #[rstest]
+    fn test_instrument_any_serde_round_trip(equity_aapl: Equity) {
+        let instrument = InstrumentAny::from(equity_aapl);
+
+        let json = serde_json::to_string(&instrument).unwrap();
+        let deserialized: InstrumentAny = serde_json::from_str(&json).unwrap();
+
+        assert!(matches!(deserialized, InstrumentAny::Equity(_)));
+        assert_eq!(deserialized.id(), instrument.id());
+    }
+}
@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::any::Any;
+pub mod any;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
@@ -226,7 +226,11 @@ pub fn default_fx_ccy(symbol: Symbol, venue: Option<Venue>) -> CurrencyPair {
     let base_currency = symbol.value.split('/').next().unwrap();
     let quote_currency = symbol.value.split('/').last().unwrap();
     let price_precision = if quote_currency == "JPY" { 3 } else { 5 };
-    let price_increment = Price::new(1.0 / 10.0f64, price_precision).unwrap();
+    let price_increment = Price::new(
+        1.0 / 10.0f64.powi(i32::from(price_precision)),
+        price_precision,
+    )
+    .unwrap();
     CurrencyPair::new(
         instrument_id,
         symbol,
@@ -0,0 +1,355 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::UnixNanos;
+
+use super::{
+    book::{BookIntegrityError, InvalidBookOperation},
+    book_mbo::OrderBookMbo,
+    book_mbp::OrderBookMbp,
+    level::Level,
+};
+use crate::{
+    data::{
+        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
+        quote::QuoteTick, trade::TradeTick,
+    },
+    enums::{BookType, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Provides an order book of any [`BookType`], dispatching to the underlying
+/// MBP or MBO implementation.
+#[derive(Clone, Debug)]
+pub enum OrderBookAny {
+    Mbp(OrderBookMbp),
+    Mbo(OrderBookMbo),
+}
+
+impl OrderBookAny {
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, book_type: BookType) -> Self {
+        match book_type {
+            BookType::L1_MBP => Self::Mbp(OrderBookMbp::new(instrument_id, true)),
+            BookType::L2_MBP => Self::Mbp(OrderBookMbp::new(instrument_id, false)),
+            BookType::L3_MBO => Self::Mbo(OrderBookMbo::new(instrument_id)),
+        }
+    }
+
+    #[must_use]
+    pub fn book_type(&self) -> BookType {
+        match self {
+            Self::Mbp(book) if book.top_only => BookType::L1_MBP,
+            Self::Mbp(_) => BookType::L2_MBP,
+            Self::Mbo(_) => BookType::L3_MBO,
+        }
+    }
+
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::Mbp(book) => book.instrument_id,
+            Self::Mbo(book) => book.instrument_id,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_last(&self) -> UnixNanos {
+        match self {
+            Self::Mbp(book) => book.ts_last,
+            Self::Mbo(book) => book.ts_last,
+        }
+    }
+
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        match self {
+            Self::Mbp(book) => book.count,
+            Self::Mbo(book) => book.count,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        match self {
+            Self::Mbp(book) => book.reset(),
+            Self::Mbo(book) => book.reset(),
+        }
+    }
+
+    pub fn add(&mut self, order: BookOrder, ts_event: u64, sequence: u64) {
+        match self {
+            Self::Mbp(book) => book.add(order, ts_event, sequence),
+            Self::Mbo(book) => book.add(order, ts_event, sequence),
+        }
+    }
+
+    pub fn clear(&mut self, ts_event: u64, sequence: u64) {
+        match self {
+            Self::Mbp(book) => book.clear(ts_event, sequence),
+            Self::Mbo(book) => book.clear(ts_event, sequence),
+        }
+    }
+
+    pub fn apply_delta(&mut self, delta: OrderBookDelta) {
+        match self {
+            Self::Mbp(book) => book.apply_delta(delta),
+            Self::Mbo(book) => book.apply_delta(delta),
+        }
+    }
+
+    pub fn apply_deltas(&mut self, deltas: OrderBookDeltas) {
+        match self {
+            Self::Mbp(book) => book.apply_deltas(deltas),
+            Self::Mbo(book) => book.apply_deltas(deltas),
+        }
+    }
+
+    pub fn apply_depth(&mut self, depth: OrderBookDepth10) {
+        match self {
+            Self::Mbp(book) => book.apply_depth(depth),
+            Self::Mbo(book) => book.apply_depth(depth),
+        }
+    }
+
+    /// Updates the top of the book from the given quote (MBP books only).
+    pub fn update_quote_tick(&mut self, quote: &QuoteTick) -> Result<(), InvalidBookOperation> {
+        match self {
+            Self::Mbp(book) => {
+                book.update_quote_tick(quote);
+                Ok(())
+            }
+            Self::Mbo(_) => Err(InvalidBookOperation::Update(BookType::L3_MBO)),
+        }
+    }
+
+    /// Updates the top of the book from the given trade (MBP books only).
+    pub fn update_trade_tick(&mut self, trade: &TradeTick) -> Result<(), InvalidBookOperation> {
+        match self {
+            Self::Mbp(book) => {
+                book.update_trade_tick(trade);
+                Ok(())
+            }
+            Self::Mbo(_) => Err(InvalidBookOperation::Update(BookType::L3_MBO)),
+        }
+    }
+
+    /// Returns the bid levels in price priority order.
+    #[must_use]
+    pub fn bids(&self) -> Vec<&Level> {
+        match self {
+            Self::Mbp(book) => book.bids().collect(),
+            Self::Mbo(book) => book.bids().collect(),
+        }
+    }
+
+    /// Returns the ask levels in price priority order.
+    #[must_use]
+    pub fn asks(&self) -> Vec<&Level> {
+        match self {
+            Self::Mbp(book) => book.asks().collect(),
+            Self::Mbo(book) => book.asks().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn has_bid(&self) -> bool {
+        match self {
+            Self::Mbp(book) => book.has_bid(),
+            Self::Mbo(book) => book.has_bid(),
+        }
+    }
+
+    #[must_use]
+    pub fn has_ask(&self) -> bool {
+        match self {
+            Self::Mbp(book) => book.has_ask(),
+            Self::Mbo(book) => book.has_ask(),
+        }
+    }
+
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        match self {
+            Self::Mbp(book) => book.best_bid_price(),
+            Self::Mbo(book) => book.best_bid_price(),
+        }
+    }
+
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        match self {
+            Self::Mbp(book) => book.best_ask_price(),
+            Self::Mbo(book) => book.best_ask_price(),
+        }
+    }
+
+    #[must_use]
+    pub fn best_bid_size(&self) -> Option<Quantity> {
+        match self {
+            Self::Mbp(book) => book.best_bid_size(),
+            Self::Mbo(book) => book.best_bid_size(),
+        }
+    }
+
+    #[must_use]
+    pub fn best_ask_size(&self) -> Option<Quantity> {
+        match self {
+            Self::Mbp(book) => book.best_ask_size(),
+            Self::Mbo(book) => book.best_ask_size(),
+        }
+    }
+
+    #[must_use]
+    pub fn spread(&self) -> Option<f64> {
+        match self {
+            Self::Mbp(book) => book.spread(),
+            Self::Mbo(book) => book.spread(),
+        }
+    }
+
+    #[must_use]
+    pub fn midpoint(&self) -> Option<f64> {
+        match self {
+            Self::Mbp(book) => book.midpoint(),
+            Self::Mbo(book) => book.midpoint(),
+        }
+    }
+
+    #[must_use]
+    pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
+        match self {
+            Self::Mbp(book) => book.simulate_fills(order),
+            Self::Mbo(book) => book.simulate_fills(order),
+        }
+    }
+
+    /// Returns the [`Level`] at the given `price` for the given `side` (if found).
+    ///
+    /// Returns `None` for [`OrderSide::NoOrderSide`].
+    #[must_use]
+    pub fn level(&self, side: OrderSide, price: Price) -> Option<&Level> {
+        let levels = match side {
+            OrderSide::Buy => self.bids(),
+            OrderSide::Sell => self.asks(),
+            OrderSide::NoOrderSide => return None,
+        };
+        levels.into_iter().find(|level| level.price.value == price)
+    }
+
+    /// Return a [`String`] representation of the order book in a human-readable table format.
+    #[must_use]
+    pub fn pprint(&self, num_levels: usize) -> String {
+        match self {
+            Self::Mbp(book) => book.pprint(num_levels),
+            Self::Mbo(book) => book.pprint(num_levels),
+        }
+    }
+
+    pub fn check_integrity(&self) -> Result<(), BookIntegrityError> {
+        match self {
+            Self::Mbp(book) => book.check_integrity(),
+            Self::Mbo(book) => book.check_integrity(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::{order::BookOrder, quote::QuoteTick},
+        enums::BookAction,
+    };
+
+    #[rstest]
+    #[case(BookType::L1_MBP)]
+    #[case(BookType::L2_MBP)]
+    #[case(BookType::L3_MBO)]
+    fn test_new_book_type(#[case] book_type: BookType) {
+        let book = OrderBookAny::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), book_type);
+
+        assert_eq!(book.book_type(), book_type);
+        assert!(!book.has_bid());
+        assert!(!book.has_ask());
+    }
+
+    #[rstest]
+    fn test_update_quote_tick_mbp() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBookAny::new(instrument_id, BookType::L1_MBP);
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("5000.00"),
+            Price::from("5001.00"),
+            Quantity::from("100.000"),
+            Quantity::from("99.000"),
+            0,
+            0,
+        )
+        .unwrap();
+
+        book.update_quote_tick(&quote).unwrap();
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("5000.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("5001.00")));
+    }
+
+    #[rstest]
+    fn test_update_quote_tick_mbo_is_invalid() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBookAny::new(instrument_id, BookType::L3_MBO);
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("5000.00"),
+            Price::from("5001.00"),
+            Quantity::from("100.000"),
+            Quantity::from("99.000"),
+            0,
+            0,
+        )
+        .unwrap();
+
+        assert!(book.update_quote_tick(&quote).is_err());
+    }
+
+    #[rstest]
+    fn test_apply_delta_and_level_lookup() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBookAny::new(instrument_id, BookType::L3_MBO);
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("10"),
+            1,
+        );
+        let delta = OrderBookDelta::new(instrument_id, BookAction::Add, order, 0, 1, 1, 2);
+
+        book.apply_delta(delta);
+
+        let level = book.level(OrderSide::Buy, Price::from("100.00")).unwrap();
+        assert_eq!(level.len(), 1);
+        assert!(book.level(OrderSide::Sell, Price::from("100.00")).is_none());
+        assert!(book
+            .level(OrderSide::NoOrderSide, Price::from("100.00"))
+            .is_none());
+        assert_eq!(book.ts_last(), 1);
+    }
+}
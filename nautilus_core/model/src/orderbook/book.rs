@@ -29,6 +29,8 @@ pub enum InvalidBookOperation {
     PreProcessOrder(BookType),
     #[error("Invalid book operation: cannot add for {0} book")]
     Add(BookType),
+    #[error("Invalid book operation: cannot update with tick for {0} book")]
+    Update(BookType),
 }
 
 #[derive(Error, Debug)]
@@ -13,6 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod any;
 pub mod book;
 pub mod book_mbo;
 pub mod book_mbp;
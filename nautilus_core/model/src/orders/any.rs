@@ -0,0 +1,285 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    base::{Order, OrderError},
+    limit::LimitOrder,
+    limit_if_touched::LimitIfTouchedOrder,
+    market::MarketOrder,
+    market_if_touched::MarketIfTouchedOrder,
+    market_to_limit::MarketToLimitOrder,
+    stop_limit::StopLimitOrder,
+    stop_market::StopMarketOrder,
+    trailing_stop_limit::TrailingStopLimitOrder,
+    trailing_stop_market::TrailingStopMarketOrder,
+};
+use crate::{
+    enums::OrderType,
+    events::order::{event::OrderEvent, initialized::OrderInitialized},
+};
+
+/// Wraps any concrete order type so that orders can be held in homogeneous collections.
+///
+/// Dereferences to `dyn Order`, so the full [`Order`] interface is available directly.
+#[derive(Clone, Debug)]
+pub enum OrderAny {
+    Limit(LimitOrder),
+    LimitIfTouched(LimitIfTouchedOrder),
+    Market(MarketOrder),
+    MarketIfTouched(MarketIfTouchedOrder),
+    MarketToLimit(MarketToLimitOrder),
+    StopLimit(StopLimitOrder),
+    StopMarket(StopMarketOrder),
+    TrailingStopLimit(TrailingStopLimitOrder),
+    TrailingStopMarket(TrailingStopMarketOrder),
+}
+
+impl OrderAny {
+    /// Rebuilds an order from its event stream, which must begin with `OrderInitialized`.
+    pub fn from_events(events: Vec<OrderEvent>) -> Result<Self, OrderError> {
+        let mut iter = events.into_iter();
+        let mut order = match iter.next() {
+            Some(OrderEvent::OrderInitialized(init)) => Self::from(init),
+            _ => return Err(OrderError::UnrecognizedEvent),
+        };
+
+        for event in iter {
+            order.apply(event)?;
+        }
+
+        Ok(order)
+    }
+
+    /// Returns whether the order rests on the book with a limit price once working.
+    #[must_use]
+    pub fn has_price(&self) -> bool {
+        self.price().is_some()
+    }
+
+    /// Returns whether the order has a trigger price (stop, if-touched and trailing orders).
+    #[must_use]
+    pub fn has_trigger_price(&self) -> bool {
+        self.trigger_price().is_some()
+    }
+}
+
+impl Deref for OrderAny {
+    type Target = dyn Order;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Limit(o) => o,
+            Self::LimitIfTouched(o) => o,
+            Self::Market(o) => o,
+            Self::MarketIfTouched(o) => o,
+            Self::MarketToLimit(o) => o,
+            Self::StopLimit(o) => o,
+            Self::StopMarket(o) => o,
+            Self::TrailingStopLimit(o) => o,
+            Self::TrailingStopMarket(o) => o,
+        }
+    }
+}
+
+impl DerefMut for OrderAny {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Limit(o) => o,
+            Self::LimitIfTouched(o) => o,
+            Self::Market(o) => o,
+            Self::MarketIfTouched(o) => o,
+            Self::MarketToLimit(o) => o,
+            Self::StopLimit(o) => o,
+            Self::StopMarket(o) => o,
+            Self::TrailingStopLimit(o) => o,
+            Self::TrailingStopMarket(o) => o,
+        }
+    }
+}
+
+impl PartialEq for OrderAny {
+    fn eq(&self, other: &Self) -> bool {
+        self.client_order_id() == other.client_order_id()
+    }
+}
+
+/// Serializes the order as its event stream.
+impl Serialize for OrderAny {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.events())
+    }
+}
+
+/// Deserializes the order by replaying its event stream.
+impl<'de> Deserialize<'de> for OrderAny {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let events = Vec::<OrderEvent>::deserialize(deserializer)?;
+        Self::from_events(events).map_err(D::Error::custom)
+    }
+}
+
+impl From<OrderInitialized> for OrderAny {
+    fn from(event: OrderInitialized) -> Self {
+        match event.order_type {
+            OrderType::Limit => Self::Limit(event.into()),
+            OrderType::LimitIfTouched => Self::LimitIfTouched(event.into()),
+            OrderType::Market => Self::Market(event.into()),
+            OrderType::MarketIfTouched => Self::MarketIfTouched(event.into()),
+            OrderType::MarketToLimit => Self::MarketToLimit(event.into()),
+            OrderType::StopLimit => Self::StopLimit(event.into()),
+            OrderType::StopMarket => Self::StopMarket(event.into()),
+            OrderType::TrailingStopLimit => Self::TrailingStopLimit(event.into()),
+            OrderType::TrailingStopMarket => Self::TrailingStopMarket(event.into()),
+        }
+    }
+}
+
+impl From<LimitOrder> for OrderAny {
+    fn from(order: LimitOrder) -> Self {
+        Self::Limit(order)
+    }
+}
+
+impl From<LimitIfTouchedOrder> for OrderAny {
+    fn from(order: LimitIfTouchedOrder) -> Self {
+        Self::LimitIfTouched(order)
+    }
+}
+
+impl From<MarketOrder> for OrderAny {
+    fn from(order: MarketOrder) -> Self {
+        Self::Market(order)
+    }
+}
+
+impl From<MarketIfTouchedOrder> for OrderAny {
+    fn from(order: MarketIfTouchedOrder) -> Self {
+        Self::MarketIfTouched(order)
+    }
+}
+
+impl From<MarketToLimitOrder> for OrderAny {
+    fn from(order: MarketToLimitOrder) -> Self {
+        Self::MarketToLimit(order)
+    }
+}
+
+impl From<StopLimitOrder> for OrderAny {
+    fn from(order: StopLimitOrder) -> Self {
+        Self::StopLimit(order)
+    }
+}
+
+impl From<StopMarketOrder> for OrderAny {
+    fn from(order: StopMarketOrder) -> Self {
+        Self::StopMarket(order)
+    }
+}
+
+impl From<TrailingStopLimitOrder> for OrderAny {
+    fn from(order: TrailingStopLimitOrder) -> Self {
+        Self::TrailingStopLimit(order)
+    }
+}
+
+impl From<TrailingStopMarketOrder> for OrderAny {
+    fn from(order: TrailingStopMarketOrder) -> Self {
+        Self::TrailingStopMarket(order)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::OrderStatus,
+        events::order::{
+            accepted::OrderAcceptedBuilder, initialized::OrderInitializedBuilder,
+            submitted::OrderSubmittedBuilder,
+        },
+        types::price::Price,
+    };
+
+    #[rstest]
+    fn test_from_order_initialized_dispatches_on_order_type() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap();
+
+        let order = OrderAny::from(init);
+
+        assert!(matches!(order, OrderAny::Limit(_)));
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.price(), Some(Price::from("1.00000")));
+        assert!(order.has_price());
+        assert!(!order.has_trigger_price());
+    }
+
+    #[rstest]
+    fn test_from_events_replays_state() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+
+        let order = OrderAny::from_events(vec![
+            OrderEvent::OrderInitialized(init),
+            OrderEvent::OrderSubmitted(submitted),
+            OrderEvent::OrderAccepted(accepted),
+        ])
+        .unwrap();
+
+        assert!(matches!(order, OrderAny::Market(_)));
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.venue_order_id(), Some(accepted.venue_order_id));
+    }
+
+    #[rstest]
+    fn test_serde_round_trip() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let order = OrderAny::from_events(vec![
+            OrderEvent::OrderInitialized(init),
+            OrderEvent::OrderSubmitted(submitted),
+        ])
+        .unwrap();
+
+        let json = serde_json::to_string(&order).unwrap();
+        let deserialized: OrderAny = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, order);
+        assert_eq!(deserialized.status(), OrderStatus::Submitted);
+        assert_eq!(deserialized.event_count(), 2);
+    }
+
+    #[rstest]
+    fn test_from_events_when_not_initialized_first() {
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+
+        let result = OrderAny::from_events(vec![OrderEvent::OrderSubmitted(submitted)]);
+
+        assert!(result.is_err());
+    }
+}
@@ -108,6 +108,7 @@ impl OrderStatus {
             (Self::Submitted, OrderEvent::OrderAccepted(_)) => Self::Accepted,
             (Self::Submitted, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Submitted, OrderEvent::OrderFilled(_)) => Self::Filled,
+            (Self::Submitted, OrderEvent::OrderUpdated(_)) => Self::Submitted,
             (Self::Accepted, OrderEvent::OrderRejected(_)) => Self::Rejected,  // StopLimit order
             (Self::Accepted, OrderEvent::OrderPendingUpdate(_)) => Self::PendingUpdate,
             (Self::Accepted, OrderEvent::OrderPendingCancel(_)) => Self::PendingCancel,
@@ -116,6 +117,7 @@ impl OrderStatus {
             (Self::Accepted, OrderEvent::OrderExpired(_)) => Self::Expired,
             (Self::Accepted, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Accepted, OrderEvent::OrderFilled(_)) => Self::Filled,
+            (Self::Accepted, OrderEvent::OrderUpdated(_)) => Self::Accepted,
             (Self::Canceled, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,  // Real world possibility
             (Self::Canceled, OrderEvent::OrderFilled(_)) => Self::Filled,  // Real world possibility
             (Self::PendingUpdate, OrderEvent::OrderRejected(_)) => Self::Rejected,
@@ -141,12 +143,14 @@ impl OrderStatus {
             (Self::Triggered, OrderEvent::OrderExpired(_)) => Self::Expired,
             (Self::Triggered, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Triggered, OrderEvent::OrderFilled(_)) => Self::Filled,
+            (Self::Triggered, OrderEvent::OrderUpdated(_)) => Self::Triggered,
             (Self::PartiallyFilled, OrderEvent::OrderPendingUpdate(_)) => Self::PendingUpdate,
             (Self::PartiallyFilled, OrderEvent::OrderPendingCancel(_)) => Self::PendingCancel,
             (Self::PartiallyFilled, OrderEvent::OrderCanceled(_)) => Self::Canceled,
             (Self::PartiallyFilled, OrderEvent::OrderExpired(_)) => Self::Expired,
             (Self::PartiallyFilled, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::PartiallyFilled, OrderEvent::OrderFilled(_)) => Self::Filled,
+            (Self::PartiallyFilled, OrderEvent::OrderUpdated(_)) => Self::PartiallyFilled,
             _ => return Err(OrderError::InvalidStateTransition),
         };
         Ok(new_state)
@@ -469,8 +473,23 @@ impl OrderCore {
         assert_eq!(self.client_order_id, event.client_order_id());
         assert_eq!(self.strategy_id, event.strategy_id());
 
-        let new_status = self.status.transition(&event)?;
-        self.previous_status = Some(self.status);
+        let new_status = match &event {
+            // A fill which does not complete the order transitions to `PartiallyFilled`
+            OrderEvent::OrderFilled(fill) if self.filled_qty + fill.last_qty < self.quantity => {
+                self.status
+                    .transition(&OrderEvent::OrderPartiallyFilled(*fill))?
+            }
+            // An update restores the status held before the update was requested
+            OrderEvent::OrderUpdated(_) if self.status == OrderStatus::PendingUpdate => {
+                self.previous_status.ok_or(OrderError::NoPreviousState)?
+            }
+            _ => self.status.transition(&event)?,
+        };
+        // Repeated update requests retain the status held before the first request
+        if !(self.status == OrderStatus::PendingUpdate && new_status == OrderStatus::PendingUpdate)
+        {
+            self.previous_status = Some(self.status);
+        }
         self.status = new_status;
 
         match &event {
@@ -488,6 +507,7 @@ impl OrderCore {
             OrderEvent::OrderTriggered(event) => self.triggered(event),
             OrderEvent::OrderCanceled(event) => self.canceled(event),
             OrderEvent::OrderExpired(event) => self.expired(event),
+            OrderEvent::OrderPartiallyFilled(event) => self.filled(event),
             OrderEvent::OrderFilled(event) => self.filled(event),
             _ => return Err(OrderError::UnrecognizedEvent),
         }
@@ -564,10 +584,10 @@ impl OrderCore {
         self.trade_ids.push(event.trade_id);
         self.last_trade_id = Some(event.trade_id);
         self.liquidity_side = Some(event.liquidity_side);
+        self.set_avg_px(event.last_qty, event.last_px);
         self.filled_qty += event.last_qty;
         self.leaves_qty -= event.last_qty;
         self.ts_last = event.ts_event;
-        self.set_avg_px(event.last_qty, event.last_px);
     }
 
     fn set_avg_px(&mut self, last_qty: Quantity, last_px: Price) {
@@ -664,7 +684,8 @@ mod tests {
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
             accepted::OrderAcceptedBuilder, denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
-            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            initialized::OrderInitializedBuilder, pending_update::OrderPendingUpdateBuilder,
+            submitted::OrderSubmittedBuilder, updated::OrderUpdatedBuilder,
         },
         orders::market::MarketOrder,
     };
@@ -778,4 +799,93 @@ mod tests {
         assert_eq!(order.commission(&Currency::USD()), None);
         assert_eq!(order.commissions(), HashMap::new());
     }
+
+    #[rstest]
+    fn test_order_life_cycle_to_partially_filled_then_filled() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let fill1 = OrderFilledBuilder::default()
+            .trade_id(TradeId::from("1"))
+            .last_qty(Quantity::from(40_000))
+            .last_px(Price::from("1.00000"))
+            .build()
+            .unwrap();
+        let fill2 = OrderFilledBuilder::default()
+            .trade_id(TradeId::from("2"))
+            .last_qty(Quantity::from(60_000))
+            .last_px(Price::from("1.00010"))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order.apply(OrderEvent::OrderFilled(fill1)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.filled_qty(), Quantity::from(40_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(60_000));
+        assert!(order.is_open());
+
+        order.apply(OrderEvent::OrderFilled(fill2)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.filled_qty(), Quantity::from(100_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(0));
+        assert!((order.avg_px().unwrap() - 1.00006).abs() < 1e-9);
+        assert!(order.is_closed());
+    }
+
+    #[rstest]
+    fn test_order_updated_retains_accepted_status() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(50_000))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order.apply(OrderEvent::OrderUpdated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.quantity(), Quantity::from(50_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(50_000));
+    }
+
+    #[rstest]
+    fn test_order_updated_restores_status_before_pending_update() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let fill = OrderFilledBuilder::default()
+            .last_qty(Quantity::from(40_000))
+            .build()
+            .unwrap();
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(80_000))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+        order.apply(OrderEvent::OrderUpdated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.quantity(), Quantity::from(80_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(40_000));
+    }
 }
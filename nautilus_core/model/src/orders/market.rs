@@ -43,6 +43,7 @@ use crate::{
     },
 };
 
+#[derive(Clone, Debug)]
 #[cfg_attr(
     feature = "python",
     pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
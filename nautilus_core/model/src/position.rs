@@ -83,7 +83,7 @@ pub struct Position {
 }
 
 impl Position {
-    pub fn new<T: Instrument>(instrument: T, fill: OrderFilled) -> Result<Self> {
+    pub fn new<T: Instrument + ?Sized>(instrument: &T, fill: OrderFilled) -> Result<Self> {
         assert_eq!(instrument.id(), fill.instrument_id);
         assert!(fill.position_id.is_some());
         assert_ne!(fill.order_side, OrderSide::NoOrderSide);
@@ -128,11 +128,6 @@ impl Position {
     }
 
     pub fn apply(&mut self, fill: &OrderFilled) {
-        assert!(
-            !self.trade_ids.contains(&fill.trade_id),
-            "`fill.trade_id` already contained in `trade_ids",
-        );
-
         if self.side == PositionSide::Flat {
             // Reset position
             self.events.clear();
@@ -153,6 +148,12 @@ impl Position {
             self.realized_pnl = None;
         }
 
+        // Checked after any reset, as a fill flipping a position reopens it with the same trade
+        assert!(
+            !self.trade_ids.contains(&fill.trade_id),
+            "`fill.trade_id` already contained in `trade_ids",
+        );
+
         self.events.push(*fill);
         self.trade_ids.push(fill.trade_id);
 
@@ -204,10 +205,11 @@ impl Position {
     }
 
     pub fn handle_buy_order_fill(&mut self, fill: &OrderFilled) {
-        let mut realized_pnl = if fill.commission.unwrap().currency == self.settlement_currency {
-            -fill.commission.unwrap().as_f64()
-        } else {
-            0.0
+        let mut realized_pnl = match fill.commission {
+            Some(commission) if commission.currency == self.settlement_currency => {
+                -commission.as_f64()
+            }
+            _ => 0.0,
         };
         let last_px = fill.last_px.as_f64();
         let last_qty = fill.last_qty.as_f64();
@@ -239,10 +241,11 @@ impl Position {
     }
 
     pub fn handle_sell_order_fill(&mut self, fill: &OrderFilled) {
-        let mut realized_pnl = if fill.commission.unwrap().currency == self.settlement_currency {
-            -fill.commission.unwrap().as_f64()
-        } else {
-            0.0
+        let mut realized_pnl = match fill.commission {
+            Some(commission) if commission.currency == self.settlement_currency => {
+                -commission.as_f64()
+            }
+            _ => 0.0,
         };
         let last_px = fill.last_px.as_f64();
         let last_qty = fill.last_qty.as_f64();
@@ -593,7 +596,7 @@ mod tests {
             None,
             None,
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         position.apply(&fill2);
     }
 
@@ -618,7 +621,7 @@ mod tests {
             None,
         );
         let last_price = Price::from_str("1.0005").unwrap();
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         assert_eq!(position.symbol(), audusd_sim.id.symbol);
         assert_eq!(position.venue(), audusd_sim.id.venue);
         assert!(!position.is_opposite_side(fill.order_side));
@@ -684,7 +687,7 @@ mod tests {
             None,
         );
         let last_price = Price::from_str("1.00050").unwrap();
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         assert_eq!(position.symbol(), audusd_sim.id.symbol);
         assert_eq!(position.venue(), audusd_sim.id.venue);
         assert!(!position.is_opposite_side(fill.order_side));
@@ -748,7 +751,7 @@ mod tests {
             None,
         );
         let last_price = Price::from_str("1.00048").unwrap();
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         assert_eq!(position.quantity, Quantity::from(50_000));
         assert_eq!(position.peak_qty, Quantity::from(50_000));
         assert_eq!(position.side, PositionSide::Long);
@@ -815,7 +818,7 @@ mod tests {
             None,
         );
         let last_price = Price::from_str("1.0005").unwrap();
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         position.apply(&fill2);
 
         assert_eq!(position.quantity, Quantity::from(100_000));
@@ -868,7 +871,7 @@ mod tests {
             None,
             Some(1_000_000_000),
         );
-        let mut position = Position::new(audusd_sim, fill).unwrap();
+        let mut position = Position::new(&audusd_sim, fill).unwrap();
 
         let fill2 = OrderFilled::new(
             order.trader_id,
@@ -958,7 +961,7 @@ mod tests {
             None,
             None,
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         // create closing from order from different venue but same strategy
         let fill2 = TestOrderEventStubs::order_filled(
             &order2,
@@ -1049,7 +1052,7 @@ mod tests {
             None,
             None,
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         let fill2 = TestOrderEventStubs::order_filled(
             &order2,
             &audusd_sim,
@@ -1157,7 +1160,7 @@ mod tests {
             None,
             None,
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         let last = Price::from("1.0005");
         position.apply(&fill2);
         position.apply(&fill3);
@@ -1223,7 +1226,7 @@ mod tests {
             Some(commission1),
             None,
         );
-        let mut position = Position::new(currency_pair_ethusdt, fill1).unwrap();
+        let mut position = Position::new(&currency_pair_ethusdt, fill1).unwrap();
         let quantity2 = Quantity::from(17);
         let order2 = TestOrderStubs::market_order(
             currency_pair_ethusdt.id,
@@ -1355,7 +1358,7 @@ mod tests {
             Some(commission1),
             Some(1_000_000_000),
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
 
         let fill2 = OrderFilled::new(
             order.trader_id,
@@ -1474,7 +1477,7 @@ mod tests {
             Some(commission1),
             None,
         );
-        let mut position = Position::new(currency_pair_btcusdt, fill1).unwrap();
+        let mut position = Position::new(&currency_pair_btcusdt, fill1).unwrap();
         let order2 = TestOrderStubs::market_order(
             currency_pair_btcusdt.id,
             OrderSide::Buy,
@@ -1631,7 +1634,7 @@ mod tests {
             None,
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let result = position.calculate_pnl(10500.0, 10500.0, Quantity::from("100000.0"));
         assert_eq!(result, Money::from("0 USDT"));
     }
@@ -1663,7 +1666,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.calculate_pnl(10500.0, 10510.0, Quantity::from("12.0"));
         assert_eq!(pnl, Money::from("120 USDT"));
         assert_eq!(position.realized_pnl, Some(Money::from("-126 USDT")));
@@ -1705,7 +1708,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.calculate_pnl(10500.0, 10480.5, Quantity::from("10.0"));
         assert_eq!(pnl, Money::from("-195 USDT"));
         assert_eq!(position.realized_pnl, Some(Money::from("-126 USDT")));
@@ -1747,7 +1750,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.calculate_pnl(10500.0, 10390.0, Quantity::from("10.15"));
         assert_eq!(pnl, Money::from("1116.5 USDT"));
         assert_eq!(
@@ -1789,7 +1792,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.calculate_pnl(10500.0, 10670.5, Quantity::from("10.0"));
         assert_eq!(pnl, Money::from("-1705 USDT"));
         assert_eq!(
@@ -1827,7 +1830,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(xbtusd_bitmex, fill).unwrap();
+        let position = Position::new(&xbtusd_bitmex, fill).unwrap();
         let pnl = position.calculate_pnl(10000.0, 11000.0, Quantity::from("100000.0"));
         assert_eq!(pnl, Money::from("-0.90909091 BTC"));
         assert_eq!(
@@ -1864,7 +1867,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(ethusdt_bitmex, fill).unwrap();
+        let position = Position::new(&ethusdt_bitmex, fill).unwrap();
 
         assert_eq!(
             position.unrealized_pnl(Price::from("370.00")),
@@ -1928,7 +1931,7 @@ mod tests {
             Some(commission2),
             None,
         );
-        let mut position = Position::new(currency_pair_btcusdt, fill1).unwrap();
+        let mut position = Position::new(&currency_pair_btcusdt, fill1).unwrap();
         position.apply(&fill2);
         let pnl = position.unrealized_pnl(Price::from("11505.60"));
         assert_eq!(pnl, Money::from("4022.40000000 USDT"));
@@ -1969,7 +1972,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.unrealized_pnl(Price::from("10407.15"));
         assert_eq!(pnl, Money::from("582.03640000 USDT"));
         assert_eq!(
@@ -2006,7 +2009,7 @@ mod tests {
             None,
         );
 
-        let position = Position::new(xbtusd_bitmex, fill).unwrap();
+        let position = Position::new(&xbtusd_bitmex, fill).unwrap();
         let pnl = position.unrealized_pnl(Price::from("11505.60"));
         assert_eq!(pnl, Money::from("0.83238969 BTC"));
         assert_eq!(position.realized_pnl, Some(Money::from("-0.00714286 BTC")));
@@ -2036,7 +2039,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(xbtusd_bitmex, fill).unwrap();
+        let position = Position::new(&xbtusd_bitmex, fill).unwrap();
         let pnl = position.unrealized_pnl(Price::from("12506.65"));
 
         assert_eq!(pnl, Money::from("19.30166700 BTC"));
@@ -2074,7 +2077,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         assert_eq!(position.signed_qty, expected);
     }
 }
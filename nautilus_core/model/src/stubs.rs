@@ -76,7 +76,7 @@ pub fn test_position_long(audusd_sim: CurrencyPair) -> Position {
         None,
         None,
     );
-    Position::new(audusd_sim, order_filled).unwrap()
+    Position::new(&audusd_sim, order_filled).unwrap()
 }
 
 #[fixture]
@@ -99,7 +99,7 @@ pub fn test_position_short(audusd_sim: CurrencyPair) -> Position {
         None,
         None,
     );
-    Position::new(audusd_sim, order_filled).unwrap()
+    Position::new(&audusd_sim, order_filled).unwrap()
 }
 
 #[must_use]
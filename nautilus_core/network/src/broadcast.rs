@@ -0,0 +1,126 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Broadcasting of frames to all the clients connected to a server.
+
+use std::{
+    future::Future,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::broadcast::{self, error::RecvError},
+    task,
+};
+use tokio_tungstenite::tungstenite::Error;
+use tracing::{error, warn};
+
+pub(crate) type FrameReceiver = broadcast::Receiver<Arc<Vec<u8>>>;
+
+/// Accepts clients on a TCP listener, serving each the frames sent after it connects.
+///
+/// A client lagging more than the channel capacity misses the oldest frames. Dropping the
+/// server stops accepting clients and closes the connected clients once served.
+pub(crate) struct BroadcastServer {
+    tx: broadcast::Sender<Arc<Vec<u8>>>,
+    local_addr: SocketAddr,
+    client_count: Arc<AtomicUsize>,
+    accept_task: task::JoinHandle<()>,
+}
+
+impl BroadcastServer {
+    /// Binds a new server to the given `addr`, serving each client accepted with `serve`.
+    ///
+    /// Must be called within a Tokio runtime, which runs the server.
+    pub(crate) async fn bind<F, Fut>(addr: &str, capacity: usize, serve: F) -> Result<Self, Error>
+    where
+        F: Fn(TcpStream, FrameReceiver) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), Error>> + Send + 'static,
+    {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let (tx, _) = broadcast::channel(capacity);
+        let client_count = Arc::new(AtomicUsize::new(0));
+
+        let accept_tx = tx.clone();
+        let accept_count = client_count.clone();
+        let accept_task = task::spawn(async move {
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        error!("Error accepting client: {e}");
+                        continue;
+                    }
+                };
+
+                let client = serve(stream, accept_tx.subscribe());
+                let client_count = accept_count.clone();
+                task::spawn(async move {
+                    client_count.fetch_add(1, Ordering::SeqCst);
+                    let result = client.await;
+                    client_count.fetch_sub(1, Ordering::SeqCst);
+
+                    if let Err(e) = result {
+                        error!("Error serving client: {e}");
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            tx,
+            local_addr,
+            client_count,
+            accept_task,
+        })
+    }
+
+    pub(crate) fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    pub(crate) fn client_count(&self) -> usize {
+        self.client_count.load(Ordering::SeqCst)
+    }
+
+    /// Sends the given `frame` to all connected clients.
+    pub(crate) fn send(&self, frame: Vec<u8>) {
+        // Only fails when there are no clients connected
+        let _ = self.tx.send(Arc::new(frame));
+    }
+}
+
+impl Drop for BroadcastServer {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+/// Receives the next frame for a client, or `None` once the server is dropped.
+pub(crate) async fn recv_frame(rx: &mut FrameReceiver) -> Option<Arc<Vec<u8>>> {
+    loop {
+        match rx.recv().await {
+            Ok(frame) => return Some(frame),
+            Err(RecvError::Lagged(count)) => warn!("Client lagged, {count} frames skipped"),
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
@@ -13,9 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+mod broadcast;
 pub mod http;
-#[allow(dead_code)]
-mod ratelimiter;
+pub mod ratelimiter;
 pub mod socket;
 pub mod websocket;
 
@@ -135,6 +135,26 @@ impl Gcra {
         t0 + self.t
     }
 
+    /// Tests a single cell against the given theoretical arrival time, without updating any state.
+    pub(crate) fn test<P: clock::Reference>(
+        &self,
+        start: P,
+        tat: Option<Nanos>,
+        t0: P,
+    ) -> Result<(), NotUntil<P>> {
+        let t0 = t0.duration_since(start);
+        let tat = tat.unwrap_or_else(|| self.starting_state(t0));
+        let earliest_time = tat.saturating_sub(self.tau);
+        if t0 < earliest_time {
+            Err(NotUntil::new(
+                StateSnapshot::new(self.t, self.tau, earliest_time, earliest_time),
+                start,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Tests a single cell against the rate limiter state and updates it at the given key.
     pub(crate) fn test_and_update<K, S: StateStore<Key = K>, P: clock::Reference>(
         &self,
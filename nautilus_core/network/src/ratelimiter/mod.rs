@@ -50,6 +50,11 @@ use self::{
 pub struct InMemoryState(AtomicU64);
 
 impl InMemoryState {
+    /// Returns the theoretical arrival time, if any cell has been measured yet.
+    pub(crate) fn measure(&self) -> Option<Nanos> {
+        NonZeroU64::new(self.0.load(Ordering::Acquire)).map(|n| n.get().into())
+    }
+
     pub(crate) fn measure_and_replace_one<T, F, E>(&self, mut f: F) -> Result<T, E>
     where
         F: FnMut(Option<Nanos>) -> Result<(T, Nanos), E>,
@@ -141,16 +146,7 @@ where
     K: Eq + Hash,
 {
     pub fn new_with_quota(base_quota: Option<Quota>, keyed_quotas: Vec<(K, Quota)>) -> Self {
-        let clock = MonotonicClock {};
-        let start = MonotonicClock::now(&clock);
-        let gcra = DashMap::from_iter(keyed_quotas.into_iter().map(|(k, q)| (k, Gcra::new(q))));
-        Self {
-            default_gcra: base_quota.map(Gcra::new),
-            state: DashMapStateStore::new(),
-            gcra,
-            clock,
-            start,
-        }
+        Self::new_with_clock(base_quota, keyed_quotas, MonotonicClock {})
     }
 }
 
@@ -168,6 +164,23 @@ where
     K: Hash + Eq + Clone,
     C: Clock,
 {
+    /// Creates a new rate limiter measuring time with the given `clock`.
+    pub fn new_with_clock(
+        base_quota: Option<Quota>,
+        keyed_quotas: Vec<(K, Quota)>,
+        clock: C,
+    ) -> Self {
+        let start = clock.now();
+        let gcra = DashMap::from_iter(keyed_quotas.into_iter().map(|(k, q)| (k, Gcra::new(q))));
+        Self {
+            default_gcra: base_quota.map(Gcra::new),
+            state: DashMapStateStore::new(),
+            gcra,
+            clock,
+            start,
+        }
+    }
+
     pub fn add_quota_for_key(&self, key: K, value: Quota) {
         self.gcra.insert(key, Gcra::new(value));
     }
@@ -181,10 +194,31 @@ where
         }
     }
 
+    /// Checks whether the given `key` is ready, without consuming a cell of its quota.
+    pub fn peek_key(&self, key: &K) -> Result<(), NotUntil<C::Instant>> {
+        let tat = self.state.get(key).and_then(|state| state.measure());
+        let now = self.clock.now();
+        match self.gcra.get(key) {
+            Some(quota) => quota.test(self.start, tat, now),
+            None => self
+                .default_gcra
+                .as_ref()
+                .map_or(Ok(()), |gcra| gcra.test(self.start, tat, now)),
+        }
+    }
+
+    /// Waits until the given `key` is ready, without consuming a cell of its quota.
+    pub async fn until_key_available(&self, key: &K) {
+        while let Err(neg) = self.peek_key(key) {
+            sleep(neg.wait_time_from(self.clock.now())).await;
+        }
+    }
+
+    /// Waits until the given `key` is ready, consuming a cell of its quota.
     pub async fn until_key_ready(&self, key: &K) {
         loop {
             match self.check_key(key) {
-                Ok(x) => x,
+                Ok(()) => break,
                 Err(neg) => {
                     sleep(neg.wait_time_from(self.clock.now())).await;
                 }
@@ -247,4 +281,34 @@ mod tests {
         assert!(mock_limiter.check_key(&"yeet".to_string()).is_ok());
         assert!(mock_limiter.check_key(&"yeet".to_string()).is_err());
     }
+
+    #[test]
+    fn test_peek_key_does_not_consume() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "key".to_string();
+
+        for _ in 0..5 {
+            assert!(mock_limiter.peek_key(&key).is_ok());
+        }
+        assert!(mock_limiter.check_key(&key).is_ok());
+        assert!(mock_limiter.check_key(&key).is_ok());
+        assert!(mock_limiter.peek_key(&key).is_err());
+
+        mock_limiter.advance_clock(Duration::from_secs(1));
+        assert!(mock_limiter.peek_key(&key).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_until_key_ready_returns_once_ready() {
+        let limiter = RateLimiter::new_with_quota(
+            Some(Quota::per_second(NonZeroU32::new(10).unwrap())),
+            vec![],
+        );
+        let key = "key".to_string();
+
+        // The last call waits for a cell to be replenished
+        for _ in 0..11 {
+            limiter.until_key_ready(&key).await;
+        }
+    }
 }
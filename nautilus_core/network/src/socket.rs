@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{sync::Arc, time::Duration};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use nautilus_core::python::to_pyruntime_err;
 use pyo3::prelude::*;
@@ -31,6 +31,8 @@ use tokio_tungstenite::{
 };
 use tracing::{debug, error};
 
+use crate::broadcast::{recv_frame, BroadcastServer};
+
 type TcpWriter = WriteHalf<MaybeTlsStream<TcpStream>>;
 type SharedTcpWriter = Arc<Mutex<WriteHalf<MaybeTlsStream<TcpStream>>>>;
 type TcpReader = ReadHalf<MaybeTlsStream<TcpStream>>;
@@ -499,6 +501,53 @@ impl SocketClient {
     }
 }
 
+/// Provides a TCP server which sends frames to all connected clients, each frame followed
+/// by the suffix (so they can be read by a [`SocketClient`]).
+///
+/// Clients only receive the frames sent after they connect, and a client lagging more
+/// than the `capacity` frames misses the oldest frames.
+pub struct SocketServer {
+    server: BroadcastServer,
+}
+
+impl SocketServer {
+    /// Binds a new [`SocketServer`] to the given `addr`.
+    ///
+    /// Must be called within a Tokio runtime, which runs the server.
+    pub async fn bind(addr: &str, suffix: Vec<u8>, capacity: usize) -> Result<Self, Error> {
+        let suffix = Arc::new(suffix);
+        let server = BroadcastServer::bind(addr, capacity, move |mut stream, mut rx| {
+            let suffix = suffix.clone();
+            async move {
+                while let Some(frame) = recv_frame(&mut rx).await {
+                    stream.write_all(&frame).await?;
+                    stream.write_all(&suffix).await?;
+                }
+                Ok(())
+            }
+        })
+        .await?;
+        Ok(Self { server })
+    }
+
+    /// Returns the address the server is listening on.
+    #[must_use]
+    pub fn local_addr(&self) -> SocketAddr {
+        self.server.local_addr()
+    }
+
+    /// Returns the number of connected clients.
+    #[must_use]
+    pub fn client_count(&self) -> usize {
+        self.server.client_count()
+    }
+
+    /// Sends the given `data` to all connected clients.
+    pub fn send_bytes(&self, data: Vec<u8>) {
+        self.server.send(data);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -506,8 +555,8 @@ impl SocketClient {
 mod tests {
     use pyo3::{prelude::*, prepare_freethreaded_python};
     use tokio::{
-        io::{AsyncReadExt, AsyncWriteExt},
-        net::TcpListener,
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::{TcpListener, TcpStream},
         task::{self, JoinHandle},
         time::{sleep, Duration},
     };
@@ -515,7 +564,7 @@ mod tests {
     use tracing::debug;
     use tracing_test::traced_test;
 
-    use crate::socket::{SocketClient, SocketConfig};
+    use crate::socket::{SocketClient, SocketConfig, SocketServer};
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -683,4 +732,24 @@ counter = Counter()",
         sleep(Duration::from_secs(1)).await;
         assert!(client.is_disconnected());
     }
+
+    #[tokio::test]
+    async fn test_server_sends_to_clients() {
+        let server = SocketServer::bind("127.0.0.1:0", b"\r\n".to_vec(), 10)
+            .await
+            .unwrap();
+        let client = TcpStream::connect(server.local_addr()).await.unwrap();
+        while server.client_count() == 0 {
+            sleep(Duration::from_millis(1)).await;
+        }
+
+        server.send_bytes(b"ping".to_vec());
+        server.send_bytes(b"pong".to_vec());
+
+        let mut reader = BufReader::new(client);
+        let mut buf = Vec::new();
+        reader.read_until(b'\n', &mut buf).await.unwrap();
+        reader.read_until(b'\n', &mut buf).await.unwrap();
+        assert_eq!(buf, b"ping\r\npong\r\n");
+    }
 }
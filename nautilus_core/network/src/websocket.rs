@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{str::FromStr, sync::Arc, time::Duration};
+use std::{net::SocketAddr, str::FromStr, sync::Arc, time::Duration};
 
 use futures_util::{
     stream::{SplitSink, SplitStream},
@@ -24,12 +24,14 @@ use nautilus_core::python::to_pyruntime_err;
 use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
 use tokio::{net::TcpStream, sync::Mutex, task, time::sleep};
 use tokio_tungstenite::{
-    connect_async,
+    accept_async, connect_async,
     tungstenite::{client::IntoClientRequest, http::HeaderValue, Error, Message},
     MaybeTlsStream, WebSocketStream,
 };
 use tracing::{debug, error};
 
+use crate::broadcast::{recv_frame, BroadcastServer};
+
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 type SharedMessageWriter =
     Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
@@ -515,6 +517,49 @@ impl WebSocketClient {
     }
 }
 
+/// Provides a WebSocket server which sends frames to all connected clients, as binary
+/// messages.
+///
+/// Clients only receive the frames sent after they connect, and a client lagging more
+/// than the `capacity` frames misses the oldest frames.
+pub struct WebSocketServer {
+    server: BroadcastServer,
+}
+
+impl WebSocketServer {
+    /// Binds a new [`WebSocketServer`] to the given `addr`.
+    ///
+    /// Must be called within a Tokio runtime, which runs the server.
+    pub async fn bind(addr: &str, capacity: usize) -> Result<Self, Error> {
+        let server = BroadcastServer::bind(addr, capacity, |stream, mut rx| async move {
+            let mut ws_stream = accept_async(stream).await?;
+            while let Some(frame) = recv_frame(&mut rx).await {
+                ws_stream.send(Message::Binary(frame.to_vec())).await?;
+            }
+            ws_stream.close(None).await
+        })
+        .await?;
+        Ok(Self { server })
+    }
+
+    /// Returns the address the server is listening on.
+    #[must_use]
+    pub fn local_addr(&self) -> SocketAddr {
+        self.server.local_addr()
+    }
+
+    /// Returns the number of connected clients.
+    #[must_use]
+    pub fn client_count(&self) -> usize {
+        self.server.client_count()
+    }
+
+    /// Sends the given `data` to all connected clients.
+    pub fn send_bytes(&self, data: Vec<u8>) {
+        self.server.send(data);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use futures_util::{SinkExt, StreamExt};
@@ -525,16 +570,17 @@ mod tests {
         time::{sleep, Duration},
     };
     use tokio_tungstenite::{
-        accept_hdr_async,
+        accept_hdr_async, connect_async,
         tungstenite::{
             handshake::server::{self, Callback},
             http::HeaderValue,
+            Message,
         },
     };
     use tracing::debug;
     use tracing_test::traced_test;
 
-    use crate::websocket::{WebSocketClient, WebSocketConfig};
+    use crate::websocket::{WebSocketClient, WebSocketConfig, WebSocketServer};
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -785,4 +831,20 @@ checker = Checker()",
         sleep(Duration::from_secs(1)).await;
         assert!(client.is_disconnected());
     }
+
+    #[tokio::test]
+    async fn test_server_sends_to_clients() {
+        let server = WebSocketServer::bind("127.0.0.1:0", 10).await.unwrap();
+        let (mut client, _) = connect_async(format!("ws://{}", server.local_addr()))
+            .await
+            .unwrap();
+        while server.client_count() == 0 {
+            sleep(Duration::from_millis(1)).await;
+        }
+
+        server.send_bytes(b"ping".to_vec());
+
+        let msg = client.next().await.unwrap().unwrap();
+        assert_eq!(msg, Message::Binary(b"ping".to_vec()));
+    }
 }
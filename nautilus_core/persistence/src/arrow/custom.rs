@@ -0,0 +1,319 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A registry of Arrow codecs for custom data types, keyed by type name.
+//!
+//! Custom data is carried by [`Data::Custom`] as a trait object, so its codecs must be
+//! registered (once, e.g. at startup) with [`register_custom_data`] before it can be
+//! written or queried. The type name is stored in the record batch metadata, so a file
+//! of custom data can be decoded without the caller naming its type.
+
+use std::{collections::HashMap, sync::RwLock};
+
+use datafusion::arrow::{datatypes::Schema, error::ArrowError, record_batch::RecordBatch};
+use nautilus_model::data::{
+    custom::{CustomData, CustomDataTrait},
+    Data,
+};
+use once_cell::sync::Lazy;
+
+use super::{DecodeFromRecordBatch, EncodeToRecordBatch, EncodingError};
+
+/// The metadata key for the registered type name of custom data.
+pub const KEY_CUSTOM_DATA_TYPE: &str = "custom_data_type";
+
+type EncodeCustomFn =
+    fn(&HashMap<String, String>, &[CustomData]) -> Result<RecordBatch, ArrowError>;
+type DecodeCustomFn = fn(&HashMap<String, String>, RecordBatch) -> Result<Vec<Data>, EncodingError>;
+
+#[derive(Clone, Copy)]
+struct CustomDataCodec {
+    schema: fn(Option<HashMap<String, String>>) -> Schema,
+    encode: EncodeCustomFn,
+    decode: DecodeCustomFn,
+}
+
+static CUSTOM_DATA_CODECS: Lazy<RwLock<HashMap<String, CustomDataCodec>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Registers the Arrow codecs of the custom data type `T` under the given `type_name`,
+/// which must match the name returned by [`CustomDataTrait::type_name`].
+///
+/// Registering a type name again replaces its codecs.
+pub fn register_custom_data<T>(type_name: &str)
+where
+    T: CustomDataTrait + Clone + EncodeToRecordBatch + DecodeFromRecordBatch,
+{
+    let codec = CustomDataCodec {
+        schema: T::get_schema,
+        encode: encode_custom::<T>,
+        decode: decode_custom::<T>,
+    };
+    CUSTOM_DATA_CODECS
+        .write()
+        .unwrap()
+        .insert(type_name.to_string(), codec);
+}
+
+/// Returns whether codecs are registered for the given custom data `type_name`.
+#[must_use]
+pub fn is_custom_data_registered(type_name: &str) -> bool {
+    CUSTOM_DATA_CODECS.read().unwrap().contains_key(type_name)
+}
+
+/// Returns the schema of the custom data type registered under `type_name`.
+#[must_use]
+pub fn get_custom_data_schema(type_name: &str) -> Option<Schema> {
+    let codec = get_codec(type_name)?;
+    Some((codec.schema)(Some(custom_metadata(
+        type_name,
+        &HashMap::new(),
+    ))))
+}
+
+/// Encodes the given custom `data`, which must all be of the type registered under
+/// `type_name`, into a record batch.
+pub fn encode_custom_data(
+    type_name: &str,
+    metadata: &HashMap<String, String>,
+    data: &[CustomData],
+) -> Result<RecordBatch, ArrowError> {
+    let codec = get_codec(type_name).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("Unregistered custom data type `{type_name}`"))
+    })?;
+    (codec.encode)(&custom_metadata(type_name, metadata), data)
+}
+
+/// Decodes a record batch of custom data, using the codecs registered for the type
+/// name in its `metadata`.
+pub fn decode_custom_data(
+    metadata: &HashMap<String, String>,
+    record_batch: RecordBatch,
+) -> Result<Vec<Data>, EncodingError> {
+    let type_name = metadata
+        .get(KEY_CUSTOM_DATA_TYPE)
+        .ok_or(EncodingError::MissingMetadata(KEY_CUSTOM_DATA_TYPE))?;
+    let codec = get_codec(type_name)
+        .ok_or_else(|| EncodingError::UnregisteredCustomData(type_name.clone()))?;
+    (codec.decode)(metadata, record_batch)
+}
+
+fn get_codec(type_name: &str) -> Option<CustomDataCodec> {
+    CUSTOM_DATA_CODECS.read().unwrap().get(type_name).copied()
+}
+
+fn custom_metadata(type_name: &str, metadata: &HashMap<String, String>) -> HashMap<String, String> {
+    let mut metadata = metadata.clone();
+    metadata.insert(KEY_CUSTOM_DATA_TYPE.to_string(), type_name.to_string());
+    metadata
+}
+
+fn encode_custom<T>(
+    metadata: &HashMap<String, String>,
+    data: &[CustomData],
+) -> Result<RecordBatch, ArrowError>
+where
+    T: CustomDataTrait + Clone + EncodeToRecordBatch,
+{
+    let items = data
+        .iter()
+        .map(|custom| {
+            custom.downcast_ref::<T>().cloned().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Expected custom data of type `{}`, was `{}`",
+                    std::any::type_name::<T>(),
+                    custom.type_name(),
+                ))
+            })
+        })
+        .collect::<Result<Vec<T>, ArrowError>>()?;
+    T::encode_batch(metadata, &items)
+}
+
+fn decode_custom<T>(
+    metadata: &HashMap<String, String>,
+    record_batch: RecordBatch,
+) -> Result<Vec<Data>, EncodingError>
+where
+    T: CustomDataTrait + DecodeFromRecordBatch,
+{
+    let items = T::decode_batch(metadata, record_batch)?;
+    Ok(items
+        .into_iter()
+        .map(|item| Data::Custom(CustomData::new(item)))
+        .collect())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod stubs {
+    use std::{collections::HashMap, sync::Arc};
+
+    use datafusion::arrow::{
+        array::{Float64Array, StringArray, UInt64Array},
+        datatypes::{DataType, Field, Schema},
+        error::ArrowError,
+        record_batch::RecordBatch,
+    };
+    use nautilus_model::data::custom::stubs::FundingRate;
+
+    use crate::arrow::{
+        extract_column, parse_value, ArrowSchemaProvider, DecodeFromRecordBatch,
+        EncodeToRecordBatch, EncodingError,
+    };
+
+    impl ArrowSchemaProvider for FundingRate {
+        fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+            let fields = vec![
+                Field::new("instrument_id", DataType::Utf8, false),
+                Field::new("rate", DataType::Float64, false),
+                Field::new("ts_event", DataType::UInt64, false),
+                Field::new("ts_init", DataType::UInt64, false),
+            ];
+
+            match metadata {
+                Some(metadata) => Schema::new_with_metadata(fields, metadata),
+                None => Schema::new(fields),
+            }
+        }
+    }
+
+    impl EncodeToRecordBatch for FundingRate {
+        fn encode_batch(
+            metadata: &HashMap<String, String>,
+            data: &[Self],
+        ) -> Result<RecordBatch, ArrowError> {
+            let instrument_id_array =
+                StringArray::from_iter_values(data.iter().map(|f| f.instrument_id.to_string()));
+            let rate_array = Float64Array::from_iter_values(data.iter().map(|f| f.rate));
+            let ts_event_array = UInt64Array::from_iter_values(data.iter().map(|f| f.ts_event));
+            let ts_init_array = UInt64Array::from_iter_values(data.iter().map(|f| f.ts_init));
+
+            RecordBatch::try_new(
+                Self::get_schema(Some(metadata.clone())).into(),
+                vec![
+                    Arc::new(instrument_id_array),
+                    Arc::new(rate_array),
+                    Arc::new(ts_event_array),
+                    Arc::new(ts_init_array),
+                ],
+            )
+        }
+    }
+
+    impl DecodeFromRecordBatch for FundingRate {
+        fn decode_batch(
+            _metadata: &HashMap<String, String>,
+            record_batch: RecordBatch,
+        ) -> Result<Vec<Self>, EncodingError> {
+            let cols = record_batch.columns();
+
+            let instrument_id_values =
+                extract_column::<StringArray>(cols, "instrument_id", 0, DataType::Utf8)?;
+            let rate_values = extract_column::<Float64Array>(cols, "rate", 1, DataType::Float64)?;
+            let ts_event_values =
+                extract_column::<UInt64Array>(cols, "ts_event", 2, DataType::UInt64)?;
+            let ts_init_values =
+                extract_column::<UInt64Array>(cols, "ts_init", 3, DataType::UInt64)?;
+
+            (0..record_batch.num_rows())
+                .map(|i| {
+                    Ok(Self {
+                        instrument_id: parse_value(instrument_id_values, "instrument_id", i)?,
+                        rate: rate_values.value(i),
+                        ts_event: ts_event_values.value(i),
+                        ts_init: ts_init_values.value(i),
+                    })
+                })
+                .collect()
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::custom::stubs::FundingRate, identifiers::instrument_id::InstrumentId,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn funding_rates() -> Vec<FundingRate> {
+        (1..=2)
+            .map(|ts| FundingRate {
+                instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+                rate: 0.0001 * ts as f64,
+                ts_event: ts,
+                ts_init: ts,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_encode_decode_registered_custom_data() {
+        register_custom_data::<FundingRate>(FundingRate::TYPE_NAME);
+        let funding_rates = funding_rates();
+        let data: Vec<CustomData> = funding_rates.iter().copied().map(CustomData::new).collect();
+
+        let record_batch =
+            encode_custom_data(FundingRate::TYPE_NAME, &HashMap::new(), &data).unwrap();
+        let metadata = record_batch.schema().metadata().clone();
+
+        assert!(is_custom_data_registered(FundingRate::TYPE_NAME));
+        assert_eq!(metadata[KEY_CUSTOM_DATA_TYPE], FundingRate::TYPE_NAME);
+        assert_eq!(
+            get_custom_data_schema(FundingRate::TYPE_NAME).unwrap(),
+            *record_batch.schema()
+        );
+
+        let decoded = decode_custom_data(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded.len(), 2);
+        for (data, expected) in decoded.iter().zip(&funding_rates) {
+            let Data::Custom(custom) = data else {
+                panic!("Expected custom data, was {data:?}");
+            };
+            assert_eq!(custom.downcast_ref::<FundingRate>(), Some(expected));
+        }
+    }
+
+    #[rstest]
+    fn test_encode_unregistered_custom_data() {
+        let data: Vec<CustomData> = funding_rates().into_iter().map(CustomData::new).collect();
+
+        let result = encode_custom_data("Unregistered", &HashMap::new(), &data);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_decode_without_type_name() {
+        register_custom_data::<FundingRate>(FundingRate::TYPE_NAME);
+        let record_batch = FundingRate::encode_batch(&HashMap::new(), &funding_rates()).unwrap();
+
+        let result = decode_custom_data(&HashMap::new(), record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMetadata(KEY_CUSTOM_DATA_TYPE))
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{BooleanArray, StringArray, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::events::account::state::AccountState;
+
+use crate::arrow::{
+    extract_column, parse_json, parse_optional_value, parse_value, to_json, ArrowSchemaProvider,
+    DecodeFromRecordBatch, EncodeToRecordBatch, EncodingError,
+};
+
+impl ArrowSchemaProvider for AccountState {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("account_id", DataType::Utf8, false),
+            Field::new("account_type", DataType::Utf8, false),
+            Field::new("base_currency", DataType::Utf8, true),
+            Field::new("balances", DataType::Utf8, false),
+            Field::new("margins", DataType::Utf8, false),
+            Field::new("is_reported", DataType::Boolean, false),
+            Field::new("event_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for AccountState {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let account_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.account_id.to_string()));
+        let account_type_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.account_type.to_string()));
+        let base_currency_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.base_currency.map(|v| v.code.to_string())),
+        );
+        let balances_array = StringArray::from(
+            data.iter()
+                .map(|e| to_json(&e.balances))
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        let margins_array = StringArray::from(
+            data.iter()
+                .map(|e| to_json(&e.margins))
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        let is_reported_array =
+            BooleanArray::from(data.iter().map(|e| e.is_reported).collect::<Vec<_>>());
+        let event_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.event_id.to_string()));
+        let ts_event_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_event));
+        let ts_init_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_init));
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(account_id_array),
+                Arc::new(account_type_array),
+                Arc::new(base_currency_array),
+                Arc::new(balances_array),
+                Arc::new(margins_array),
+                Arc::new(is_reported_array),
+                Arc::new(event_id_array),
+                Arc::new(ts_event_array),
+                Arc::new(ts_init_array),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for AccountState {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let account_id_values =
+            extract_column::<StringArray>(cols, "account_id", 0, DataType::Utf8)?;
+        let account_type_values =
+            extract_column::<StringArray>(cols, "account_type", 1, DataType::Utf8)?;
+        let base_currency_values =
+            extract_column::<StringArray>(cols, "base_currency", 2, DataType::Utf8)?;
+        let balances_values = extract_column::<StringArray>(cols, "balances", 3, DataType::Utf8)?;
+        let margins_values = extract_column::<StringArray>(cols, "margins", 4, DataType::Utf8)?;
+        let is_reported_values =
+            extract_column::<BooleanArray>(cols, "is_reported", 5, DataType::Boolean)?;
+        let event_id_values = extract_column::<StringArray>(cols, "event_id", 6, DataType::Utf8)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 7, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 8, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Ok(Self {
+                    account_id: parse_value(account_id_values, "account_id", i)?,
+                    account_type: parse_value(account_type_values, "account_type", i)?,
+                    base_currency: parse_optional_value(base_currency_values, "base_currency", i)?,
+                    balances: parse_json(balances_values, "balances", i)?,
+                    margins: parse_json(margins_values, "margins", i)?,
+                    is_reported: is_reported_values.value(i),
+                    event_id: parse_value(event_id_values, "event_id", i)?,
+                    ts_event: ts_event_values.value(i),
+                    ts_init: ts_init_values.value(i),
+                })
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::account::stubs::cash_account_state_multi;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = AccountState::get_schema_map();
+
+        assert_eq!(schema_map.len(), 9);
+        assert_eq!(schema_map["ts_init"], "UInt64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let event = cash_account_state_multi();
+
+        let metadata = HashMap::new();
+        let record_batch =
+            AccountState::encode_batch(&metadata, &[event.clone(), event.clone()]).unwrap();
+
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = AccountState::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, vec![event.clone(), event]);
+    }
+}
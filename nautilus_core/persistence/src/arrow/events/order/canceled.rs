@@ -0,0 +1,178 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{StringArray, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::events::order::canceled::OrderCanceled;
+
+use crate::arrow::{
+    extract_column, parse_optional_value, parse_value, ArrowSchemaProvider, DecodeFromRecordBatch,
+    EncodeToRecordBatch, EncodingError,
+};
+
+impl ArrowSchemaProvider for OrderCanceled {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("trader_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("client_order_id", DataType::Utf8, false),
+            Field::new("event_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+            Field::new("reconciliation", DataType::UInt8, false),
+            Field::new("venue_order_id", DataType::Utf8, true),
+            Field::new("account_id", DataType::Utf8, true),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for OrderCanceled {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let trader_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.trader_id.to_string()));
+        let strategy_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.strategy_id.to_string()));
+        let instrument_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.instrument_id.to_string()));
+        let client_order_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.client_order_id.to_string()));
+        let event_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.event_id.to_string()));
+        let ts_event_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_event));
+        let ts_init_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_init));
+        let reconciliation_array =
+            UInt8Array::from_iter_values(data.iter().map(|e| e.reconciliation));
+        let venue_order_id_array =
+            StringArray::from_iter(data.iter().map(|e| e.venue_order_id.map(|v| v.to_string())));
+        let account_id_array =
+            StringArray::from_iter(data.iter().map(|e| e.account_id.map(|v| v.to_string())));
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(trader_id_array),
+                Arc::new(strategy_id_array),
+                Arc::new(instrument_id_array),
+                Arc::new(client_order_id_array),
+                Arc::new(event_id_array),
+                Arc::new(ts_event_array),
+                Arc::new(ts_init_array),
+                Arc::new(reconciliation_array),
+                Arc::new(venue_order_id_array),
+                Arc::new(account_id_array),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for OrderCanceled {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let trader_id_values = extract_column::<StringArray>(cols, "trader_id", 0, DataType::Utf8)?;
+        let strategy_id_values =
+            extract_column::<StringArray>(cols, "strategy_id", 1, DataType::Utf8)?;
+        let instrument_id_values =
+            extract_column::<StringArray>(cols, "instrument_id", 2, DataType::Utf8)?;
+        let client_order_id_values =
+            extract_column::<StringArray>(cols, "client_order_id", 3, DataType::Utf8)?;
+        let event_id_values = extract_column::<StringArray>(cols, "event_id", 4, DataType::Utf8)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 5, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 6, DataType::UInt64)?;
+        let reconciliation_values =
+            extract_column::<UInt8Array>(cols, "reconciliation", 7, DataType::UInt8)?;
+        let venue_order_id_values =
+            extract_column::<StringArray>(cols, "venue_order_id", 8, DataType::Utf8)?;
+        let account_id_values =
+            extract_column::<StringArray>(cols, "account_id", 9, DataType::Utf8)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Ok(Self {
+                    trader_id: parse_value(trader_id_values, "trader_id", i)?,
+                    strategy_id: parse_value(strategy_id_values, "strategy_id", i)?,
+                    instrument_id: parse_value(instrument_id_values, "instrument_id", i)?,
+                    client_order_id: parse_value(client_order_id_values, "client_order_id", i)?,
+                    event_id: parse_value(event_id_values, "event_id", i)?,
+                    ts_event: ts_event_values.value(i),
+                    ts_init: ts_init_values.value(i),
+                    reconciliation: reconciliation_values.value(i),
+                    venue_order_id: parse_optional_value(
+                        venue_order_id_values,
+                        "venue_order_id",
+                        i,
+                    )?,
+                    account_id: parse_optional_value(account_id_values, "account_id", i)?,
+                })
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        events::order::canceled::OrderCanceledBuilder, identifiers::venue_order_id::VenueOrderId,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = OrderCanceled::get_schema_map();
+
+        assert_eq!(schema_map.len(), 10);
+        assert_eq!(schema_map["ts_init"], "UInt64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let event = OrderCanceledBuilder::default()
+            .venue_order_id(Some(VenueOrderId::from("V-001")))
+            .build()
+            .unwrap();
+
+        let metadata = HashMap::new();
+        let record_batch = OrderCanceled::encode_batch(&metadata, &[event, event]).unwrap();
+
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = OrderCanceled::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, vec![event, event]);
+    }
+}
@@ -0,0 +1,285 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Array, BooleanArray, Int64Array, StringArray, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    events::order::filled::OrderFilled,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+use crate::arrow::{
+    extract_column, parse_optional_value, parse_value, ArrowSchemaProvider, DecodeFromRecordBatch,
+    EncodeToRecordBatch, EncodingError,
+};
+
+// The fill quantities are stored as raw fixed-point values, with their precisions in separate
+// columns (a batch may hold fills for instruments of differing precisions). The commission
+// precision is that of its currency.
+
+impl ArrowSchemaProvider for OrderFilled {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("trader_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("client_order_id", DataType::Utf8, false),
+            Field::new("venue_order_id", DataType::Utf8, false),
+            Field::new("account_id", DataType::Utf8, false),
+            Field::new("trade_id", DataType::Utf8, false),
+            Field::new("order_side", DataType::Utf8, false),
+            Field::new("order_type", DataType::Utf8, false),
+            Field::new("last_qty", DataType::UInt64, false),
+            Field::new("last_qty_precision", DataType::UInt8, false),
+            Field::new("last_px", DataType::Int64, false),
+            Field::new("last_px_precision", DataType::UInt8, false),
+            Field::new("currency", DataType::Utf8, false),
+            Field::new("liquidity_side", DataType::Utf8, false),
+            Field::new("event_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+            Field::new("reconciliation", DataType::Boolean, false),
+            Field::new("position_id", DataType::Utf8, true),
+            Field::new("commission", DataType::Int64, true),
+            Field::new("commission_currency", DataType::Utf8, true),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for OrderFilled {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let trader_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.trader_id.to_string()));
+        let strategy_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.strategy_id.to_string()));
+        let instrument_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.instrument_id.to_string()));
+        let client_order_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.client_order_id.to_string()));
+        let venue_order_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.venue_order_id.to_string()));
+        let account_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.account_id.to_string()));
+        let trade_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.trade_id.to_string()));
+        let order_side_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.order_side.to_string()));
+        let order_type_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.order_type.to_string()));
+        let last_qty_array = UInt64Array::from_iter_values(data.iter().map(|e| e.last_qty.raw));
+        let last_qty_precision_array =
+            UInt8Array::from_iter_values(data.iter().map(|e| e.last_qty.precision));
+        let last_px_array = Int64Array::from_iter_values(data.iter().map(|e| e.last_px.raw));
+        let last_px_precision_array =
+            UInt8Array::from_iter_values(data.iter().map(|e| e.last_px.precision));
+        let currency_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.currency.code.as_str()));
+        let liquidity_side_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.liquidity_side.to_string()));
+        let event_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.event_id.to_string()));
+        let ts_event_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_event));
+        let ts_init_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_init));
+        let reconciliation_array =
+            BooleanArray::from(data.iter().map(|e| e.reconciliation).collect::<Vec<_>>());
+        let position_id_array =
+            StringArray::from_iter(data.iter().map(|e| e.position_id.map(|v| v.to_string())));
+        let commission_array =
+            Int64Array::from_iter(data.iter().map(|e| e.commission.map(|v| v.raw)));
+        let commission_currency_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.commission.map(|v| v.currency.code.as_str())),
+        );
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(trader_id_array),
+                Arc::new(strategy_id_array),
+                Arc::new(instrument_id_array),
+                Arc::new(client_order_id_array),
+                Arc::new(venue_order_id_array),
+                Arc::new(account_id_array),
+                Arc::new(trade_id_array),
+                Arc::new(order_side_array),
+                Arc::new(order_type_array),
+                Arc::new(last_qty_array),
+                Arc::new(last_qty_precision_array),
+                Arc::new(last_px_array),
+                Arc::new(last_px_precision_array),
+                Arc::new(currency_array),
+                Arc::new(liquidity_side_array),
+                Arc::new(event_id_array),
+                Arc::new(ts_event_array),
+                Arc::new(ts_init_array),
+                Arc::new(reconciliation_array),
+                Arc::new(position_id_array),
+                Arc::new(commission_array),
+                Arc::new(commission_currency_array),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for OrderFilled {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let trader_id_values = extract_column::<StringArray>(cols, "trader_id", 0, DataType::Utf8)?;
+        let strategy_id_values =
+            extract_column::<StringArray>(cols, "strategy_id", 1, DataType::Utf8)?;
+        let instrument_id_values =
+            extract_column::<StringArray>(cols, "instrument_id", 2, DataType::Utf8)?;
+        let client_order_id_values =
+            extract_column::<StringArray>(cols, "client_order_id", 3, DataType::Utf8)?;
+        let venue_order_id_values =
+            extract_column::<StringArray>(cols, "venue_order_id", 4, DataType::Utf8)?;
+        let account_id_values =
+            extract_column::<StringArray>(cols, "account_id", 5, DataType::Utf8)?;
+        let trade_id_values = extract_column::<StringArray>(cols, "trade_id", 6, DataType::Utf8)?;
+        let order_side_values =
+            extract_column::<StringArray>(cols, "order_side", 7, DataType::Utf8)?;
+        let order_type_values =
+            extract_column::<StringArray>(cols, "order_type", 8, DataType::Utf8)?;
+        let last_qty_values = extract_column::<UInt64Array>(cols, "last_qty", 9, DataType::UInt64)?;
+        let last_qty_precision_values =
+            extract_column::<UInt8Array>(cols, "last_qty_precision", 10, DataType::UInt8)?;
+        let last_px_values = extract_column::<Int64Array>(cols, "last_px", 11, DataType::Int64)?;
+        let last_px_precision_values =
+            extract_column::<UInt8Array>(cols, "last_px_precision", 12, DataType::UInt8)?;
+        let currency_values = extract_column::<StringArray>(cols, "currency", 13, DataType::Utf8)?;
+        let liquidity_side_values =
+            extract_column::<StringArray>(cols, "liquidity_side", 14, DataType::Utf8)?;
+        let event_id_values = extract_column::<StringArray>(cols, "event_id", 15, DataType::Utf8)?;
+        let ts_event_values =
+            extract_column::<UInt64Array>(cols, "ts_event", 16, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 17, DataType::UInt64)?;
+        let reconciliation_values =
+            extract_column::<BooleanArray>(cols, "reconciliation", 18, DataType::Boolean)?;
+        let position_id_values =
+            extract_column::<StringArray>(cols, "position_id", 19, DataType::Utf8)?;
+        let commission_values =
+            extract_column::<Int64Array>(cols, "commission", 20, DataType::Int64)?;
+        let commission_currency_values =
+            extract_column::<StringArray>(cols, "commission_currency", 21, DataType::Utf8)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let last_qty = Quantity::from_raw(
+                    last_qty_values.value(i),
+                    last_qty_precision_values.value(i),
+                )
+                .map_err(|e| EncodingError::ParseError("last_qty", e.to_string()))?;
+                let last_px =
+                    Price::from_raw(last_px_values.value(i), last_px_precision_values.value(i))
+                        .map_err(|e| EncodingError::ParseError("last_px", e.to_string()))?;
+                let commission_currency =
+                    parse_optional_value(commission_currency_values, "commission_currency", i)?;
+                let commission = match (commission_values.is_valid(i), commission_currency) {
+                    (true, Some(currency)) => {
+                        Some(Money::from_raw(commission_values.value(i), currency))
+                    }
+                    (false, None) => None,
+                    _ => {
+                        return Err(EncodingError::ParseError(
+                            "commission",
+                            format!("Mismatched commission and currency at row {i}"),
+                        ))
+                    }
+                };
+
+                Ok(Self {
+                    trader_id: parse_value(trader_id_values, "trader_id", i)?,
+                    strategy_id: parse_value(strategy_id_values, "strategy_id", i)?,
+                    instrument_id: parse_value(instrument_id_values, "instrument_id", i)?,
+                    client_order_id: parse_value(client_order_id_values, "client_order_id", i)?,
+                    venue_order_id: parse_value(venue_order_id_values, "venue_order_id", i)?,
+                    account_id: parse_value(account_id_values, "account_id", i)?,
+                    trade_id: parse_value(trade_id_values, "trade_id", i)?,
+                    order_side: parse_value(order_side_values, "order_side", i)?,
+                    order_type: parse_value(order_type_values, "order_type", i)?,
+                    last_qty,
+                    last_px,
+                    currency: parse_value(currency_values, "currency", i)?,
+                    liquidity_side: parse_value(liquidity_side_values, "liquidity_side", i)?,
+                    event_id: parse_value(event_id_values, "event_id", i)?,
+                    ts_event: ts_event_values.value(i),
+                    ts_init: ts_init_values.value(i),
+                    reconciliation: reconciliation_values.value(i),
+                    position_id: parse_optional_value(position_id_values, "position_id", i)?,
+                    commission,
+                })
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::order::stubs::order_filled;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = OrderFilled::get_schema_map();
+
+        assert_eq!(schema_map.len(), 22);
+        assert_eq!(schema_map["ts_init"], "UInt64");
+        assert_eq!(schema_map["last_qty"], "UInt64");
+        assert_eq!(schema_map["last_px"], "Int64");
+        assert_eq!(schema_map["commission"], "Int64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(order_filled: OrderFilled) {
+        let event = order_filled;
+        let mut event_with_commission = order_filled;
+        event_with_commission.last_qty = Quantity::from("100");
+        event_with_commission.last_px = Price::from("1.00001");
+        event_with_commission.commission = Some(Money::from("2.50 USD"));
+
+        let metadata = HashMap::new();
+        let record_batch =
+            OrderFilled::encode_batch(&metadata, &[event, event_with_commission]).unwrap();
+
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = OrderFilled::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, vec![event, event_with_commission]);
+    }
+}
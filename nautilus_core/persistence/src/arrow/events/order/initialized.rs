@@ -0,0 +1,385 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Array, BooleanArray, StringArray, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::events::order::initialized::OrderInitialized;
+use ustr::Ustr;
+
+use crate::arrow::{
+    extract_column, parse_optional_json, parse_optional_value, parse_value, to_json,
+    ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch, EncodingError,
+};
+
+impl ArrowSchemaProvider for OrderInitialized {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("trader_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("client_order_id", DataType::Utf8, false),
+            Field::new("order_side", DataType::Utf8, false),
+            Field::new("order_type", DataType::Utf8, false),
+            Field::new("quantity", DataType::Utf8, false),
+            Field::new("time_in_force", DataType::Utf8, false),
+            Field::new("post_only", DataType::Boolean, false),
+            Field::new("reduce_only", DataType::Boolean, false),
+            Field::new("quote_quantity", DataType::Boolean, false),
+            Field::new("reconciliation", DataType::Boolean, false),
+            Field::new("event_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+            Field::new("price", DataType::Utf8, true),
+            Field::new("trigger_price", DataType::Utf8, true),
+            Field::new("trigger_type", DataType::Utf8, true),
+            Field::new("limit_offset", DataType::Utf8, true),
+            Field::new("trailing_offset", DataType::Utf8, true),
+            Field::new("trailing_offset_type", DataType::Utf8, true),
+            Field::new("expire_time", DataType::UInt64, true),
+            Field::new("display_qty", DataType::Utf8, true),
+            Field::new("emulation_trigger", DataType::Utf8, true),
+            Field::new("trigger_instrument_id", DataType::Utf8, true),
+            Field::new("contingency_type", DataType::Utf8, true),
+            Field::new("order_list_id", DataType::Utf8, true),
+            Field::new("linked_order_ids", DataType::Utf8, true),
+            Field::new("parent_order_id", DataType::Utf8, true),
+            Field::new("exec_algorithm_id", DataType::Utf8, true),
+            Field::new("exec_algorithm_params", DataType::Utf8, true),
+            Field::new("exec_spawn_id", DataType::Utf8, true),
+            Field::new("tags", DataType::Utf8, true),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for OrderInitialized {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let trader_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.trader_id.to_string()));
+        let strategy_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.strategy_id.to_string()));
+        let instrument_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.instrument_id.to_string()));
+        let client_order_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.client_order_id.to_string()));
+        let order_side_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.order_side.to_string()));
+        let order_type_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.order_type.to_string()));
+        let quantity_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.quantity.to_string()));
+        let time_in_force_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.time_in_force.to_string()));
+        let post_only_array =
+            BooleanArray::from(data.iter().map(|e| e.post_only).collect::<Vec<_>>());
+        let reduce_only_array =
+            BooleanArray::from(data.iter().map(|e| e.reduce_only).collect::<Vec<_>>());
+        let quote_quantity_array =
+            BooleanArray::from(data.iter().map(|e| e.quote_quantity).collect::<Vec<_>>());
+        let reconciliation_array =
+            BooleanArray::from(data.iter().map(|e| e.reconciliation).collect::<Vec<_>>());
+        let event_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.event_id.to_string()));
+        let ts_event_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_event));
+        let ts_init_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_init));
+        let price_array =
+            StringArray::from_iter(data.iter().map(|e| e.price.map(|v| v.to_string())));
+        let trigger_price_array =
+            StringArray::from_iter(data.iter().map(|e| e.trigger_price.map(|v| v.to_string())));
+        let trigger_type_array =
+            StringArray::from_iter(data.iter().map(|e| e.trigger_type.map(|v| v.to_string())));
+        let limit_offset_array =
+            StringArray::from_iter(data.iter().map(|e| e.limit_offset.map(|v| v.to_string())));
+        let trailing_offset_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.trailing_offset.map(|v| v.to_string())),
+        );
+        let trailing_offset_type_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.trailing_offset_type.map(|v| v.to_string())),
+        );
+        let expire_time_array = UInt64Array::from_iter(data.iter().map(|e| e.expire_time));
+        let display_qty_array =
+            StringArray::from_iter(data.iter().map(|e| e.display_qty.map(|v| v.to_string())));
+        let emulation_trigger_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.emulation_trigger.map(|v| v.to_string())),
+        );
+        let trigger_instrument_id_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.trigger_instrument_id.map(|v| v.to_string())),
+        );
+        let contingency_type_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.contingency_type.map(|v| v.to_string())),
+        );
+        let order_list_id_array =
+            StringArray::from_iter(data.iter().map(|e| e.order_list_id.map(|v| v.to_string())));
+        let linked_order_ids_array = StringArray::from(
+            data.iter()
+                .map(|e| e.linked_order_ids.as_ref().map(to_json).transpose())
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        let parent_order_id_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.parent_order_id.map(|v| v.to_string())),
+        );
+        let exec_algorithm_id_array = StringArray::from_iter(
+            data.iter()
+                .map(|e| e.exec_algorithm_id.map(|v| v.to_string())),
+        );
+        let exec_algorithm_params_array = StringArray::from(
+            data.iter()
+                .map(|e| e.exec_algorithm_params.as_ref().map(to_json).transpose())
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        let exec_spawn_id_array =
+            StringArray::from_iter(data.iter().map(|e| e.exec_spawn_id.map(|v| v.to_string())));
+        let tags_array = StringArray::from_iter(data.iter().map(|e| e.tags.map(|v| v.to_string())));
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(trader_id_array),
+                Arc::new(strategy_id_array),
+                Arc::new(instrument_id_array),
+                Arc::new(client_order_id_array),
+                Arc::new(order_side_array),
+                Arc::new(order_type_array),
+                Arc::new(quantity_array),
+                Arc::new(time_in_force_array),
+                Arc::new(post_only_array),
+                Arc::new(reduce_only_array),
+                Arc::new(quote_quantity_array),
+                Arc::new(reconciliation_array),
+                Arc::new(event_id_array),
+                Arc::new(ts_event_array),
+                Arc::new(ts_init_array),
+                Arc::new(price_array),
+                Arc::new(trigger_price_array),
+                Arc::new(trigger_type_array),
+                Arc::new(limit_offset_array),
+                Arc::new(trailing_offset_array),
+                Arc::new(trailing_offset_type_array),
+                Arc::new(expire_time_array),
+                Arc::new(display_qty_array),
+                Arc::new(emulation_trigger_array),
+                Arc::new(trigger_instrument_id_array),
+                Arc::new(contingency_type_array),
+                Arc::new(order_list_id_array),
+                Arc::new(linked_order_ids_array),
+                Arc::new(parent_order_id_array),
+                Arc::new(exec_algorithm_id_array),
+                Arc::new(exec_algorithm_params_array),
+                Arc::new(exec_spawn_id_array),
+                Arc::new(tags_array),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for OrderInitialized {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let trader_id_values = extract_column::<StringArray>(cols, "trader_id", 0, DataType::Utf8)?;
+        let strategy_id_values =
+            extract_column::<StringArray>(cols, "strategy_id", 1, DataType::Utf8)?;
+        let instrument_id_values =
+            extract_column::<StringArray>(cols, "instrument_id", 2, DataType::Utf8)?;
+        let client_order_id_values =
+            extract_column::<StringArray>(cols, "client_order_id", 3, DataType::Utf8)?;
+        let order_side_values =
+            extract_column::<StringArray>(cols, "order_side", 4, DataType::Utf8)?;
+        let order_type_values =
+            extract_column::<StringArray>(cols, "order_type", 5, DataType::Utf8)?;
+        let quantity_values = extract_column::<StringArray>(cols, "quantity", 6, DataType::Utf8)?;
+        let time_in_force_values =
+            extract_column::<StringArray>(cols, "time_in_force", 7, DataType::Utf8)?;
+        let post_only_values =
+            extract_column::<BooleanArray>(cols, "post_only", 8, DataType::Boolean)?;
+        let reduce_only_values =
+            extract_column::<BooleanArray>(cols, "reduce_only", 9, DataType::Boolean)?;
+        let quote_quantity_values =
+            extract_column::<BooleanArray>(cols, "quote_quantity", 10, DataType::Boolean)?;
+        let reconciliation_values =
+            extract_column::<BooleanArray>(cols, "reconciliation", 11, DataType::Boolean)?;
+        let event_id_values = extract_column::<StringArray>(cols, "event_id", 12, DataType::Utf8)?;
+        let ts_event_values =
+            extract_column::<UInt64Array>(cols, "ts_event", 13, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 14, DataType::UInt64)?;
+        let price_values = extract_column::<StringArray>(cols, "price", 15, DataType::Utf8)?;
+        let trigger_price_values =
+            extract_column::<StringArray>(cols, "trigger_price", 16, DataType::Utf8)?;
+        let trigger_type_values =
+            extract_column::<StringArray>(cols, "trigger_type", 17, DataType::Utf8)?;
+        let limit_offset_values =
+            extract_column::<StringArray>(cols, "limit_offset", 18, DataType::Utf8)?;
+        let trailing_offset_values =
+            extract_column::<StringArray>(cols, "trailing_offset", 19, DataType::Utf8)?;
+        let trailing_offset_type_values =
+            extract_column::<StringArray>(cols, "trailing_offset_type", 20, DataType::Utf8)?;
+        let expire_time_values =
+            extract_column::<UInt64Array>(cols, "expire_time", 21, DataType::UInt64)?;
+        let display_qty_values =
+            extract_column::<StringArray>(cols, "display_qty", 22, DataType::Utf8)?;
+        let emulation_trigger_values =
+            extract_column::<StringArray>(cols, "emulation_trigger", 23, DataType::Utf8)?;
+        let trigger_instrument_id_values =
+            extract_column::<StringArray>(cols, "trigger_instrument_id", 24, DataType::Utf8)?;
+        let contingency_type_values =
+            extract_column::<StringArray>(cols, "contingency_type", 25, DataType::Utf8)?;
+        let order_list_id_values =
+            extract_column::<StringArray>(cols, "order_list_id", 26, DataType::Utf8)?;
+        let linked_order_ids_values =
+            extract_column::<StringArray>(cols, "linked_order_ids", 27, DataType::Utf8)?;
+        let parent_order_id_values =
+            extract_column::<StringArray>(cols, "parent_order_id", 28, DataType::Utf8)?;
+        let exec_algorithm_id_values =
+            extract_column::<StringArray>(cols, "exec_algorithm_id", 29, DataType::Utf8)?;
+        let exec_algorithm_params_values =
+            extract_column::<StringArray>(cols, "exec_algorithm_params", 30, DataType::Utf8)?;
+        let exec_spawn_id_values =
+            extract_column::<StringArray>(cols, "exec_spawn_id", 31, DataType::Utf8)?;
+        let tags_values = extract_column::<StringArray>(cols, "tags", 32, DataType::Utf8)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Ok(Self {
+                    trader_id: parse_value(trader_id_values, "trader_id", i)?,
+                    strategy_id: parse_value(strategy_id_values, "strategy_id", i)?,
+                    instrument_id: parse_value(instrument_id_values, "instrument_id", i)?,
+                    client_order_id: parse_value(client_order_id_values, "client_order_id", i)?,
+                    order_side: parse_value(order_side_values, "order_side", i)?,
+                    order_type: parse_value(order_type_values, "order_type", i)?,
+                    quantity: parse_value(quantity_values, "quantity", i)?,
+                    time_in_force: parse_value(time_in_force_values, "time_in_force", i)?,
+                    post_only: post_only_values.value(i),
+                    reduce_only: reduce_only_values.value(i),
+                    quote_quantity: quote_quantity_values.value(i),
+                    reconciliation: reconciliation_values.value(i),
+                    event_id: parse_value(event_id_values, "event_id", i)?,
+                    ts_event: ts_event_values.value(i),
+                    ts_init: ts_init_values.value(i),
+                    price: parse_optional_value(price_values, "price", i)?,
+                    trigger_price: parse_optional_value(trigger_price_values, "trigger_price", i)?,
+                    trigger_type: parse_optional_value(trigger_type_values, "trigger_type", i)?,
+                    limit_offset: parse_optional_value(limit_offset_values, "limit_offset", i)?,
+                    trailing_offset: parse_optional_value(
+                        trailing_offset_values,
+                        "trailing_offset",
+                        i,
+                    )?,
+                    trailing_offset_type: parse_optional_value(
+                        trailing_offset_type_values,
+                        "trailing_offset_type",
+                        i,
+                    )?,
+                    expire_time: (!expire_time_values.is_null(i))
+                        .then(|| expire_time_values.value(i)),
+                    display_qty: parse_optional_value(display_qty_values, "display_qty", i)?,
+                    emulation_trigger: parse_optional_value(
+                        emulation_trigger_values,
+                        "emulation_trigger",
+                        i,
+                    )?,
+                    trigger_instrument_id: parse_optional_value(
+                        trigger_instrument_id_values,
+                        "trigger_instrument_id",
+                        i,
+                    )?,
+                    contingency_type: parse_optional_value(
+                        contingency_type_values,
+                        "contingency_type",
+                        i,
+                    )?,
+                    order_list_id: parse_optional_value(order_list_id_values, "order_list_id", i)?,
+                    linked_order_ids: parse_optional_json(
+                        linked_order_ids_values,
+                        "linked_order_ids",
+                        i,
+                    )?,
+                    parent_order_id: parse_optional_value(
+                        parent_order_id_values,
+                        "parent_order_id",
+                        i,
+                    )?,
+                    exec_algorithm_id: parse_optional_value(
+                        exec_algorithm_id_values,
+                        "exec_algorithm_id",
+                        i,
+                    )?,
+                    exec_algorithm_params: parse_optional_json(
+                        exec_algorithm_params_values,
+                        "exec_algorithm_params",
+                        i,
+                    )?,
+                    exec_spawn_id: parse_optional_value(exec_spawn_id_values, "exec_spawn_id", i)?,
+                    tags: (!tags_values.is_null(i)).then(|| Ustr::from(tags_values.value(i))),
+                })
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::order::stubs::order_initialized_buy_limit;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = OrderInitialized::get_schema_map();
+
+        assert_eq!(schema_map.len(), 33);
+        assert_eq!(schema_map["ts_init"], "UInt64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(order_initialized_buy_limit: OrderInitialized) {
+        let event = order_initialized_buy_limit;
+
+        let metadata = HashMap::new();
+        let record_batch =
+            OrderInitialized::encode_batch(&metadata, &[event.clone(), event.clone()]).unwrap();
+
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = OrderInitialized::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, vec![event.clone(), event]);
+    }
+}
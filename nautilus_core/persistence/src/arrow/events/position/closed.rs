@@ -0,0 +1,331 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Float64Array, Int64Array, StringArray, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::events::position::closed::PositionClosed;
+
+use super::{decode_money, decode_price, decode_quantity};
+use crate::arrow::{
+    extract_column, parse_value, ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch,
+    EncodingError,
+};
+
+impl ArrowSchemaProvider for PositionClosed {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("trader_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("position_id", DataType::Utf8, false),
+            Field::new("account_id", DataType::Utf8, false),
+            Field::new("opening_order_id", DataType::Utf8, false),
+            Field::new("closing_order_id", DataType::Utf8, false),
+            Field::new("entry", DataType::Utf8, false),
+            Field::new("side", DataType::Utf8, false),
+            Field::new("signed_qty", DataType::Float64, false),
+            Field::new("quantity", DataType::UInt64, false),
+            Field::new("quantity_precision", DataType::UInt8, false),
+            Field::new("peak_quantity", DataType::UInt64, false),
+            Field::new("peak_quantity_precision", DataType::UInt8, false),
+            Field::new("last_qty", DataType::UInt64, false),
+            Field::new("last_qty_precision", DataType::UInt8, false),
+            Field::new("last_px", DataType::Int64, false),
+            Field::new("last_px_precision", DataType::UInt8, false),
+            Field::new("currency", DataType::Utf8, false),
+            Field::new("avg_px_open", DataType::Float64, false),
+            Field::new("avg_px_closed", DataType::Float64, false),
+            Field::new("realized_return", DataType::Float64, false),
+            Field::new("realized_pnl", DataType::Int64, false),
+            Field::new("realized_pnl_currency", DataType::Utf8, false),
+            Field::new("unrealized_pnl", DataType::Int64, false),
+            Field::new("unrealized_pnl_currency", DataType::Utf8, false),
+            Field::new("duration", DataType::Int64, false),
+            Field::new("ts_opened", DataType::UInt64, false),
+            Field::new("ts_closed", DataType::UInt64, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for PositionClosed {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let trader_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.trader_id.to_string()));
+        let strategy_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.strategy_id.to_string()));
+        let instrument_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.instrument_id.to_string()));
+        let position_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.position_id.to_string()));
+        let account_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.account_id.to_string()));
+        let opening_order_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.opening_order_id.to_string()));
+        let closing_order_id_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.closing_order_id.to_string()));
+        let entry_array = StringArray::from_iter_values(data.iter().map(|e| e.entry.to_string()));
+        let side_array = StringArray::from_iter_values(data.iter().map(|e| e.side.to_string()));
+        let signed_qty_array = Float64Array::from_iter_values(data.iter().map(|e| e.signed_qty));
+        let quantity_array = UInt64Array::from_iter_values(data.iter().map(|e| e.quantity.raw));
+        let quantity_precision_array =
+            UInt8Array::from_iter_values(data.iter().map(|e| e.quantity.precision));
+        let peak_quantity_array =
+            UInt64Array::from_iter_values(data.iter().map(|e| e.peak_quantity.raw));
+        let peak_quantity_precision_array =
+            UInt8Array::from_iter_values(data.iter().map(|e| e.peak_quantity.precision));
+        let last_qty_array = UInt64Array::from_iter_values(data.iter().map(|e| e.last_qty.raw));
+        let last_qty_precision_array =
+            UInt8Array::from_iter_values(data.iter().map(|e| e.last_qty.precision));
+        let last_px_array = Int64Array::from_iter_values(data.iter().map(|e| e.last_px.raw));
+        let last_px_precision_array =
+            UInt8Array::from_iter_values(data.iter().map(|e| e.last_px.precision));
+        let currency_array =
+            StringArray::from_iter_values(data.iter().map(|e| e.currency.code.as_str()));
+        let avg_px_open_array = Float64Array::from_iter_values(data.iter().map(|e| e.avg_px_open));
+        let avg_px_closed_array =
+            Float64Array::from_iter_values(data.iter().map(|e| e.avg_px_closed));
+        let realized_return_array =
+            Float64Array::from_iter_values(data.iter().map(|e| e.realized_return));
+        let realized_pnl_array =
+            Int64Array::from_iter_values(data.iter().map(|e| e.realized_pnl.raw));
+        let realized_pnl_currency_array = StringArray::from_iter_values(
+            data.iter().map(|e| e.realized_pnl.currency.code.as_str()),
+        );
+        let unrealized_pnl_array =
+            Int64Array::from_iter_values(data.iter().map(|e| e.unrealized_pnl.raw));
+        let unrealized_pnl_currency_array = StringArray::from_iter_values(
+            data.iter().map(|e| e.unrealized_pnl.currency.code.as_str()),
+        );
+        let duration_array = Int64Array::from_iter_values(data.iter().map(|e| e.duration));
+        let ts_opened_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_opened));
+        let ts_closed_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_closed));
+        let ts_event_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_event));
+        let ts_init_array = UInt64Array::from_iter_values(data.iter().map(|e| e.ts_init));
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(trader_id_array),
+                Arc::new(strategy_id_array),
+                Arc::new(instrument_id_array),
+                Arc::new(position_id_array),
+                Arc::new(account_id_array),
+                Arc::new(opening_order_id_array),
+                Arc::new(closing_order_id_array),
+                Arc::new(entry_array),
+                Arc::new(side_array),
+                Arc::new(signed_qty_array),
+                Arc::new(quantity_array),
+                Arc::new(quantity_precision_array),
+                Arc::new(peak_quantity_array),
+                Arc::new(peak_quantity_precision_array),
+                Arc::new(last_qty_array),
+                Arc::new(last_qty_precision_array),
+                Arc::new(last_px_array),
+                Arc::new(last_px_precision_array),
+                Arc::new(currency_array),
+                Arc::new(avg_px_open_array),
+                Arc::new(avg_px_closed_array),
+                Arc::new(realized_return_array),
+                Arc::new(realized_pnl_array),
+                Arc::new(realized_pnl_currency_array),
+                Arc::new(unrealized_pnl_array),
+                Arc::new(unrealized_pnl_currency_array),
+                Arc::new(duration_array),
+                Arc::new(ts_opened_array),
+                Arc::new(ts_closed_array),
+                Arc::new(ts_event_array),
+                Arc::new(ts_init_array),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for PositionClosed {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let trader_id_values = extract_column::<StringArray>(cols, "trader_id", 0, DataType::Utf8)?;
+        let strategy_id_values =
+            extract_column::<StringArray>(cols, "strategy_id", 1, DataType::Utf8)?;
+        let instrument_id_values =
+            extract_column::<StringArray>(cols, "instrument_id", 2, DataType::Utf8)?;
+        let position_id_values =
+            extract_column::<StringArray>(cols, "position_id", 3, DataType::Utf8)?;
+        let account_id_values =
+            extract_column::<StringArray>(cols, "account_id", 4, DataType::Utf8)?;
+        let opening_order_id_values =
+            extract_column::<StringArray>(cols, "opening_order_id", 5, DataType::Utf8)?;
+        let closing_order_id_values =
+            extract_column::<StringArray>(cols, "closing_order_id", 6, DataType::Utf8)?;
+        let entry_values = extract_column::<StringArray>(cols, "entry", 7, DataType::Utf8)?;
+        let side_values = extract_column::<StringArray>(cols, "side", 8, DataType::Utf8)?;
+        let signed_qty_values =
+            extract_column::<Float64Array>(cols, "signed_qty", 9, DataType::Float64)?;
+        let quantity_values =
+            extract_column::<UInt64Array>(cols, "quantity", 10, DataType::UInt64)?;
+        let quantity_precision_values =
+            extract_column::<UInt8Array>(cols, "quantity_precision", 11, DataType::UInt8)?;
+        let peak_quantity_values =
+            extract_column::<UInt64Array>(cols, "peak_quantity", 12, DataType::UInt64)?;
+        let peak_quantity_precision_values =
+            extract_column::<UInt8Array>(cols, "peak_quantity_precision", 13, DataType::UInt8)?;
+        let last_qty_values =
+            extract_column::<UInt64Array>(cols, "last_qty", 14, DataType::UInt64)?;
+        let last_qty_precision_values =
+            extract_column::<UInt8Array>(cols, "last_qty_precision", 15, DataType::UInt8)?;
+        let last_px_values = extract_column::<Int64Array>(cols, "last_px", 16, DataType::Int64)?;
+        let last_px_precision_values =
+            extract_column::<UInt8Array>(cols, "last_px_precision", 17, DataType::UInt8)?;
+        let currency_values = extract_column::<StringArray>(cols, "currency", 18, DataType::Utf8)?;
+        let avg_px_open_values =
+            extract_column::<Float64Array>(cols, "avg_px_open", 19, DataType::Float64)?;
+        let avg_px_closed_values =
+            extract_column::<Float64Array>(cols, "avg_px_closed", 20, DataType::Float64)?;
+        let realized_return_values =
+            extract_column::<Float64Array>(cols, "realized_return", 21, DataType::Float64)?;
+        let realized_pnl_values =
+            extract_column::<Int64Array>(cols, "realized_pnl", 22, DataType::Int64)?;
+        let realized_pnl_currency_values =
+            extract_column::<StringArray>(cols, "realized_pnl_currency", 23, DataType::Utf8)?;
+        let unrealized_pnl_values =
+            extract_column::<Int64Array>(cols, "unrealized_pnl", 24, DataType::Int64)?;
+        let unrealized_pnl_currency_values =
+            extract_column::<StringArray>(cols, "unrealized_pnl_currency", 25, DataType::Utf8)?;
+        let duration_values = extract_column::<Int64Array>(cols, "duration", 26, DataType::Int64)?;
+        let ts_opened_values =
+            extract_column::<UInt64Array>(cols, "ts_opened", 27, DataType::UInt64)?;
+        let ts_closed_values =
+            extract_column::<UInt64Array>(cols, "ts_closed", 28, DataType::UInt64)?;
+        let ts_event_values =
+            extract_column::<UInt64Array>(cols, "ts_event", 29, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 30, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Ok(Self {
+                    trader_id: parse_value(trader_id_values, "trader_id", i)?,
+                    strategy_id: parse_value(strategy_id_values, "strategy_id", i)?,
+                    instrument_id: parse_value(instrument_id_values, "instrument_id", i)?,
+                    position_id: parse_value(position_id_values, "position_id", i)?,
+                    account_id: parse_value(account_id_values, "account_id", i)?,
+                    opening_order_id: parse_value(opening_order_id_values, "opening_order_id", i)?,
+                    closing_order_id: parse_value(closing_order_id_values, "closing_order_id", i)?,
+                    entry: parse_value(entry_values, "entry", i)?,
+                    side: parse_value(side_values, "side", i)?,
+                    signed_qty: signed_qty_values.value(i),
+                    quantity: decode_quantity(
+                        quantity_values,
+                        quantity_precision_values,
+                        "quantity",
+                        i,
+                    )?,
+                    peak_quantity: decode_quantity(
+                        peak_quantity_values,
+                        peak_quantity_precision_values,
+                        "peak_quantity",
+                        i,
+                    )?,
+                    last_qty: decode_quantity(
+                        last_qty_values,
+                        last_qty_precision_values,
+                        "last_qty",
+                        i,
+                    )?,
+                    last_px: decode_price(last_px_values, last_px_precision_values, "last_px", i)?,
+                    currency: parse_value(currency_values, "currency", i)?,
+                    avg_px_open: avg_px_open_values.value(i),
+                    avg_px_closed: avg_px_closed_values.value(i),
+                    realized_return: realized_return_values.value(i),
+                    realized_pnl: decode_money(
+                        realized_pnl_values,
+                        realized_pnl_currency_values,
+                        "realized_pnl_currency",
+                        i,
+                    )?,
+                    unrealized_pnl: decode_money(
+                        unrealized_pnl_values,
+                        unrealized_pnl_currency_values,
+                        "unrealized_pnl_currency",
+                        i,
+                    )?,
+                    duration: duration_values.value(i),
+                    ts_opened: ts_opened_values.value(i),
+                    ts_closed: ts_closed_values.value(i),
+                    ts_event: ts_event_values.value(i),
+                    ts_init: ts_init_values.value(i),
+                })
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::instruments::{currency_pair::CurrencyPair, stubs::audusd_sim};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::arrow::events::position::stubs::position;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = PositionClosed::get_schema_map();
+
+        assert_eq!(schema_map.len(), 31);
+        assert_eq!(schema_map["ts_init"], "UInt64");
+        assert_eq!(schema_map["peak_quantity"], "UInt64");
+        assert_eq!(schema_map["realized_pnl"], "Int64");
+        assert_eq!(schema_map["unrealized_pnl_currency"], "Utf8");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(audusd_sim: CurrencyPair) {
+        let (position, fill) = position(&audusd_sim, true);
+        let event = PositionClosed::create(&position, &fill, 2);
+
+        let metadata = HashMap::new();
+        let record_batch =
+            PositionClosed::encode_batch(&metadata, &[event.clone(), event.clone()]).unwrap();
+
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = PositionClosed::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, vec![event.clone(), event]);
+    }
+}
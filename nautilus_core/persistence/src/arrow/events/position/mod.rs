@@ -0,0 +1,102 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use datafusion::arrow::array::{Int64Array, StringArray, UInt64Array, UInt8Array};
+use nautilus_model::types::{money::Money, price::Price, quantity::Quantity};
+
+use crate::arrow::{parse_value, EncodingError};
+
+pub mod changed;
+pub mod closed;
+pub mod opened;
+
+// Position quantities, prices and PnLs are stored as raw fixed-point values (as for fills),
+// with their precisions or currencies in separate columns, so they can be aggregated in SQL.
+
+/// Decodes the [`Quantity`] at row `i` from its raw value and precision columns.
+fn decode_quantity(
+    values: &UInt64Array,
+    precisions: &UInt8Array,
+    column_key: &'static str,
+    i: usize,
+) -> Result<Quantity, EncodingError> {
+    Quantity::from_raw(values.value(i), precisions.value(i))
+        .map_err(|e| EncodingError::ParseError(column_key, e.to_string()))
+}
+
+/// Decodes the [`Price`] at row `i` from its raw value and precision columns.
+fn decode_price(
+    values: &Int64Array,
+    precisions: &UInt8Array,
+    column_key: &'static str,
+    i: usize,
+) -> Result<Price, EncodingError> {
+    Price::from_raw(values.value(i), precisions.value(i))
+        .map_err(|e| EncodingError::ParseError(column_key, e.to_string()))
+}
+
+/// Decodes the [`Money`] at row `i` from its raw value and currency columns.
+fn decode_money(
+    values: &Int64Array,
+    currencies: &StringArray,
+    currency_key: &'static str,
+    i: usize,
+) -> Result<Money, EncodingError> {
+    let currency = parse_value(currencies, currency_key, i)?;
+    Ok(Money::from_raw(values.value(i), currency))
+}
+
+#[cfg(test)]
+pub(crate) mod stubs {
+    use nautilus_model::{
+        enums::OrderSide,
+        events::order::filled::{OrderFilled, OrderFilledBuilder},
+        identifiers::{position_id::PositionId, trade_id::TradeId},
+        instruments::currency_pair::CurrencyPair,
+        position::Position,
+        types::{money::Money, price::Price, quantity::Quantity},
+    };
+
+    /// Returns a position opened with a buy fill, along with the last fill applied to
+    /// it (a sell fill closing the position when `closed` is true).
+    pub fn position(instrument: &CurrencyPair, closed: bool) -> (Position, OrderFilled) {
+        let open = OrderFilledBuilder::default()
+            .instrument_id(instrument.id)
+            .trade_id(TradeId::from("T-1"))
+            .order_side(OrderSide::Buy)
+            .last_qty(Quantity::from("100000"))
+            .last_px(Price::from("1.00000"))
+            .currency(instrument.quote_currency)
+            .ts_event(1)
+            .position_id(Some(PositionId::from("P-001")))
+            .commission(Some(Money::from("2.00 USD")))
+            .build()
+            .unwrap();
+        let mut position = Position::new(instrument, open).unwrap();
+        if !closed {
+            return (position, open);
+        }
+
+        let close = OrderFilled {
+            trade_id: TradeId::from("T-2"),
+            order_side: OrderSide::Sell,
+            last_px: Price::from("1.00010"),
+            ts_event: 2,
+            ..open
+        };
+        position.apply(&close);
+        (position, close)
+    }
+}
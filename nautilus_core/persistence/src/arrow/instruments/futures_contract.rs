@@ -0,0 +1,226 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{StringArray, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{identifiers::symbol::Symbol, instruments::futures_contract::FuturesContract};
+use ustr::Ustr;
+
+use super::instrument_error;
+use crate::arrow::{
+    extract_column, parse_optional_value, parse_value, ArrowSchemaProvider, DecodeFromRecordBatch,
+    EncodeToRecordBatch, EncodingError,
+};
+
+impl ArrowSchemaProvider for FuturesContract {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("raw_symbol", DataType::Utf8, false),
+            Field::new("asset_class", DataType::Utf8, false),
+            Field::new("underlying", DataType::Utf8, false),
+            Field::new("activation_ns", DataType::UInt64, false),
+            Field::new("expiration_ns", DataType::UInt64, false),
+            Field::new("currency", DataType::Utf8, false),
+            Field::new("price_precision", DataType::UInt8, false),
+            Field::new("price_increment", DataType::Utf8, false),
+            Field::new("multiplier", DataType::Utf8, false),
+            Field::new("lot_size", DataType::Utf8, false),
+            Field::new("max_quantity", DataType::Utf8, true),
+            Field::new("min_quantity", DataType::Utf8, true),
+            Field::new("max_price", DataType::Utf8, true),
+            Field::new("min_price", DataType::Utf8, true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for FuturesContract {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let id_array = StringArray::from_iter_values(data.iter().map(|i| i.id.to_string()));
+        let raw_symbol_array =
+            StringArray::from_iter_values(data.iter().map(|i| i.raw_symbol.to_string()));
+        let asset_class_array =
+            StringArray::from_iter_values(data.iter().map(|i| i.asset_class.to_string()));
+        let underlying_array =
+            StringArray::from_iter_values(data.iter().map(|i| i.underlying.to_string()));
+        let activation_ns_array =
+            UInt64Array::from_iter_values(data.iter().map(|i| i.activation_ns));
+        let expiration_ns_array =
+            UInt64Array::from_iter_values(data.iter().map(|i| i.expiration_ns));
+        let currency_array =
+            StringArray::from_iter_values(data.iter().map(|i| i.currency.code.to_string()));
+        let price_precision_array =
+            UInt8Array::from_iter_values(data.iter().map(|i| i.price_precision));
+        let price_increment_array =
+            StringArray::from_iter_values(data.iter().map(|i| i.price_increment.to_string()));
+        let multiplier_array =
+            StringArray::from_iter_values(data.iter().map(|i| i.multiplier.to_string()));
+        let lot_size_array =
+            StringArray::from_iter_values(data.iter().map(|i| i.lot_size.to_string()));
+        let max_quantity_array =
+            StringArray::from_iter(data.iter().map(|i| i.max_quantity.map(|v| v.to_string())));
+        let min_quantity_array =
+            StringArray::from_iter(data.iter().map(|i| i.min_quantity.map(|v| v.to_string())));
+        let max_price_array =
+            StringArray::from_iter(data.iter().map(|i| i.max_price.map(|v| v.to_string())));
+        let min_price_array =
+            StringArray::from_iter(data.iter().map(|i| i.min_price.map(|v| v.to_string())));
+        let ts_event_array = UInt64Array::from_iter_values(data.iter().map(|i| i.ts_event));
+        let ts_init_array = UInt64Array::from_iter_values(data.iter().map(|i| i.ts_init));
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(id_array),
+                Arc::new(raw_symbol_array),
+                Arc::new(asset_class_array),
+                Arc::new(underlying_array),
+                Arc::new(activation_ns_array),
+                Arc::new(expiration_ns_array),
+                Arc::new(currency_array),
+                Arc::new(price_precision_array),
+                Arc::new(price_increment_array),
+                Arc::new(multiplier_array),
+                Arc::new(lot_size_array),
+                Arc::new(max_quantity_array),
+                Arc::new(min_quantity_array),
+                Arc::new(max_price_array),
+                Arc::new(min_price_array),
+                Arc::new(ts_event_array),
+                Arc::new(ts_init_array),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for FuturesContract {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let id_values = extract_column::<StringArray>(cols, "id", 0, DataType::Utf8)?;
+        let raw_symbol_values =
+            extract_column::<StringArray>(cols, "raw_symbol", 1, DataType::Utf8)?;
+        let asset_class_values =
+            extract_column::<StringArray>(cols, "asset_class", 2, DataType::Utf8)?;
+        let underlying_values =
+            extract_column::<StringArray>(cols, "underlying", 3, DataType::Utf8)?;
+        let activation_ns_values =
+            extract_column::<UInt64Array>(cols, "activation_ns", 4, DataType::UInt64)?;
+        let expiration_ns_values =
+            extract_column::<UInt64Array>(cols, "expiration_ns", 5, DataType::UInt64)?;
+        let currency_values = extract_column::<StringArray>(cols, "currency", 6, DataType::Utf8)?;
+        let price_precision_values =
+            extract_column::<UInt8Array>(cols, "price_precision", 7, DataType::UInt8)?;
+        let price_increment_values =
+            extract_column::<StringArray>(cols, "price_increment", 8, DataType::Utf8)?;
+        let multiplier_values =
+            extract_column::<StringArray>(cols, "multiplier", 9, DataType::Utf8)?;
+        let lot_size_values = extract_column::<StringArray>(cols, "lot_size", 10, DataType::Utf8)?;
+        let max_quantity_values =
+            extract_column::<StringArray>(cols, "max_quantity", 11, DataType::Utf8)?;
+        let min_quantity_values =
+            extract_column::<StringArray>(cols, "min_quantity", 12, DataType::Utf8)?;
+        let max_price_values =
+            extract_column::<StringArray>(cols, "max_price", 13, DataType::Utf8)?;
+        let min_price_values =
+            extract_column::<StringArray>(cols, "min_price", 14, DataType::Utf8)?;
+        let ts_event_values =
+            extract_column::<UInt64Array>(cols, "ts_event", 15, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 16, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Self::new(
+                    parse_value(id_values, "id", i)?,
+                    Symbol::from(raw_symbol_values.value(i)),
+                    parse_value(asset_class_values, "asset_class", i)?,
+                    Ustr::from(underlying_values.value(i)),
+                    activation_ns_values.value(i),
+                    expiration_ns_values.value(i),
+                    parse_value(currency_values, "currency", i)?,
+                    price_precision_values.value(i),
+                    parse_value(price_increment_values, "price_increment", i)?,
+                    parse_value(multiplier_values, "multiplier", i)?,
+                    parse_value(lot_size_values, "lot_size", i)?,
+                    parse_optional_value(max_quantity_values, "max_quantity", i)?,
+                    parse_optional_value(min_quantity_values, "min_quantity", i)?,
+                    parse_optional_value(max_price_values, "max_price", i)?,
+                    parse_optional_value(min_price_values, "min_price", i)?,
+                    ts_event_values.value(i),
+                    ts_init_values.value(i),
+                )
+                .map_err(instrument_error)
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::instruments::stubs::futures_contract_es;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = FuturesContract::get_schema_map();
+
+        assert_eq!(schema_map.len(), 17);
+        assert_eq!(schema_map["id"], "Utf8");
+        assert_eq!(schema_map["price_precision"], "UInt8");
+        assert_eq!(schema_map["ts_init"], "UInt64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(futures_contract_es: FuturesContract) {
+        let metadata = HashMap::new();
+        let record_batch =
+            FuturesContract::encode_batch(&metadata, &[futures_contract_es, futures_contract_es])
+                .unwrap();
+
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = FuturesContract::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(
+            serde_json::to_value(decoded[0]).unwrap(),
+            serde_json::to_value(futures_contract_es).unwrap()
+        );
+    }
+}
@@ -0,0 +1,37 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arrow codecs for instrument definitions.
+//!
+//! Unlike market data, a batch of instruments can mix price and size precisions, so
+//! prices, quantities, money amounts and decimals are encoded as strings (as with the
+//! Python instrument schemas), and precisions as columns of their own.
+
+pub mod crypto_future;
+pub mod crypto_perpetual;
+pub mod currency_pair;
+pub mod equity;
+pub mod futures_contract;
+pub mod futures_spread;
+pub mod options_contract;
+pub mod options_spread;
+pub mod synthetic;
+
+use super::EncodingError;
+
+/// Maps an error from constructing an instrument to an [`EncodingError`].
+fn instrument_error(e: anyhow::Error) -> EncodingError {
+    EncodingError::ParseError("instrument", e.to_string())
+}
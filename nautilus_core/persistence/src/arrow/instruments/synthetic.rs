@@ -0,0 +1,193 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Array, ListArray, ListBuilder, StringArray, StringBuilder, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    identifiers::instrument_id::InstrumentId, instruments::synthetic::SyntheticInstrument,
+};
+
+use super::instrument_error;
+use crate::arrow::{
+    extract_column, parse_value, ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch,
+    EncodingError,
+};
+
+fn components_data_type() -> DataType {
+    DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)))
+}
+
+impl ArrowSchemaProvider for SyntheticInstrument {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("price_precision", DataType::UInt8, false),
+            Field::new("components", components_data_type(), false),
+            Field::new("formula", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for SyntheticInstrument {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut components_builder = ListBuilder::new(StringBuilder::new());
+        for synthetic in data {
+            for component in &synthetic.components {
+                components_builder
+                    .values()
+                    .append_value(component.to_string());
+            }
+            components_builder.append(true);
+        }
+
+        let id_array = StringArray::from_iter_values(data.iter().map(|i| i.id.to_string()));
+        let price_precision_array =
+            UInt8Array::from_iter_values(data.iter().map(|i| i.price_precision));
+        let components_array = components_builder.finish();
+        let formula_array = StringArray::from_iter_values(data.iter().map(|i| i.formula.as_str()));
+        let ts_event_array = UInt64Array::from_iter_values(data.iter().map(|i| i.ts_event));
+        let ts_init_array = UInt64Array::from_iter_values(data.iter().map(|i| i.ts_init));
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(id_array),
+                Arc::new(price_precision_array),
+                Arc::new(components_array),
+                Arc::new(formula_array),
+                Arc::new(ts_event_array),
+                Arc::new(ts_init_array),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for SyntheticInstrument {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let id_values = extract_column::<StringArray>(cols, "id", 0, DataType::Utf8)?;
+        let price_precision_values =
+            extract_column::<UInt8Array>(cols, "price_precision", 1, DataType::UInt8)?;
+        let components_values =
+            extract_column::<ListArray>(cols, "components", 2, components_data_type())?;
+        let formula_values = extract_column::<StringArray>(cols, "formula", 3, DataType::Utf8)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 4, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 5, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let id: InstrumentId = parse_value(id_values, "id", i)?;
+                let component_array = components_values.value(i);
+                let component_values = component_array
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .ok_or_else(|| {
+                        EncodingError::InvalidColumnType(
+                            "components",
+                            2,
+                            components_data_type(),
+                            components_values.data_type().clone(),
+                        )
+                    })?;
+                let components = (0..component_values.len())
+                    .map(|j| parse_value(component_values, "components", j))
+                    .collect::<Result<Vec<InstrumentId>, EncodingError>>()?;
+
+                Self::new(
+                    id.symbol,
+                    price_precision_values.value(i),
+                    components,
+                    formula_values.value(i).to_string(),
+                    ts_event_values.value(i),
+                    ts_init_values.value(i),
+                )
+                .map_err(instrument_error)
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::identifiers::symbol::Symbol;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn synthetic() -> SyntheticInstrument {
+        SyntheticInstrument::new(
+            Symbol::from("BTC-LTC"),
+            2,
+            vec![
+                InstrumentId::from("BTC.BINANCE"),
+                InstrumentId::from("LTC.BINANCE"),
+            ],
+            "(BTC.BINANCE + LTC.BINANCE) / 2.0".to_string(),
+            1,
+            2,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = SyntheticInstrument::get_schema_map();
+
+        assert_eq!(schema_map.len(), 6);
+        assert_eq!(schema_map["formula"], "Utf8");
+        assert!(schema_map["components"].starts_with("List"));
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let metadata = HashMap::new();
+        let record_batch = SyntheticInstrument::encode_batch(&metadata, &[synthetic()]).unwrap();
+
+        let decoded = SyntheticInstrument::decode_batch(&metadata, record_batch).unwrap();
+
+        let expected = synthetic();
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].id, expected.id);
+        assert_eq!(decoded[0].price_precision, expected.price_precision);
+        assert_eq!(decoded[0].price_increment, expected.price_increment);
+        assert_eq!(decoded[0].components, expected.components);
+        assert_eq!(decoded[0].formula, expected.formula);
+        assert_eq!(decoded[0].ts_event, 1);
+        assert_eq!(decoded[0].ts_init, 2);
+    }
+}
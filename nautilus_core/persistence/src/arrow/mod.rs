@@ -14,18 +14,23 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod bar;
+pub mod custom;
 pub mod delta;
 pub mod depth;
+pub mod events;
+pub mod instruments;
 pub mod quote;
 pub mod trade;
 
 use std::{
     collections::HashMap,
+    fmt::Display,
     io::{self, Write},
+    str::FromStr,
 };
 
 use datafusion::arrow::{
-    array::{Array, ArrayRef},
+    array::{Array, ArrayRef, StringArray},
     datatypes::{DataType, Schema},
     error::ArrowError,
     ipc::writer::StreamWriter,
@@ -33,6 +38,7 @@ use datafusion::arrow::{
 };
 use nautilus_model::data::Data;
 use pyo3::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
 
 // Define metadata key constants constants
 const KEY_BAR_TYPE: &str = "bar_type";
@@ -46,6 +52,8 @@ pub enum DataStreamingError {
     ArrowError(#[from] datafusion::arrow::error::ArrowError),
     #[error("I/O error: {0}")]
     IoError(#[from] io::Error),
+    #[error("Parquet error: {0}")]
+    ParquetError(#[from] datafusion::parquet::errors::ParquetError),
     #[error("Python error: {0}")]
     PythonError(#[from] PyErr),
 }
@@ -60,6 +68,8 @@ pub enum EncodingError {
     ParseError(&'static str, String),
     #[error("Invalid column type `{0}` at index {1}: expected {2}, found {3}")]
     InvalidColumnType(&'static str, usize, DataType, DataType),
+    #[error("Unregistered custom data type: `{0}`")]
+    UnregisteredCustomData(String),
     #[error("Arrow error: {0}")]
     ArrowError(#[from] datafusion::arrow::error::ArrowError),
 }
@@ -92,7 +102,7 @@ where
 
 pub trait DecodeFromRecordBatch
 where
-    Self: Sized + Into<Data> + ArrowSchemaProvider,
+    Self: Sized + ArrowSchemaProvider,
 {
     fn decode_batch(
         metadata: &HashMap<String, String>,
@@ -144,3 +154,64 @@ pub fn extract_column<'a, T: Array + 'static>(
             ))?;
     Ok(downcasted_values)
 }
+
+/// Parses the value at row `i` of the given string column.
+pub fn parse_value<T>(
+    values: &StringArray,
+    column_key: &'static str,
+    i: usize,
+) -> Result<T, EncodingError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    values
+        .value(i)
+        .parse()
+        .map_err(|e: T::Err| EncodingError::ParseError(column_key, e.to_string()))
+}
+
+/// Parses the value at row `i` of the given nullable string column.
+pub fn parse_optional_value<T>(
+    values: &StringArray,
+    column_key: &'static str,
+    i: usize,
+) -> Result<Option<T>, EncodingError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if values.is_null(i) {
+        Ok(None)
+    } else {
+        parse_value(values, column_key, i).map(Some)
+    }
+}
+
+/// Encodes the given `value` as JSON, for nested values stored in a string column.
+pub fn to_json<T: Serialize>(value: &T) -> Result<String, ArrowError> {
+    serde_json::to_string(value).map_err(|e| ArrowError::ExternalError(Box::new(e)))
+}
+
+/// Parses the JSON value at row `i` of the given string column.
+pub fn parse_json<T: DeserializeOwned>(
+    values: &StringArray,
+    column_key: &'static str,
+    i: usize,
+) -> Result<T, EncodingError> {
+    serde_json::from_str(values.value(i))
+        .map_err(|e| EncodingError::ParseError(column_key, e.to_string()))
+}
+
+/// Parses the JSON value at row `i` of the given nullable string column.
+pub fn parse_optional_json<T: DeserializeOwned>(
+    values: &StringArray,
+    column_key: &'static str,
+    i: usize,
+) -> Result<Option<T>, EncodingError> {
+    if values.is_null(i) {
+        Ok(None)
+    } else {
+        parse_json(values, column_key, i).map(Some)
+    }
+}
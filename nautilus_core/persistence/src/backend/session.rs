@@ -13,20 +13,29 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, sync::Arc, vec::IntoIter};
+use std::{collections::HashMap, fs::File, path::Path, sync::Arc, vec::IntoIter};
 
 use compare::Compare;
 use datafusion::{
-    error::Result, logical_expr::expr::Sort, physical_plan::SendableRecordBatchStream, prelude::*,
+    arrow::record_batch::RecordBatch,
+    error::{DataFusionError, Result},
+    logical_expr::expr::Sort,
+    parquet::arrow::ArrowWriter,
+    physical_plan::SendableRecordBatchStream,
+    prelude::*,
 };
 use futures::StreamExt;
 use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{Data, HasTsInit};
+use nautilus_model::data::{custom::CustomData, Data, HasTsInit};
 use pyo3::prelude::*;
 
 use super::kmerge_batch::{EagerStream, ElementBatchIter, KMerge};
 use crate::arrow::{
-    DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, WriteStream,
+    custom::{
+        decode_custom_data, encode_custom_data, is_custom_data_registered, KEY_CUSTOM_DATA_TYPE,
+    },
+    DataStreamingError, DecodeDataFromRecordBatch, DecodeFromRecordBatch, EncodeToRecordBatch,
+    EncodingError, WriteStream,
 };
 
 #[derive(Debug, Default)]
@@ -46,6 +55,8 @@ where
     }
 }
 
+type DecodeDataFn = fn(&HashMap<String, String>, RecordBatch) -> Result<Vec<Data>, EncodingError>;
+
 pub type QueryResult = KMerge<EagerStream<std::vec::IntoIter<Data>>, Data, TsInitComparator>;
 
 /// Provides a DataFusion session and registers DataFusion queries.
@@ -89,6 +100,34 @@ impl DataBackendSession {
         Ok(())
     }
 
+    /// Writes the given `data` to a new Parquet file at `file_path`.
+    ///
+    /// The data should be ordered by `ts_init` in ascending order, so the file can be
+    /// queried with [`DataBackendSession::add_file`] or [`DataBackendSession::query_file`].
+    pub fn write_parquet<T: EncodeToRecordBatch>(
+        data: &[T],
+        metadata: &HashMap<String, String>,
+        file_path: impl AsRef<Path>,
+    ) -> Result<(), DataStreamingError> {
+        let record_batch = T::encode_batch(metadata, data)?;
+        write_parquet_batch(&record_batch, file_path)
+    }
+
+    /// Writes the given custom `data`, of the type registered under `type_name`, to a
+    /// new Parquet file at `file_path`.
+    ///
+    /// The data should be ordered by `ts_init` in ascending order, so the file can be
+    /// queried with [`DataBackendSession::add_custom_file`].
+    pub fn write_custom_parquet(
+        type_name: &str,
+        data: &[CustomData],
+        metadata: &HashMap<String, String>,
+        file_path: impl AsRef<Path>,
+    ) -> Result<(), DataStreamingError> {
+        let record_batch = encode_custom_data(type_name, metadata, data)?;
+        write_parquet_batch(&record_batch, file_path)
+    }
+
     /// Query a file for its records. the caller must specify `T` to indicate
     /// the kind of data expected from this query.
     ///
@@ -111,6 +150,89 @@ impl DataBackendSession {
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
     {
+        self.register_file(table_name, file_path)?;
+        let batch_stream = self.execute_stream(table_name, sql_query)?;
+        self.add_batch_stream(batch_stream, T::decode_data_batch);
+        Ok(())
+    }
+
+    /// Query a file of custom data for its records, which are merged with the other
+    /// queries by `ts_init` as for [`DataBackendSession::add_file`].
+    ///
+    /// The custom data type is read from the file metadata, and must have been
+    /// registered with [`register_custom_data`](crate::arrow::custom::register_custom_data),
+    /// otherwise an error is returned and the file is not queried.
+    ///
+    /// # Safety
+    ///
+    /// The file data must be ordered by the `ts_init` in ascending order for this
+    /// to work correctly.
+    pub fn add_custom_file(
+        &mut self,
+        table_name: &str,
+        file_path: &str,
+        sql_query: Option<&str>,
+    ) -> Result<()> {
+        self.register_file(table_name, file_path)?;
+
+        let table = self.runtime.block_on(self.session_ctx.table(table_name))?;
+        let error = match table.schema().metadata().get(KEY_CUSTOM_DATA_TYPE) {
+            Some(type_name) if is_custom_data_registered(type_name) => None,
+            Some(type_name) => Some(EncodingError::UnregisteredCustomData(type_name.clone())),
+            None => Some(EncodingError::MissingMetadata(KEY_CUSTOM_DATA_TYPE)),
+        };
+        if let Some(e) = error {
+            self.session_ctx.deregister_table(table_name)?;
+            return Err(DataFusionError::External(Box::new(e)));
+        }
+
+        let batch_stream = self.execute_stream(table_name, sql_query)?;
+        self.add_batch_stream(batch_stream, decode_custom_data);
+        Ok(())
+    }
+
+    /// Query a file for records which are not market data, such as order, position
+    /// and account events, decoding them as `T`.
+    ///
+    /// Unlike [`DataBackendSession::add_file`] the records are returned directly (in
+    /// the order given by the query) rather than merged into the query result.
+    ///
+    /// `table_name`: Logical `table_name` assigned to this file, which can also be
+    /// addressed by later calls to [`DataBackendSession::sql`].
+    /// `file_path`: Path to file
+    /// `sql_query`: A custom sql query to retrieve records from file. If no query is provided a default
+    /// query "SELECT * FROM <`table_name`>" is run. The query must select all columns of `T`.
+    pub fn query_file<T>(
+        &mut self,
+        table_name: &str,
+        file_path: &str,
+        sql_query: Option<&str>,
+    ) -> Result<Vec<T>>
+    where
+        T: DecodeFromRecordBatch,
+    {
+        self.register_file(table_name, file_path)?;
+
+        let default_query = format!("SELECT * FROM {}", &table_name);
+        let batches = self.sql(sql_query.unwrap_or(&default_query))?;
+
+        let mut records = Vec::new();
+        for batch in batches {
+            let decoded = T::decode_batch(batch.schema().metadata(), batch)
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            records.extend(decoded);
+        }
+        Ok(records)
+    }
+
+    /// Runs the given SQL query against the registered files, returning the raw
+    /// record batches (e.g. for aggregations over fills).
+    pub fn sql(&self, sql_query: &str) -> Result<Vec<RecordBatch>> {
+        let query = self.runtime.block_on(self.session_ctx.sql(sql_query))?;
+        self.runtime.block_on(query.collect())
+    }
+
+    fn register_file(&self, table_name: &str, file_path: &str) -> Result<()> {
         let parquet_options = ParquetReadOptions::<'_> {
             skip_metadata: Some(false),
             file_sort_order: vec![vec![Expr::Sort(Sort {
@@ -124,24 +246,24 @@ impl DataBackendSession {
             table_name,
             file_path,
             parquet_options,
-        ))?;
+        ))
+    }
 
+    fn execute_stream(
+        &self,
+        table_name: &str,
+        sql_query: Option<&str>,
+    ) -> Result<SendableRecordBatchStream> {
         let default_query = format!("SELECT * FROM {}", &table_name);
         let sql_query = sql_query.unwrap_or(&default_query);
         let query = self.runtime.block_on(self.session_ctx.sql(sql_query))?;
 
-        let batch_stream = self.runtime.block_on(query.execute_stream())?;
-
-        self.add_batch_stream::<T>(batch_stream);
-        Ok(())
+        self.runtime.block_on(query.execute_stream())
     }
 
-    fn add_batch_stream<T>(&mut self, stream: SendableRecordBatchStream)
-    where
-        T: DecodeDataFromRecordBatch + Into<Data>,
-    {
-        let transform = stream.map(|result| match result {
-            Ok(batch) => T::decode_data_batch(batch.schema().metadata(), batch)
+    fn add_batch_stream(&mut self, stream: SendableRecordBatchStream, decode: DecodeDataFn) {
+        let transform = stream.map(move |result| match result {
+            Ok(batch) => decode(batch.schema().metadata(), batch)
                 .unwrap()
                 .into_iter(),
             Err(_err) => panic!("Error getting next batch from RecordBatchStream"),
@@ -169,6 +291,17 @@ impl DataBackendSession {
     }
 }
 
+fn write_parquet_batch(
+    record_batch: &RecordBatch,
+    file_path: impl AsRef<Path>,
+) -> Result<(), DataStreamingError> {
+    let file = File::create(file_path)?;
+    let mut writer = ArrowWriter::try_new(file, record_batch.schema(), None)?;
+    writer.write(record_batch)?;
+    writer.close()?;
+    Ok(())
+}
+
 // Note: Intended to be used on a single Python thread
 unsafe impl Send for DataBackendSession {}
 
@@ -244,3 +377,165 @@ impl Drop for DataQueryResult {
 
 // Note: Intended to be used on a single Python thread
 unsafe impl Send for DataQueryResult {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::{Array, Int64Array};
+    use nautilus_model::{
+        data::{custom::stubs::FundingRate, quote::QuoteTick},
+        enums::OrderSide,
+        events::order::filled::{OrderFilled, OrderFilledBuilder},
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::arrow::custom::register_custom_data;
+
+    #[rstest]
+    fn test_write_and_query_order_fills() {
+        let fills: Vec<OrderFilled> = [
+            ("T-1", OrderSide::Buy),
+            ("T-2", OrderSide::Sell),
+            ("T-3", OrderSide::Buy),
+        ]
+        .into_iter()
+        .zip(1..)
+        .map(|((trade_id, order_side), ts)| {
+            OrderFilledBuilder::default()
+                .trade_id(TradeId::from(trade_id))
+                .order_side(order_side)
+                .ts_event(ts)
+                .ts_init(ts)
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("fills.parquet");
+        DataBackendSession::write_parquet(&fills, &HashMap::new(), &file_path).unwrap();
+
+        let mut session = DataBackendSession::new(1_000);
+        let buys: Vec<OrderFilled> = session
+            .query_file(
+                "fills",
+                file_path.to_str().unwrap(),
+                Some("SELECT * FROM fills WHERE order_side = 'BUY' ORDER BY ts_init"),
+            )
+            .unwrap();
+
+        assert_eq!(buys, vec![fills[0], fills[2]]);
+
+        let batches = session
+            .sql("SELECT COUNT(*) FROM fills WHERE ts_init > 1")
+            .unwrap();
+        let counts = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+
+        assert_eq!(counts.value(0), 2);
+    }
+
+    #[rstest]
+    fn test_merge_custom_data_with_market_data() {
+        register_custom_data::<FundingRate>(FundingRate::TYPE_NAME);
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let quotes: Vec<QuoteTick> = [1, 3]
+            .into_iter()
+            .map(|ts| {
+                QuoteTick::new(
+                    instrument_id,
+                    Price::from("100.00"),
+                    Price::from("100.01"),
+                    Quantity::from(1),
+                    Quantity::from(1),
+                    ts,
+                    ts,
+                )
+                .unwrap()
+            })
+            .collect();
+        let funding_rates: Vec<CustomData> = [2, 4]
+            .into_iter()
+            .map(|ts| {
+                CustomData::new(FundingRate {
+                    instrument_id,
+                    rate: 0.0001,
+                    ts_event: ts,
+                    ts_init: ts,
+                })
+            })
+            .collect();
+
+        let dir = tempdir().unwrap();
+        let quotes_path = dir.path().join("quotes.parquet");
+        let funding_path = dir.path().join("funding.parquet");
+        let metadata = QuoteTick::get_metadata(&instrument_id, 2, 0);
+        DataBackendSession::write_parquet(&quotes, &metadata, &quotes_path).unwrap();
+        DataBackendSession::write_custom_parquet(
+            FundingRate::TYPE_NAME,
+            &funding_rates,
+            &HashMap::new(),
+            &funding_path,
+        )
+        .unwrap();
+
+        let mut session = DataBackendSession::new(1_000);
+        session
+            .add_file::<QuoteTick>("quotes", quotes_path.to_str().unwrap(), None)
+            .unwrap();
+        session
+            .add_custom_file("funding", funding_path.to_str().unwrap(), None)
+            .unwrap();
+        let result: Vec<Data> = session.get_query_result().collect();
+
+        assert_eq!(
+            result
+                .iter()
+                .map(HasTsInit::get_ts_init)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert!(matches!(result[0], Data::Quote(_)));
+        let Data::Custom(custom) = &result[1] else {
+            panic!("Expected custom data, was {:?}", result[1]);
+        };
+        assert_eq!(custom.type_name(), FundingRate::TYPE_NAME);
+    }
+
+    #[rstest]
+    fn test_add_custom_file_with_unregistered_type() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("100.00"),
+            Price::from("100.01"),
+            Quantity::from(1),
+            Quantity::from(1),
+            1,
+            1,
+        )
+        .unwrap();
+        let mut metadata = QuoteTick::get_metadata(&instrument_id, 2, 0);
+        metadata.insert(KEY_CUSTOM_DATA_TYPE.to_string(), "Unregistered".to_string());
+
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("unregistered.parquet");
+        DataBackendSession::write_parquet(&[quote], &metadata, &file_path).unwrap();
+
+        let mut session = DataBackendSession::new(1_000);
+        let result = session.add_custom_file("unregistered", file_path.to_str().unwrap(), None);
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("Unregistered custom data type"), "{error}");
+        assert_eq!(session.get_query_result().count(), 0);
+    }
+}
@@ -14,7 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use dotenv::dotenv;
-use nautilus_persistence::db::database::{Database, DatabaseEngine};
+use nautilus_persistence::db::database::{drop_db_schema, Database, DatabaseEngine};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -23,6 +23,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let db = Database::new(Some(DatabaseEngine::POSTGRES), None).await;
 
+    drop_db_schema(&db).await?;
+    println!("Dropped nautilus tables.");
+
     db.execute("DROP SCHEMA IF EXISTS nautilus CASCADE;")
         .await
         .map_err(|e| e.to_string())?;
@@ -32,7 +32,7 @@ pub enum DatabaseEngine {
     SQLITE,
 }
 
-fn str_to_database_engine(engine_str: &str) -> DatabaseEngine {
+pub(crate) fn str_to_database_engine(engine_str: &str) -> DatabaseEngine {
     match engine_str {
         "POSTGRES" | "postgres" => DatabaseEngine::POSTGRES,
         "SQLITE" | "sqlite" => DatabaseEngine::SQLITE,
@@ -108,13 +108,50 @@ impl Database {
     }
 }
 
+/// The tables created by the schema migrations, in the order they can be dropped.
+pub const SCHEMA_TABLES: [&str; 11] = [
+    "order_events",
+    "orders",
+    "account_balances",
+    "account_margins",
+    "accounts",
+    "position_fills",
+    "positions",
+    "instruments",
+    "currencies",
+    "general",
+    MIGRATIONS_TABLE,
+];
+
+const MIGRATIONS_TABLE: &str = "schema_migrations";
+
+/// Applies the SQL migration files in `schema_dir` which have not yet been applied.
+///
+/// Files are applied in file name order, and each applied file name is recorded in the
+/// `schema_migrations` table so that initializing an existing database is a no-op.
 pub async fn init_db_schema(db: &Database, schema_dir: &str) -> Result<()> {
-    // scan all the files in the current directory
+    db.execute(&format!(
+        "CREATE TABLE IF NOT EXISTS {MIGRATIONS_TABLE} (name TEXT PRIMARY KEY)"
+    ))
+    .await?;
+
     let mut sql_files =
         std::fs::read_dir(schema_dir)?.collect::<Result<Vec<_>, std::io::Error>>()?;
+    sql_files.retain(|file| file.path().extension().map_or(false, |ext| ext == "sql"));
+    sql_files.sort_by_key(std::fs::DirEntry::file_name);
+
+    for file in &sql_files {
+        let file_name = file.file_name().to_string_lossy().to_string();
+        let applied = sqlx::query(&format!(
+            "SELECT name FROM {MIGRATIONS_TABLE} WHERE name = $1"
+        ))
+        .bind(&file_name)
+        .fetch_optional(&db.pool)
+        .await?;
+        if applied.is_some() {
+            continue;
+        }
 
-    for file in &mut sql_files {
-        let file_name = file.file_name();
         println!("Executing SQL file: {file_name:?}");
         let file_path = file.path();
         let sql_content = std::fs::read_to_string(file_path.clone())?;
@@ -127,6 +164,21 @@ pub async fn init_db_schema(db: &Database, schema_dir: &str) -> Result<()> {
                 )
             });
         }
+
+        sqlx::query(&format!(
+            "INSERT INTO {MIGRATIONS_TABLE} (name) VALUES ($1)"
+        ))
+        .bind(&file_name)
+        .execute(&db.pool)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Drops all the tables created by the schema migrations (including the migration history).
+pub async fn drop_db_schema(db: &Database) -> Result<()> {
+    for table in SCHEMA_TABLES {
+        db.execute(&format!("DROP TABLE IF EXISTS {table}")).await?;
     }
     Ok(())
 }
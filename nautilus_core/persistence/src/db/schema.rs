@@ -13,8 +13,173 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use ColumnKind::{Boolean, Integer, Json, Text};
+
 #[derive(sqlx::FromRow)]
 pub struct GeneralItem {
     pub key: String,
     pub value: String,
 }
+
+/// The kind of value held in a column of the cache tables, as represented in
+/// the JSON payloads written by the cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnKind {
+    /// A string, stored as `TEXT`.
+    Text,
+    /// An integer, stored as `BIGINT`.
+    Integer,
+    /// A floating point number, stored as `DOUBLE PRECISION`.
+    Real,
+    /// A boolean (or integer flag), stored as `BOOLEAN`.
+    Boolean,
+    /// An array or object, stored as JSON `TEXT`.
+    Json,
+}
+
+/// A column of the cache tables, named after the payload field it holds.
+pub type Column = (&'static str, ColumnKind);
+
+/// The columns of the `currencies` table.
+pub const CURRENCY_COLUMNS: &[Column] = &[
+    ("code", Text),
+    ("precision", Integer),
+    ("iso4217", Integer),
+    ("name", Text),
+    ("currency_type", Text),
+];
+
+/// The columns of the `instruments` table, for all instrument kinds.
+pub const INSTRUMENT_COLUMNS: &[Column] = &[
+    ("id", Text),
+    ("raw_symbol", Text),
+    ("asset_class", Text),
+    ("underlying", Text),
+    ("option_kind", Text),
+    ("strategy_type", Text),
+    ("isin", Text),
+    ("base_currency", Text),
+    ("quote_currency", Text),
+    ("settlement_currency", Text),
+    ("currency", Text),
+    ("is_inverse", Boolean),
+    ("activation_ns", Integer),
+    ("expiration_ns", Integer),
+    ("strike_price", Text),
+    ("price_precision", Integer),
+    ("size_precision", Integer),
+    ("price_increment", Text),
+    ("size_increment", Text),
+    ("multiplier", Text),
+    ("lot_size", Text),
+    ("max_quantity", Text),
+    ("min_quantity", Text),
+    ("max_notional", Text),
+    ("min_notional", Text),
+    ("max_price", Text),
+    ("min_price", Text),
+    ("maker_fee", Text),
+    ("taker_fee", Text),
+    ("margin_init", Text),
+    ("margin_maint", Text),
+    ("ts_event", Integer),
+    ("ts_init", Integer),
+];
+
+/// The columns of the `accounts` table, holding the account states.
+pub const ACCOUNT_COLUMNS: &[Column] = &[
+    ("account_id", Text),
+    ("account_type", Text),
+    ("base_currency", Text),
+    ("is_reported", Boolean),
+    ("event_id", Text),
+    ("ts_event", Integer),
+    ("ts_init", Integer),
+];
+
+/// The columns of the `account_balances` table.
+pub const ACCOUNT_BALANCE_COLUMNS: &[Column] = &[
+    ("currency", Text),
+    ("total", Text),
+    ("locked", Text),
+    ("free", Text),
+];
+
+/// The columns of the `account_margins` table.
+pub const ACCOUNT_MARGIN_COLUMNS: &[Column] = &[
+    ("instrument_id", Text),
+    ("currency", Text),
+    ("initial", Text),
+    ("maintenance", Text),
+];
+
+/// The columns of the `order_events` table, for all order event kinds.
+pub const ORDER_EVENT_COLUMNS: &[Column] = &[
+    ("client_order_id", Text),
+    ("trader_id", Text),
+    ("strategy_id", Text),
+    ("instrument_id", Text),
+    ("venue_order_id", Text),
+    ("account_id", Text),
+    ("trade_id", Text),
+    ("position_id", Text),
+    ("order_side", Text),
+    ("order_type", Text),
+    ("quantity", Text),
+    ("price", Text),
+    ("trigger_price", Text),
+    ("trigger_type", Text),
+    ("limit_offset", Text),
+    ("trailing_offset", Text),
+    ("trailing_offset_type", Text),
+    ("time_in_force", Text),
+    ("expire_time", Integer),
+    ("display_qty", Text),
+    ("emulation_trigger", Text),
+    ("trigger_instrument_id", Text),
+    ("contingency_type", Text),
+    ("order_list_id", Text),
+    ("linked_order_ids", Json),
+    ("parent_order_id", Text),
+    ("exec_algorithm_id", Text),
+    ("exec_algorithm_params", Json),
+    ("exec_spawn_id", Text),
+    ("tags", Text),
+    ("post_only", Boolean),
+    ("reduce_only", Boolean),
+    ("quote_quantity", Boolean),
+    ("released_price", Text),
+    ("last_qty", Text),
+    ("last_px", Text),
+    ("currency", Text),
+    ("commission", Text),
+    ("liquidity_side", Text),
+    ("reason", Text),
+    ("event_id", Text),
+    ("ts_event", Integer),
+    ("ts_init", Integer),
+    ("reconciliation", Boolean),
+];
+
+/// The columns of the `position_fills` table, holding the fills of each position
+/// (keyed by the `position_id` of the position and their `trade_id`).
+pub const POSITION_FILL_COLUMNS: &[Column] = &[
+    ("trader_id", Text),
+    ("strategy_id", Text),
+    ("instrument_id", Text),
+    ("client_order_id", Text),
+    ("venue_order_id", Text),
+    ("account_id", Text),
+    ("trade_id", Text),
+    ("order_side", Text),
+    ("order_type", Text),
+    ("last_qty", Text),
+    ("last_px", Text),
+    ("currency", Text),
+    ("commission", Text),
+    ("liquidity_side", Text),
+    ("event_id", Text),
+    ("ts_event", Integer),
+    ("ts_init", Integer),
+    ("reconciliation", Boolean),
+];
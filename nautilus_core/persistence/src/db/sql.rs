@@ -13,11 +13,97 @@
 //  limitations under the License.
 // ------------------------------------------------------------------------------------------------
 
-use nautilus_model::identifiers::trader_id::TraderId;
-use sqlx::Error;
+use std::{
+    collections::HashMap, future::Future, panic, str::FromStr, sync::mpsc::Receiver, thread,
+};
 
-use crate::db::{database::Database, schema::GeneralItem};
+use anyhow::{anyhow, bail, Result};
+use nautilus_core::uuid::UUID4;
+use nautilus_infrastructure::cache::{CacheDatabase, DatabaseCommand, DatabaseOperation};
+use nautilus_model::{identifiers::trader_id::TraderId, types::currency::Currency};
+use once_cell::sync::Lazy;
+use serde_json::{Map, Value};
+use sqlx::{
+    any::{AnyArguments, AnyRow},
+    query::Query,
+    Any, Error, Row, Transaction,
+};
+use tokio::runtime::{Handle, Runtime};
 
+use crate::db::{
+    database::{str_to_database_engine, Database},
+    schema::{
+        Column, ColumnKind, GeneralItem, ACCOUNT_BALANCE_COLUMNS, ACCOUNT_COLUMNS,
+        ACCOUNT_MARGIN_COLUMNS, CURRENCY_COLUMNS, INSTRUMENT_COLUMNS, ORDER_EVENT_COLUMNS,
+        POSITION_FILL_COLUMNS,
+    },
+};
+
+const DELIMITER: char = ':';
+
+// Collection keys
+const CURRENCIES: &str = "currencies";
+const INSTRUMENTS: &str = "instruments";
+const ACCOUNTS: &str = "accounts";
+const ORDERS: &str = "orders";
+const POSITIONS: &str = "positions";
+const COLLECTIONS: [&str; 5] = [CURRENCIES, INSTRUMENTS, ACCOUNTS, ORDERS, POSITIONS];
+
+// Tables nested under a collection
+const ACCOUNT_BALANCES: &str = "account_balances";
+const ACCOUNT_MARGINS: &str = "account_margins";
+const ORDER_EVENTS: &str = "order_events";
+const POSITION_FILLS: &str = "position_fills";
+
+const SEQ: &[Column] = &[("seq", ColumnKind::Integer)];
+const KIND: &[Column] = &[("kind", ColumnKind::Text)];
+
+/// The runtime the synchronous [`CacheDatabase`] operations are run on (see [`block_on`]).
+static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .expect("Failed to build the database runtime")
+});
+
+/// Runs the `future` to completion on the database runtime.
+///
+/// Blocking on a runtime panics within another runtime, so the future is then run
+/// from a worker thread which the calling thread waits on.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    if Handle::try_current().is_err() {
+        return RUNTIME.block_on(future);
+    }
+
+    thread::scope(|scope| {
+        scope
+            .spawn(|| RUNTIME.block_on(future))
+            .join()
+            .unwrap_or_else(|e| panic::resume_unwind(e))
+    })
+}
+
+/// Provides a [`CacheDatabase`] backed by a SQL database (PostgreSQL or SQLite).
+///
+/// The JSON payloads written by the cache are stored one field per column, in the
+/// tables created by the `0002_cache.sql` schema migration (see the `init-db` binary):
+/// - `currencies` and `instruments` hold one row per ID, with instruments of every
+///   kind sharing a table and their kind in the `kind` column.
+/// - `accounts` and `order_events` hold an ordered sequence of account states and order
+///   events per ID, with the balances and margins of each account state in child tables.
+/// - `positions` holds one row per ID, with the fills of each position stored once
+///   (keyed by their trade ID) in order in the `position_fills` table.
+///
+/// All rows are scoped by the trader key.
+///
+/// The database is configured with the `database_engine` (`postgres` or `sqlite`)
+/// and `database_url` config entries, which default to the `DATABASE_ENGINE` and
+/// `DATABASE_URL` environment variables.
 pub struct SqlCacheDatabase {
     trader_id: TraderId,
     db: Database,
@@ -31,6 +117,7 @@ impl SqlCacheDatabase {
             db: database,
         }
     }
+
     #[must_use]
     pub fn key_trader(&self) -> String {
         format!("trader-{}", self.trader_id)
@@ -55,6 +142,731 @@ impl SqlCacheDatabase {
             .await
             .unwrap()
     }
+
+    async fn connect(config: &HashMap<String, serde_json::Value>) -> Database {
+        let engine = config
+            .get("database_engine")
+            .and_then(|v| v.as_str())
+            .map(str_to_database_engine);
+        let url = config.get("database_url").and_then(|v| v.as_str());
+        Database::new(engine, url).await
+    }
+
+    async fn read_async(&self, key: &str) -> Result<Vec<Vec<u8>>> {
+        let (collection, id) = split_key(key)?;
+        let values: Vec<Value> = match collection {
+            CURRENCIES => self
+                .select(CURRENCIES, "code", id, CURRENCY_COLUMNS, "")
+                .await?
+                .into_iter()
+                .map(|mut currency| currency.remove("code").unwrap_or_default())
+                .collect(),
+            INSTRUMENTS => self
+                .select(
+                    INSTRUMENTS,
+                    "id",
+                    id,
+                    &[KIND, INSTRUMENT_COLUMNS].concat(),
+                    "",
+                )
+                .await?
+                .into_iter()
+                .map(into_tagged)
+                .collect::<Result<Vec<_>>>()?,
+            ACCOUNTS => self.read_account_states(id).await?,
+            ORDERS => self.read_order_events(id).await?,
+            POSITIONS => self.read_position_fills(id).await?,
+            _ => bail!("Unsupported operation: `read` for collection '{collection}'"),
+        };
+
+        values
+            .iter()
+            .map(|value| Ok(serde_json::to_vec(value)?))
+            .collect()
+    }
+
+    async fn read_account_states(&self, id: &str) -> Result<Vec<Value>> {
+        let balance_columns = [SEQ, ACCOUNT_BALANCE_COLUMNS].concat();
+        let margin_columns = [SEQ, ACCOUNT_MARGIN_COLUMNS].concat();
+        let order_by = " ORDER BY seq, idx";
+        let mut balances = group_by_seq(
+            self.select(
+                ACCOUNT_BALANCES,
+                "account_id",
+                id,
+                &balance_columns,
+                order_by,
+            )
+            .await?,
+        )?;
+        let mut margins = group_by_seq(
+            self.select(ACCOUNT_MARGINS, "account_id", id, &margin_columns, order_by)
+                .await?,
+        )?;
+
+        let columns = [SEQ, ACCOUNT_COLUMNS].concat();
+        self.select(ACCOUNTS, "account_id", id, &columns, " ORDER BY seq")
+            .await?
+            .into_iter()
+            .map(|mut state| {
+                let seq = take_seq(&mut state)?;
+                let state_balances = balances.remove(&seq).unwrap_or_default();
+                let state_margins = margins.remove(&seq).unwrap_or_default();
+                state.insert("balances".to_string(), Value::Array(state_balances));
+                state.insert("margins".to_string(), Value::Array(state_margins));
+                Ok(Value::Object(state))
+            })
+            .collect()
+    }
+
+    async fn read_order_events(&self, id: &str) -> Result<Vec<Value>> {
+        let columns = [KIND, ORDER_EVENT_COLUMNS].concat();
+        self.select(
+            ORDER_EVENTS,
+            "client_order_id",
+            id,
+            &columns,
+            " ORDER BY seq",
+        )
+        .await?
+        .into_iter()
+        .map(|mut event| {
+            restore_order_event(&mut event);
+            into_tagged(event)
+        })
+        .collect()
+    }
+
+    async fn read_position_fills(&self, id: &str) -> Result<Vec<Value>> {
+        let fills = self
+            .select(
+                POSITION_FILLS,
+                "position_id",
+                id,
+                POSITION_FILL_COLUMNS,
+                " ORDER BY seq",
+            )
+            .await?
+            .into_iter()
+            .map(|mut fill| {
+                fill.insert("type".to_string(), Value::from("OrderFilled"));
+                fill.insert("position_id".to_string(), Value::from(id));
+                Value::Object(fill)
+            })
+            .collect();
+        Ok(fills)
+    }
+
+    /// Selects the given `columns` of the rows for `id` in `table`, as JSON objects.
+    async fn select(
+        &self,
+        table: &str,
+        id_column: &str,
+        id: &str,
+        columns: &[Column],
+        order_by: &str,
+    ) -> Result<Vec<Map<String, Value>>> {
+        let names = columns
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let query = format!(
+            "SELECT {names} FROM {table} WHERE trader_key = $1 AND {id_column} = $2{order_by}"
+        );
+        let rows = sqlx::query(&query)
+            .bind(self.key_trader())
+            .bind(id)
+            .fetch_all(&self.db.pool)
+            .await?;
+
+        rows.iter().map(|row| read_columns(row, columns)).collect()
+    }
+
+    async fn keys_async(&self) -> Result<Vec<String>> {
+        let trader_key = self.key_trader();
+        let mut keys = Vec::new();
+        for collection in COLLECTIONS {
+            let (table, id_column) = collection_table(collection)?;
+            let rows = sqlx::query(&format!(
+                "SELECT DISTINCT {id_column} FROM {table} WHERE trader_key = $1"
+            ))
+            .bind(&trader_key)
+            .fetch_all(&self.db.pool)
+            .await?;
+
+            for row in rows {
+                let id: String = row.try_get(0)?;
+                keys.push(format!(
+                    "{trader_key}{DELIMITER}{collection}{DELIMITER}{id}"
+                ));
+            }
+        }
+        Ok(keys)
+    }
+
+    async fn write_async(&self, command: DatabaseCommand) -> Result<()> {
+        let (collection, id) = split_key(&command.key)?;
+        let tables = collection_tables(collection)?;
+        let trader_key = self.key_trader();
+
+        let payload = match (&command.op_type, command.payload) {
+            (DatabaseOperation::Delete, _) => vec![],
+            (_, Some(payload)) if !payload.is_empty() => payload
+                .iter()
+                .map(|value| {
+                    serde_json::from_slice::<Value>(value)
+                        .map_err(|e| anyhow!("Invalid `payload`, was not JSON: {e}"))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            (op_type, _) => bail!("Empty `payload` for `{op_type:?}`"),
+        };
+
+        let mut tx = self.db.pool.begin().await?;
+
+        match (&command.op_type, collection) {
+            (DatabaseOperation::Insert, _) => {
+                delete_rows(&mut tx, tables, &trader_key, id).await?;
+                if matches!(collection, ORDERS | POSITIONS) {
+                    let (table, id_column) = collection_table(collection)?;
+                    sqlx::query(&format!(
+                        "INSERT INTO {table} (trader_key, {id_column}) VALUES ($1, $2)"
+                    ))
+                    .bind(&trader_key)
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                for value in payload {
+                    append(&mut tx, collection, &trader_key, id, value).await?;
+                }
+            }
+            (DatabaseOperation::Update, ACCOUNTS | POSITIONS | ORDERS) => {
+                let (table, id_column) = collection_table(collection)?;
+                let exists = sqlx::query(&format!(
+                    "SELECT 1 FROM {table} WHERE trader_key = $1 AND {id_column} = $2 LIMIT 1"
+                ))
+                .bind(&trader_key)
+                .bind(id)
+                .fetch_optional(&mut *tx)
+                .await?;
+                if exists.is_none() {
+                    bail!("Cannot `update` '{}', key does not exist", command.key);
+                }
+                for value in payload {
+                    append(&mut tx, collection, &trader_key, id, value).await?;
+                }
+            }
+            (DatabaseOperation::Delete, _) => {
+                delete_rows(&mut tx, tables, &trader_key, id).await?;
+            }
+            (op_type, _) => {
+                bail!("Unsupported operation: `{op_type:?}` for collection '{collection}'")
+            }
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn flushdb_async(&self) -> Result<()> {
+        let trader_key = self.key_trader();
+        let mut tx = self.db.pool.begin().await?;
+        for collection in COLLECTIONS {
+            for (table, _) in collection_tables(collection)? {
+                sqlx::query(&format!("DELETE FROM {table} WHERE trader_key = $1"))
+                    .bind(&trader_key)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+impl CacheDatabase for SqlCacheDatabase {
+    fn new(
+        trader_id: TraderId,
+        _instance_id: UUID4,
+        config: HashMap<String, serde_json::Value>,
+    ) -> Result<Self> {
+        let db = block_on(Self::connect(&config));
+        Ok(Self { trader_id, db })
+    }
+
+    fn trader_key(&self) -> String {
+        self.key_trader()
+    }
+
+    fn flushdb(&mut self) -> Result<()> {
+        block_on(self.flushdb_async())
+    }
+
+    fn keys(&mut self, pattern: &str) -> Result<Vec<String>> {
+        let keys = block_on(self.keys_async())?;
+        Ok(keys
+            .into_iter()
+            .filter(|key| glob_match(pattern, key))
+            .collect())
+    }
+
+    fn read(&mut self, key: &str) -> Result<Vec<Vec<u8>>> {
+        block_on(self.read_async(key))
+    }
+
+    fn insert(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let command = DatabaseCommand::new(DatabaseOperation::Insert, key, payload);
+        block_on(self.write_async(command))
+    }
+
+    fn update(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let command = DatabaseCommand::new(DatabaseOperation::Update, key, payload);
+        block_on(self.write_async(command))
+    }
+
+    fn delete(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let command = DatabaseCommand::new(DatabaseOperation::Delete, key, payload);
+        block_on(self.write_async(command))
+    }
+
+    /// Payloads are stored one field per column, so are always JSON.
+    fn encoding(&self) -> &str {
+        "json"
+    }
+
+    fn handle_messages(
+        rx: Receiver<DatabaseCommand>,
+        trader_key: String,
+        config: HashMap<String, serde_json::Value>,
+    ) {
+        let trader_id = match trader_key.strip_prefix("trader-") {
+            Some(trader_id) => TraderId::from(trader_id),
+            None => TraderId::from(trader_key.as_str()),
+        };
+        let database = match <Self as CacheDatabase>::new(trader_id, UUID4::new(), config) {
+            Ok(database) => database,
+            Err(e) => {
+                eprintln!("{e}");
+                return;
+            }
+        };
+
+        // Continue to receive and handle messages until channel is hung up
+        while let Ok(command) = rx.recv() {
+            if let Err(e) = block_on(database.write_async(command)) {
+                eprintln!("{e}");
+            }
+        }
+    }
+}
+
+/// Returns the tables holding the given `collection` with their ID column, nested tables
+/// first so that rows can be deleted in order.
+fn collection_tables(collection: &str) -> Result<&'static [(&'static str, &'static str)]> {
+    let tables: &'static [(&str, &str)] = match collection {
+        CURRENCIES => &[(CURRENCIES, "code")],
+        INSTRUMENTS => &[(INSTRUMENTS, "id")],
+        ACCOUNTS => &[
+            (ACCOUNT_BALANCES, "account_id"),
+            (ACCOUNT_MARGINS, "account_id"),
+            (ACCOUNTS, "account_id"),
+        ],
+        ORDERS => &[
+            (ORDER_EVENTS, "client_order_id"),
+            (ORDERS, "client_order_id"),
+        ],
+        POSITIONS => &[(POSITION_FILLS, "position_id"), (POSITIONS, "id")],
+        _ => bail!("Unsupported collection '{collection}'"),
+    };
+    Ok(tables)
+}
+
+/// Returns the table listing the IDs of the given `collection`, with its ID column.
+fn collection_table(collection: &str) -> Result<(&'static str, &'static str)> {
+    let tables = collection_tables(collection)?;
+    Ok(tables[tables.len() - 1])
+}
+
+/// Deletes the rows for `id` from the given `tables`.
+async fn delete_rows(
+    tx: &mut Transaction<'_, Any>,
+    tables: &[(&str, &str)],
+    trader_key: &str,
+    id: &str,
+) -> Result<()> {
+    for (table, id_column) in tables {
+        sqlx::query(&format!(
+            "DELETE FROM {table} WHERE trader_key = $1 AND {id_column} = $2"
+        ))
+        .bind(trader_key)
+        .bind(id)
+        .execute(&mut **tx)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Appends the given payload `value` to the rows for `id` in the given `collection`.
+async fn append(
+    tx: &mut Transaction<'_, Any>,
+    collection: &str,
+    trader_key: &str,
+    id: &str,
+    value: Value,
+) -> Result<()> {
+    match collection {
+        CURRENCIES => {
+            let Some(code) = value.as_str() else {
+                bail!("Invalid `payload`, expected a currency code, was {value}");
+            };
+            let currency = Currency::from_str(code)?;
+            let row = Map::from_iter([
+                ("code".to_string(), Value::from(id)),
+                ("precision".to_string(), Value::from(currency.precision)),
+                ("iso4217".to_string(), Value::from(currency.iso4217)),
+                ("name".to_string(), Value::from(currency.name.as_str())),
+                (
+                    "currency_type".to_string(),
+                    Value::from(currency.currency_type.to_string()),
+                ),
+            ]);
+            let keys = [("trader_key", Value::from(trader_key))];
+            insert_row(tx, CURRENCIES, &keys, CURRENCY_COLUMNS, &row).await
+        }
+        INSTRUMENTS => {
+            let (kind, mut instrument) = from_tagged(value)?;
+            instrument.insert("id".to_string(), Value::from(id));
+            let keys = [
+                ("trader_key", Value::from(trader_key)),
+                ("kind", Value::from(kind)),
+            ];
+            insert_row(tx, INSTRUMENTS, &keys, INSTRUMENT_COLUMNS, &instrument).await
+        }
+        ACCOUNTS => {
+            let mut state = into_object(value)?;
+            let balances = take_objects(&mut state, "balances")?;
+            let margins = take_objects(&mut state, "margins")?;
+            state.insert("account_id".to_string(), Value::from(id));
+
+            let seq = next_seq(tx, ACCOUNTS, "account_id", trader_key, id).await?;
+            let keys = [
+                ("trader_key", Value::from(trader_key)),
+                ("seq", Value::from(seq)),
+            ];
+            insert_row(tx, ACCOUNTS, &keys, ACCOUNT_COLUMNS, &state).await?;
+
+            let keys = [
+                ("trader_key", Value::from(trader_key)),
+                ("account_id", Value::from(id)),
+                ("seq", Value::from(seq)),
+            ];
+            insert_nested(
+                tx,
+                ACCOUNT_BALANCES,
+                &keys,
+                ACCOUNT_BALANCE_COLUMNS,
+                &balances,
+            )
+            .await?;
+            insert_nested(tx, ACCOUNT_MARGINS, &keys, ACCOUNT_MARGIN_COLUMNS, &margins).await
+        }
+        ORDERS => {
+            let (kind, mut event) = from_tagged(value)?;
+            event.remove("type");
+            event.insert("client_order_id".to_string(), Value::from(id));
+
+            let seq = next_seq(tx, ORDER_EVENTS, "client_order_id", trader_key, id).await?;
+            let keys = [
+                ("trader_key", Value::from(trader_key)),
+                ("seq", Value::from(seq)),
+                ("kind", Value::from(kind)),
+            ];
+            insert_row(tx, ORDER_EVENTS, &keys, ORDER_EVENT_COLUMNS, &event).await
+        }
+        POSITIONS => {
+            let mut fill = into_object(value)?;
+            // Fills are keyed by the position they belong to
+            fill.remove("type");
+            fill.remove("position_id");
+            let Some(trade_id) = fill.get("trade_id").and_then(Value::as_str) else {
+                bail!("Invalid `payload`, missing a `trade_id`");
+            };
+
+            // Each fill is stored once, however often it is written
+            let exists = sqlx::query(
+                "SELECT 1 FROM position_fills WHERE trader_key = $1 AND position_id = $2 AND trade_id = $3",
+            )
+            .bind(trader_key)
+            .bind(id)
+            .bind(trade_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+            if exists.is_some() {
+                return Ok(());
+            }
+
+            let seq = next_seq(tx, POSITION_FILLS, "position_id", trader_key, id).await?;
+            let keys = [
+                ("trader_key", Value::from(trader_key)),
+                ("position_id", Value::from(id)),
+                ("seq", Value::from(seq)),
+            ];
+            insert_row(tx, POSITION_FILLS, &keys, POSITION_FILL_COLUMNS, &fill).await
+        }
+        _ => bail!("Unsupported collection '{collection}'"),
+    }
+}
+
+/// Returns the next sequence number for `id` in the given `table`.
+async fn next_seq(
+    tx: &mut Transaction<'_, Any>,
+    table: &str,
+    id_column: &str,
+    trader_key: &str,
+    id: &str,
+) -> Result<i64> {
+    let row = sqlx::query(&format!(
+        "SELECT COALESCE(MAX(seq), 0) + 1 FROM {table} WHERE trader_key = $1 AND {id_column} = $2"
+    ))
+    .bind(trader_key)
+    .bind(id)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(row.try_get::<i64, _>(0)?)
+}
+
+/// Inserts the nested `rows` of a parent row with the given `keys`, in order.
+async fn insert_nested(
+    tx: &mut Transaction<'_, Any>,
+    table: &str,
+    keys: &[(&str, Value)],
+    columns: &[Column],
+    rows: &[Map<String, Value>],
+) -> Result<()> {
+    for (idx, row) in rows.iter().enumerate() {
+        let mut row_keys = keys.to_vec();
+        row_keys.push(("idx", Value::from(idx)));
+        insert_row(tx, table, &row_keys, columns, row).await?;
+    }
+    Ok(())
+}
+
+/// Inserts a row with the given `keys` into `table`, with the `columns` taken from the
+/// fields of `object` (which must all have a column).
+async fn insert_row(
+    tx: &mut Transaction<'_, Any>,
+    table: &str,
+    keys: &[(&str, Value)],
+    columns: &[Column],
+    object: &Map<String, Value>,
+) -> Result<()> {
+    if let Some(field) = object
+        .keys()
+        .find(|field| !columns.iter().any(|(name, _)| *name == field.as_str()))
+    {
+        bail!("Invalid `payload`, no column for field `{field}` in '{table}'");
+    }
+
+    let names = keys
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(columns.iter().map(|(name, _)| *name))
+        .collect::<Vec<_>>();
+    let placeholders = (1..=names.len())
+        .map(|i| format!("${i}"))
+        .collect::<Vec<_>>();
+    let sql = format!(
+        "INSERT INTO {table} ({}) VALUES ({})",
+        names.join(", "),
+        placeholders.join(", ")
+    );
+
+    let mut query = sqlx::query(&sql);
+    for (name, value) in keys {
+        let kind = if value.is_i64() {
+            ColumnKind::Integer
+        } else {
+            ColumnKind::Text
+        };
+        query = bind_value(query, name, kind, Some(value))?;
+    }
+    for (name, kind) in columns {
+        query = bind_value(query, name, *kind, object.get(*name))?;
+    }
+    query.execute(&mut **tx).await?;
+    Ok(())
+}
+
+/// Binds the given JSON `value` (null when missing) to the query, as a `kind` column.
+fn bind_value<'q>(
+    query: Query<'q, Any, AnyArguments<'q>>,
+    name: &str,
+    kind: ColumnKind,
+    value: Option<&Value>,
+) -> Result<Query<'q, Any, AnyArguments<'q>>> {
+    let value = value.filter(|value| !value.is_null());
+    let invalid = || anyhow!("Invalid `{name}`, expected a {kind:?} value, was {value:?}");
+    let query = match kind {
+        ColumnKind::Text => query.bind(
+            value
+                .map(|value| value.as_str().map(str::to_string).ok_or_else(invalid))
+                .transpose()?,
+        ),
+        ColumnKind::Integer => query.bind(
+            value
+                .map(|value| value.as_i64().ok_or_else(invalid))
+                .transpose()?,
+        ),
+        ColumnKind::Real => query.bind(
+            value
+                .map(|value| value.as_f64().ok_or_else(invalid))
+                .transpose()?,
+        ),
+        ColumnKind::Boolean => query.bind(
+            value
+                .map(|value| {
+                    value
+                        .as_bool()
+                        .or_else(|| value.as_u64().map(|flag| flag != 0))
+                        .ok_or_else(invalid)
+                })
+                .transpose()?,
+        ),
+        ColumnKind::Json => query.bind(value.map(serde_json::to_string).transpose()?),
+    };
+    Ok(query)
+}
+
+/// Reads the given `columns` of the `row` as the fields of a JSON object, omitting nulls.
+fn read_columns(row: &AnyRow, columns: &[Column]) -> Result<Map<String, Value>> {
+    let mut object = Map::new();
+    for (name, kind) in columns {
+        let value = match kind {
+            ColumnKind::Text => row.try_get::<Option<String>, _>(*name)?.map(Value::from),
+            ColumnKind::Integer => row.try_get::<Option<i64>, _>(*name)?.map(Value::from),
+            ColumnKind::Real => row.try_get::<Option<f64>, _>(*name)?.map(Value::from),
+            ColumnKind::Boolean => row.try_get::<Option<bool>, _>(*name)?.map(Value::from),
+            ColumnKind::Json => row
+                .try_get::<Option<String>, _>(*name)?
+                .map(|json| serde_json::from_str(&json))
+                .transpose()?,
+        };
+        if let Some(value) = value {
+            object.insert((*name).to_string(), value);
+        }
+    }
+    Ok(object)
+}
+
+/// Restores the fields of an order event which have no column of their own: the `type`
+/// of the event struct, and the reconciliation flag as an integer for events other
+/// than `OrderInitialized` and fills.
+fn restore_order_event(event: &mut Map<String, Value>) {
+    let event_type = match event.get("kind").and_then(Value::as_str) {
+        Some("OrderPartiallyFilled") => "OrderFilled".to_string(),
+        Some(kind) => kind.to_string(),
+        None => return,
+    };
+    if !matches!(event_type.as_str(), "OrderInitialized" | "OrderFilled") {
+        if let Some(reconciliation) = event.get("reconciliation").and_then(Value::as_bool) {
+            let flag = u8::from(reconciliation);
+            event.insert("reconciliation".to_string(), Value::from(flag));
+        }
+    }
+    event.insert("type".to_string(), Value::from(event_type));
+}
+
+/// Returns the kind and fields of an externally tagged enum `value`.
+fn from_tagged(value: Value) -> Result<(String, Map<String, Value>)> {
+    let Value::Object(object) = value else {
+        bail!("Invalid `payload`, expected a JSON object, was {value}");
+    };
+    let mut entries = object.into_iter();
+    match (entries.next(), entries.next()) {
+        (Some((kind, Value::Object(fields))), None) => Ok((kind, fields)),
+        _ => bail!("Invalid `payload`, expected a single tagged object"),
+    }
+}
+
+/// Wraps the fields of `object` in its `kind`, as an externally tagged enum value.
+fn into_tagged(mut object: Map<String, Value>) -> Result<Value> {
+    let Some(Value::String(kind)) = object.remove("kind") else {
+        bail!("Missing `kind` column");
+    };
+    Ok(Value::Object(Map::from_iter([(
+        kind,
+        Value::Object(object),
+    )])))
+}
+
+fn into_object(value: Value) -> Result<Map<String, Value>> {
+    match value {
+        Value::Object(object) => Ok(object),
+        value => bail!("Invalid `payload`, expected a JSON object, was {value}"),
+    }
+}
+
+/// Removes the nested array `field` from `object`, returning its objects.
+fn take_objects(object: &mut Map<String, Value>, field: &str) -> Result<Vec<Map<String, Value>>> {
+    match object.remove(field) {
+        Some(Value::Array(values)) => values.into_iter().map(into_object).collect(),
+        Some(Value::Null) | None => Ok(Vec::new()),
+        Some(value) => bail!("Invalid `{field}`, expected an array, was {value}"),
+    }
+}
+
+fn take_seq(object: &mut Map<String, Value>) -> Result<i64> {
+    object
+        .remove("seq")
+        .and_then(|seq| seq.as_i64())
+        .ok_or_else(|| anyhow!("Missing `seq` column"))
+}
+
+/// Groups the given nested rows by the sequence number of their parent row.
+fn group_by_seq(rows: Vec<Map<String, Value>>) -> Result<HashMap<i64, Vec<Value>>> {
+    let mut groups: HashMap<i64, Vec<Value>> = HashMap::new();
+    for mut row in rows {
+        let seq = take_seq(&mut row)?;
+        groups.entry(seq).or_default().push(Value::Object(row));
+    }
+    Ok(groups)
+}
+
+fn split_key(key: &str) -> Result<(&str, &str)> {
+    key.split_once(DELIMITER)
+        .ok_or_else(|| anyhow!("Invalid `key`, missing a '{DELIMITER}' delimiter, was {key}"))
+}
+
+/// Returns whether `text` matches the glob `pattern` (supporting `*` and `?`, as for Redis keys).
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -62,33 +874,98 @@ impl SqlCacheDatabase {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_model::identifiers::stubs::trader_id;
+    use nautilus_accounting::account::any::AccountAny;
+    use nautilus_infrastructure::cache::memory::{Cache, CacheConfig};
+    use nautilus_model::{
+        events::{
+            account::stubs::cash_account_state_million_usd,
+            order::{
+                accepted::OrderAcceptedBuilder, event::OrderEvent,
+                initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            },
+        },
+        identifiers::{client_order_id::ClientOrderId, stubs::trader_id},
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::any::OrderAny,
+        position::Position,
+        stubs::test_position_long,
+    };
+    use rstest::rstest;
+    use serde_json::json;
+    use tempfile::{tempdir, TempDir};
 
     use crate::db::{
-        database::{init_db_schema, setup_test_database},
+        database::{init_db_schema, Database, DatabaseEngine},
         sql::SqlCacheDatabase,
     };
 
-    async fn setup_sql_cache_database() -> SqlCacheDatabase {
-        let db = setup_test_database().await;
-        let schema_dir = "../../schema";
-        init_db_schema(&db, schema_dir)
+    use super::*;
+
+    const SCHEMA_DIR: &str = "../../schema";
+
+    fn database_url(dir: &TempDir) -> String {
+        format!(
+            "sqlite:{}?mode=rwc",
+            dir.path().join("test_db.sqlite").display()
+        )
+    }
+
+    async fn setup_sql_cache_database(dir: &TempDir) -> SqlCacheDatabase {
+        let db = Database::new(Some(DatabaseEngine::SQLITE), Some(&database_url(dir))).await;
+        init_db_schema(&db, SCHEMA_DIR)
             .await
             .expect("Failed to init db schema");
         let trader = trader_id();
         SqlCacheDatabase::new(trader, db)
     }
 
+    fn cache_database(trader_id: &str, dir: &TempDir) -> SqlCacheDatabase {
+        let config = HashMap::from([
+            ("database_engine".to_string(), json!("sqlite")),
+            ("database_url".to_string(), json!(database_url(dir))),
+        ]);
+        let database = <SqlCacheDatabase as CacheDatabase>::new(
+            TraderId::from(trader_id),
+            UUID4::new(),
+            config,
+        )
+        .unwrap();
+        block_on(init_db_schema(&database.db, SCHEMA_DIR)).expect("Failed to init db schema");
+        database
+    }
+
+    fn payload(values: &[&str]) -> Option<Vec<Vec<u8>>> {
+        Some(values.iter().map(|v| v.as_bytes().to_vec()).collect())
+    }
+
+    fn order_payload(event: &str, ts_event: u64) -> String {
+        json!({
+            event: {
+                "trader_id": "TRADER-001",
+                "strategy_id": "S-001",
+                "instrument_id": "AUD/USD.SIM",
+                "client_order_id": "O-123456789",
+                "account_id": "SIM-001",
+                "event_id": "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+                "ts_event": ts_event,
+                "ts_init": ts_event,
+            }
+        })
+        .to_string()
+    }
+
     #[tokio::test]
     async fn test_keys() {
-        let cache = setup_sql_cache_database().await;
+        let dir = tempdir().unwrap();
+        let cache = setup_sql_cache_database(&dir).await;
         assert_eq!(cache.key_trader(), "trader-TRADER-001");
         assert_eq!(cache.key_general(), "trader-TRADER-001:general:");
     }
 
     #[tokio::test]
     async fn test_add_get_general() {
-        let cache = setup_sql_cache_database().await;
+        let dir = tempdir().unwrap();
+        let cache = setup_sql_cache_database(&dir).await;
         cache
             .add(String::from("key1"), String::from("value1"))
             .await
@@ -99,4 +976,172 @@ mod tests {
         assert_eq!(item.key, "key1");
         assert_eq!(item.value, "value1");
     }
+
+    #[rstest]
+    fn test_insert_and_read_currency() {
+        let dir = tempdir().unwrap();
+        let mut cache = cache_database("TRADER-002", &dir);
+        let key = "currencies:AUD".to_string();
+
+        cache.insert(key.clone(), payload(&["\"AUD\""])).unwrap();
+
+        assert_eq!(cache.read(&key).unwrap(), payload(&["\"AUD\""]).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_insert_and_read_within_runtime() {
+        let dir = tempdir().unwrap();
+        let mut cache = cache_database("TRADER-002", &dir);
+        let key = "currencies:USD".to_string();
+
+        cache.insert(key.clone(), payload(&["\"USD\""])).unwrap();
+
+        assert_eq!(cache.read(&key).unwrap(), payload(&["\"USD\""]).unwrap());
+    }
+
+    #[rstest]
+    fn test_insert_and_update_order_events() {
+        let dir = tempdir().unwrap();
+        let mut cache = cache_database("TRADER-003", &dir);
+        let key = "orders:O-123456789".to_string();
+        let submitted = order_payload("OrderSubmitted", 1);
+        let accepted = order_payload("OrderAccepted", 2);
+
+        cache.insert(key.clone(), payload(&[&submitted])).unwrap();
+        cache.update(key.clone(), payload(&[&accepted])).unwrap();
+
+        let events = cache.read(&key).unwrap();
+        assert_eq!(events.len(), 2);
+        let accepted: Value = serde_json::from_slice(&events[1]).unwrap();
+        assert_eq!(accepted["OrderAccepted"]["ts_event"], json!(2));
+        assert_eq!(accepted["OrderAccepted"]["account_id"], json!("SIM-001"));
+    }
+
+    #[rstest]
+    fn test_insert_with_unknown_field() {
+        let dir = tempdir().unwrap();
+        let mut cache = cache_database("TRADER-003", &dir);
+
+        let result = cache.insert(
+            "orders:O-123456789".to_string(),
+            payload(&[r#"{"OrderSubmitted": {"unknown": 1}}"#]),
+        );
+
+        assert!(result.is_err());
+        assert!(cache.read("orders:O-123456789").unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_update_when_key_does_not_exist() {
+        let dir = tempdir().unwrap();
+        let mut cache = cache_database("TRADER-004", &dir);
+
+        let result = cache.update("positions:P-001".to_string(), payload(&["{}"]));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_position_fills_are_stored_once(test_position_long: Position) {
+        let dir = tempdir().unwrap();
+        let mut cache = cache_database("TRADER-004", &dir);
+        let key = format!("positions:{}", test_position_long.id);
+        let fill = serde_json::to_string(&test_position_long.events[0]).unwrap();
+
+        cache.insert(key.clone(), payload(&[&fill])).unwrap();
+        cache.update(key.clone(), payload(&[&fill])).unwrap();
+
+        let fills = cache.read(&key).unwrap();
+        assert_eq!(fills.len(), 1);
+        let fill: Value = serde_json::from_slice(&fills[0]).unwrap();
+        assert_eq!(fill["type"], json!("OrderFilled"));
+        assert_eq!(
+            fill["trade_id"],
+            json!(test_position_long.events[0].trade_id)
+        );
+    }
+
+    #[rstest]
+    fn test_keys_with_pattern_and_delete() {
+        let dir = tempdir().unwrap();
+        let mut cache = cache_database("TRADER-005", &dir);
+        let account = serde_json::to_string(&cash_account_state_million_usd()).unwrap();
+        cache
+            .insert("accounts:SIM-001".to_string(), payload(&[&account]))
+            .unwrap();
+        cache
+            .insert("currencies:USD".to_string(), payload(&["\"USD\""]))
+            .unwrap();
+
+        assert_eq!(
+            cache.keys("*accounts:*").unwrap(),
+            vec!["trader-TRADER-005:accounts:SIM-001"]
+        );
+
+        cache.delete("accounts:SIM-001".to_string(), None).unwrap();
+
+        assert!(cache.keys("*accounts:*").unwrap().is_empty());
+        assert!(cache.read("accounts:SIM-001").unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_cache_write_through_and_load(audusd_sim: CurrencyPair, test_position_long: Position) {
+        let dir = tempdir().unwrap();
+        let database = cache_database("TRADER-006", &dir);
+        let mut cache = Cache::new(CacheConfig::default(), Some(Box::new(database))).unwrap();
+        let account = AccountAny::from_events(vec![cash_account_state_million_usd()]).unwrap();
+        let init = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from("O-123456789"))
+            .instrument_id(audusd_sim.id)
+            .build()
+            .unwrap();
+        let mut order = OrderAny::from(init);
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(order.client_order_id())
+            .strategy_id(order.strategy_id())
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .client_order_id(order.client_order_id())
+            .strategy_id(order.strategy_id())
+            .build()
+            .unwrap();
+
+        cache
+            .add_instrument(InstrumentAny::from(audusd_sim))
+            .unwrap();
+        cache.add_account(account).unwrap();
+        cache.add_order(order.clone(), None).unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        cache.update_order(order.clone()).unwrap();
+        cache.add_position(test_position_long.clone()).unwrap();
+        cache.update_position(test_position_long.clone()).unwrap();
+
+        let database = cache_database("TRADER-006", &dir);
+        let mut loaded = Cache::new(CacheConfig::default(), Some(Box::new(database))).unwrap();
+        loaded.load().unwrap();
+
+        let instrument = loaded.instrument(&audusd_sim.id).unwrap();
+        assert_eq!(instrument.price_increment(), audusd_sim.price_increment);
+        let account = loaded
+            .account(&cash_account_state_million_usd().account_id)
+            .unwrap();
+        assert_eq!(account.events().len(), 1);
+        let loaded_order = loaded.order(&ClientOrderId::from("O-123456789")).unwrap();
+        assert_eq!(loaded_order.events(), order.events());
+        let position = loaded.position(&test_position_long.id).unwrap();
+        assert_eq!(position.quantity, test_position_long.quantity);
+        assert_eq!(position.events, test_position_long.events);
+    }
+
+    #[rstest]
+    #[case("*", "trader-T:orders:O-1", true)]
+    #[case("*orders:*", "trader-T:orders:O-1", true)]
+    #[case("*orders:?-1", "trader-T:orders:O-1", true)]
+    #[case("*positions:*", "trader-T:orders:O-1", false)]
+    #[case("trader-T:orders", "trader-T:orders:O-1", false)]
+    fn test_glob_match(#[case] pattern: &str, #[case] text: &str, #[case] expected: bool) {
+        assert_eq!(glob_match(pattern, text), expected);
+    }
 }
@@ -0,0 +1,273 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An event-sourced audit journal of order events, position events, account states and
+//! trading commands.
+//!
+//! Entries are appended to a directory of numbered segment files, each holding a sequence
+//! of records laid out as:
+//!
+//! | Field      | Size     | Description                                     |
+//! |------------|----------|-------------------------------------------------|
+//! | `len`      | 4 bytes  | Length of the payload (little endian)           |
+//! | `checksum` | 4 bytes  | CRC32 of `ts_init` and the payload              |
+//! | `ts_init`  | 8 bytes  | Initialization UNIX timestamp (nanoseconds)     |
+//! | `payload`  | `len`    | The `MsgPack` encoded [`JournalEntry`]          |
+//!
+//! A record torn by a crash mid-write can only be incomplete at the end of the last
+//! segment, which is truncated when the journal is next opened for writing. Any record
+//! failing its checksum is corrupt, and is reported as an error.
+
+pub mod reader;
+pub mod replay;
+pub mod writer;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use nautilus_execution::messages::TradingCommand;
+use nautilus_model::events::{
+    account::state::AccountState,
+    order::{event::OrderEvent, filled::OrderFilled},
+    position::PositionEvent,
+};
+use serde::{Deserialize, Serialize};
+
+/// The file extension of journal segments.
+pub const SEGMENT_EXTENSION: &str = "journal";
+
+/// The length of a record header (payload length, checksum and timestamp).
+pub const RECORD_HEADER_LEN: usize = 16;
+
+/// An entry of the audit journal.
+///
+/// Position events are journaled with the fill applied to the position for the event,
+/// carrying the position ID and the quantity and commission actually applied (a fill
+/// flipping a position is applied as a closing then an opening fill).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum JournalEntry {
+    OrderEvent(OrderEvent),
+    PositionEvent {
+        event: PositionEvent,
+        fill: OrderFilled,
+    },
+    AccountState(AccountState),
+    Command(TradingCommand),
+}
+
+impl JournalEntry {
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::OrderEvent(event) => event.ts_init(),
+            Self::PositionEvent { event, .. } => event.ts_init(),
+            Self::AccountState(event) => event.ts_init,
+            Self::Command(command) => command.ts_init(),
+        }
+    }
+}
+
+impl Serializable for JournalEntry {}
+
+impl From<OrderEvent> for JournalEntry {
+    fn from(event: OrderEvent) -> Self {
+        Self::OrderEvent(event)
+    }
+}
+
+impl From<(PositionEvent, OrderFilled)> for JournalEntry {
+    fn from((event, fill): (PositionEvent, OrderFilled)) -> Self {
+        Self::PositionEvent { event, fill }
+    }
+}
+
+impl From<AccountState> for JournalEntry {
+    fn from(event: AccountState) -> Self {
+        Self::AccountState(event)
+    }
+}
+
+impl From<TradingCommand> for JournalEntry {
+    fn from(command: TradingCommand) -> Self {
+        Self::Command(command)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum JournalError {
+    #[error("I/O error: {0}")]
+    IoError(#[from] io::Error),
+    #[error("Error encoding journal entry: {0}")]
+    EncodeError(#[from] rmp_serde::encode::Error),
+    #[error("Error decoding journal entry in {segment} at offset {offset}: {source}")]
+    DecodeError {
+        segment: PathBuf,
+        offset: usize,
+        source: rmp_serde::decode::Error,
+    },
+    #[error("Corrupt journal record in {segment} at offset {offset}")]
+    CorruptRecord { segment: PathBuf, offset: usize },
+}
+
+/// The result of reading a single record from a segment.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum RecordRead<'a> {
+    /// A valid record, with the offset of the next record.
+    Record {
+        ts_init: UnixNanos,
+        payload: &'a [u8],
+        next: usize,
+    },
+    /// The end of the segment.
+    End,
+    /// A record extending past the end of the segment.
+    Incomplete,
+    /// A record failing its checksum.
+    Corrupt,
+}
+
+/// Encodes the given `entry` as a record.
+pub(crate) fn encode_record(entry: &JournalEntry) -> Result<Vec<u8>, JournalError> {
+    let payload = entry.as_msgpack_bytes()?;
+    let ts_bytes = entry.ts_init().to_le_bytes();
+
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(&ts_bytes);
+    hasher.update(&payload);
+
+    let mut record = Vec::with_capacity(RECORD_HEADER_LEN + payload.len());
+    record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    record.extend_from_slice(&hasher.finalize().to_le_bytes());
+    record.extend_from_slice(&ts_bytes);
+    record.extend_from_slice(&payload);
+    Ok(record)
+}
+
+/// Reads the record starting at `offset` in the given segment `data`.
+pub(crate) fn read_record(data: &[u8], offset: usize) -> RecordRead<'_> {
+    if offset == data.len() {
+        return RecordRead::End;
+    }
+
+    let Some(header) = data.get(offset..offset + RECORD_HEADER_LEN) else {
+        return RecordRead::Incomplete;
+    };
+    let len = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+    let checksum = u32::from_le_bytes(header[4..8].try_into().unwrap());
+    let ts_bytes = &header[8..16];
+
+    let start = offset + RECORD_HEADER_LEN;
+    let Some(payload) = data.get(start..start + len) else {
+        return RecordRead::Incomplete;
+    };
+
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(ts_bytes);
+    hasher.update(payload);
+    if hasher.finalize() != checksum {
+        return RecordRead::Corrupt;
+    }
+
+    RecordRead::Record {
+        ts_init: u64::from_le_bytes(ts_bytes.try_into().unwrap()),
+        payload,
+        next: start + len,
+    }
+}
+
+/// Returns the path of the segment with the given `index` in the journal directory.
+#[must_use]
+pub fn segment_path(dir: &Path, index: u64) -> PathBuf {
+    dir.join(format!("{index:010}.{SEGMENT_EXTENSION}"))
+}
+
+/// Returns the indices and paths of the segments in the journal directory, in order.
+pub fn list_segments(dir: &Path) -> io::Result<Vec<(u64, PathBuf)>> {
+    let mut segments = Vec::new();
+    for dir_entry in fs::read_dir(dir)? {
+        let path = dir_entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(SEGMENT_EXTENSION) {
+            continue;
+        }
+        if let Some(index) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u64>().ok())
+        {
+            segments.push((index, path));
+        }
+    }
+    segments.sort_unstable_by_key(|(index, _)| *index);
+    Ok(segments)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::account::stubs::cash_account_state;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_record_round_trip() {
+        let entry = JournalEntry::from(cash_account_state());
+        let record = encode_record(&entry).unwrap();
+
+        let RecordRead::Record {
+            ts_init,
+            payload,
+            next,
+        } = read_record(&record, 0)
+        else {
+            panic!("Expected a valid record")
+        };
+        let decoded = JournalEntry::from_msgpack_bytes(payload.to_vec()).unwrap();
+
+        assert_eq!(ts_init, entry.ts_init());
+        assert_eq!(next, record.len());
+        assert!(
+            matches!(decoded, JournalEntry::AccountState(state) if state == cash_account_state())
+        );
+        assert_eq!(read_record(&record, next), RecordRead::End);
+    }
+
+    #[rstest]
+    fn test_read_record_when_corrupt() {
+        let entry = JournalEntry::from(cash_account_state());
+        let mut record = encode_record(&entry).unwrap();
+        let last = record.len() - 1;
+        record[last] ^= 0xFF;
+
+        assert_eq!(read_record(&record, 0), RecordRead::Corrupt);
+    }
+
+    #[rstest]
+    fn test_read_record_when_incomplete() {
+        let entry = JournalEntry::from(cash_account_state());
+        let record = encode_record(&entry).unwrap();
+
+        assert_eq!(read_record(&record[..10], 0), RecordRead::Incomplete);
+        assert_eq!(
+            read_record(&record[..record.len() - 1], 0),
+            RecordRead::Incomplete
+        );
+    }
+}
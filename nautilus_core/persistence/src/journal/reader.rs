@@ -0,0 +1,237 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+
+use super::{list_segments, read_record, JournalEntry, JournalError, RecordRead};
+
+/// Reads the entries of an audit journal, verifying the checksum of each record.
+#[derive(Debug)]
+pub struct JournalReader {
+    segments: Vec<PathBuf>,
+}
+
+impl JournalReader {
+    /// Opens the journal in the given directory for reading.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, JournalError> {
+        let segments = list_segments(dir.as_ref())?
+            .into_iter()
+            .map(|(_, path)| path)
+            .collect();
+        Ok(Self { segments })
+    }
+
+    /// Returns the paths of the journal segments, in order.
+    #[must_use]
+    pub fn segments(&self) -> &[PathBuf] {
+        &self.segments
+    }
+
+    /// Returns an iterator over the journal entries, in the order they were appended.
+    ///
+    /// A corrupt record (failing its checksum) ends the iteration with an error, as does an
+    /// incomplete record, unless it is at the end of the last segment (where it was torn by
+    /// a crash, and is ignored).
+    #[must_use]
+    pub fn entries(&self) -> JournalEntries<'_> {
+        JournalEntries {
+            segments: &self.segments,
+            segment: 0,
+            data: None,
+            offset: 0,
+        }
+    }
+
+    /// Reads all the journal entries.
+    pub fn read_all(&self) -> Result<Vec<JournalEntry>, JournalError> {
+        self.entries()
+            .map(|result| result.map(|(_, entry)| entry))
+            .collect()
+    }
+}
+
+/// An iterator over the entries of an audit journal, with their `ts_init` timestamps.
+#[derive(Debug)]
+pub struct JournalEntries<'a> {
+    segments: &'a [PathBuf],
+    segment: usize,
+    data: Option<Vec<u8>>,
+    offset: usize,
+}
+
+impl Iterator for JournalEntries<'_> {
+    type Item = Result<(UnixNanos, JournalEntry), JournalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let path = self.segments.get(self.segment)?;
+            if self.data.is_none() {
+                match fs::read(path) {
+                    Ok(data) => self.data = Some(data),
+                    Err(e) => {
+                        self.segment = self.segments.len();
+                        return Some(Err(e.into()));
+                    }
+                }
+            }
+
+            let offset = self.offset;
+            match read_record(self.data.as_deref().unwrap_or_default(), offset) {
+                RecordRead::Record {
+                    ts_init,
+                    payload,
+                    next,
+                } => {
+                    self.offset = next;
+                    let result = JournalEntry::from_msgpack_bytes(payload.to_vec())
+                        .map(|entry| (ts_init, entry))
+                        .map_err(|source| JournalError::DecodeError {
+                            segment: path.clone(),
+                            offset,
+                            source,
+                        });
+                    return Some(result);
+                }
+                RecordRead::End => {
+                    self.segment += 1;
+                    self.data = None;
+                    self.offset = 0;
+                }
+                RecordRead::Incomplete if self.segment == self.segments.len() - 1 => {
+                    self.segment = self.segments.len();
+                    return None;
+                }
+                RecordRead::Incomplete | RecordRead::Corrupt => {
+                    self.segment = self.segments.len();
+                    return Some(Err(JournalError::CorruptRecord {
+                        segment: path.clone(),
+                        offset,
+                    }));
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{fs::OpenOptions, io::Write};
+
+    use nautilus_model::events::account::stubs::{cash_account_state, margin_account_state};
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::journal::{
+        encode_record, segment_path,
+        writer::{JournalConfig, JournalWriter},
+    };
+
+    fn write_journal(dir: &Path, max_segment_bytes: u64) {
+        let config = JournalConfig {
+            max_segment_bytes,
+            sync_on_append: false,
+        };
+        let mut writer = JournalWriter::open(dir, config).unwrap();
+        writer.append(cash_account_state()).unwrap();
+        writer.append(margin_account_state()).unwrap();
+        writer.flush().unwrap();
+    }
+
+    #[rstest]
+    fn test_read_entries_in_order() {
+        let dir = tempdir().unwrap();
+        write_journal(dir.path(), 1);
+
+        let reader = JournalReader::open(dir.path()).unwrap();
+        let entries = reader.read_all().unwrap();
+
+        assert_eq!(reader.segments().len(), 2);
+        assert!(
+            matches!(&entries[0], JournalEntry::AccountState(state) if *state == cash_account_state())
+        );
+        assert!(
+            matches!(&entries[1], JournalEntry::AccountState(state) if *state == margin_account_state())
+        );
+    }
+
+    #[rstest]
+    fn test_read_empty_journal() {
+        let dir = tempdir().unwrap();
+
+        let reader = JournalReader::open(dir.path()).unwrap();
+
+        assert!(reader.read_all().unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_read_ignores_torn_record_at_end() {
+        let dir = tempdir().unwrap();
+        write_journal(dir.path(), u64::MAX);
+        let record = encode_record(&cash_account_state().into()).unwrap();
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(segment_path(dir.path(), 0))
+            .unwrap();
+        file.write_all(&record[..record.len() - 1]).unwrap();
+
+        let entries = JournalReader::open(dir.path()).unwrap().read_all().unwrap();
+
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[rstest]
+    fn test_read_when_checksum_mismatch() {
+        let dir = tempdir().unwrap();
+        write_journal(dir.path(), 1);
+        let path = segment_path(dir.path(), 0);
+        let mut data = fs::read(&path).unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 0xFF;
+        fs::write(&path, data).unwrap();
+
+        let result = JournalReader::open(dir.path()).unwrap().read_all();
+
+        assert!(matches!(
+            result,
+            Err(JournalError::CorruptRecord { segment, offset: 0 }) if segment == path
+        ));
+    }
+
+    #[rstest]
+    fn test_read_when_checksum_mismatch_in_last_segment() {
+        let dir = tempdir().unwrap();
+        write_journal(dir.path(), u64::MAX);
+        let path = segment_path(dir.path(), 0);
+        let mut data = fs::read(&path).unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 0xFF;
+        fs::write(&path, data).unwrap();
+
+        let result = JournalReader::open(dir.path()).unwrap().read_all();
+
+        assert!(
+            matches!(result, Err(JournalError::CorruptRecord { segment, .. }) if segment == path)
+        );
+    }
+}
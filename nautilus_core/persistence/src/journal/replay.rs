@@ -0,0 +1,365 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::IndexMap;
+use nautilus_accounting::account::any::AccountAny;
+use nautilus_core::time::UnixNanos;
+use nautilus_execution::messages::TradingCommand;
+use nautilus_model::{
+    events::{
+        account::state::AccountState,
+        order::{event::OrderEvent, filled::OrderFilled},
+        position::PositionEvent,
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId,
+    },
+    instruments::any::InstrumentAny,
+    orders::any::OrderAny,
+    position::Position,
+};
+
+use super::{reader::JournalReader, JournalEntry};
+
+/// The execution state reconstructed by replaying an audit journal.
+#[derive(Debug, Default)]
+pub struct JournalState {
+    pub orders: IndexMap<ClientOrderId, OrderAny>,
+    pub positions: IndexMap<PositionId, Position>,
+    pub accounts: IndexMap<AccountId, AccountAny>,
+    pub commands: Vec<TradingCommand>,
+}
+
+/// Replays the audit journal in the given directory, reconstructing the orders, positions
+/// and accounts as they were at `until` (or at the end of the journal when `None`).
+///
+/// Entries with a `ts_init` after `until` are skipped. Positions are rebuilt by applying
+/// the fill journaled with each position event, as it was originally applied (including
+/// fills split by a position flip).
+pub fn replay_journal(
+    dir: impl AsRef<Path>,
+    instruments: &HashMap<InstrumentId, InstrumentAny>,
+    until: Option<UnixNanos>,
+) -> Result<JournalState> {
+    let reader = JournalReader::open(dir)?;
+    let mut replay = JournalReplay::new(instruments);
+
+    for result in reader.entries() {
+        let (ts_init, entry) = result?;
+        if until.is_some_and(|until| ts_init > until) {
+            continue;
+        }
+        replay.apply(entry)?;
+    }
+
+    replay.finish()
+}
+
+struct JournalReplay<'a> {
+    instruments: &'a HashMap<InstrumentId, InstrumentAny>,
+    order_events: IndexMap<ClientOrderId, Vec<OrderEvent>>,
+    account_events: IndexMap<AccountId, Vec<AccountState>>,
+    state: JournalState,
+}
+
+impl<'a> JournalReplay<'a> {
+    fn new(instruments: &'a HashMap<InstrumentId, InstrumentAny>) -> Self {
+        Self {
+            instruments,
+            order_events: IndexMap::new(),
+            account_events: IndexMap::new(),
+            state: JournalState::default(),
+        }
+    }
+
+    fn apply(&mut self, entry: JournalEntry) -> Result<()> {
+        match entry {
+            JournalEntry::OrderEvent(event) => {
+                self.order_events
+                    .entry(event.client_order_id())
+                    .or_default()
+                    .push(event);
+            }
+            JournalEntry::PositionEvent { event, fill } => {
+                self.apply_position_event(&event, fill)?;
+            }
+            JournalEntry::AccountState(event) => {
+                self.account_events
+                    .entry(event.account_id)
+                    .or_default()
+                    .push(event);
+            }
+            JournalEntry::Command(command) => self.state.commands.push(command),
+        }
+        Ok(())
+    }
+
+    fn apply_position_event(&mut self, event: &PositionEvent, fill: OrderFilled) -> Result<()> {
+        let position_id = event.position_id();
+        if fill.position_id != Some(position_id) {
+            bail!(
+                "Fill {} journaled with position {position_id} event was for {:?}",
+                fill.trade_id,
+                fill.position_id
+            );
+        }
+
+        match (event, self.state.positions.get_mut(&position_id)) {
+            // Reopens a closed position
+            (PositionEvent::PositionOpened(_), Some(position)) => position.apply(&fill),
+            (PositionEvent::PositionOpened(_), None) => {
+                let instrument = self
+                    .instruments
+                    .get(&fill.instrument_id)
+                    .ok_or_else(|| anyhow!("Instrument {} not found", fill.instrument_id))?;
+                let position = Position::new(&**instrument, fill)?;
+                self.state.positions.insert(position_id, position);
+            }
+            (_, Some(position)) => position.apply(&fill),
+            (_, None) => bail!("Position {position_id} changed before being opened"),
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<JournalState> {
+        for (client_order_id, events) in self.order_events {
+            let order = OrderAny::from_events(events)
+                .map_err(|e| anyhow!("Error replaying order {client_order_id}: {e}"))?;
+            self.state.orders.insert(client_order_id, order);
+        }
+        for (account_id, events) in self.account_events {
+            let account = AccountAny::from_events(events)
+                .map_err(|e| anyhow!("Error replaying account {account_id}: {e}"))?;
+            self.state.accounts.insert(account_id, account);
+        }
+        Ok(self.state)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_execution::messages::cancel_all::CancelAllOrders;
+    use nautilus_model::{
+        enums::{OrderSide, OrderStatus, PositionSide},
+        events::{
+            account::stubs::cash_account_state,
+            order::{
+                accepted::OrderAcceptedBuilder, filled::OrderFilledBuilder,
+                initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            },
+            position::{changed::PositionChanged, closed::PositionClosed, opened::PositionOpened},
+        },
+        identifiers::{client_id::ClientId, trade_id::TradeId},
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::journal::writer::{JournalConfig, JournalWriter};
+
+    fn order_events(
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        side: OrderSide,
+        ts: UnixNanos,
+    ) -> Vec<OrderEvent> {
+        let client_order_id = ClientOrderId::from(client_order_id);
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(client_order_id)
+            .order_side(side)
+            .quantity(Quantity::from("200000"))
+            .ts_init(ts)
+            .build()
+            .unwrap();
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(client_order_id)
+            .ts_init(ts)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .client_order_id(client_order_id)
+            .ts_init(ts)
+            .build()
+            .unwrap();
+        vec![
+            OrderEvent::OrderInitialized(init),
+            OrderEvent::OrderSubmitted(submitted),
+            OrderEvent::OrderAccepted(accepted),
+        ]
+    }
+
+    fn fill(
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        side: OrderSide,
+        last_qty: &str,
+        ts: UnixNanos,
+    ) -> OrderFilled {
+        OrderFilledBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .trade_id(TradeId::from(format!("T-{ts}").as_str()))
+            .order_side(side)
+            .last_qty(Quantity::from(last_qty))
+            .last_px(Price::from("1.00000"))
+            .ts_event(ts)
+            .ts_init(ts)
+            .build()
+            .unwrap()
+    }
+
+    fn instruments(instrument: CurrencyPair) -> HashMap<InstrumentId, InstrumentAny> {
+        HashMap::from([(instrument.id, InstrumentAny::from(instrument))])
+    }
+
+    /// Writes a journal where a long position is opened at 1, reduced at 2, then
+    /// flipped short at 3 (splitting the fill into a closing and an opening fill).
+    fn write_journal(dir: &Path, instrument: &CurrencyPair) {
+        let mut writer = JournalWriter::open(dir, JournalConfig::default()).unwrap();
+        let position_id = PositionId::from("P-1");
+        writer.append(cash_account_state()).unwrap();
+
+        let command = CancelAllOrders::new(
+            Default::default(),
+            ClientId::from("SIM"),
+            Default::default(),
+            instrument.id,
+            OrderSide::NoOrderSide,
+            UUID4::new(),
+            1,
+        )
+        .unwrap();
+        writer
+            .append(TradingCommand::CancelAllOrders(command))
+            .unwrap();
+
+        for (client_order_id, side) in [("O-1", OrderSide::Buy), ("O-2", OrderSide::Sell)] {
+            for event in order_events(instrument.id, client_order_id, side, 1) {
+                writer.append(event).unwrap();
+            }
+        }
+
+        let fill1 = fill(instrument.id, "O-1", OrderSide::Buy, "100000", 1);
+        writer.append(OrderEvent::OrderFilled(fill1)).unwrap();
+        let mut applied = fill1;
+        applied.position_id = Some(position_id);
+        let mut position = Position::new(instrument, applied).unwrap();
+        let opened = PositionOpened::create(&position, &applied, 1);
+        writer
+            .append((PositionEvent::PositionOpened(opened), applied))
+            .unwrap();
+
+        let fill2 = fill(instrument.id, "O-2", OrderSide::Sell, "40000", 2);
+        writer
+            .append(OrderEvent::OrderPartiallyFilled(fill2))
+            .unwrap();
+        let mut applied = fill2;
+        applied.position_id = Some(position_id);
+        position.apply(&applied);
+        let changed = PositionChanged::create(&position, &applied, 2);
+        writer
+            .append((PositionEvent::PositionChanged(changed), applied))
+            .unwrap();
+
+        let fill3 = fill(instrument.id, "O-2", OrderSide::Sell, "160000", 3);
+        writer.append(OrderEvent::OrderFilled(fill3)).unwrap();
+        let mut close = fill3;
+        close.position_id = Some(position_id);
+        close.last_qty = Quantity::from("60000");
+        position.apply(&close);
+        let closed = PositionClosed::create(&position, &close, 3);
+        writer
+            .append((PositionEvent::PositionClosed(closed), close))
+            .unwrap();
+        let mut open = close;
+        open.last_qty = Quantity::from("100000");
+        position.apply(&open);
+        let opened = PositionOpened::create(&position, &open, 3);
+        writer
+            .append((PositionEvent::PositionOpened(opened), open))
+            .unwrap();
+    }
+
+    #[rstest]
+    fn test_replay_full_journal(audusd_sim: CurrencyPair) {
+        let dir = tempdir().unwrap();
+        write_journal(dir.path(), &audusd_sim);
+
+        let state = replay_journal(dir.path(), &instruments(audusd_sim), None).unwrap();
+
+        assert_eq!(state.commands.len(), 1);
+        assert_eq!(state.accounts.len(), 1);
+        assert_eq!(state.orders.len(), 2);
+        assert_eq!(
+            state.orders[&ClientOrderId::from("O-2")].status(),
+            OrderStatus::Filled
+        );
+        let position = &state.positions[&PositionId::from("P-1")];
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.quantity, Quantity::from("100000"));
+    }
+
+    #[rstest]
+    fn test_replay_until_timestamp(audusd_sim: CurrencyPair) {
+        let dir = tempdir().unwrap();
+        write_journal(dir.path(), &audusd_sim);
+
+        let state = replay_journal(dir.path(), &instruments(audusd_sim), Some(2)).unwrap();
+
+        assert_eq!(
+            state.orders[&ClientOrderId::from("O-2")].status(),
+            OrderStatus::PartiallyFilled
+        );
+        let position = &state.positions[&PositionId::from("P-1")];
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.quantity, Quantity::from("60000"));
+    }
+
+    #[rstest]
+    fn test_replay_when_instrument_missing(audusd_sim: CurrencyPair) {
+        let dir = tempdir().unwrap();
+        write_journal(dir.path(), &audusd_sim);
+
+        assert!(replay_journal(dir.path(), &HashMap::new(), None).is_err());
+    }
+
+    #[rstest]
+    fn test_replay_when_fill_not_for_position(audusd_sim: CurrencyPair) {
+        let dir = tempdir().unwrap();
+        let mut writer = JournalWriter::open(dir.path(), JournalConfig::default()).unwrap();
+        let mut applied = fill(audusd_sim.id, "O-1", OrderSide::Buy, "100000", 1);
+        applied.position_id = Some(PositionId::from("P-1"));
+        let position = Position::new(&audusd_sim, applied).unwrap();
+        let opened = PositionOpened::create(&position, &applied, 1);
+        applied.position_id = Some(PositionId::from("P-2"));
+        writer
+            .append((PositionEvent::PositionOpened(opened), applied))
+            .unwrap();
+        drop(writer);
+
+        assert!(replay_journal(dir.path(), &instruments(audusd_sim), None).is_err());
+    }
+}
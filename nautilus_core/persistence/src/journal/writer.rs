@@ -0,0 +1,247 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use super::{
+    encode_record, list_segments, read_record, segment_path, JournalEntry, JournalError, RecordRead,
+};
+
+/// Configuration for a [`JournalWriter`].
+#[derive(Clone, Debug)]
+pub struct JournalConfig {
+    /// The size in bytes beyond which a new segment is started.
+    pub max_segment_bytes: u64,
+    /// If each appended entry is synced to disk before returning.
+    pub sync_on_append: bool,
+}
+
+impl Default for JournalConfig {
+    fn default() -> Self {
+        Self {
+            max_segment_bytes: 64 * 1024 * 1024,
+            sync_on_append: true,
+        }
+    }
+}
+
+/// Appends entries to an audit journal, rotating to a new segment once the current
+/// segment reaches the configured size.
+#[derive(Debug)]
+pub struct JournalWriter {
+    dir: PathBuf,
+    config: JournalConfig,
+    segment_index: u64,
+    segment_len: u64,
+    file: File,
+}
+
+impl JournalWriter {
+    /// Opens the journal in the given directory (creating it if needed), to append to
+    /// its last segment.
+    ///
+    /// An incomplete record at the end of the last segment, left by a write torn by a
+    /// crash, is truncated. Returns an error if the last segment has a corrupt record.
+    pub fn open(dir: impl AsRef<Path>, config: JournalConfig) -> Result<Self, JournalError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let (segment_index, segment_len, file) = match list_segments(&dir)?.pop() {
+            Some((index, path)) => {
+                let valid_len = recover_segment(&path)?;
+                (index, valid_len, open_segment(&path)?)
+            }
+            None => (0, 0, open_segment(&segment_path(&dir, 0))?),
+        };
+
+        Ok(Self {
+            dir,
+            config,
+            segment_index,
+            segment_len,
+            file,
+        })
+    }
+
+    /// Returns the directory of the journal.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the index of the segment currently being appended to.
+    #[must_use]
+    pub fn segment_index(&self) -> u64 {
+        self.segment_index
+    }
+
+    /// Appends the given `entry` to the journal.
+    pub fn append(&mut self, entry: impl Into<JournalEntry>) -> Result<(), JournalError> {
+        let record = encode_record(&entry.into())?;
+        if self.segment_len > 0
+            && self.segment_len + record.len() as u64 > self.config.max_segment_bytes
+        {
+            self.rotate()?;
+        }
+
+        self.file.write_all(&record)?;
+        self.segment_len += record.len() as u64;
+
+        if self.config.sync_on_append {
+            self.file.sync_data()?;
+        }
+        Ok(())
+    }
+
+    /// Syncs all appended entries to disk.
+    pub fn flush(&mut self) -> Result<(), JournalError> {
+        self.file.sync_data()?;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<(), JournalError> {
+        self.file.sync_all()?;
+        self.segment_index += 1;
+        self.segment_len = 0;
+        self.file = open_segment(&segment_path(&self.dir, self.segment_index))?;
+        Ok(())
+    }
+}
+
+fn open_segment(path: &Path) -> Result<File, JournalError> {
+    Ok(OpenOptions::new().create(true).append(true).open(path)?)
+}
+
+/// Truncates an incomplete record at the end of the segment at `path` (torn by a crash),
+/// returning its new length.
+///
+/// Returns an error for a corrupt record, which is left for inspection.
+fn recover_segment(path: &Path) -> Result<u64, JournalError> {
+    let data = fs::read(path)?;
+    let mut offset = 0;
+    loop {
+        match read_record(&data, offset) {
+            RecordRead::Record { next, .. } => offset = next,
+            RecordRead::End => return Ok(offset as u64),
+            RecordRead::Incomplete => break,
+            RecordRead::Corrupt => {
+                return Err(JournalError::CorruptRecord {
+                    segment: path.to_path_buf(),
+                    offset,
+                })
+            }
+        }
+    }
+
+    let file = OpenOptions::new().write(true).open(path)?;
+    file.set_len(offset as u64)?;
+    file.sync_all()?;
+    Ok(offset as u64)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::account::stubs::cash_account_state;
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::journal::{reader::JournalReader, RECORD_HEADER_LEN};
+
+    #[rstest]
+    fn test_append_rotates_segments() {
+        let dir = tempdir().unwrap();
+        let config = JournalConfig {
+            max_segment_bytes: 1,
+            sync_on_append: false,
+        };
+        let mut writer = JournalWriter::open(dir.path(), config).unwrap();
+
+        for _ in 0..3 {
+            writer.append(cash_account_state()).unwrap();
+        }
+        writer.flush().unwrap();
+
+        assert_eq!(writer.segment_index(), 2);
+        assert_eq!(list_segments(dir.path()).unwrap().len(), 3);
+        assert_eq!(
+            JournalReader::open(dir.path())
+                .unwrap()
+                .read_all()
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[rstest]
+    fn test_open_truncates_torn_record() {
+        let dir = tempdir().unwrap();
+        let mut writer = JournalWriter::open(dir.path(), JournalConfig::default()).unwrap();
+        writer.append(cash_account_state()).unwrap();
+        drop(writer);
+
+        // Simulate a crash part way through appending a record
+        let path = segment_path(dir.path(), 0);
+        let valid_len = fs::metadata(&path).unwrap().len();
+        let record = encode_record(&cash_account_state().into()).unwrap();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&record[..record.len() / 2]).unwrap();
+
+        let mut writer = JournalWriter::open(dir.path(), JournalConfig::default()).unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().len(), valid_len);
+
+        writer.append(cash_account_state()).unwrap();
+        assert_eq!(
+            JournalReader::open(dir.path())
+                .unwrap()
+                .read_all()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[rstest]
+    fn test_open_when_corrupt_record() {
+        let dir = tempdir().unwrap();
+        let mut writer = JournalWriter::open(dir.path(), JournalConfig::default()).unwrap();
+        writer.append(cash_account_state()).unwrap();
+        writer.append(cash_account_state()).unwrap();
+        drop(writer);
+
+        // Corrupt the payload of the first record
+        let path = segment_path(dir.path(), 0);
+        let mut data = fs::read(&path).unwrap();
+        let len = data.len();
+        data[RECORD_HEADER_LEN] ^= 0xFF;
+        fs::write(&path, data).unwrap();
+
+        let result = JournalWriter::open(dir.path(), JournalConfig::default());
+
+        assert!(matches!(
+            result,
+            Err(JournalError::CorruptRecord { offset: 0, .. })
+        ));
+        assert_eq!(fs::metadata(&path).unwrap().len(), len as u64);
+    }
+}
@@ -16,6 +16,7 @@
 pub mod arrow;
 pub mod backend;
 pub mod db;
+pub mod journal;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -16,6 +16,7 @@
 use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err};
 use nautilus_model::data::{
     bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
+    Data,
 };
 use pyo3::{prelude::*, types::PyCapsule};
 
@@ -97,16 +98,27 @@ impl DataQueryResult {
     }
 
     /// Each iteration returns a chunk of values read from the parquet file.
+    ///
+    /// Custom data has no representation in Python, so is filtered out of the chunks.
     fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<PyObject>> {
-        match slf.next() {
-            Some(acc) if !acc.is_empty() => {
-                let cvec = slf.set_chunk(acc);
-                Python::with_gil(|py| match PyCapsule::new::<CVec>(py, cvec, None) {
-                    Ok(capsule) => Ok(Some(capsule.into_py(py))),
-                    Err(err) => Err(to_pyruntime_err(err)),
-                })
+        while let Some(acc) = slf.next() {
+            if acc.is_empty() {
+                break;
+            }
+            let acc: Vec<Data> = acc
+                .into_iter()
+                .filter(|data| !matches!(data, Data::Custom(_)))
+                .collect();
+            if acc.is_empty() {
+                continue;
             }
-            _ => Ok(None),
+
+            let cvec = slf.set_chunk(acc);
+            return Python::with_gil(|py| match PyCapsule::new::<CVec>(py, cvec, None) {
+                Ok(capsule) => Ok(Some(capsule.into_py(py))),
+                Err(err) => Err(to_pyruntime_err(err)),
+            });
         }
+        Ok(None)
     }
 }
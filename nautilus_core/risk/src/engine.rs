@@ -0,0 +1,880 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_accounting::account::{any::AccountAny, margin::MarginAccount, Account};
+use nautilus_core::{time::AtomicTime, uuid::UUID4};
+use nautilus_execution::messages::{modify::ModifyOrder, submit::SubmitOrder, TradingCommand};
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::{InstrumentClass, OrderSide, TradingState},
+    events::order::{
+        denied::OrderDenied, event::OrderEvent, filled::OrderFilled,
+        modify_rejected::OrderModifyRejected,
+    },
+    identifiers::{instrument_id::InstrumentId, venue::Venue},
+    instruments::Instrument,
+    orders::any::OrderAny,
+    types::{price::Price, quantity::Quantity},
+};
+use rust_decimal::prelude::ToPrimitive;
+use ustr::Ustr;
+
+use crate::throttler::{OrderThrottler, ThrottleAction, ThrottleConfig};
+
+/// Configuration for a [`RiskEngine`].
+#[derive(Clone, Debug, Default)]
+pub struct RiskEngineConfig {
+    /// If all pre-trade risk checks are bypassed.
+    pub bypass: bool,
+    /// The maximum notional value of a single order, for each instrument.
+    pub max_notional_per_order: HashMap<InstrumentId, f64>,
+    /// The order submit and modify rate limits.
+    pub throttle: ThrottleConfig,
+}
+
+/// Provides a pre-trade risk engine, which validates order commands before they are
+/// sent on for execution.
+///
+/// Orders are checked against the instrument's price and size increments and limits,
+/// the configured maximum notional per order, the free balance of cash accounts, and
+/// the current [`TradingState`]. Orders failing a check are denied with an
+/// [`OrderDenied`] event, and modifications with an [`OrderModifyRejected`] event.
+///
+/// Submit and modify commands passing the checks are then rate limited by an
+/// [`OrderThrottler`], and are either denied or queued when a limit is exceeded.
+pub struct RiskEngine {
+    pub config: RiskEngineConfig,
+    clock: &'static AtomicTime,
+    trading_state: TradingState,
+    instruments: HashMap<InstrumentId, Box<dyn Instrument>>,
+    accounts: HashMap<Ustr, AccountAny>,
+    last_quotes: HashMap<InstrumentId, QuoteTick>,
+    last_trades: HashMap<InstrumentId, Price>,
+    net_positions: HashMap<InstrumentId, f64>,
+    throttler: OrderThrottler,
+    queued: VecDeque<TradingCommand>,
+}
+
+impl RiskEngine {
+    /// Initializes a new [`RiskEngine`] instance.
+    #[must_use]
+    pub fn new(config: RiskEngineConfig, clock: &'static AtomicTime) -> Self {
+        let throttler = OrderThrottler::new(&config.throttle);
+        Self {
+            config,
+            clock,
+            trading_state: TradingState::Active,
+            instruments: HashMap::new(),
+            accounts: HashMap::new(),
+            last_quotes: HashMap::new(),
+            last_trades: HashMap::new(),
+            net_positions: HashMap::new(),
+            throttler,
+            queued: VecDeque::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    pub fn set_trading_state(&mut self, state: TradingState) {
+        self.trading_state = state;
+    }
+
+    pub fn add_instrument(&mut self, instrument: Box<dyn Instrument>) {
+        self.instruments.insert(instrument.id(), instrument);
+    }
+
+    /// Adds the given `account`, replacing any account from the same issuer.
+    pub fn add_account(&mut self, account: AccountAny) {
+        self.accounts.insert(account.id().get_issuer(), account);
+    }
+
+    /// Returns the account for the given `venue` (if found).
+    #[must_use]
+    pub fn get_account(&self, venue: &Venue) -> Option<&AccountAny> {
+        self.accounts.get(&venue.value)
+    }
+
+    pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
+        self.last_quotes.insert(quote.instrument_id, *quote);
+    }
+
+    pub fn process_trade_tick(&mut self, trade: &TradeTick) {
+        self.last_trades.insert(trade.instrument_id, trade.price);
+    }
+
+    /// Updates the net position for the filled instrument, used for the `REDUCING` trading state.
+    pub fn process_order_filled(&mut self, fill: &OrderFilled) {
+        let qty = match fill.order_side {
+            OrderSide::Buy => fill.last_qty.as_f64(),
+            _ => -fill.last_qty.as_f64(),
+        };
+        *self.net_positions.entry(fill.instrument_id).or_default() += qty;
+    }
+
+    /// Executes the pre-trade risk checks and rate limits for the given `command`.
+    ///
+    /// Returns the command when it passes, or `None` when it was queued by the
+    /// throttler (see [`RiskEngine::next_queued`]). Otherwise returns the order
+    /// event denying the order or rejecting the modification.
+    pub fn execute(
+        &mut self,
+        command: TradingCommand,
+    ) -> Result<Option<TradingCommand>, OrderEvent> {
+        if self.config.bypass {
+            return Ok(Some(command));
+        }
+
+        let result = match &command {
+            TradingCommand::SubmitOrder(submit) => self.check_submit_order(submit),
+            TradingCommand::ModifyOrder(modify) => self.check_modify_order(modify),
+            // Cancels always reduce risk, so are permitted in any trading state
+            TradingCommand::CancelOrder(_) | TradingCommand::CancelAllOrders(_) => {
+                return Ok(Some(command));
+            }
+        };
+        if let Err(reason) = result {
+            return Err(self.command_denied(&command, &reason));
+        }
+
+        // Queued commands are released in order, so later commands queue behind them
+        if self.throttler.action == ThrottleAction::Queue && !self.queued.is_empty() {
+            self.queued.push_back(command);
+            return Ok(None);
+        }
+
+        if let Err(reason) = self.throttler.check(&command) {
+            return match self.throttler.action {
+                ThrottleAction::Deny => Err(self.command_denied(&command, &reason)),
+                ThrottleAction::Queue => {
+                    self.queued.push_back(command);
+                    Ok(None)
+                }
+            };
+        }
+
+        Ok(Some(command))
+    }
+
+    /// Returns the number of commands queued by the throttler.
+    #[must_use]
+    pub fn queued_count(&self) -> usize {
+        self.queued.len()
+    }
+
+    /// Returns the next command queued by the throttler, waiting until its rate
+    /// limits are ready.
+    pub async fn next_queued(&mut self) -> Option<TradingCommand> {
+        let command = self.queued.pop_front()?;
+        self.throttler.until_ready(&command).await;
+        Some(command)
+    }
+
+    // -- RISK CHECKS -----------------------------------------------------------------------------
+
+    fn check_submit_order(&self, command: &SubmitOrder) -> Result<(), String> {
+        let order = &command.order;
+        let instrument = self.get_instrument(&command.instrument_id)?;
+
+        if let Some(price) = order.price() {
+            check_price(instrument, price)?;
+        }
+        if let Some(trigger_price) = order.trigger_price() {
+            check_price(instrument, trigger_price)?;
+        }
+        check_quantity(instrument, order.quantity())?;
+        self.check_notional(instrument, order)?;
+        self.check_trading_state(order)
+    }
+
+    fn check_modify_order(&self, command: &ModifyOrder) -> Result<(), String> {
+        if self.trading_state == TradingState::Halted {
+            return Err(format!("TradingState is {}", self.trading_state));
+        }
+
+        let instrument = self.get_instrument(&command.instrument_id)?;
+        if let Some(price) = command.price {
+            check_price(instrument, price)?;
+        }
+        if let Some(trigger_price) = command.trigger_price {
+            check_price(instrument, trigger_price)?;
+        }
+        if let Some(quantity) = command.quantity {
+            check_quantity(instrument, quantity)?;
+        }
+        Ok(())
+    }
+
+    fn get_instrument(&self, instrument_id: &InstrumentId) -> Result<&dyn Instrument, String> {
+        self.instruments
+            .get(instrument_id)
+            .map(AsRef::as_ref)
+            .ok_or_else(|| format!("Instrument for {instrument_id} not found"))
+    }
+
+    fn check_notional(&self, instrument: &dyn Instrument, order: &OrderAny) -> Result<(), String> {
+        let side = order.side();
+        let quantity = order.quantity();
+        let last_px = order
+            .price()
+            .or_else(|| order.trigger_price())
+            .or_else(|| self.market_price(&instrument.id(), side))
+            .ok_or_else(|| {
+                format!(
+                    "No market price to calculate notional for {}",
+                    instrument.id()
+                )
+            })?;
+
+        let notional = instrument.calculate_notional_value(quantity, last_px, Some(true));
+        if let Some(max_notional) = self.config.max_notional_per_order.get(&instrument.id()) {
+            if notional.as_f64() > *max_notional {
+                return Err(format!(
+                    "NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional={max_notional}, notional={notional}"
+                ));
+            }
+        }
+
+        let account = match self.get_account(&instrument.id().venue) {
+            Some(AccountAny::Margin(account)) => {
+                return check_margin(account, instrument, quantity, last_px);
+            }
+            Some(account) => account,
+            None => return Ok(()),
+        };
+
+        let (required, currency) = match side {
+            OrderSide::Buy => {
+                let cost = instrument.calculate_notional_value(quantity, last_px, None);
+                (cost.as_f64(), cost.currency)
+            }
+            _ => match instrument.base_currency() {
+                Some(base_currency) if !instrument.is_inverse() => (
+                    quantity.as_f64() * instrument.multiplier().as_f64(),
+                    base_currency,
+                ),
+                _ => return Ok(()),
+            },
+        };
+
+        match account.balance_free(Some(currency)) {
+            Some(free) if required > free.as_f64() => Err(format!(
+                "NOTIONAL_EXCEEDS_FREE_BALANCE: free={free}, notional={required} {currency}"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_trading_state(&self, order: &OrderAny) -> Result<(), String> {
+        match self.trading_state {
+            TradingState::Active => Ok(()),
+            TradingState::Halted => Err(format!("TradingState is {}", self.trading_state)),
+            TradingState::Reducing => {
+                let instrument_id = order.instrument_id();
+                let net_position = self
+                    .net_positions
+                    .get(&instrument_id)
+                    .copied()
+                    .unwrap_or_default();
+                match order.side() {
+                    OrderSide::Buy if net_position > 0.0 => Err(format!(
+                        "BUY when TradingState is REDUCING and LONG {instrument_id}"
+                    )),
+                    OrderSide::Sell if net_position < 0.0 => Err(format!(
+                        "SELL when TradingState is REDUCING and SHORT {instrument_id}"
+                    )),
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+
+    fn market_price(&self, instrument_id: &InstrumentId, side: OrderSide) -> Option<Price> {
+        let quote_px = self.last_quotes.get(instrument_id).map(|quote| match side {
+            OrderSide::Buy => quote.ask_price,
+            _ => quote.bid_price,
+        });
+        quote_px.or_else(|| self.last_trades.get(instrument_id).copied())
+    }
+
+    // -- EVENT GENERATION ------------------------------------------------------------------------
+
+    fn command_denied(&self, command: &TradingCommand, reason: &str) -> OrderEvent {
+        match command {
+            TradingCommand::SubmitOrder(submit) => self.order_denied(&submit.order, reason),
+            TradingCommand::ModifyOrder(modify) => self.modify_rejected(modify, reason),
+            TradingCommand::CancelOrder(_) | TradingCommand::CancelAllOrders(_) => {
+                unreachable!("Cancel commands are never denied")
+            }
+        }
+    }
+
+    fn order_denied(&self, order: &OrderAny, reason: &str) -> OrderEvent {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        )
+        .unwrap();
+        OrderEvent::OrderDenied(event)
+    }
+
+    fn modify_rejected(&self, command: &ModifyOrder, reason: &str) -> OrderEvent {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderModifyRejected::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            command.venue_order_id,
+            None,
+        )
+        .unwrap();
+        OrderEvent::OrderModifyRejected(event)
+    }
+}
+
+/// Checks the initial margin required for an order, at the account leverage for the
+/// instrument and including taker fees for the round trip, against the free balance.
+fn check_margin(
+    account: &MarginAccount,
+    instrument: &dyn Instrument,
+    quantity: Quantity,
+    last_px: Price,
+) -> Result<(), String> {
+    let notional = instrument.calculate_notional_value(quantity, last_px, None);
+    let leverage = account.get_leverage(&instrument.id());
+    let adjusted_notional = notional.as_f64() / leverage;
+    let mut required = adjusted_notional * instrument.margin_init().to_f64().unwrap_or_default();
+    required += adjusted_notional * instrument.taker_fee().to_f64().unwrap_or_default() * 2.0;
+    let currency = notional.currency;
+
+    match account.balance_free(Some(currency)) {
+        Some(free) if required > free.as_f64() => Err(format!(
+            "MARGIN_INIT_EXCEEDS_FREE_BALANCE: free={free}, margin_init={required} {currency}"
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_price(instrument: &dyn Instrument, price: Price) -> Result<(), String> {
+    let price_precision = instrument.price_precision();
+    if price.precision > price_precision {
+        return Err(format!(
+            "price {price} invalid (precision {} > {price_precision})",
+            price.precision
+        ));
+    }
+    if instrument.instrument_class() != InstrumentClass::Option && price.raw <= 0 {
+        return Err(format!("price {price} invalid (<= 0)"));
+    }
+
+    let price_increment = instrument.price_increment();
+    if price_increment.raw > 0 && price.raw % price_increment.raw != 0 {
+        return Err(format!(
+            "price {price} invalid (not a multiple of price increment {price_increment})"
+        ));
+    }
+    if let Some(max_price) = instrument.max_price() {
+        if price > max_price {
+            return Err(format!(
+                "price {price} invalid (> maximum price of {max_price})"
+            ));
+        }
+    }
+    if let Some(min_price) = instrument.min_price() {
+        if price < min_price {
+            return Err(format!(
+                "price {price} invalid (< minimum price of {min_price})"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn check_quantity(instrument: &dyn Instrument, quantity: Quantity) -> Result<(), String> {
+    let size_precision = instrument.size_precision();
+    if quantity.precision > size_precision {
+        return Err(format!(
+            "quantity {quantity} invalid (precision {} > {size_precision})",
+            quantity.precision
+        ));
+    }
+
+    let size_increment = instrument.size_increment();
+    if size_increment.raw > 0 && quantity.raw % size_increment.raw != 0 {
+        return Err(format!(
+            "quantity {quantity} invalid (not a multiple of size increment {size_increment})"
+        ));
+    }
+    if let Some(max_quantity) = instrument.max_quantity() {
+        if quantity > max_quantity {
+            return Err(format!(
+                "quantity {quantity} invalid (> maximum trade size of {max_quantity})"
+            ));
+        }
+    }
+    if let Some(min_quantity) = instrument.min_quantity() {
+        if quantity < min_quantity {
+            return Err(format!(
+                "quantity {quantity} invalid (< minimum trade size of {min_quantity})"
+            ));
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU32, time::Duration};
+
+    use nautilus_accounting::account::cash::CashAccount;
+    use nautilus_execution::messages::cancel::CancelOrder;
+    use nautilus_model::{
+        enums::{AccountType, OrderType, TimeInForce},
+        events::{
+            account::{state::AccountState, stubs::cash_account_state_million_usd},
+            order::{filled::OrderFilledBuilder, initialized::OrderInitializedBuilder},
+        },
+        identifiers::{
+            account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+            strategy_id::StrategyId, trader_id::TraderId,
+        },
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        types::{balance::AccountBalance, currency::Currency, money::Money},
+    };
+    use nautilus_network::ratelimiter::quota::Quota;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn time() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, 0)))
+    }
+
+    fn risk_engine(
+        config: RiskEngineConfig,
+        instrument: CurrencyPair,
+        time: &'static AtomicTime,
+    ) -> RiskEngine {
+        let mut engine = RiskEngine::new(config, time);
+        engine.add_instrument(Box::new(instrument));
+        let account = CashAccount::new(cash_account_state_million_usd(), true).unwrap();
+        engine.add_account(AccountAny::from(account));
+        engine
+    }
+
+    fn margin_account(free: &str) -> AccountAny {
+        let state = AccountState::new(
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            vec![
+                AccountBalance::new(Money::from(free), Money::from("0 USD"), Money::from(free))
+                    .unwrap(),
+            ],
+            vec![],
+            true,
+            UUID4::new(),
+            0,
+            0,
+            Some(Currency::USD()),
+        )
+        .unwrap();
+        AccountAny::from(MarginAccount::new(state, true).unwrap())
+    }
+
+    fn submit(
+        instrument_id: InstrumentId,
+        order_type: OrderType,
+        side: OrderSide,
+        quantity: &str,
+        price: Option<&str>,
+    ) -> TradingCommand {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from("O-1"))
+            .order_type(order_type)
+            .order_side(side)
+            .quantity(Quantity::from(quantity))
+            .time_in_force(TimeInForce::Gtc)
+            .price(price.map(Price::from))
+            .build()
+            .unwrap();
+        let order = OrderAny::from(init);
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            instrument_id,
+            order,
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        TradingCommand::SubmitOrder(command)
+    }
+
+    fn denied_reason(result: Result<Option<TradingCommand>, OrderEvent>) -> String {
+        match result {
+            Err(OrderEvent::OrderDenied(denied)) => denied.reason.to_string(),
+            other => panic!("Expected `OrderDenied`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_submit_valid_limit_order(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "100000",
+            Some("1.00000"),
+        );
+
+        assert!(engine.execute(command).is_ok());
+    }
+
+    #[rstest]
+    fn test_submit_with_invalid_price_precision(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "100000",
+            Some("1.000001"),
+        );
+
+        let reason = denied_reason(engine.execute(command));
+
+        assert_eq!(reason, "price 1.000001 invalid (precision 6 > 5)");
+    }
+
+    #[rstest]
+    fn test_submit_with_quantity_above_maximum(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Sell,
+            "2000000",
+            Some("1.00000"),
+        );
+
+        let reason = denied_reason(engine.execute(command));
+
+        assert!(reason.contains("> maximum trade size of"));
+    }
+
+    #[rstest]
+    fn test_submit_exceeding_max_notional_per_order(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let config = RiskEngineConfig {
+            max_notional_per_order: HashMap::from([(audusd_sim.id, 50_000.0)]),
+            ..Default::default()
+        };
+        let mut engine = risk_engine(config, audusd_sim, time);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Sell,
+            "100000",
+            Some("1.00000"),
+        );
+
+        let reason = denied_reason(engine.execute(command));
+
+        assert!(reason.starts_with("NOTIONAL_EXCEEDS_MAX_PER_ORDER"));
+    }
+
+    #[rstest]
+    fn test_submit_exceeding_free_balance(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "1000000",
+            Some("1.10000"),
+        );
+
+        let reason = denied_reason(engine.execute(command));
+
+        assert!(reason.starts_with("NOTIONAL_EXCEEDS_FREE_BALANCE"));
+    }
+
+    #[rstest]
+    fn test_submit_exceeding_free_margin(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        engine.add_account(margin_account("10000 USD"));
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "1000000",
+            Some("1.10000"),
+        );
+
+        let reason = denied_reason(engine.execute(command));
+
+        assert!(reason.starts_with("MARGIN_INIT_EXCEEDS_FREE_BALANCE"));
+    }
+
+    #[rstest]
+    fn test_submit_within_free_margin(audusd_sim: CurrencyPair, time: &'static AtomicTime) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        engine.add_account(margin_account("10000 USD"));
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "100000",
+            Some("1.10000"),
+        );
+
+        assert!(engine.execute(command).is_ok());
+    }
+
+    #[rstest]
+    fn test_submit_market_order_without_market_price(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Market,
+            OrderSide::Buy,
+            "100000",
+            None,
+        );
+
+        let reason = denied_reason(engine.execute(command.clone()));
+        assert_eq!(
+            reason,
+            "No market price to calculate notional for AUD/USD.SIM"
+        );
+
+        let quote = QuoteTick::new(
+            audusd_sim.id,
+            Price::from("1.00000"),
+            Price::from("1.00001"),
+            Quantity::from("1000000"),
+            Quantity::from("1000000"),
+            0,
+            0,
+        )
+        .unwrap();
+        engine.process_quote_tick(&quote);
+
+        assert!(engine.execute(command).is_ok());
+    }
+
+    #[rstest]
+    fn test_halted_denies_submit_and_permits_cancel(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        engine.set_trading_state(TradingState::Halted);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "100000",
+            Some("1.00000"),
+        );
+        let cancel = CancelOrder::new(
+            command.trader_id(),
+            ClientId::from("SIM"),
+            command.strategy_id(),
+            audusd_sim.id,
+            ClientOrderId::from("O-1"),
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+
+        let reason = denied_reason(engine.execute(command));
+
+        assert_eq!(reason, "TradingState is HALTED");
+        assert!(engine.execute(TradingCommand::CancelOrder(cancel)).is_ok());
+    }
+
+    #[rstest]
+    fn test_reducing_denies_orders_increasing_position(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        let fill = OrderFilledBuilder::default()
+            .instrument_id(audusd_sim.id)
+            .order_side(OrderSide::Buy)
+            .last_qty(Quantity::from("100000"))
+            .last_px(Price::from("1.00000"))
+            .build()
+            .unwrap();
+        engine.process_order_filled(&fill);
+        engine.set_trading_state(TradingState::Reducing);
+
+        let buy = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "100000",
+            Some("1.00000"),
+        );
+        let sell = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Sell,
+            "100000",
+            Some("1.00000"),
+        );
+
+        let reason = denied_reason(engine.execute(buy));
+
+        assert_eq!(
+            reason,
+            "BUY when TradingState is REDUCING and LONG AUD/USD.SIM"
+        );
+        assert!(engine.execute(sell).is_ok());
+    }
+
+    #[rstest]
+    fn test_modify_with_invalid_price_is_rejected(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let mut engine = risk_engine(RiskEngineConfig::default(), audusd_sim, time);
+        let modify = ModifyOrder::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::default(),
+            audusd_sim.id,
+            ClientOrderId::from("O-1"),
+            None,
+            None,
+            Some(Price::from("-1.00000")),
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+
+        let result = engine.execute(TradingCommand::ModifyOrder(modify));
+
+        match result {
+            Err(OrderEvent::OrderModifyRejected(rejected)) => {
+                assert_eq!(rejected.reason.as_str(), "price -1.00000 invalid (<= 0)");
+            }
+            other => panic!("Expected `OrderModifyRejected`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_submit_exceeding_rate_limit_is_denied(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let config = RiskEngineConfig {
+            throttle: ThrottleConfig {
+                max_submit_rate_per_venue: Some(Quota::per_minute(NonZeroU32::new(1).unwrap())),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut engine = risk_engine(config, audusd_sim, time);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "100000",
+            Some("1.00000"),
+        );
+
+        assert!(matches!(engine.execute(command.clone()), Ok(Some(_))));
+        let reason = denied_reason(engine.execute(command));
+
+        assert_eq!(
+            reason,
+            "REJECTED BY THROTTLER: submit rate exceeded for venue SIM"
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_submit_exceeding_rate_limit_is_queued(
+        audusd_sim: CurrencyPair,
+        time: &'static AtomicTime,
+    ) {
+        let config = RiskEngineConfig {
+            throttle: ThrottleConfig {
+                max_submit_rate_per_strategy: Quota::with_period(Duration::from_millis(50)),
+                action: ThrottleAction::Queue,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut engine = risk_engine(config, audusd_sim, time);
+        let command = submit(
+            audusd_sim.id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            "100000",
+            Some("1.00000"),
+        );
+
+        assert!(matches!(engine.execute(command.clone()), Ok(Some(_))));
+        assert!(matches!(engine.execute(command.clone()), Ok(None)));
+        assert!(matches!(engine.execute(command), Ok(None)));
+        assert_eq!(engine.queued_count(), 2);
+
+        assert!(engine.next_queued().await.is_some());
+        assert!(engine.next_queued().await.is_some());
+        assert!(engine.next_queued().await.is_none());
+    }
+}
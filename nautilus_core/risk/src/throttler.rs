@@ -0,0 +1,274 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_execution::messages::TradingCommand;
+use nautilus_network::ratelimiter::{
+    clock::{Clock, MonotonicClock},
+    quota::Quota,
+    RateLimiter,
+};
+use ustr::Ustr;
+
+/// The action taken for an order command exceeding a rate limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThrottleAction {
+    /// The order is denied (or the modification rejected).
+    #[default]
+    Deny,
+    /// The command is queued until the rate limits are ready.
+    Queue,
+}
+
+/// Configuration for an [`OrderThrottler`].
+///
+/// Each quota applies separately to every venue or strategy, and no limit is
+/// enforced when a quota is `None`.
+#[derive(Clone, Debug, Default)]
+pub struct ThrottleConfig {
+    pub max_submit_rate_per_venue: Option<Quota>,
+    pub max_submit_rate_per_strategy: Option<Quota>,
+    pub max_modify_rate_per_venue: Option<Quota>,
+    pub max_modify_rate_per_strategy: Option<Quota>,
+    pub action: ThrottleAction,
+}
+
+/// Provides order submit and modify rate limits per venue and per strategy,
+/// using GCRA rate limiters.
+///
+/// Cancel commands are never throttled.
+pub struct OrderThrottler<C: Clock = MonotonicClock> {
+    pub action: ThrottleAction,
+    submit_per_venue: Option<RateLimiter<Ustr, C>>,
+    submit_per_strategy: Option<RateLimiter<Ustr, C>>,
+    modify_per_venue: Option<RateLimiter<Ustr, C>>,
+    modify_per_strategy: Option<RateLimiter<Ustr, C>>,
+}
+
+impl OrderThrottler {
+    /// Initializes a new [`OrderThrottler`] instance measuring time with a monotonic clock.
+    #[must_use]
+    pub fn new(config: &ThrottleConfig) -> Self {
+        Self::new_with_clock(config, MonotonicClock)
+    }
+}
+
+impl<C: Clock> OrderThrottler<C> {
+    /// Initializes a new [`OrderThrottler`] instance measuring time with the given `clock`.
+    #[must_use]
+    pub fn new_with_clock(config: &ThrottleConfig, clock: C) -> Self {
+        let limiter = |quota: Option<Quota>| {
+            quota.map(|quota| RateLimiter::new_with_clock(Some(quota), vec![], clock.clone()))
+        };
+        Self {
+            action: config.action,
+            submit_per_venue: limiter(config.max_submit_rate_per_venue),
+            submit_per_strategy: limiter(config.max_submit_rate_per_strategy),
+            modify_per_venue: limiter(config.max_modify_rate_per_venue),
+            modify_per_strategy: limiter(config.max_modify_rate_per_strategy),
+        }
+    }
+
+    /// Checks the rate limits for the given `command`, consuming a cell of each
+    /// limit only when all of them pass.
+    ///
+    /// Returns the reason for the first limit exceeded, if any.
+    pub fn check(&self, command: &TradingCommand) -> Result<(), String> {
+        let limiters = self.limiters(command);
+        for (limiter, key, name, scope) in &limiters {
+            if limiter.peek_key(key).is_err() {
+                return Err(format!(
+                    "REJECTED BY THROTTLER: {name} rate exceeded for {scope} {key}"
+                ));
+            }
+        }
+        Self::consume(&limiters);
+        Ok(())
+    }
+
+    /// Waits until the rate limits for the given `command` are ready, then
+    /// consumes a cell of each limit.
+    pub async fn until_ready(&self, command: &TradingCommand) {
+        let limiters = self.limiters(command);
+        // Waiting never consumes, so limits already ready stay ready
+        for (limiter, key, _, _) in &limiters {
+            limiter.until_key_available(key).await;
+        }
+        Self::consume(&limiters);
+    }
+
+    fn consume(limiters: &[(&RateLimiter<Ustr, C>, Ustr, &'static str, &'static str)]) {
+        for (limiter, key, _, _) in limiters {
+            // Cannot fail as every limit was ready, and commands are throttled on one thread
+            let _ = limiter.check_key(key);
+        }
+    }
+
+    fn limiters(
+        &self,
+        command: &TradingCommand,
+    ) -> Vec<(&RateLimiter<Ustr, C>, Ustr, &'static str, &'static str)> {
+        let (per_venue, per_strategy, name) = match command {
+            TradingCommand::SubmitOrder(_) => {
+                (&self.submit_per_venue, &self.submit_per_strategy, "submit")
+            }
+            TradingCommand::ModifyOrder(_) => {
+                (&self.modify_per_venue, &self.modify_per_strategy, "modify")
+            }
+            TradingCommand::CancelOrder(_) | TradingCommand::CancelAllOrders(_) => {
+                return Vec::new()
+            }
+        };
+
+        let venue = command.instrument_id().venue.value;
+        let strategy = command.strategy_id().value;
+        [
+            (per_venue, venue, "venue"),
+            (per_strategy, strategy, "strategy"),
+        ]
+        .into_iter()
+        .filter_map(|(limiter, key, scope)| {
+            limiter.as_ref().map(|limiter| (limiter, key, name, scope))
+        })
+        .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU32, time::Duration};
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_execution::messages::{cancel::CancelOrder, modify::ModifyOrder};
+    use nautilus_model::identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    };
+    use nautilus_network::ratelimiter::clock::FakeRelativeClock;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn per_second(max_burst: u32) -> Option<Quota> {
+        Some(Quota::per_second(NonZeroU32::new(max_burst).unwrap()))
+    }
+
+    fn modify(instrument_id: &str, strategy_id: &str) -> TradingCommand {
+        let command = ModifyOrder::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::from(strategy_id),
+            InstrumentId::from(instrument_id),
+            ClientOrderId::from("O-1"),
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        TradingCommand::ModifyOrder(command)
+    }
+
+    fn cancel(instrument_id: &str, strategy_id: &str) -> TradingCommand {
+        let command = CancelOrder::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::from(strategy_id),
+            InstrumentId::from(instrument_id),
+            ClientOrderId::from("O-1"),
+            None,
+            UUID4::new(),
+            0,
+        )
+        .unwrap();
+        TradingCommand::CancelOrder(command)
+    }
+
+    #[rstest]
+    fn test_rate_per_venue() {
+        let config = ThrottleConfig {
+            max_modify_rate_per_venue: per_second(2),
+            ..Default::default()
+        };
+        let clock = FakeRelativeClock::default();
+        let throttler = OrderThrottler::new_with_clock(&config, clock.clone());
+
+        assert!(throttler.check(&modify("AUD/USD.SIM", "S-001")).is_ok());
+        assert!(throttler.check(&modify("EUR/USD.SIM", "S-002")).is_ok());
+        assert_eq!(
+            throttler.check(&modify("AUD/USD.SIM", "S-003")),
+            Err("REJECTED BY THROTTLER: modify rate exceeded for venue SIM".to_string())
+        );
+        assert!(throttler
+            .check(&modify("AUD/USD.IDEALPRO", "S-001"))
+            .is_ok());
+        assert!(throttler.check(&cancel("AUD/USD.SIM", "S-001")).is_ok());
+
+        clock.advance(Duration::from_secs(1));
+
+        assert!(throttler.check(&modify("AUD/USD.SIM", "S-001")).is_ok());
+    }
+
+    #[rstest]
+    fn test_rate_per_strategy() {
+        let config = ThrottleConfig {
+            max_modify_rate_per_strategy: per_second(1),
+            ..Default::default()
+        };
+        let throttler = OrderThrottler::new_with_clock(&config, FakeRelativeClock::default());
+
+        assert!(throttler.check(&modify("AUD/USD.SIM", "S-001")).is_ok());
+        assert!(throttler.check(&modify("AUD/USD.SIM", "S-002")).is_ok());
+        assert_eq!(
+            throttler.check(&modify("EUR/USD.SIM", "S-001")),
+            Err("REJECTED BY THROTTLER: modify rate exceeded for strategy S-001".to_string())
+        );
+    }
+
+    #[rstest]
+    fn test_rejection_does_not_consume_other_limits() {
+        let config = ThrottleConfig {
+            max_modify_rate_per_venue: per_second(2),
+            max_modify_rate_per_strategy: per_second(1),
+            ..Default::default()
+        };
+        let throttler = OrderThrottler::new_with_clock(&config, FakeRelativeClock::default());
+
+        assert!(throttler.check(&modify("AUD/USD.SIM", "S-001")).is_ok());
+        for _ in 0..3 {
+            assert!(throttler.check(&modify("AUD/USD.SIM", "S-001")).is_err());
+        }
+
+        // The rejected commands did not consume the remaining venue cell
+        assert!(throttler.check(&modify("AUD/USD.SIM", "S-002")).is_ok());
+    }
+
+    #[rstest]
+    fn test_submit_rate_does_not_limit_modify() {
+        let config = ThrottleConfig {
+            max_submit_rate_per_venue: per_second(1),
+            ..Default::default()
+        };
+        let throttler = OrderThrottler::new_with_clock(&config, FakeRelativeClock::default());
+
+        for _ in 0..5 {
+            assert!(throttler.check(&modify("AUD/USD.SIM", "S-001")).is_ok());
+        }
+    }
+}
@@ -671,6 +671,12 @@ uint64_t msgbus_res_count(const struct MessageBus_API *bus);
 
 uint64_t msgbus_pub_count(const struct MessageBus_API *bus);
 
+/**
+ * Publishes the messages consumed from external streams since the last call, returning
+ * the number of messages published.
+ */
+uint64_t msgbus_process_external_messages(struct MessageBus_API *bus);
+
 /**
  * # Safety
  *
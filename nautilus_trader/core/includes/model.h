@@ -666,6 +666,8 @@ typedef enum TriggerType {
     INDEX_PRICE = 9,
 } TriggerType;
 
+typedef struct Arc_dyn_CustomDataTrait Arc_dyn_CustomDataTrait;
+
 /**
  * Represents a discrete price level in an order book.
  *
@@ -1015,6 +1017,16 @@ typedef struct Bar_t {
     uint64_t ts_init;
 } Bar_t;
 
+/**
+ * Represents user-defined data carried by the [`Data`](super::Data) enum.
+ *
+ * The data is shared behind a boxed `Arc`, keeping this a thin pointer which is cheap
+ * to clone.
+ */
+typedef struct CustomData_t {
+    struct Arc_dyn_CustomDataTrait *_0;
+} CustomData_t;
+
 typedef enum Data_t_Tag {
     DELTA,
     DELTAS,
@@ -1022,6 +1034,7 @@ typedef enum Data_t_Tag {
     QUOTE,
     TRADE,
     BAR,
+    CUSTOM,
 } Data_t_Tag;
 
 typedef struct Data_t {
@@ -1045,6 +1058,9 @@ typedef struct Data_t {
         struct {
             struct Bar_t bar;
         };
+        struct {
+            struct CustomData_t custom;
+        };
     };
 } Data_t;
 
@@ -764,6 +764,12 @@ class MovingAverageType(Enum):
     WILDER = "WILDER"
     HULL = "HULL"
 
+class VolatilityEstimator(Enum):
+    CLOSE_TO_CLOSE = "CLOSE_TO_CLOSE"
+    PARKINSON = "PARKINSON"
+    GARMAN_KLASS = "GARMAN_KLASS"
+    YANG_ZHANG = "YANG_ZHANG"
+
 
 ### Identifiers
 
@@ -2178,6 +2184,281 @@ class AroonOscillator:
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
+class MovingAverageConvergenceDivergence:
+    def __init__(
+        self,
+        fast_period: int,
+        slow_period: int,
+        signal_period: int | None = None,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def fast_period(self) -> int: ...
+    @property
+    def slow_period(self) -> int: ...
+    @property
+    def signal_period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def signal(self) -> float: ...
+    @property
+    def histogram(self) -> float: ...
+    def update_raw(self, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class Stochastics:
+    def __init__(
+        self,
+        period_k: int,
+        period_d: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period_k(self) -> int: ...
+    @property
+    def period_d(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value_k(self) -> float: ...
+    @property
+    def value_d(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class CommodityChannelIndex:
+    def __init__(
+        self,
+        period: int,
+        scalar: float | None = None,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def scalar(self) -> float: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class RateOfChange:
+    def __init__(
+        self,
+        period: int,
+        use_log: bool | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def use_log(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, price: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class WilliamsPercentR:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class DirectionalMovement:
+    def __init__(
+        self,
+        period: int,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def pos(self) -> float: ...
+    @property
+    def neg(self) -> float: ...
+    def update_raw(self, high: float, low: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class AverageDirectionalIndex:
+    def __init__(
+        self,
+        period: int,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def plus_di(self) -> float: ...
+    @property
+    def minus_di(self) -> float: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class KnowSureThing:
+    def __init__(
+        self,
+        roc_periods: list[int] | None = None,
+        ma_periods: list[int] | None = None,
+        signal_period: int | None = None,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def roc_periods(self) -> list[int]: ...
+    @property
+    def ma_periods(self) -> list[int]: ...
+    @property
+    def signal_period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def signal(self) -> float: ...
+    def update_raw(self, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class PsychologicalLine:
+    def __init__(
+        self,
+        period: int,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class Pressure:
+    def __init__(
+        self,
+        period: int,
+        ma_type: MovingAverageType | None = None,
+        atr_floor: float | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def value_cumulative(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float, volume: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
 class AverageTrueRange:
     def __init__(
         self,
@@ -2202,23 +2483,494 @@ class AverageTrueRange:
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-# Book
+class BollingerBands:
+    def __init__(
+        self,
+        period: int,
+        k: float,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def k(self) -> float: ...
+    @property
+    def upper(self) -> float: ...
+    @property
+    def middle(self) -> float: ...
+    @property
+    def lower(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
 
-class BookImbalanceRatio:
-    def __init__(self) -> None: ...
+class DonchianChannel:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
+    def period(self) -> int: ...
+    @property
     def count(self) -> int: ...
     @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
-    def value(self) -> float: ...
-    def handle_book_mbo(self, book: OrderBookMbo) -> None:...
-    def handle_book_mbp(self, book: OrderBookMbp) -> None:...
-    def update(self, best_bid: Quantity | None, best_ask: Quantity) -> None: ...
+    def upper(self) -> float: ...
+    @property
+    def middle(self) -> float: ...
+    @property
+    def lower(self) -> float: ...
+    def update_raw(self, high: float, low: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class HistoricalVolatility:
+    def __init__(
+        self,
+        period: int,
+        estimator: VolatilityEstimator | None = None,
+        annualization: float | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def annualization(self) -> float: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class KeltnerChannel:
+    def __init__(
+        self,
+        period: int,
+        k_multiplier: float,
+        ma_type: MovingAverageType | None = None,
+        ma_type_atr: MovingAverageType | None = None,
+        use_previous: bool | None = None,
+        atr_floor: float | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def k_multiplier(self) -> float: ...
+    @property
+    def upper(self) -> float: ...
+    @property
+    def middle(self) -> float: ...
+    @property
+    def lower(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class VerticalHorizontalFilter:
+    def __init__(
+        self,
+        period: int,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, close: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class VolumeWeightedAveragePrice:
+    def __init__(
+        self,
+        session_offset_ns: int | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def session_offset_ns(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, price: float, volume: float, ts: int) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class RollingVolumeWeightedAveragePrice:
+    def __init__(
+        self,
+        window_ns: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def window_ns(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, price: float, volume: float, ts: int) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class VolumeWeightedMovingAverage:
+    def __init__(
+        self,
+        period: int,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, price: float, volume: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class OnBalanceVolume:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, open: float, close: float, volume: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class KlingerVolumeOscillator:
+    def __init__(
+        self,
+        fast_period: int,
+        slow_period: int,
+        signal_period: int,
+        ma_type: MovingAverageType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def fast_period(self) -> int: ...
+    @property
+    def slow_period(self) -> int: ...
+    @property
+    def signal_period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float, volume: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class MoneyFlowIndex:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float, volume: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class AccumulationDistribution:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float, volume: float) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+# Book
+
+class BookImbalanceRatio:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book_mbo(self, book: OrderBookMbo) -> None:...
+    def handle_book_mbp(self, book: OrderBookMbp) -> None:...
+    def update(self, best_bid: Quantity | None, best_ask: Quantity) -> None: ...
+    def reset(self) -> None: ...
+
+class DepthImbalance:
+    def __init__(
+        self,
+        num_levels: int,
+        decay: float | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def num_levels(self) -> int: ...
+    @property
+    def decay(self) -> float: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, bid_sizes: list[float], ask_sizes: list[float]) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_book_mbo(self, book: OrderBookMbo) -> None: ...
+    def handle_book_mbp(self, book: OrderBookMbp) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def reset(self) -> None: ...
+
+class MicroPrice:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, bid_price: float, bid_size: float, ask_price: float, ask_size: float) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_book_mbo(self, book: OrderBookMbo) -> None: ...
+    def handle_book_mbp(self, book: OrderBookMbp) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def reset(self) -> None: ...
+
+class OrderFlowImbalance:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, bid_price: float, bid_size: float, ask_price: float, ask_size: float) -> None: ...
+    def handle_delta(self, delta: OrderBookDelta) -> None: ...
+    def handle_deltas(self, deltas: OrderBookDeltas) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_book_mbo(self, book: OrderBookMbo) -> None: ...
+    def handle_book_mbp(self, book: OrderBookMbp) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def reset(self) -> None: ...
+
+class QueueDepletion:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def bid_depletion_rate(self) -> float: ...
+    @property
+    def ask_depletion_rate(self) -> float: ...
+    @property
+    def bid_cancel_rate(self) -> float: ...
+    @property
+    def ask_cancel_rate(self) -> float: ...
+    def update_raw(
+        self,
+        bid_removed: float,
+        bid_traded: float,
+        bid_queue_size: float,
+        ask_removed: float,
+        ask_traded: float,
+        ask_queue_size: float,
+    ) -> None: ...
+    def handle_book_mbo(self, book: OrderBookMbo) -> None: ...
+    def handle_trade_tick(self, tick: TradeTick) -> None: ...
+    def reset(self) -> None: ...
+
+class SpreadAnalyzer:
+    def __init__(
+        self,
+        capacity: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def capacity(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def current(self) -> float: ...
+    @property
+    def average(self) -> float: ...
+    @property
+    def min(self) -> float: ...
+    @property
+    def max(self) -> float: ...
+    @property
+    def std(self) -> float: ...
+    def update_raw(self, bid: float, ask: float) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_book_mbo(self, book: OrderBookMbo) -> None: ...
+    def handle_book_mbp(self, book: OrderBookMbp) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
+    def reset(self) -> None: ...
+
+class WeightedMid:
+    def __init__(
+        self,
+        num_levels: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def num_levels(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, bids: list[tuple[float, float]], asks: list[tuple[float, float]]) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_book_mbo(self, book: OrderBookMbo) -> None: ...
+    def handle_book_mbp(self, book: OrderBookMbp) -> None: ...
+    def handle_quote_tick(self, tick: QuoteTick) -> None: ...
     def reset(self) -> None: ...
 
 ###################################################################################################
@@ -486,6 +486,10 @@ cdef extern from "../includes/common.h":
 
     uint64_t msgbus_pub_count(const MessageBus_API *bus);
 
+    # Publishes the messages consumed from external streams since the last call, returning
+    # the number of messages published.
+    uint64_t msgbus_process_external_messages(MessageBus_API *bus);
+
     # # Safety
     #
     # - Assumes `endpoint_ptr` is a valid C string pointer.
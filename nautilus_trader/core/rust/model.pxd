@@ -356,6 +356,9 @@ cdef extern from "../includes/model.h":
         # Based on the index price for the instrument.
         INDEX_PRICE # = 9,
 
+    cdef struct Arc_dyn_CustomDataTrait:
+        pass
+
     # Represents a discrete price level in an order book.
     #
     # The level maintains a collection of orders as well as tracking insertion order
@@ -556,6 +559,13 @@ cdef extern from "../includes/model.h":
         # The UNIX timestamp (nanoseconds) when the data object was initialized.
         uint64_t ts_init;
 
+    # Represents user-defined data carried by the [`Data`](super::Data) enum.
+    #
+    # The data is shared behind a boxed `Arc`, keeping this a thin pointer which is cheap
+    # to clone.
+    cdef struct CustomData_t:
+        Arc_dyn_CustomDataTrait *_0;
+
     cpdef enum Data_t_Tag:
         DELTA,
         DELTAS,
@@ -563,6 +573,7 @@ cdef extern from "../includes/model.h":
         QUOTE,
         TRADE,
         BAR,
+        CUSTOM,
 
     cdef struct Data_t:
         Data_t_Tag tag;
@@ -572,6 +583,7 @@ cdef extern from "../includes/model.h":
         QuoteTick_t quote;
         TradeTick_t trade;
         Bar_t bar;
+        CustomData_t custom;
 
     # Represents a valid trader ID.
     #
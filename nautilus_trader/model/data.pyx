@@ -197,6 +197,9 @@ cpdef list capsule_to_list(capsule):
             objects.append(trade_from_mem_c(ptr[i].trade))
         elif ptr[i].tag == Data_t_Tag.BAR:
             objects.append(bar_from_mem_c(ptr[i].bar))
+        elif ptr[i].tag == Data_t_Tag.CUSTOM:
+            # Custom data has no Python representation, so is skipped
+            continue
 
     return objects
 
@@ -217,6 +220,8 @@ cpdef Data capsule_to_data(capsule):
         return trade_from_mem_c(ptr.trade)
     elif ptr.tag == Data_t_Tag.BAR:
         return bar_from_mem_c(ptr.bar)
+    elif ptr.tag == Data_t_Tag.CUSTOM:
+        raise RuntimeError("Custom data cannot be converted from `PyCapsule`")
     else:
         raise RuntimeError("Invalid data element to convert from `PyCapsule`")
 
@@ -0,0 +1,182 @@
+-- Tables for the `SqlCacheDatabase`, all rows are scoped by the trader key.
+-- Accounts and orders keep every state written for them in sequence (`seq`), with their
+-- nested balances in child tables ordered by `idx`. Positions keep each of their fills
+-- once (keyed by trade ID) in sequence.
+
+CREATE TABLE IF NOT EXISTS currencies (
+    trader_key TEXT NOT NULL,
+    code TEXT NOT NULL,
+    precision BIGINT NOT NULL,
+    iso4217 BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    currency_type TEXT NOT NULL,
+    PRIMARY KEY (trader_key, code)
+);
+
+CREATE TABLE IF NOT EXISTS instruments (
+    trader_key TEXT NOT NULL,
+    id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    raw_symbol TEXT NOT NULL,
+    asset_class TEXT,
+    underlying TEXT,
+    option_kind TEXT,
+    strategy_type TEXT,
+    isin TEXT,
+    base_currency TEXT,
+    quote_currency TEXT,
+    settlement_currency TEXT,
+    currency TEXT,
+    is_inverse BOOLEAN,
+    activation_ns BIGINT,
+    expiration_ns BIGINT,
+    strike_price TEXT,
+    price_precision BIGINT NOT NULL,
+    size_precision BIGINT,
+    price_increment TEXT NOT NULL,
+    size_increment TEXT,
+    multiplier TEXT,
+    lot_size TEXT,
+    max_quantity TEXT,
+    min_quantity TEXT,
+    max_notional TEXT,
+    min_notional TEXT,
+    max_price TEXT,
+    min_price TEXT,
+    maker_fee TEXT,
+    taker_fee TEXT,
+    margin_init TEXT,
+    margin_maint TEXT,
+    ts_event BIGINT NOT NULL,
+    ts_init BIGINT NOT NULL,
+    PRIMARY KEY (trader_key, id)
+);
+
+CREATE TABLE IF NOT EXISTS accounts (
+    trader_key TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    seq BIGINT NOT NULL,
+    account_type TEXT NOT NULL,
+    base_currency TEXT,
+    is_reported BOOLEAN NOT NULL,
+    event_id TEXT NOT NULL,
+    ts_event BIGINT NOT NULL,
+    ts_init BIGINT NOT NULL,
+    PRIMARY KEY (trader_key, account_id, seq)
+);
+
+CREATE TABLE IF NOT EXISTS account_balances (
+    trader_key TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    seq BIGINT NOT NULL,
+    idx BIGINT NOT NULL,
+    currency TEXT NOT NULL,
+    total TEXT NOT NULL,
+    locked TEXT NOT NULL,
+    free TEXT NOT NULL,
+    PRIMARY KEY (trader_key, account_id, seq, idx),
+    FOREIGN KEY (trader_key, account_id, seq) REFERENCES accounts (trader_key, account_id, seq) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS account_margins (
+    trader_key TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    seq BIGINT NOT NULL,
+    idx BIGINT NOT NULL,
+    instrument_id TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    initial TEXT NOT NULL,
+    maintenance TEXT NOT NULL,
+    PRIMARY KEY (trader_key, account_id, seq, idx),
+    FOREIGN KEY (trader_key, account_id, seq) REFERENCES accounts (trader_key, account_id, seq) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS orders (
+    trader_key TEXT NOT NULL,
+    client_order_id TEXT NOT NULL,
+    PRIMARY KEY (trader_key, client_order_id)
+);
+
+CREATE TABLE IF NOT EXISTS order_events (
+    trader_key TEXT NOT NULL,
+    client_order_id TEXT NOT NULL,
+    seq BIGINT NOT NULL,
+    kind TEXT NOT NULL,
+    trader_id TEXT NOT NULL,
+    strategy_id TEXT NOT NULL,
+    instrument_id TEXT NOT NULL,
+    venue_order_id TEXT,
+    account_id TEXT,
+    trade_id TEXT,
+    position_id TEXT,
+    order_side TEXT,
+    order_type TEXT,
+    quantity TEXT,
+    price TEXT,
+    trigger_price TEXT,
+    trigger_type TEXT,
+    limit_offset TEXT,
+    trailing_offset TEXT,
+    trailing_offset_type TEXT,
+    time_in_force TEXT,
+    expire_time BIGINT,
+    display_qty TEXT,
+    emulation_trigger TEXT,
+    trigger_instrument_id TEXT,
+    contingency_type TEXT,
+    order_list_id TEXT,
+    linked_order_ids TEXT,
+    parent_order_id TEXT,
+    exec_algorithm_id TEXT,
+    exec_algorithm_params TEXT,
+    exec_spawn_id TEXT,
+    tags TEXT,
+    post_only BOOLEAN,
+    reduce_only BOOLEAN,
+    quote_quantity BOOLEAN,
+    released_price TEXT,
+    last_qty TEXT,
+    last_px TEXT,
+    currency TEXT,
+    commission TEXT,
+    liquidity_side TEXT,
+    reason TEXT,
+    event_id TEXT NOT NULL,
+    ts_event BIGINT NOT NULL,
+    ts_init BIGINT NOT NULL,
+    reconciliation BOOLEAN,
+    PRIMARY KEY (trader_key, client_order_id, seq),
+    FOREIGN KEY (trader_key, client_order_id) REFERENCES orders (trader_key, client_order_id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS positions (
+    trader_key TEXT NOT NULL,
+    id TEXT NOT NULL,
+    PRIMARY KEY (trader_key, id)
+);
+
+CREATE TABLE IF NOT EXISTS position_fills (
+    trader_key TEXT NOT NULL,
+    position_id TEXT NOT NULL,
+    seq BIGINT NOT NULL,
+    trader_id TEXT NOT NULL,
+    strategy_id TEXT NOT NULL,
+    instrument_id TEXT NOT NULL,
+    client_order_id TEXT NOT NULL,
+    venue_order_id TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    trade_id TEXT NOT NULL,
+    order_side TEXT NOT NULL,
+    order_type TEXT NOT NULL,
+    last_qty TEXT NOT NULL,
+    last_px TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    commission TEXT,
+    liquidity_side TEXT NOT NULL,
+    event_id TEXT NOT NULL,
+    ts_event BIGINT NOT NULL,
+    ts_init BIGINT NOT NULL,
+    reconciliation BOOLEAN NOT NULL,
+    PRIMARY KEY (trader_key, position_id, trade_id),
+    FOREIGN KEY (trader_key, position_id) REFERENCES positions (trader_key, id) ON DELETE CASCADE
+);